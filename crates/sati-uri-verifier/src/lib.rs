@@ -0,0 +1,216 @@
+//! Off-chain verifier for SATI registration file commitments.
+//!
+//! An agent's TokenMetadata may carry a `uri_hash` entry of the form
+//! `sha256:<hex>` or `keccak256:<hex>` committing to the raw bytes of the
+//! registration file behind `uri`. This crate fetches the file and checks the
+//! digest, so consumers can detect a silently swapped registration file.
+//!
+//! Supported locations:
+//! - local paths (`./agent.json`, `/srv/agent.json`) and `file://` URIs
+//! - plain `http://` URIs (e.g. a local stand-in server or an HTTP gateway)
+//!
+//! `https://`, `ipfs://` and `ar://` are not fetched directly; resolve them through
+//! a gateway or download the file first and use [`verify_bytes`].
+
+use std::fmt;
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::str::FromStr;
+use std::time::Duration;
+
+use sha2::{Digest, Sha256};
+use sha3::Keccak256;
+
+/// TokenMetadata key holding the commitment (mirrors `sati::constants::URI_HASH_KEY`)
+pub const URI_HASH_KEY: &str = "uri_hash";
+
+/// Timeout for HTTP connect, read and write
+const HTTP_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Digest algorithm of a `uri_hash`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HashAlgorithm {
+    Sha256,
+    Keccak256,
+}
+
+impl HashAlgorithm {
+    /// Prefix used in the `uri_hash` value
+    pub fn prefix(self) -> &'static str {
+        match self {
+            HashAlgorithm::Sha256 => "sha256",
+            HashAlgorithm::Keccak256 => "keccak256",
+        }
+    }
+
+    /// Digest `bytes` with this algorithm
+    pub fn digest(self, bytes: &[u8]) -> [u8; 32] {
+        match self {
+            HashAlgorithm::Sha256 => Sha256::digest(bytes).into(),
+            HashAlgorithm::Keccak256 => Keccak256::digest(bytes).into(),
+        }
+    }
+}
+
+/// Parsed `uri_hash` commitment
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UriHash {
+    pub algorithm: HashAlgorithm,
+    pub digest: [u8; 32],
+}
+
+impl UriHash {
+    /// Commitment to `bytes` with `algorithm` (what a registrant stores on-chain)
+    pub fn compute(algorithm: HashAlgorithm, bytes: &[u8]) -> Self {
+        Self {
+            algorithm,
+            digest: algorithm.digest(bytes),
+        }
+    }
+}
+
+impl FromStr for UriHash {
+    type Err = VerifyError;
+
+    /// Parse with the same rules the program enforces: known algorithm,
+    /// 64 lowercase hex characters.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid = || VerifyError::InvalidUriHash(value.to_string());
+        let (prefix, hex_digest) = value.split_once(':').ok_or_else(invalid)?;
+        let algorithm = match prefix {
+            "sha256" => HashAlgorithm::Sha256,
+            "keccak256" => HashAlgorithm::Keccak256,
+            _ => return Err(invalid()),
+        };
+        if hex_digest.len() != 64 || hex_digest.bytes().any(|b| b.is_ascii_uppercase()) {
+            return Err(invalid());
+        }
+        let mut digest = [0u8; 32];
+        hex::decode_to_slice(hex_digest, &mut digest).map_err(|_| invalid())?;
+        Ok(Self { algorithm, digest })
+    }
+}
+
+impl fmt::Display for UriHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}",
+            self.algorithm.prefix(),
+            hex::encode(self.digest)
+        )
+    }
+}
+
+/// Verification failure
+#[derive(Debug)]
+pub enum VerifyError {
+    /// `uri_hash` value is malformed
+    InvalidUriHash(String),
+    /// URI scheme cannot be fetched by this crate
+    UnsupportedScheme(String),
+    /// Local read or network failure
+    Io(std::io::Error),
+    /// HTTP response was not `200 OK` or could not be parsed
+    Http(String),
+    /// Fetched content does not match the commitment
+    Mismatch { expected: UriHash, actual: UriHash },
+}
+
+impl fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VerifyError::InvalidUriHash(value) => write!(f, "invalid uri_hash: {value}"),
+            VerifyError::UnsupportedScheme(uri) => write!(f, "unsupported uri scheme: {uri}"),
+            VerifyError::Io(err) => write!(f, "fetch failed: {err}"),
+            VerifyError::Http(msg) => write!(f, "http error: {msg}"),
+            VerifyError::Mismatch { expected, actual } => {
+                write!(
+                    f,
+                    "registration file hash mismatch: expected {expected}, got {actual}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for VerifyError {}
+
+impl From<std::io::Error> for VerifyError {
+    fn from(err: std::io::Error) -> Self {
+        VerifyError::Io(err)
+    }
+}
+
+/// Check `bytes` against a commitment
+pub fn verify_bytes(bytes: &[u8], expected: &UriHash) -> Result<(), VerifyError> {
+    let actual = UriHash::compute(expected.algorithm, bytes);
+    if actual != *expected {
+        return Err(VerifyError::Mismatch {
+            expected: *expected,
+            actual,
+        });
+    }
+    Ok(())
+}
+
+/// Fetch the registration file at `uri` and check it against `uri_hash`.
+/// Returns the verified bytes.
+pub fn verify(uri: &str, uri_hash: &str) -> Result<Vec<u8>, VerifyError> {
+    let expected: UriHash = uri_hash.parse()?;
+    let bytes = fetch(uri)?;
+    verify_bytes(&bytes, &expected)?;
+    Ok(bytes)
+}
+
+/// Fetch raw bytes from a local path, `file://` URI or `http://` URI
+pub fn fetch(uri: &str) -> Result<Vec<u8>, VerifyError> {
+    if let Some(path) = uri.strip_prefix("file://") {
+        return Ok(std::fs::read(path)?);
+    }
+    if let Some(rest) = uri.strip_prefix("http://") {
+        return fetch_http(rest);
+    }
+    if uri.contains("://") {
+        return Err(VerifyError::UnsupportedScheme(uri.to_string()));
+    }
+    Ok(std::fs::read(uri)?)
+}
+
+/// Minimal HTTP/1.0 GET (no redirects, no chunked encoding)
+fn fetch_http(rest: &str) -> Result<Vec<u8>, VerifyError> {
+    let (authority, path) = match rest.find('/') {
+        Some(i) => (&rest[..i], &rest[i..]),
+        None => (rest, "/"),
+    };
+    let address = if authority.contains(':') {
+        authority.to_string()
+    } else {
+        format!("{authority}:80")
+    };
+
+    let mut stream = TcpStream::connect(&address)?;
+    stream.set_read_timeout(Some(HTTP_TIMEOUT))?;
+    stream.set_write_timeout(Some(HTTP_TIMEOUT))?;
+    write!(
+        stream,
+        "GET {path} HTTP/1.0\r\nHost: {authority}\r\nConnection: close\r\n\r\n"
+    )?;
+
+    let mut response = Vec::new();
+    stream.read_to_end(&mut response)?;
+
+    let header_end = response
+        .windows(4)
+        .position(|w| w == b"\r\n\r\n")
+        .ok_or_else(|| VerifyError::Http("malformed response".to_string()))?;
+    let status_line = response[..header_end]
+        .split(|&b| b == b'\r')
+        .next()
+        .map(String::from_utf8_lossy)
+        .unwrap_or_default();
+    match status_line.split_whitespace().nth(1) {
+        Some("200") => Ok(response[header_end + 4..].to_vec()),
+        _ => Err(VerifyError::Http(status_line.into_owned())),
+    }
+}
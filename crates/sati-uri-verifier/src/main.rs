@@ -0,0 +1,25 @@
+//! `sati-uri-verifier <uri> <uri_hash>`
+//!
+//! Fetches an agent registration file and checks it against the on-chain
+//! `uri_hash` commitment. Exits non-zero on mismatch or fetch failure.
+
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let [uri, uri_hash] = args.as_slice() else {
+        eprintln!("usage: sati-uri-verifier <uri> <uri_hash>");
+        return ExitCode::from(2);
+    };
+
+    match sati_uri_verifier::verify(uri, uri_hash) {
+        Ok(bytes) => {
+            println!("ok: {uri} matches {uri_hash} ({} bytes)", bytes.len());
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
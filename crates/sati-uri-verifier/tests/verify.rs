@@ -0,0 +1,112 @@
+//! Verifier tests against a temporary file and a local stand-in HTTP server
+
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::thread;
+
+use sati_uri_verifier::{fetch, verify, HashAlgorithm, UriHash, VerifyError};
+
+const REGISTRATION: &[u8] =
+    br#"{"type":"https://eips.ethereum.org/EIPS/eip-8004#registration-v1","name":"myAgent"}"#;
+
+/// Serve `body` once with `status` on a random local port; returns the base URL
+fn serve_once(status: &'static str, body: &'static [u8]) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut request = [0u8; 1024];
+        let _ = stream.read(&mut request).unwrap();
+        write!(
+            stream,
+            "HTTP/1.0 {status}\r\nContent-Length: {}\r\n\r\n",
+            body.len()
+        )
+        .unwrap();
+        stream.write_all(body).unwrap();
+    });
+    format!("http://{address}")
+}
+
+fn temp_file(name: &str, contents: &[u8]) -> std::path::PathBuf {
+    let path =
+        std::env::temp_dir().join(format!("sati-uri-verifier-{}-{name}", std::process::id()));
+    std::fs::write(&path, contents).unwrap();
+    path
+}
+
+#[test]
+fn test_known_digests() {
+    // Empty input test vectors
+    assert_eq!(
+        UriHash::compute(HashAlgorithm::Sha256, b"").to_string(),
+        "sha256:e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+    );
+    assert_eq!(
+        UriHash::compute(HashAlgorithm::Keccak256, b"").to_string(),
+        "keccak256:c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+    );
+}
+
+#[test]
+fn test_parse_roundtrip_and_rejects_malformed() {
+    let hash = UriHash::compute(HashAlgorithm::Keccak256, REGISTRATION);
+    assert_eq!(hash.to_string().parse::<UriHash>().unwrap(), hash);
+
+    let upper = hash
+        .to_string()
+        .to_uppercase()
+        .replace("KECCAK256", "keccak256");
+    for value in ["md5:00", "sha256:zz", &upper] {
+        assert!(matches!(
+            value.parse::<UriHash>(),
+            Err(VerifyError::InvalidUriHash(_))
+        ));
+    }
+}
+
+#[test]
+fn test_verify_local_file() {
+    let path = temp_file("agent.json", REGISTRATION);
+    let commitment = UriHash::compute(HashAlgorithm::Sha256, REGISTRATION).to_string();
+
+    verify(path.to_str().unwrap(), &commitment).expect("plain path verifies");
+    verify(&format!("file://{}", path.display()), &commitment).expect("file:// verifies");
+
+    // Swapped file is detected
+    std::fs::write(&path, br#"{"name":"impostor"}"#).unwrap();
+    assert!(matches!(
+        verify(path.to_str().unwrap(), &commitment),
+        Err(VerifyError::Mismatch { .. })
+    ));
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_verify_http_stand_in_server() {
+    let commitment = UriHash::compute(HashAlgorithm::Keccak256, REGISTRATION).to_string();
+
+    let base = serve_once("200 OK", REGISTRATION);
+    let bytes = verify(&format!("{base}/agent.json"), &commitment).expect("http verifies");
+    assert_eq!(bytes, REGISTRATION);
+
+    let base = serve_once("200 OK", br#"{"name":"impostor"}"#);
+    assert!(matches!(
+        verify(&format!("{base}/agent.json"), &commitment),
+        Err(VerifyError::Mismatch { .. })
+    ));
+
+    let base = serve_once("404 Not Found", b"");
+    assert!(matches!(
+        fetch(&format!("{base}/missing.json")),
+        Err(VerifyError::Http(_))
+    ));
+}
+
+#[test]
+fn test_unsupported_scheme() {
+    assert!(matches!(
+        fetch("ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi"),
+        Err(VerifyError::UnsupportedScheme(_))
+    ));
+}
@@ -31,6 +31,7 @@ import {
   createSolanaRpcSubscriptions,
   sendAndConfirmTransactionFactory,
   address,
+  fetchEncodedAccount,
   verifySignature,
   signatureBytes,
   type Address,
@@ -43,9 +44,6 @@ import {
   fetchMint as fetchToken2022Mint,
   fetchToken as fetchToken2022Token,
   getTransferInstruction,
-  getUpdateTokenMetadataUpdateAuthorityInstruction,
-  getUpdateTokenMetadataFieldInstruction,
-  tokenMetadataField,
   type Extension,
 } from "@solana-program/token-2022";
 
@@ -54,6 +52,7 @@ import { getSetComputeUnitLimitInstruction } from "@solana-program/compute-budge
 
 import {
   getRegisterAgentInstructionAsync,
+  getUpdateAgentMetadataInstructionAsync,
   getRegisterSchemaConfigInstructionAsync,
   getCreateCompressedAttestationInstructionAsync,
   getCloseCompressedAttestationInstructionAsync,
@@ -75,6 +74,9 @@ import {
   findRegistryConfigPda,
   findSchemaConfigPda,
   findAgentIndexPda,
+  findAgentRecordPda,
+  findTreasuryPda,
+  findEvmLinkPda,
   TOKEN_2022_PROGRAM_ADDRESS,
 } from "./helpers";
 
@@ -101,7 +103,7 @@ import {
   type ReputationScoreData,
 } from "./schemas";
 
-import { SignatureMode, type StorageType } from "./generated";
+import { EvmLinkFormat, SignatureMode, type StorageType } from "./generated";
 
 import {
   type SATILightClient as LightClient,
@@ -540,6 +542,9 @@ export class Sati {
     const memberNumber = registryConfig.data.totalAgents + 1n;
     const [agentIndex] = await findAgentIndexPda(memberNumber);
 
+    // Registration fee accounts (only needed when the registry charges a fee)
+    const feeAccounts = await this.resolveRegistrationFeeAccounts(payer.address, registryConfig.data);
+
     // Build instruction
     const registerIx = await getRegisterAgentInstructionAsync({
       payer,
@@ -548,6 +553,7 @@ export class Sati {
       agentMint,
       agentTokenAccount,
       agentIndex,
+      ...feeAccounts,
       name,
       symbol: "", // Empty - vestigial field from fungible tokens
       uri,
@@ -583,6 +589,42 @@ export class Sati {
     };
   }
 
+  /**
+   * Resolve the optional fee accounts for register_agent
+   *
+   * Lamport fees are paid into the treasury PDA. SPL fees are paid from the
+   * payer's ATA into the treasury's ATA, both under the fee mint's token program.
+   */
+  private async resolveRegistrationFeeAccounts(
+    payer: Address,
+    registry: { feeMint: Address; feeAmount: bigint },
+  ): Promise<{
+    treasury?: Address;
+    feeMint?: Address;
+    payerFeeAccount?: Address;
+    treasuryFeeAccount?: Address;
+    feeTokenProgram?: Address;
+  }> {
+    if (registry.feeAmount === 0n) {
+      return {};
+    }
+
+    const [treasury] = await findTreasuryPda();
+    if (registry.feeMint === "11111111111111111111111111111111") {
+      return { treasury };
+    }
+
+    const feeMintAccount = await fetchEncodedAccount(this.rpc, registry.feeMint);
+    if (!feeMintAccount.exists) {
+      throw new Error(`Registration fee mint ${registry.feeMint} not found`);
+    }
+    const feeTokenProgram = feeMintAccount.programAddress;
+    const [payerFeeAccount] = await findAssociatedTokenAddress(registry.feeMint, payer, feeTokenProgram);
+    const [treasuryFeeAccount] = await findAssociatedTokenAddress(registry.feeMint, treasury, feeTokenProgram);
+
+    return { feeMint: registry.feeMint, payerFeeAccount, treasuryFeeAccount, feeTokenProgram };
+  }
+
   /**
    * Load agent identity from mint address
    */
@@ -779,6 +821,10 @@ export class Sati {
 
   /**
    * Transfer agent with metadata update authority
+   *
+   * @deprecated The TokenMetadata update authority is held by the registry PDA
+   * and follows the NFT holder automatically, so this is equivalent to
+   * {@link transferAgent}.
    */
   async transferAgentWithAuthority(params: {
     payer: KeyPairSigner;
@@ -786,42 +832,7 @@ export class Sati {
     mint: Address;
     newOwner: Address;
   }): Promise<{ signature: string }> {
-    const { payer, owner, mint, newOwner } = params;
-
-    const [sourceAta] = await findAssociatedTokenAddress(mint, owner.address);
-    const [destAta] = await findAssociatedTokenAddress(mint, newOwner);
-
-    const transferIx = getTransferInstruction({
-      source: sourceAta,
-      destination: destAta,
-      authority: owner,
-      amount: 1n,
-    });
-
-    const updateAuthorityIx = getUpdateTokenMetadataUpdateAuthorityInstruction({
-      metadata: mint,
-      updateAuthority: owner,
-      newUpdateAuthority: newOwner,
-    });
-
-    const { value: latestBlockhash } = await this.rpc.getLatestBlockhash().send();
-
-    const tx = pipe(
-      createTransactionMessage({ version: 0 }),
-      (msg) => setTransactionMessageFeePayer(payer.address, msg),
-      (msg) => setTransactionMessageLifetimeUsingBlockhash(latestBlockhash, msg),
-      (msg) => appendTransactionMessageInstructions([transferIx, updateAuthorityIx], msg),
-      // Attach payer signer to fee payer (needed when payer !== owner)
-      (msg) => addSignersToTransactionMessage([payer, owner], msg),
-    );
-
-    const signedTx = await signTransactionMessageWithSigners(tx);
-    await this.sendAndConfirm(signedTx as SignedBlockhashTransaction, {
-      commitment: "confirmed",
-    });
-
-    const signature = getSignatureFromTransaction(signedTx);
-    return { signature: signature.toString() };
+    return this.transferAgent(params);
   }
 
   /**
@@ -1071,55 +1082,28 @@ export class Sati {
   async updateAgentMetadata(params: UpdateAgentMetadataParams): Promise<UpdateAgentMetadataResult> {
     const { payer, owner, mint, updates } = params;
 
-    type UpdateInstruction = ReturnType<typeof getUpdateTokenMetadataFieldInstruction>;
-    const ixList: UpdateInstruction[] = [];
-
-    if (updates.name !== undefined) {
-      ixList.push(
-        getUpdateTokenMetadataFieldInstruction({
-          metadata: mint,
-          updateAuthority: owner,
-          field: tokenMetadataField("Name"),
-          value: updates.name,
-        }),
-      );
-    }
-
-    if (updates.uri !== undefined) {
-      ixList.push(
-        getUpdateTokenMetadataFieldInstruction({
-          metadata: mint,
-          updateAuthority: owner,
-          field: tokenMetadataField("Uri"),
-          value: updates.uri,
-        }),
-      );
-    }
-
-    if (updates.additionalMetadata) {
-      for (const [key, value] of updates.additionalMetadata) {
-        ixList.push(
-          getUpdateTokenMetadataFieldInstruction({
-            metadata: mint,
-            updateAuthority: owner,
-            field: tokenMetadataField("Key", [key]),
-            value,
-          }),
-        );
-      }
-    }
-
-    if (ixList.length === 0) {
+    if (updates.name === undefined && updates.uri === undefined && !updates.additionalMetadata?.length) {
       throw new Error("No updates specified");
     }
 
+    // The registry PDA holds the TokenMetadata update authority; the program
+    // checks that the owner holds the agent NFT and signs the CPI itself.
+    const updateIx = await getUpdateAgentMetadataInstructionAsync({
+      owner,
+      agentMint: mint,
+      name: updates.name ?? null,
+      uri: updates.uri ?? null,
+      additionalMetadata: (updates.additionalMetadata ?? []).map(([key, value]) => ({ key, value })),
+      removeKeys: [],
+    });
+
     const { value: latestBlockhash } = await this.rpc.getLatestBlockhash().send();
 
     const tx = pipe(
       createTransactionMessage({ version: 0 }),
       (msg) => setTransactionMessageFeePayer(payer.address, msg),
       (msg) => setTransactionMessageLifetimeUsingBlockhash(latestBlockhash, msg),
-      (msg) => appendTransactionMessageInstructions(ixList, msg),
+      (msg) => appendTransactionMessageInstruction(updateIx, msg),
       // Attach payer signer to fee payer (needed when payer !== owner)
       (msg) => addSignersToTransactionMessage([payer, owner], msg),
     );
@@ -1143,9 +1127,9 @@ export class Sati {
    *
    * @example
    * ```typescript
-   * // 1. Build the message hash
+   * // 1. Build the message hash (bound to the holder that submits the link)
    * const evmAddressBytes = hexToBytes("742d35Cc6634C0532925a3b844Bc9e7595f0bEb7");
-   * const messageHash = computeEvmLinkHash(agentMint, evmAddressBytes, "eip155:1");
+   * const messageHash = computeEvmLinkHash(agentMint, keypair.address, evmAddressBytes, "eip155:1");
    *
    * // 2. Sign with Ethereum wallet (e.g., using ethers.js or viem)
    * const signature = await wallet.signMessage(messageHash);
@@ -1174,8 +1158,21 @@ export class Sati {
     signature: Uint8Array;
     /** Recovery ID (0 or 1) */
     recoveryId: number;
+    /** Unix timestamp after which the link is treated as expired (default: never; must match the signed hash) */
+    expiresAt?: bigint;
+    /** Message format the wallet signed (default: raw digest) */
+    format?: EvmLinkFormat;
   }): Promise<LinkEvmAddressResult> {
-    const { payer, agentMint, evmAddress, chainId, signature, recoveryId } = params;
+    const {
+      payer,
+      agentMint,
+      evmAddress,
+      chainId,
+      signature,
+      recoveryId,
+      expiresAt,
+      format = EvmLinkFormat.Raw,
+    } = params;
 
     // Parse EVM address (remove 0x prefix if present)
     const evmAddressClean = evmAddress.startsWith("0x") ? evmAddress.slice(2) : evmAddress;
@@ -1191,18 +1188,24 @@ export class Sati {
       throw new Error("Recovery ID must be 0 or 1");
     }
 
-    // Find owner's ATA
+    // Find owner's ATA, the registry config (pause switch) and the link PDA
     const [ata] = await findAssociatedTokenAddress(agentMint, payer.address);
+    const [registryConfig] = await findRegistryConfigPda();
+    const [evmLink] = await findEvmLinkPda(agentMint, chainId, evmAddressBytes);
 
     // Build instruction
     const ix = getLinkEvmAddressInstruction({
       owner: payer,
+      registryConfig,
       agentMint,
       ata,
+      evmLink,
       evmAddress: evmAddressBytes,
       chainId,
       signature,
       recoveryId,
+      format,
+      expiresAt: expiresAt ?? null,
     });
 
     // Send transaction
@@ -1212,10 +1215,18 @@ export class Sati {
 
   /**
    * Build the EVM link hash that the EVM wallet should sign.
-   * This is a convenience wrapper around computeEvmLinkHash.
+   * This is a convenience wrapper around computeEvmLinkHash; `holder` is the
+   * wallet that will submit linkEvmAddress and `expiresAt` must match the value
+   * passed there.
    */
-  buildEvmLinkHash(agentMint: Address, evmAddress: Uint8Array, chainId: string): Uint8Array {
-    return computeEvmLinkHash(agentMint, evmAddress, chainId);
+  buildEvmLinkHash(
+    agentMint: Address,
+    holder: Address,
+    evmAddress: Uint8Array,
+    chainId: string,
+    expiresAt: bigint | null = null,
+  ): Uint8Array {
+    return computeEvmLinkHash(agentMint, holder, evmAddress, chainId, expiresAt);
   }
 
   // ============================================================
@@ -1416,9 +1427,12 @@ export class Sati {
       rootIndex: packedAddressTreeInfo.rootIndex,
     };
 
+    // Target agent must have an active AgentRecord
+    const [agentRecord] = await findAgentRecordPda(tokenAccount);
     const baseCreateIx = await getCreateCompressedAttestationInstructionAsync({
       payer,
       schemaConfig: schemaConfigPda,
+      agentRecord,
       agentAta, // Proves signer owns the agent NFT
       tokenProgram: TOKEN_2022_PROGRAM_ADDRESS, // Agent NFTs use Token-2022
       program: SATI_PROGRAM_ADDRESS,
@@ -1592,9 +1606,12 @@ export class Sati {
       rootIndex: packedAddressTreeInfo.rootIndex,
     };
 
+    // Target agent must have an active AgentRecord
+    const [agentRecord] = await findAgentRecordPda(tokenAccount);
     const baseCreateIx = await getCreateCompressedAttestationInstructionAsync({
       payer: { address: payer } as KeyPairSigner,
       schemaConfig: schemaConfigPda,
+      agentRecord,
       agentAta, // Proves signer owns the agent NFT
       tokenProgram: TOKEN_2022_PROGRAM_ADDRESS, // Agent NFTs use Token-2022
       program: SATI_PROGRAM_ADDRESS,
@@ -1788,9 +1805,12 @@ export class Sati {
       rootIndex: packedAddressTreeInfo.rootIndex,
     };
 
+    // Target agent must have an active AgentRecord
+    const [agentRecord] = await findAgentRecordPda(tokenAccount);
     const baseCreateIx = await getCreateCompressedAttestationInstructionAsync({
       payer,
       schemaConfig: schemaConfigPda,
+      agentRecord,
       agentAta, // Proves signer owns the agent NFT
       tokenProgram: TOKEN_2022_PROGRAM_ADDRESS, // Agent NFTs use Token-2022
       program: SATI_PROGRAM_ADDRESS,
@@ -1903,6 +1923,7 @@ export class Sati {
       address: attestationAddress,
       proof,
       accountMeta,
+      revision: parsedAttestation.attestation.revision,
     });
 
     const closeIx = {
@@ -1982,9 +2003,12 @@ export class Sati {
       },
     ]);
 
+    // Target agent must have an active AgentRecord
+    const [agentRecord] = await findAgentRecordPda(tokenAccount);
     const createIx = await getCreateRegularAttestationInstructionAsync({
       payer,
       schemaConfig: schemaConfigPda,
+      agentRecord,
       satiCredential,
       sasSchema,
       attestation: attestationPda,
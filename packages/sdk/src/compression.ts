@@ -673,6 +673,12 @@ export class SATILightClientImpl implements SATILightClient {
     const numSignatures = data[schemaDataEnd];
     const signature1 = data.slice(schemaDataEnd + 1, schemaDataEnd + 65);
     const signature2 = numSignatures > 1 ? data.slice(schemaDataEnd + 65, schemaDataEnd + 129) : new Uint8Array(64);
+    // revision (u32 LE) follows the signatures only once amended; unamended attestations keep the
+    // original layout (and hash), so a missing revision reads as 0
+    const revision =
+      data.length >= schemaDataEnd + 133
+        ? new DataView(data.buffer, data.byteOffset + schemaDataEnd + 129, 4).getUint32(0, true)
+        : 0;
 
     const attestation: CompressedAttestation = {
       sasSchema,
@@ -681,6 +687,7 @@ export class SATILightClientImpl implements SATILightClient {
       data: schemaData,
       signature1,
       signature2,
+      revision,
     };
 
     // Deserialize schema-specific data using provided deserializer
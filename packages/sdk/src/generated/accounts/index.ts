@@ -6,6 +6,15 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from "./agentHandle";
 export * from "./agentIndex";
+export * from "./agentParent";
+export * from "./agentRecord";
+export * from "./agentSuccessor";
+export * from "./agentVerification";
+export * from "./evmLink";
+export * from "./handleClaim";
+export * from "./pendingAction";
 export * from "./registryConfig";
 export * from "./schemaConfig";
+export * from "./snsLink";
@@ -19,6 +19,8 @@ import {
   getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getI64Decoder,
+  getI64Encoder,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
@@ -62,6 +64,29 @@ export type RegistryConfig = {
   totalAgents: bigint;
   /** PDA bump seed (stored for efficient CPI signing) */
   bump: number;
+  /** Account layout version (see migrate_registry_config) */
+  version: number;
+  /**
+   * Proposed next authority awaiting accept_registry_authority
+   * Pubkey::default() = no transfer pending
+   */
+  pendingAuthority: Address;
+  /**
+   * Minimum delay (seconds) between queueing and executing governance actions
+   * 0 = timelock disabled (authority actions take effect immediately)
+   */
+  timelockDelay: bigint;
+  /** Next PendingAction id (counter, used as PDA seed) */
+  nextActionId: bigint;
+  /** Pause bitfield (see PAUSE_* constants). 0 = fully operational. */
+  paused: number;
+  /**
+   * Mint the registration fee is paid in
+   * Pubkey::default() = lamports
+   */
+  feeMint: Address;
+  /** Registration fee per agent (lamports or fee_mint base units). 0 = no fee. */
+  feeAmount: bigint;
 };
 
 export type RegistryConfigArgs = {
@@ -76,6 +101,29 @@ export type RegistryConfigArgs = {
   totalAgents: number | bigint;
   /** PDA bump seed (stored for efficient CPI signing) */
   bump: number;
+  /** Account layout version (see migrate_registry_config) */
+  version: number;
+  /**
+   * Proposed next authority awaiting accept_registry_authority
+   * Pubkey::default() = no transfer pending
+   */
+  pendingAuthority: Address;
+  /**
+   * Minimum delay (seconds) between queueing and executing governance actions
+   * 0 = timelock disabled (authority actions take effect immediately)
+   */
+  timelockDelay: number | bigint;
+  /** Next PendingAction id (counter, used as PDA seed) */
+  nextActionId: number | bigint;
+  /** Pause bitfield (see PAUSE_* constants). 0 = fully operational. */
+  paused: number;
+  /**
+   * Mint the registration fee is paid in
+   * Pubkey::default() = lamports
+   */
+  feeMint: Address;
+  /** Registration fee per agent (lamports or fee_mint base units). 0 = no fee. */
+  feeAmount: number | bigint;
 };
 
 /** Gets the encoder for {@link RegistryConfigArgs} account data. */
@@ -87,6 +135,13 @@ export function getRegistryConfigEncoder(): FixedSizeEncoder<RegistryConfigArgs>
       ["authority", getAddressEncoder()],
       ["totalAgents", getU64Encoder()],
       ["bump", getU8Encoder()],
+      ["version", getU8Encoder()],
+      ["pendingAuthority", getAddressEncoder()],
+      ["timelockDelay", getI64Encoder()],
+      ["nextActionId", getU64Encoder()],
+      ["paused", getU8Encoder()],
+      ["feeMint", getAddressEncoder()],
+      ["feeAmount", getU64Encoder()],
     ]),
     (value) => ({ ...value, discriminator: REGISTRY_CONFIG_DISCRIMINATOR }),
   );
@@ -100,6 +155,13 @@ export function getRegistryConfigDecoder(): FixedSizeDecoder<RegistryConfig> {
     ["authority", getAddressDecoder()],
     ["totalAgents", getU64Decoder()],
     ["bump", getU8Decoder()],
+    ["version", getU8Decoder()],
+    ["pendingAuthority", getAddressDecoder()],
+    ["timelockDelay", getI64Decoder()],
+    ["nextActionId", getU64Decoder()],
+    ["paused", getU8Decoder()],
+    ["feeMint", getAddressDecoder()],
+    ["feeAmount", getU64Decoder()],
   ]);
 }
 
@@ -173,5 +235,5 @@ export async function fetchAllMaybeRegistryConfig(
 }
 
 export function getRegistryConfigSize(): number {
-  return 81;
+  return 171;
 }
@@ -89,6 +89,8 @@ export type SchemaConfig = {
   name: string;
   /** PDA bump seed */
   bump: number;
+  /** Minimum AgentVerification level required of attested agents (0 = none) */
+  minVerificationLevel: number;
 };
 
 export type SchemaConfigArgs = {
@@ -110,6 +112,8 @@ export type SchemaConfigArgs = {
   name: string;
   /** PDA bump seed */
   bump: number;
+  /** Minimum AgentVerification level required of attested agents (0 = none) */
+  minVerificationLevel: number;
 };
 
 /** Gets the encoder for {@link SchemaConfigArgs} account data. */
@@ -124,6 +128,7 @@ export function getSchemaConfigEncoder(): Encoder<SchemaConfigArgs> {
       ["closeable", getBooleanEncoder()],
       ["name", addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
       ["bump", getU8Encoder()],
+      ["minVerificationLevel", getU8Encoder()],
     ]),
     (value) => ({ ...value, discriminator: SCHEMA_CONFIG_DISCRIMINATOR }),
   );
@@ -140,6 +145,7 @@ export function getSchemaConfigDecoder(): Decoder<SchemaConfig> {
     ["closeable", getBooleanDecoder()],
     ["name", addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
     ["bump", getU8Decoder()],
+    ["minVerificationLevel", getU8Decoder()],
   ]);
 }
 
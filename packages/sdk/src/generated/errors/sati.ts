@@ -112,55 +112,262 @@ export const SATI_ERROR__SECP256K1_RECOVERY_FAILED = 0x179e; // 6046
 export const SATI_ERROR__EVM_ADDRESS_MISMATCH = 0x179f; // 6047
 /** InvalidEvmAddressRecovery: Failed to extract EVM address from secp256k1 key recovery */
 export const SATI_ERROR__INVALID_EVM_ADDRESS_RECOVERY = 0x17a0; // 6048
+/** EmptyMetadataUpdate: Metadata update must change at least one field */
+export const SATI_ERROR__EMPTY_METADATA_UPDATE = 0x17a1; // 6049
+/** InvalidAgentMetadata: Agent mint does not contain valid TokenMetadata */
+export const SATI_ERROR__INVALID_AGENT_METADATA = 0x17a2; // 6050
+/** MetadataKeyNotFound: Metadata key not found */
+export const SATI_ERROR__METADATA_KEY_NOT_FOUND = 0x17a3; // 6051
+/** AgentAuthorityAlreadySynced: Agent metadata authority is already held by the registry */
+export const SATI_ERROR__AGENT_AUTHORITY_ALREADY_SYNCED = 0x17a4; // 6052
+/** AgentIndexMismatch: Agent index does not reference this mint */
+export const SATI_ERROR__AGENT_INDEX_MISMATCH = 0x17a5; // 6053
+/** AgentMintAccountMismatch: Agent record does not match token_account in attestation data */
+export const SATI_ERROR__AGENT_MINT_ACCOUNT_MISMATCH = 0x17a6; // 6054
+/** AgentRetired: Agent has been retired */
+export const SATI_ERROR__AGENT_RETIRED = 0x17a7; // 6055
+/** AgentNotRegistered: Agent is not registered in SATI (no AgentRecord for token_account) */
+export const SATI_ERROR__AGENT_NOT_REGISTERED = 0x17a8; // 6056
+/** InvalidBatchSize: Batch must contain between 1 and 5 agents */
+export const SATI_ERROR__INVALID_BATCH_SIZE = 0x17a9; // 6057
+/** InvalidBatchAccounts: Remaining accounts do not match the batch layout */
+export const SATI_ERROR__INVALID_BATCH_ACCOUNTS = 0x17aa; // 6058
+/** AuthorityTransferRequiresAcceptance: Direct authority transfer is disabled; use propose_registry_authority */
+export const SATI_ERROR__AUTHORITY_TRANSFER_REQUIRES_ACCEPTANCE = 0x17ab; // 6059
+/** InvalidPendingAuthority: Invalid pending authority */
+export const SATI_ERROR__INVALID_PENDING_AUTHORITY = 0x17ac; // 6060
+/** NoPendingAuthority: No authority transfer is pending */
+export const SATI_ERROR__NO_PENDING_AUTHORITY = 0x17ad; // 6061
+/** RegistryAlreadyMigrated: Registry config is already at the current version */
+export const SATI_ERROR__REGISTRY_ALREADY_MIGRATED = 0x17ae; // 6062
+/** TimelockRequired: Registry is timelocked; queue this action with queue_governance_action */
+export const SATI_ERROR__TIMELOCK_REQUIRED = 0x17af; // 6063
+/** TimelockNotElapsed: Timelock has not elapsed for this action */
+export const SATI_ERROR__TIMELOCK_NOT_ELAPSED = 0x17b0; // 6064
+/** InvalidTimelockDelay: Timelock delay must be between 0 and 30 days */
+export const SATI_ERROR__INVALID_TIMELOCK_DELAY = 0x17b1; // 6065
+/** StaleGovernanceAction: Action was queued by a previous registry authority */
+export const SATI_ERROR__STALE_GOVERNANCE_ACTION = 0x17b2; // 6066
+/** MissingSchemaConfigAccount: Schema config account required to execute this action */
+export const SATI_ERROR__MISSING_SCHEMA_CONFIG_ACCOUNT = 0x17b3; // 6067
+/** RegistryPaused: This operation is paused by the registry authority */
+export const SATI_ERROR__REGISTRY_PAUSED = 0x17b4; // 6068
+/** InvalidPauseFlags: Pause flags must be non-zero and only use defined bits */
+export const SATI_ERROR__INVALID_PAUSE_FLAGS = 0x17b5; // 6069
+/** MissingFeeAccounts: Registration fee accounts required (treasury for lamports, token accounts for SPL fees) */
+export const SATI_ERROR__MISSING_FEE_ACCOUNTS = 0x17b6; // 6070
+/** InvalidFeeAccount: Fee account does not match the registry treasury or fee mint */
+export const SATI_ERROR__INVALID_FEE_ACCOUNT = 0x17b7; // 6071
+/** InvalidRegistrationFee: Invalid registration fee mint */
+export const SATI_ERROR__INVALID_REGISTRATION_FEE = 0x17b8; // 6072
+/** InsufficientTreasuryBalance: Treasury balance too low for withdrawal */
+export const SATI_ERROR__INSUFFICIENT_TREASURY_BALANCE = 0x17b9; // 6073
+/** InvalidHandle: Handle must be 3-32 ASCII letters, digits, '-' or '_', starting and ending alphanumeric */
+export const SATI_ERROR__INVALID_HANDLE = 0x17ba; // 6074
+/** HandleTaken: Handle (or a confusable variant) is already claimed */
+export const SATI_ERROR__HANDLE_TAKEN = 0x17bb; // 6075
+/** HandleReleaseNotAllowed: Only the agent holder or registry authority can release a handle of an active agent */
+export const SATI_ERROR__HANDLE_RELEASE_NOT_ALLOWED = 0x17bc; // 6076
+/** AgentNotVerified: Schema requires a verified agent (missing or insufficient AgentVerification) */
+export const SATI_ERROR__AGENT_NOT_VERIFIED = 0x17bd; // 6077
+/** InvalidVerificationLevel: Verification level must be non-zero (use revoke_agent_verification) */
+export const SATI_ERROR__INVALID_VERIFICATION_LEVEL = 0x17be; // 6078
+/** SchemaConfigAlreadyMigrated: Schema config is already at the current layout */
+export const SATI_ERROR__SCHEMA_CONFIG_ALREADY_MIGRATED = 0x17bf; // 6079
+/** AgentSuspended: Agent is suspended by the registry authority */
+export const SATI_ERROR__AGENT_SUSPENDED = 0x17c0; // 6080
+/** InvalidAgentStatus: Invalid agent status transition */
+export const SATI_ERROR__INVALID_AGENT_STATUS = 0x17c1; // 6081
+/** InvalidSuccessor: Successor must be a different agent that has not itself been succeeded */
+export const SATI_ERROR__INVALID_SUCCESSOR = 0x17c2; // 6082
+/** TooManyServices: Too many service entries (max 8) */
+export const SATI_ERROR__TOO_MANY_SERVICES = 0x17c3; // 6083
+/** InvalidServiceEntry: Service entry needs a protocol and endpoint within length limits */
+export const SATI_ERROR__INVALID_SERVICE_ENTRY = 0x17c4; // 6084
+/** InvalidUriHash: uri_hash must be 'sha256:<64 hex>' or 'keccak256:<64 hex>' (lowercase) */
+export const SATI_ERROR__INVALID_URI_HASH = 0x17c5; // 6085
+/** InvalidChainId: Chain ID must be a non-empty CAIP-2 identifier (max 41 bytes) */
+export const SATI_ERROR__INVALID_CHAIN_ID = 0x17c6; // 6086
+/** InvalidEvmLinkExpiry: EVM link expiry must be in the future */
+export const SATI_ERROR__INVALID_EVM_LINK_EXPIRY = 0x17c7; // 6087
+/** EvmUnlinkNotAllowed: Only the current holder can unlink an active EVM link */
+export const SATI_ERROR__EVM_UNLINK_NOT_ALLOWED = 0x17c8; // 6088
+/** Secp256k1HighS: secp256k1 signature s value must be in the lower half of the curve order */
+export const SATI_ERROR__SECP256K1_HIGH_S = 0x17c9; // 6089
+/** InvalidRecoveryId: secp256k1 recovery ID must be 0, 1, 27 or 28 */
+export const SATI_ERROR__INVALID_RECOVERY_ID = 0x17ca; // 6090
+/** UnsupportedChainNamespace: Only eip155 chain IDs are supported */
+export const SATI_ERROR__UNSUPPORTED_CHAIN_NAMESPACE = 0x17cb; // 6091
+/** InvalidChainReference: eip155 chain reference must be a canonical non-zero decimal (max 20 digits) */
+export const SATI_ERROR__INVALID_CHAIN_REFERENCE = 0x17cc; // 6092
+/** InvalidExternalIdentityProof: External identity proof is malformed */
+export const SATI_ERROR__INVALID_EXTERNAL_IDENTITY_PROOF = 0x17cd; // 6093
+/** ExternalIdentityChainMismatch: Chain ID namespace does not match the external identity type */
+export const SATI_ERROR__EXTERNAL_IDENTITY_CHAIN_MISMATCH = 0x17ce; // 6094
+/** ExternalSignatureNotFound: No precompile instruction verifies the external key's signature over the link message */
+export const SATI_ERROR__EXTERNAL_SIGNATURE_NOT_FOUND = 0x17cf; // 6095
+/** InvalidWebAuthnAssertion: WebAuthn assertion must be a user-present webauthn.get over the link challenge */
+export const SATI_ERROR__INVALID_WEB_AUTHN_ASSERTION = 0x17d0; // 6096
+/** ExternalIdentityMismatch: Recovered key does not match the claimed external identity */
+export const SATI_ERROR__EXTERNAL_IDENTITY_MISMATCH = 0x17d1; // 6097
+/** InvalidSnsDomain: SNS domain must be a .sol label of 1-63 bytes without dots or whitespace */
+export const SATI_ERROR__INVALID_SNS_DOMAIN = 0x17d2; // 6098
+/** InvalidNameRegistry: Account is not the SNS name registry of the .sol domain */
+export const SATI_ERROR__INVALID_NAME_REGISTRY = 0x17d3; // 6099
+/** SnsOwnerMismatch: Domain is not owned by the agent holder */
+export const SATI_ERROR__SNS_OWNER_MISMATCH = 0x17d4; // 6100
+/** InvalidParentAgent: Parent must be a different active agent */
+export const SATI_ERROR__INVALID_PARENT_AGENT = 0x17d5; // 6101
+/** InvalidParentLink: Parent link does not belong to this agent */
+export const SATI_ERROR__INVALID_PARENT_LINK = 0x17d6; // 6102
+/** ParentLinkStale: Parent link was created by a previous holder of the agent */
+export const SATI_ERROR__PARENT_LINK_STALE = 0x17d7; // 6103
+/** ParentHolderMismatch: Signer does not hold the parent agent */
+export const SATI_ERROR__PARENT_HOLDER_MISMATCH = 0x17d8; // 6104
+/** AttestationNotAmendable: Only counterparty-signed attestations can be amended */
+export const SATI_ERROR__ATTESTATION_NOT_AMENDABLE = 0x17d9; // 6105
+/** ImmutableAttestationField: Amendment may only change the outcome, content type and content */
+export const SATI_ERROR__IMMUTABLE_ATTESTATION_FIELD = 0x17da; // 6106
+/** InvalidAttestationBatchSize: Batch must contain between 1 and 5 attestations */
+export const SATI_ERROR__INVALID_ATTESTATION_BATCH_SIZE = 0x17db; // 6107
+/** BatchProviderMismatch: All batch entries must name the same provider as counterparty */
+export const SATI_ERROR__BATCH_PROVIDER_MISMATCH = 0x17dc; // 6108
+/** SasSchemaMismatch: SAS schema does not match the schema config */
+export const SATI_ERROR__SAS_SCHEMA_MISMATCH = 0x17dd; // 6109
+/** SasCredentialMismatch: Attestation was not issued under the SATI credential */
+export const SATI_ERROR__SAS_CREDENTIAL_MISMATCH = 0x17de; // 6110
+/** InvalidAttestationBatchAccounts: Remaining accounts do not match the attestation batch layout */
+export const SATI_ERROR__INVALID_ATTESTATION_BATCH_ACCOUNTS = 0x17df; // 6111
+/** InvalidAttestationRevisionAccount: Attestation revision account does not match the attestation */
+export const SATI_ERROR__INVALID_ATTESTATION_REVISION_ACCOUNT = 0x17e0; // 6112
+/** RenounceWhilePaused: Registry must be fully unpaused before renouncing authority */
+export const SATI_ERROR__RENOUNCE_WHILE_PAUSED = 0x17e1; // 6113
+/** RenounceWithFee: Registration fee must be zero before renouncing authority */
+export const SATI_ERROR__RENOUNCE_WITH_FEE = 0x17e2; // 6114
+/** InvalidExternalLinkExpiry: External identity link expiry must be in the future */
+export const SATI_ERROR__INVALID_EXTERNAL_LINK_EXPIRY = 0x17e3; // 6115
+/** DuplicateBatchAttestation: Attestation batch contains the same attestation more than once */
+export const SATI_ERROR__DUPLICATE_BATCH_ATTESTATION = 0x17e4; // 6116
+/** SnsUnlinkNotAllowed: Only the current holder can unlink an active SNS link */
+export const SATI_ERROR__SNS_UNLINK_NOT_ALLOWED = 0x17e5; // 6117
 
 export type SatiError =
   | typeof SATI_ERROR__AGENT_ATA_EMPTY
   | typeof SATI_ERROR__AGENT_ATA_MINT_MISMATCH
   | typeof SATI_ERROR__AGENT_ATA_REQUIRED
+  | typeof SATI_ERROR__AGENT_AUTHORITY_ALREADY_SYNCED
+  | typeof SATI_ERROR__AGENT_INDEX_MISMATCH
+  | typeof SATI_ERROR__AGENT_MINT_ACCOUNT_MISMATCH
   | typeof SATI_ERROR__AGENT_MINT_MISMATCH
+  | typeof SATI_ERROR__AGENT_NOT_REGISTERED
+  | typeof SATI_ERROR__AGENT_NOT_VERIFIED
+  | typeof SATI_ERROR__AGENT_RETIRED
   | typeof SATI_ERROR__AGENT_SIGNATURE_NOT_FOUND
+  | typeof SATI_ERROR__AGENT_SUSPENDED
   | typeof SATI_ERROR__ATTESTATION_DATA_TOO_LARGE
   | typeof SATI_ERROR__ATTESTATION_DATA_TOO_SMALL
+  | typeof SATI_ERROR__ATTESTATION_NOT_AMENDABLE
   | typeof SATI_ERROR__ATTESTATION_NOT_CLOSEABLE
+  | typeof SATI_ERROR__AUTHORITY_TRANSFER_REQUIRES_ACCEPTANCE
+  | typeof SATI_ERROR__BATCH_PROVIDER_MISMATCH
   | typeof SATI_ERROR__CONTENT_TOO_LARGE
   | typeof SATI_ERROR__COUNTERPARTY_SIGNATURE_NOT_FOUND
   | typeof SATI_ERROR__DELEGATE_MISMATCH
   | typeof SATI_ERROR__DELEGATION_ATTESTATION_REQUIRED
   | typeof SATI_ERROR__DELEGATION_EXPIRED
   | typeof SATI_ERROR__DELEGATION_OWNER_MISMATCH
+  | typeof SATI_ERROR__DUPLICATE_BATCH_ATTESTATION
   | typeof SATI_ERROR__DUPLICATE_SIGNERS
   | typeof SATI_ERROR__ED25519_INSTRUCTION_NOT_FOUND
+  | typeof SATI_ERROR__EMPTY_METADATA_UPDATE
   | typeof SATI_ERROR__EVM_ADDRESS_MISMATCH
+  | typeof SATI_ERROR__EVM_UNLINK_NOT_ALLOWED
+  | typeof SATI_ERROR__EXTERNAL_IDENTITY_CHAIN_MISMATCH
+  | typeof SATI_ERROR__EXTERNAL_IDENTITY_MISMATCH
+  | typeof SATI_ERROR__EXTERNAL_SIGNATURE_NOT_FOUND
+  | typeof SATI_ERROR__HANDLE_RELEASE_NOT_ALLOWED
+  | typeof SATI_ERROR__HANDLE_TAKEN
+  | typeof SATI_ERROR__IMMUTABLE_ATTESTATION_FIELD
   | typeof SATI_ERROR__IMMUTABLE_AUTHORITY
+  | typeof SATI_ERROR__INSUFFICIENT_TREASURY_BALANCE
+  | typeof SATI_ERROR__INVALID_AGENT_METADATA
+  | typeof SATI_ERROR__INVALID_AGENT_STATUS
+  | typeof SATI_ERROR__INVALID_ATTESTATION_BATCH_ACCOUNTS
+  | typeof SATI_ERROR__INVALID_ATTESTATION_BATCH_SIZE
+  | typeof SATI_ERROR__INVALID_ATTESTATION_REVISION_ACCOUNT
   | typeof SATI_ERROR__INVALID_AUTHORITY
+  | typeof SATI_ERROR__INVALID_BATCH_ACCOUNTS
+  | typeof SATI_ERROR__INVALID_BATCH_SIZE
+  | typeof SATI_ERROR__INVALID_CHAIN_ID
+  | typeof SATI_ERROR__INVALID_CHAIN_REFERENCE
   | typeof SATI_ERROR__INVALID_CONTENT_TYPE
   | typeof SATI_ERROR__INVALID_DELEGATION_P_D_A
   | typeof SATI_ERROR__INVALID_ED25519_INSTRUCTION
   | typeof SATI_ERROR__INVALID_EVM_ADDRESS_RECOVERY
+  | typeof SATI_ERROR__INVALID_EVM_LINK_EXPIRY
+  | typeof SATI_ERROR__INVALID_EXTERNAL_IDENTITY_PROOF
+  | typeof SATI_ERROR__INVALID_EXTERNAL_LINK_EXPIRY
+  | typeof SATI_ERROR__INVALID_FEE_ACCOUNT
   | typeof SATI_ERROR__INVALID_GROUP_MINT
+  | typeof SATI_ERROR__INVALID_HANDLE
   | typeof SATI_ERROR__INVALID_INSTRUCTIONS_SYSVAR
+  | typeof SATI_ERROR__INVALID_NAME_REGISTRY
   | typeof SATI_ERROR__INVALID_OUTCOME
+  | typeof SATI_ERROR__INVALID_PARENT_AGENT
+  | typeof SATI_ERROR__INVALID_PARENT_LINK
+  | typeof SATI_ERROR__INVALID_PAUSE_FLAGS
+  | typeof SATI_ERROR__INVALID_PENDING_AUTHORITY
+  | typeof SATI_ERROR__INVALID_RECOVERY_ID
+  | typeof SATI_ERROR__INVALID_REGISTRATION_FEE
   | typeof SATI_ERROR__INVALID_SECP256K1_SIGNATURE
+  | typeof SATI_ERROR__INVALID_SERVICE_ENTRY
   | typeof SATI_ERROR__INVALID_SIGNATURE
   | typeof SATI_ERROR__INVALID_SIGNATURE_COUNT
+  | typeof SATI_ERROR__INVALID_SNS_DOMAIN
+  | typeof SATI_ERROR__INVALID_SUCCESSOR
+  | typeof SATI_ERROR__INVALID_TIMELOCK_DELAY
+  | typeof SATI_ERROR__INVALID_URI_HASH
+  | typeof SATI_ERROR__INVALID_VERIFICATION_LEVEL
+  | typeof SATI_ERROR__INVALID_WEB_AUTHN_ASSERTION
   | typeof SATI_ERROR__LIGHT_CPI_INVOCATION_FAILED
   | typeof SATI_ERROR__MESSAGE_MISMATCH
+  | typeof SATI_ERROR__METADATA_KEY_NOT_FOUND
   | typeof SATI_ERROR__METADATA_KEY_TOO_LONG
   | typeof SATI_ERROR__METADATA_VALUE_TOO_LONG
   | typeof SATI_ERROR__MINT_AUTHORITY_NOT_RENOUNCED
+  | typeof SATI_ERROR__MISSING_FEE_ACCOUNTS
+  | typeof SATI_ERROR__MISSING_SCHEMA_CONFIG_ACCOUNT
   | typeof SATI_ERROR__MISSING_SIGNATURES
   | typeof SATI_ERROR__NAME_TOO_LONG
+  | typeof SATI_ERROR__NO_PENDING_AUTHORITY
   | typeof SATI_ERROR__OVERFLOW
   | typeof SATI_ERROR__OWNER_ONLY
+  | typeof SATI_ERROR__PARENT_HOLDER_MISMATCH
+  | typeof SATI_ERROR__PARENT_LINK_STALE
+  | typeof SATI_ERROR__REGISTRY_ALREADY_MIGRATED
+  | typeof SATI_ERROR__REGISTRY_PAUSED
+  | typeof SATI_ERROR__RENOUNCE_WHILE_PAUSED
+  | typeof SATI_ERROR__RENOUNCE_WITH_FEE
+  | typeof SATI_ERROR__SAS_CREDENTIAL_MISMATCH
+  | typeof SATI_ERROR__SAS_SCHEMA_MISMATCH
+  | typeof SATI_ERROR__SCHEMA_CONFIG_ALREADY_MIGRATED
   | typeof SATI_ERROR__SCHEMA_CONFIG_NOT_FOUND
+  | typeof SATI_ERROR__SECP256K1_HIGH_S
   | typeof SATI_ERROR__SECP256K1_RECOVERY_FAILED
   | typeof SATI_ERROR__SELF_ATTESTATION_NOT_ALLOWED
   | typeof SATI_ERROR__SIGNATURE_MISMATCH
+  | typeof SATI_ERROR__SNS_OWNER_MISMATCH
+  | typeof SATI_ERROR__SNS_UNLINK_NOT_ALLOWED
+  | typeof SATI_ERROR__STALE_GOVERNANCE_ACTION
   | typeof SATI_ERROR__STORAGE_TYPE_MISMATCH
   | typeof SATI_ERROR__STORAGE_TYPE_NOT_SUPPORTED
   | typeof SATI_ERROR__SYMBOL_TOO_LONG
+  | typeof SATI_ERROR__TIMELOCK_NOT_ELAPSED
+  | typeof SATI_ERROR__TIMELOCK_REQUIRED
   | typeof SATI_ERROR__TOO_MANY_METADATA_ENTRIES
+  | typeof SATI_ERROR__TOO_MANY_SERVICES
   | typeof SATI_ERROR__UNAUTHORIZED_CLOSE
+  | typeof SATI_ERROR__UNSUPPORTED_CHAIN_NAMESPACE
   | typeof SATI_ERROR__UNSUPPORTED_LAYOUT_VERSION
   | typeof SATI_ERROR__URI_TOO_LONG;
 
@@ -170,50 +377,119 @@ if (process.env.NODE_ENV !== "production") {
     [SATI_ERROR__AGENT_ATA_EMPTY]: `Agent ATA is empty - signer does not own the agent NFT`,
     [SATI_ERROR__AGENT_ATA_MINT_MISMATCH]: `Agent ATA mint does not match token_account in attestation data`,
     [SATI_ERROR__AGENT_ATA_REQUIRED]: `Agent ATA required for this signature mode`,
+    [SATI_ERROR__AGENT_AUTHORITY_ALREADY_SYNCED]: `Agent metadata authority is already held by the registry`,
+    [SATI_ERROR__AGENT_INDEX_MISMATCH]: `Agent index does not reference this mint`,
+    [SATI_ERROR__AGENT_MINT_ACCOUNT_MISMATCH]: `Agent record does not match token_account in attestation data`,
     [SATI_ERROR__AGENT_MINT_MISMATCH]: `Delegation attestation agent doesn't match target agent`,
+    [SATI_ERROR__AGENT_NOT_REGISTERED]: `Agent is not registered in SATI (no AgentRecord for token_account)`,
+    [SATI_ERROR__AGENT_NOT_VERIFIED]: `Schema requires a verified agent (missing or insufficient AgentVerification)`,
+    [SATI_ERROR__AGENT_RETIRED]: `Agent has been retired`,
     [SATI_ERROR__AGENT_SIGNATURE_NOT_FOUND]: `Agent's Ed25519 signature not found (message content mismatch)`,
+    [SATI_ERROR__AGENT_SUSPENDED]: `Agent is suspended by the registry authority`,
     [SATI_ERROR__ATTESTATION_DATA_TOO_LARGE]: `Attestation data exceeds maximum size`,
     [SATI_ERROR__ATTESTATION_DATA_TOO_SMALL]: `Attestation data too small (minimum 130 bytes for universal base layout)`,
+    [SATI_ERROR__ATTESTATION_NOT_AMENDABLE]: `Only counterparty-signed attestations can be amended`,
     [SATI_ERROR__ATTESTATION_NOT_CLOSEABLE]: `Attestation cannot be closed for this schema`,
+    [SATI_ERROR__AUTHORITY_TRANSFER_REQUIRES_ACCEPTANCE]: `Direct authority transfer is disabled; use propose_registry_authority`,
+    [SATI_ERROR__BATCH_PROVIDER_MISMATCH]: `All batch entries must name the same provider as counterparty`,
     [SATI_ERROR__CONTENT_TOO_LARGE]: `Content exceeds maximum size (512 bytes)`,
     [SATI_ERROR__COUNTERPARTY_SIGNATURE_NOT_FOUND]: `Counterparty's Ed25519 signature not found (message content mismatch)`,
     [SATI_ERROR__DELEGATE_MISMATCH]: `Delegation attestation delegate doesn't match signer`,
     [SATI_ERROR__DELEGATION_ATTESTATION_REQUIRED]: `Delegate signed but no delegation attestation provided`,
     [SATI_ERROR__DELEGATION_EXPIRED]: `Delegation attestation has expired`,
     [SATI_ERROR__DELEGATION_OWNER_MISMATCH]: `Delegation was created by different owner (NFT was transferred)`,
+    [SATI_ERROR__DUPLICATE_BATCH_ATTESTATION]: `Attestation batch contains the same attestation more than once`,
     [SATI_ERROR__DUPLICATE_SIGNERS]: `Duplicate signers not allowed for dual signature mode`,
     [SATI_ERROR__ED25519_INSTRUCTION_NOT_FOUND]: `No Ed25519 instruction found in transaction`,
+    [SATI_ERROR__EMPTY_METADATA_UPDATE]: `Metadata update must change at least one field`,
     [SATI_ERROR__EVM_ADDRESS_MISMATCH]: `EVM address mismatch - recovered address does not match expected`,
+    [SATI_ERROR__EVM_UNLINK_NOT_ALLOWED]: `Only the current holder can unlink an active EVM link`,
+    [SATI_ERROR__EXTERNAL_IDENTITY_CHAIN_MISMATCH]: `Chain ID namespace does not match the external identity type`,
+    [SATI_ERROR__EXTERNAL_IDENTITY_MISMATCH]: `Recovered key does not match the claimed external identity`,
+    [SATI_ERROR__EXTERNAL_SIGNATURE_NOT_FOUND]: `No precompile instruction verifies the external key's signature over the link message`,
+    [SATI_ERROR__HANDLE_RELEASE_NOT_ALLOWED]: `Only the agent holder or registry authority can release a handle of an active agent`,
+    [SATI_ERROR__HANDLE_TAKEN]: `Handle (or a confusable variant) is already claimed`,
+    [SATI_ERROR__IMMUTABLE_ATTESTATION_FIELD]: `Amendment may only change the outcome, content type and content`,
     [SATI_ERROR__IMMUTABLE_AUTHORITY]: `Authority is immutable (renounced)`,
+    [SATI_ERROR__INSUFFICIENT_TREASURY_BALANCE]: `Treasury balance too low for withdrawal`,
+    [SATI_ERROR__INVALID_AGENT_METADATA]: `Agent mint does not contain valid TokenMetadata`,
+    [SATI_ERROR__INVALID_AGENT_STATUS]: `Invalid agent status transition`,
+    [SATI_ERROR__INVALID_ATTESTATION_BATCH_ACCOUNTS]: `Remaining accounts do not match the attestation batch layout`,
+    [SATI_ERROR__INVALID_ATTESTATION_BATCH_SIZE]: `Batch must contain between 1 and 5 attestations`,
+    [SATI_ERROR__INVALID_ATTESTATION_REVISION_ACCOUNT]: `Attestation revision account does not match the attestation`,
     [SATI_ERROR__INVALID_AUTHORITY]: `Invalid authority`,
+    [SATI_ERROR__INVALID_BATCH_ACCOUNTS]: `Remaining accounts do not match the batch layout`,
+    [SATI_ERROR__INVALID_BATCH_SIZE]: `Batch must contain between 1 and 5 agents`,
+    [SATI_ERROR__INVALID_CHAIN_ID]: `Chain ID must be a non-empty CAIP-2 identifier (max 41 bytes)`,
+    [SATI_ERROR__INVALID_CHAIN_REFERENCE]: `eip155 chain reference must be a canonical non-zero decimal (max 20 digits)`,
     [SATI_ERROR__INVALID_CONTENT_TYPE]: `Invalid content type (must be 0-15)`,
     [SATI_ERROR__INVALID_DELEGATION_P_D_A]: `Delegation attestation PDA doesn't match expected derivation`,
     [SATI_ERROR__INVALID_ED25519_INSTRUCTION]: `Invalid Ed25519 instruction format`,
     [SATI_ERROR__INVALID_EVM_ADDRESS_RECOVERY]: `Failed to extract EVM address from secp256k1 key recovery`,
+    [SATI_ERROR__INVALID_EVM_LINK_EXPIRY]: `EVM link expiry must be in the future`,
+    [SATI_ERROR__INVALID_EXTERNAL_IDENTITY_PROOF]: `External identity proof is malformed`,
+    [SATI_ERROR__INVALID_EXTERNAL_LINK_EXPIRY]: `External identity link expiry must be in the future`,
+    [SATI_ERROR__INVALID_FEE_ACCOUNT]: `Fee account does not match the registry treasury or fee mint`,
     [SATI_ERROR__INVALID_GROUP_MINT]: `Invalid group mint - must be owned by Token-2022 with TokenGroup extension`,
+    [SATI_ERROR__INVALID_HANDLE]: `Handle must be 3-32 ASCII letters, digits, '-' or '_', starting and ending alphanumeric`,
     [SATI_ERROR__INVALID_INSTRUCTIONS_SYSVAR]: `Invalid instructions sysvar`,
+    [SATI_ERROR__INVALID_NAME_REGISTRY]: `Account is not the SNS name registry of the .sol domain`,
     [SATI_ERROR__INVALID_OUTCOME]: `Invalid outcome value (must be 0, 1, or 2)`,
+    [SATI_ERROR__INVALID_PARENT_AGENT]: `Parent must be a different active agent`,
+    [SATI_ERROR__INVALID_PARENT_LINK]: `Parent link does not belong to this agent`,
+    [SATI_ERROR__INVALID_PAUSE_FLAGS]: `Pause flags must be non-zero and only use defined bits`,
+    [SATI_ERROR__INVALID_PENDING_AUTHORITY]: `Invalid pending authority`,
+    [SATI_ERROR__INVALID_RECOVERY_ID]: `secp256k1 recovery ID must be 0, 1, 27 or 28`,
+    [SATI_ERROR__INVALID_REGISTRATION_FEE]: `Invalid registration fee mint`,
     [SATI_ERROR__INVALID_SECP256K1_SIGNATURE]: `Invalid secp256k1 signature`,
+    [SATI_ERROR__INVALID_SERVICE_ENTRY]: `Service entry needs a protocol and endpoint within length limits`,
     [SATI_ERROR__INVALID_SIGNATURE]: `Invalid Ed25519 signature`,
     [SATI_ERROR__INVALID_SIGNATURE_COUNT]: `Invalid signature count for signature mode`,
+    [SATI_ERROR__INVALID_SNS_DOMAIN]: `SNS domain must be a .sol label of 1-63 bytes without dots or whitespace`,
+    [SATI_ERROR__INVALID_SUCCESSOR]: `Successor must be a different agent that has not itself been succeeded`,
+    [SATI_ERROR__INVALID_TIMELOCK_DELAY]: `Timelock delay must be between 0 and 30 days`,
+    [SATI_ERROR__INVALID_URI_HASH]: `uri_hash must be 'sha256:<64 hex>' or 'keccak256:<64 hex>' (lowercase)`,
+    [SATI_ERROR__INVALID_VERIFICATION_LEVEL]: `Verification level must be non-zero (use revoke_agent_verification)`,
+    [SATI_ERROR__INVALID_WEB_AUTHN_ASSERTION]: `WebAuthn assertion must be a user-present webauthn.get over the link challenge`,
     [SATI_ERROR__LIGHT_CPI_INVOCATION_FAILED]: `Light Protocol CPI invocation failed`,
     [SATI_ERROR__MESSAGE_MISMATCH]: `Message hash mismatch - signature was for different data`,
+    [SATI_ERROR__METADATA_KEY_NOT_FOUND]: `Metadata key not found`,
     [SATI_ERROR__METADATA_KEY_TOO_LONG]: `Metadata key too long (max 32 bytes)`,
     [SATI_ERROR__METADATA_VALUE_TOO_LONG]: `Metadata value too long (max 200 bytes)`,
     [SATI_ERROR__MINT_AUTHORITY_NOT_RENOUNCED]: `Failed to renounce mint authority - supply guarantee violated`,
+    [SATI_ERROR__MISSING_FEE_ACCOUNTS]: `Registration fee accounts required (treasury for lamports, token accounts for SPL fees)`,
+    [SATI_ERROR__MISSING_SCHEMA_CONFIG_ACCOUNT]: `Schema config account required to execute this action`,
     [SATI_ERROR__MISSING_SIGNATURES]: `Missing required Ed25519 signatures in transaction`,
     [SATI_ERROR__NAME_TOO_LONG]: `Name too long (max 32 bytes)`,
+    [SATI_ERROR__NO_PENDING_AUTHORITY]: `No authority transfer is pending`,
     [SATI_ERROR__OVERFLOW]: `Arithmetic overflow`,
     [SATI_ERROR__OWNER_ONLY]: `Schema requires owner signature but delegate attempted`,
+    [SATI_ERROR__PARENT_HOLDER_MISMATCH]: `Signer does not hold the parent agent`,
+    [SATI_ERROR__PARENT_LINK_STALE]: `Parent link was created by a previous holder of the agent`,
+    [SATI_ERROR__REGISTRY_ALREADY_MIGRATED]: `Registry config is already at the current version`,
+    [SATI_ERROR__REGISTRY_PAUSED]: `This operation is paused by the registry authority`,
+    [SATI_ERROR__RENOUNCE_WHILE_PAUSED]: `Registry must be fully unpaused before renouncing authority`,
+    [SATI_ERROR__RENOUNCE_WITH_FEE]: `Registration fee must be zero before renouncing authority`,
+    [SATI_ERROR__SAS_CREDENTIAL_MISMATCH]: `Attestation was not issued under the SATI credential`,
+    [SATI_ERROR__SAS_SCHEMA_MISMATCH]: `SAS schema does not match the schema config`,
+    [SATI_ERROR__SCHEMA_CONFIG_ALREADY_MIGRATED]: `Schema config is already at the current layout`,
     [SATI_ERROR__SCHEMA_CONFIG_NOT_FOUND]: `Schema config not found`,
+    [SATI_ERROR__SECP256K1_HIGH_S]: `secp256k1 signature s value must be in the lower half of the curve order`,
     [SATI_ERROR__SECP256K1_RECOVERY_FAILED]: `Secp256k1 recovery failed`,
     [SATI_ERROR__SELF_ATTESTATION_NOT_ALLOWED]: `Self-attestation is not allowed (token_account == counterparty)`,
     [SATI_ERROR__SIGNATURE_MISMATCH]: `Signature pubkey does not match expected account`,
+    [SATI_ERROR__SNS_OWNER_MISMATCH]: `Domain is not owned by the agent holder`,
+    [SATI_ERROR__SNS_UNLINK_NOT_ALLOWED]: `Only the current holder can unlink an active SNS link`,
+    [SATI_ERROR__STALE_GOVERNANCE_ACTION]: `Action was queued by a previous registry authority`,
     [SATI_ERROR__STORAGE_TYPE_MISMATCH]: `Storage type mismatch`,
     [SATI_ERROR__STORAGE_TYPE_NOT_SUPPORTED]: `Storage type not supported for this operation`,
     [SATI_ERROR__SYMBOL_TOO_LONG]: `Symbol too long (max 10 bytes)`,
+    [SATI_ERROR__TIMELOCK_NOT_ELAPSED]: `Timelock has not elapsed for this action`,
+    [SATI_ERROR__TIMELOCK_REQUIRED]: `Registry is timelocked; queue this action with queue_governance_action`,
     [SATI_ERROR__TOO_MANY_METADATA_ENTRIES]: `Too many metadata entries (max 10)`,
+    [SATI_ERROR__TOO_MANY_SERVICES]: `Too many service entries (max 8)`,
     [SATI_ERROR__UNAUTHORIZED_CLOSE]: `Unauthorized to close attestation`,
+    [SATI_ERROR__UNSUPPORTED_CHAIN_NAMESPACE]: `Only eip155 chain IDs are supported`,
     [SATI_ERROR__UNSUPPORTED_LAYOUT_VERSION]: `Unsupported layout version`,
     [SATI_ERROR__URI_TOO_LONG]: `URI too long (max 200 bytes)`,
   };
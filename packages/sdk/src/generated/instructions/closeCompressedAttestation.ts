@@ -16,6 +16,8 @@ import {
   getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
@@ -33,6 +35,8 @@ import {
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
@@ -65,6 +69,7 @@ export type CloseCompressedAttestationInstruction<
   TProgram extends string = typeof SATI_PROGRAM_ADDRESS,
   TAccountSigner extends string | AccountMeta<string> = string,
   TAccountSchemaConfig extends string | AccountMeta<string> = string,
+  TAccountRegistryConfig extends string | AccountMeta<string> = string,
   TAccountAgentAta extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends string | AccountMeta<string> = string,
   TAccountEventAuthority extends string | AccountMeta<string> = string,
@@ -81,6 +86,9 @@ export type CloseCompressedAttestationInstruction<
       TAccountSchemaConfig extends string
         ? ReadonlyAccount<TAccountSchemaConfig>
         : TAccountSchemaConfig,
+      TAccountRegistryConfig extends string
+        ? ReadonlyAccount<TAccountRegistryConfig>
+        : TAccountRegistryConfig,
       TAccountAgentAta extends string
         ? ReadonlyAccount<TAccountAgentAta>
         : TAccountAgentAta,
@@ -113,6 +121,8 @@ export type CloseCompressedAttestationInstructionData = {
   proof: ValidityProof;
   /** Light Protocol compressed account metadata */
   accountMeta: CompressedAccountMeta;
+  /** Current revision of the attestation (None or 0 = never amended) */
+  revision: Option<number>;
 };
 
 export type CloseCompressedAttestationInstructionDataArgs = {
@@ -130,6 +140,8 @@ export type CloseCompressedAttestationInstructionDataArgs = {
   proof: ValidityProofArgs;
   /** Light Protocol compressed account metadata */
   accountMeta: CompressedAccountMetaArgs;
+  /** Current revision of the attestation (None or 0 = never amended) */
+  revision: OptionOrNullable<number>;
 };
 
 export function getCloseCompressedAttestationInstructionDataEncoder(): Encoder<CloseCompressedAttestationInstructionDataArgs> {
@@ -143,6 +155,7 @@ export function getCloseCompressedAttestationInstructionDataEncoder(): Encoder<C
       ["address", getAddressEncoder()],
       ["proof", getValidityProofEncoder()],
       ["accountMeta", getCompressedAccountMetaEncoder()],
+      ["revision", getOptionEncoder(getU32Encoder())],
     ]),
     (value) => ({
       ...value,
@@ -161,6 +174,7 @@ export function getCloseCompressedAttestationInstructionDataDecoder(): Decoder<C
     ["address", getAddressDecoder()],
     ["proof", getValidityProofDecoder()],
     ["accountMeta", getCompressedAccountMetaDecoder()],
+    ["revision", getOptionDecoder(getU32Decoder())],
   ]);
 }
 
@@ -177,6 +191,7 @@ export function getCloseCompressedAttestationInstructionDataCodec(): Codec<
 export type CloseCompressedAttestationAsyncInput<
   TAccountSigner extends string = string,
   TAccountSchemaConfig extends string = string,
+  TAccountRegistryConfig extends string = string,
   TAccountAgentAta extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountEventAuthority extends string = string,
@@ -186,6 +201,8 @@ export type CloseCompressedAttestationAsyncInput<
   signer: TransactionSigner<TAccountSigner>;
   /** Schema config PDA */
   schemaConfig: Address<TAccountSchemaConfig>;
+  /** Registry config - checked for the pause switch */
+  registryConfig?: Address<TAccountRegistryConfig>;
   /**
    * Optional: Agent's ATA (required if signer is NFT owner, not counterparty).
    * If provided, must hold the agent NFT (mint matches token_account from data).
@@ -203,11 +220,13 @@ export type CloseCompressedAttestationAsyncInput<
   address: CloseCompressedAttestationInstructionDataArgs["address"];
   proof: CloseCompressedAttestationInstructionDataArgs["proof"];
   accountMeta: CloseCompressedAttestationInstructionDataArgs["accountMeta"];
+  revision: CloseCompressedAttestationInstructionDataArgs["revision"];
 };
 
 export async function getCloseCompressedAttestationInstructionAsync<
   TAccountSigner extends string,
   TAccountSchemaConfig extends string,
+  TAccountRegistryConfig extends string,
   TAccountAgentAta extends string,
   TAccountTokenProgram extends string,
   TAccountEventAuthority extends string,
@@ -217,6 +236,7 @@ export async function getCloseCompressedAttestationInstructionAsync<
   input: CloseCompressedAttestationAsyncInput<
     TAccountSigner,
     TAccountSchemaConfig,
+    TAccountRegistryConfig,
     TAccountAgentAta,
     TAccountTokenProgram,
     TAccountEventAuthority,
@@ -228,6 +248,7 @@ export async function getCloseCompressedAttestationInstructionAsync<
     TProgramAddress,
     TAccountSigner,
     TAccountSchemaConfig,
+    TAccountRegistryConfig,
     TAccountAgentAta,
     TAccountTokenProgram,
     TAccountEventAuthority,
@@ -241,6 +262,7 @@ export async function getCloseCompressedAttestationInstructionAsync<
   const originalAccounts = {
     signer: { value: input.signer ?? null, isWritable: true },
     schemaConfig: { value: input.schemaConfig ?? null, isWritable: false },
+    registryConfig: { value: input.registryConfig ?? null, isWritable: false },
     agentAta: { value: input.agentAta ?? null, isWritable: false },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
@@ -255,6 +277,16 @@ export async function getCloseCompressedAttestationInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
+  if (!accounts.registryConfig.value) {
+    accounts.registryConfig.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([114, 101, 103, 105, 115, 116, 114, 121]),
+        ),
+      ],
+    });
+  }
   if (!accounts.eventAuthority.value) {
     accounts.eventAuthority.value = await getProgramDerivedAddress({
       programAddress,
@@ -274,6 +306,7 @@ export async function getCloseCompressedAttestationInstructionAsync<
     accounts: [
       getAccountMeta(accounts.signer),
       getAccountMeta(accounts.schemaConfig),
+      getAccountMeta(accounts.registryConfig),
       getAccountMeta(accounts.agentAta),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.eventAuthority),
@@ -287,6 +320,7 @@ export async function getCloseCompressedAttestationInstructionAsync<
     TProgramAddress,
     TAccountSigner,
     TAccountSchemaConfig,
+    TAccountRegistryConfig,
     TAccountAgentAta,
     TAccountTokenProgram,
     TAccountEventAuthority,
@@ -297,6 +331,7 @@ export async function getCloseCompressedAttestationInstructionAsync<
 export type CloseCompressedAttestationInput<
   TAccountSigner extends string = string,
   TAccountSchemaConfig extends string = string,
+  TAccountRegistryConfig extends string = string,
   TAccountAgentAta extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountEventAuthority extends string = string,
@@ -306,6 +341,8 @@ export type CloseCompressedAttestationInput<
   signer: TransactionSigner<TAccountSigner>;
   /** Schema config PDA */
   schemaConfig: Address<TAccountSchemaConfig>;
+  /** Registry config - checked for the pause switch */
+  registryConfig: Address<TAccountRegistryConfig>;
   /**
    * Optional: Agent's ATA (required if signer is NFT owner, not counterparty).
    * If provided, must hold the agent NFT (mint matches token_account from data).
@@ -323,11 +360,13 @@ export type CloseCompressedAttestationInput<
   address: CloseCompressedAttestationInstructionDataArgs["address"];
   proof: CloseCompressedAttestationInstructionDataArgs["proof"];
   accountMeta: CloseCompressedAttestationInstructionDataArgs["accountMeta"];
+  revision: CloseCompressedAttestationInstructionDataArgs["revision"];
 };
 
 export function getCloseCompressedAttestationInstruction<
   TAccountSigner extends string,
   TAccountSchemaConfig extends string,
+  TAccountRegistryConfig extends string,
   TAccountAgentAta extends string,
   TAccountTokenProgram extends string,
   TAccountEventAuthority extends string,
@@ -337,6 +376,7 @@ export function getCloseCompressedAttestationInstruction<
   input: CloseCompressedAttestationInput<
     TAccountSigner,
     TAccountSchemaConfig,
+    TAccountRegistryConfig,
     TAccountAgentAta,
     TAccountTokenProgram,
     TAccountEventAuthority,
@@ -347,6 +387,7 @@ export function getCloseCompressedAttestationInstruction<
   TProgramAddress,
   TAccountSigner,
   TAccountSchemaConfig,
+  TAccountRegistryConfig,
   TAccountAgentAta,
   TAccountTokenProgram,
   TAccountEventAuthority,
@@ -359,6 +400,7 @@ export function getCloseCompressedAttestationInstruction<
   const originalAccounts = {
     signer: { value: input.signer ?? null, isWritable: true },
     schemaConfig: { value: input.schemaConfig ?? null, isWritable: false },
+    registryConfig: { value: input.registryConfig ?? null, isWritable: false },
     agentAta: { value: input.agentAta ?? null, isWritable: false },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
@@ -377,6 +419,7 @@ export function getCloseCompressedAttestationInstruction<
     accounts: [
       getAccountMeta(accounts.signer),
       getAccountMeta(accounts.schemaConfig),
+      getAccountMeta(accounts.registryConfig),
       getAccountMeta(accounts.agentAta),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.eventAuthority),
@@ -390,6 +433,7 @@ export function getCloseCompressedAttestationInstruction<
     TProgramAddress,
     TAccountSigner,
     TAccountSchemaConfig,
+    TAccountRegistryConfig,
     TAccountAgentAta,
     TAccountTokenProgram,
     TAccountEventAuthority,
@@ -407,16 +451,18 @@ export type ParsedCloseCompressedAttestationInstruction<
     signer: TAccountMetas[0];
     /** Schema config PDA */
     schemaConfig: TAccountMetas[1];
+    /** Registry config - checked for the pause switch */
+    registryConfig: TAccountMetas[2];
     /**
      * Optional: Agent's ATA (required if signer is NFT owner, not counterparty).
      * If provided, must hold the agent NFT (mint matches token_account from data).
      * Note: token_account in data is the MINT address; this is the holder's ATA.
      */
-    agentAta?: TAccountMetas[2] | undefined;
+    agentAta?: TAccountMetas[3] | undefined;
     /** Token-2022 program for ATA verification (optional, required with agent_ata) */
-    tokenProgram?: TAccountMetas[3] | undefined;
-    eventAuthority: TAccountMetas[4];
-    program: TAccountMetas[5];
+    tokenProgram?: TAccountMetas[4] | undefined;
+    eventAuthority: TAccountMetas[5];
+    program: TAccountMetas[6];
   };
   data: CloseCompressedAttestationInstructionData;
 };
@@ -429,7 +475,7 @@ export function parseCloseCompressedAttestationInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>,
 ): ParsedCloseCompressedAttestationInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error("Not enough accounts");
   }
@@ -450,6 +496,7 @@ export function parseCloseCompressedAttestationInstruction<
     accounts: {
       signer: getNextAccount(),
       schemaConfig: getNextAccount(),
+      registryConfig: getNextAccount(),
       agentAta: getNextOptionalAccount(),
       tokenProgram: getNextOptionalAccount(),
       eventAuthority: getNextAccount(),
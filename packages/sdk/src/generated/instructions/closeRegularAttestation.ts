@@ -50,6 +50,7 @@ export type CloseRegularAttestationInstruction<
   TAccountPayer extends string | AccountMeta<string> = string,
   TAccountSigner extends string | AccountMeta<string> = string,
   TAccountSchemaConfig extends string | AccountMeta<string> = string,
+  TAccountRegistryConfig extends string | AccountMeta<string> = string,
   TAccountSatiPda extends string | AccountMeta<string> = string,
   TAccountSatiCredential extends string | AccountMeta<string> = string,
   TAccountAttestation extends string | AccountMeta<string> = string,
@@ -75,6 +76,9 @@ export type CloseRegularAttestationInstruction<
       TAccountSchemaConfig extends string
         ? ReadonlyAccount<TAccountSchemaConfig>
         : TAccountSchemaConfig,
+      TAccountRegistryConfig extends string
+        ? ReadonlyAccount<TAccountRegistryConfig>
+        : TAccountRegistryConfig,
       TAccountSatiPda extends string
         ? ReadonlyAccount<TAccountSatiPda>
         : TAccountSatiPda,
@@ -139,6 +143,7 @@ export type CloseRegularAttestationAsyncInput<
   TAccountPayer extends string = string,
   TAccountSigner extends string = string,
   TAccountSchemaConfig extends string = string,
+  TAccountRegistryConfig extends string = string,
   TAccountSatiPda extends string = string,
   TAccountSatiCredential extends string = string,
   TAccountAttestation extends string = string,
@@ -154,6 +159,8 @@ export type CloseRegularAttestationAsyncInput<
   signer: TransactionSigner<TAccountSigner>;
   /** Schema config PDA */
   schemaConfig: Address<TAccountSchemaConfig>;
+  /** Registry config - checked for the pause switch */
+  registryConfig?: Address<TAccountRegistryConfig>;
   /** SATI Attestation Program PDA - authorized signer on SAS credential */
   satiPda?: Address<TAccountSatiPda>;
   /** SATI SAS credential account */
@@ -178,6 +185,7 @@ export async function getCloseRegularAttestationInstructionAsync<
   TAccountPayer extends string,
   TAccountSigner extends string,
   TAccountSchemaConfig extends string,
+  TAccountRegistryConfig extends string,
   TAccountSatiPda extends string,
   TAccountSatiCredential extends string,
   TAccountAttestation extends string,
@@ -192,6 +200,7 @@ export async function getCloseRegularAttestationInstructionAsync<
     TAccountPayer,
     TAccountSigner,
     TAccountSchemaConfig,
+    TAccountRegistryConfig,
     TAccountSatiPda,
     TAccountSatiCredential,
     TAccountAttestation,
@@ -208,6 +217,7 @@ export async function getCloseRegularAttestationInstructionAsync<
     TAccountPayer,
     TAccountSigner,
     TAccountSchemaConfig,
+    TAccountRegistryConfig,
     TAccountSatiPda,
     TAccountSatiCredential,
     TAccountAttestation,
@@ -226,6 +236,7 @@ export async function getCloseRegularAttestationInstructionAsync<
     payer: { value: input.payer ?? null, isWritable: true },
     signer: { value: input.signer ?? null, isWritable: false },
     schemaConfig: { value: input.schemaConfig ?? null, isWritable: false },
+    registryConfig: { value: input.registryConfig ?? null, isWritable: false },
     satiPda: { value: input.satiPda ?? null, isWritable: false },
     satiCredential: { value: input.satiCredential ?? null, isWritable: false },
     attestation: { value: input.attestation ?? null, isWritable: true },
@@ -241,6 +252,16 @@ export async function getCloseRegularAttestationInstructionAsync<
   >;
 
   // Resolve default values.
+  if (!accounts.registryConfig.value) {
+    accounts.registryConfig.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([114, 101, 103, 105, 115, 116, 114, 121]),
+        ),
+      ],
+    });
+  }
   if (!accounts.satiPda.value) {
     accounts.satiPda.value = await getProgramDerivedAddress({
       programAddress,
@@ -278,6 +299,7 @@ export async function getCloseRegularAttestationInstructionAsync<
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.signer),
       getAccountMeta(accounts.schemaConfig),
+      getAccountMeta(accounts.registryConfig),
       getAccountMeta(accounts.satiPda),
       getAccountMeta(accounts.satiCredential),
       getAccountMeta(accounts.attestation),
@@ -294,6 +316,7 @@ export async function getCloseRegularAttestationInstructionAsync<
     TAccountPayer,
     TAccountSigner,
     TAccountSchemaConfig,
+    TAccountRegistryConfig,
     TAccountSatiPda,
     TAccountSatiCredential,
     TAccountAttestation,
@@ -309,6 +332,7 @@ export type CloseRegularAttestationInput<
   TAccountPayer extends string = string,
   TAccountSigner extends string = string,
   TAccountSchemaConfig extends string = string,
+  TAccountRegistryConfig extends string = string,
   TAccountSatiPda extends string = string,
   TAccountSatiCredential extends string = string,
   TAccountAttestation extends string = string,
@@ -324,6 +348,8 @@ export type CloseRegularAttestationInput<
   signer: TransactionSigner<TAccountSigner>;
   /** Schema config PDA */
   schemaConfig: Address<TAccountSchemaConfig>;
+  /** Registry config - checked for the pause switch */
+  registryConfig: Address<TAccountRegistryConfig>;
   /** SATI Attestation Program PDA - authorized signer on SAS credential */
   satiPda: Address<TAccountSatiPda>;
   /** SATI SAS credential account */
@@ -348,6 +374,7 @@ export function getCloseRegularAttestationInstruction<
   TAccountPayer extends string,
   TAccountSigner extends string,
   TAccountSchemaConfig extends string,
+  TAccountRegistryConfig extends string,
   TAccountSatiPda extends string,
   TAccountSatiCredential extends string,
   TAccountAttestation extends string,
@@ -362,6 +389,7 @@ export function getCloseRegularAttestationInstruction<
     TAccountPayer,
     TAccountSigner,
     TAccountSchemaConfig,
+    TAccountRegistryConfig,
     TAccountSatiPda,
     TAccountSatiCredential,
     TAccountAttestation,
@@ -377,6 +405,7 @@ export function getCloseRegularAttestationInstruction<
   TAccountPayer,
   TAccountSigner,
   TAccountSchemaConfig,
+  TAccountRegistryConfig,
   TAccountSatiPda,
   TAccountSatiCredential,
   TAccountAttestation,
@@ -394,6 +423,7 @@ export function getCloseRegularAttestationInstruction<
     payer: { value: input.payer ?? null, isWritable: true },
     signer: { value: input.signer ?? null, isWritable: false },
     schemaConfig: { value: input.schemaConfig ?? null, isWritable: false },
+    registryConfig: { value: input.registryConfig ?? null, isWritable: false },
     satiPda: { value: input.satiPda ?? null, isWritable: false },
     satiCredential: { value: input.satiCredential ?? null, isWritable: false },
     attestation: { value: input.attestation ?? null, isWritable: true },
@@ -420,6 +450,7 @@ export function getCloseRegularAttestationInstruction<
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.signer),
       getAccountMeta(accounts.schemaConfig),
+      getAccountMeta(accounts.registryConfig),
       getAccountMeta(accounts.satiPda),
       getAccountMeta(accounts.satiCredential),
       getAccountMeta(accounts.attestation),
@@ -436,6 +467,7 @@ export function getCloseRegularAttestationInstruction<
     TAccountPayer,
     TAccountSigner,
     TAccountSchemaConfig,
+    TAccountRegistryConfig,
     TAccountSatiPda,
     TAccountSatiCredential,
     TAccountAttestation,
@@ -459,24 +491,26 @@ export type ParsedCloseRegularAttestationInstruction<
     signer: TAccountMetas[1];
     /** Schema config PDA */
     schemaConfig: TAccountMetas[2];
+    /** Registry config - checked for the pause switch */
+    registryConfig: TAccountMetas[3];
     /** SATI Attestation Program PDA - authorized signer on SAS credential */
-    satiPda: TAccountMetas[3];
+    satiPda: TAccountMetas[4];
     /** SATI SAS credential account */
-    satiCredential: TAccountMetas[4];
+    satiCredential: TAccountMetas[5];
     /** Attestation account to be closed */
-    attestation: TAccountMetas[5];
+    attestation: TAccountMetas[6];
     /** SAS program */
-    sasProgram: TAccountMetas[6];
+    sasProgram: TAccountMetas[7];
     /**
      * Optional: Agent's ATA (required if signer is NFT owner, not counterparty).
      * If provided, must hold the agent NFT (mint matches token_account from data).
      * Note: token_account in data is the MINT address; this is the holder's ATA.
      */
-    agentAta?: TAccountMetas[7] | undefined;
+    agentAta?: TAccountMetas[8] | undefined;
     /** Token-2022 program for ATA verification (optional, required with agent_ata) */
-    tokenProgram?: TAccountMetas[8] | undefined;
-    eventAuthority: TAccountMetas[9];
-    program: TAccountMetas[10];
+    tokenProgram?: TAccountMetas[9] | undefined;
+    eventAuthority: TAccountMetas[10];
+    program: TAccountMetas[11];
   };
   data: CloseRegularAttestationInstructionData;
 };
@@ -489,7 +523,7 @@ export function parseCloseRegularAttestationInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>,
 ): ParsedCloseRegularAttestationInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 11) {
+  if (instruction.accounts.length < 12) {
     // TODO: Coded error.
     throw new Error("Not enough accounts");
   }
@@ -511,6 +545,7 @@ export function parseCloseRegularAttestationInstruction<
       payer: getNextAccount(),
       signer: getNextAccount(),
       schemaConfig: getNextAccount(),
+      registryConfig: getNextAccount(),
       satiPda: getNextAccount(),
       satiCredential: getNextAccount(),
       attestation: getNextAccount(),
@@ -63,14 +63,19 @@ export type CreateCompressedAttestationInstruction<
   TProgram extends string = typeof SATI_PROGRAM_ADDRESS,
   TAccountPayer extends string | AccountMeta<string> = string,
   TAccountSchemaConfig extends string | AccountMeta<string> = string,
+  TAccountRegistryConfig extends string | AccountMeta<string> = string,
   TAccountInstructionsSysvar extends string | AccountMeta<string> =
     "Sysvar1nstructions1111111111111111111111111",
+  TAccountAgentRecord extends string | AccountMeta<string> = string,
+  TAccountAgentVerification extends string | AccountMeta<string> = string,
   TAccountAgentAta extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends string | AccountMeta<string> = string,
   TAccountDelegationAttestation extends string | AccountMeta<string> = string,
   TAccountSatiCredential extends string | AccountMeta<string> = string,
   TAccountClock extends string | AccountMeta<string> =
     "SysvarC1ock11111111111111111111111111111111",
+  TAccountAgentParent extends string | AccountMeta<string> = string,
+  TAccountParentAta extends string | AccountMeta<string> = string,
   TAccountEventAuthority extends string | AccountMeta<string> = string,
   TAccountProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
@@ -85,9 +90,18 @@ export type CreateCompressedAttestationInstruction<
       TAccountSchemaConfig extends string
         ? ReadonlyAccount<TAccountSchemaConfig>
         : TAccountSchemaConfig,
+      TAccountRegistryConfig extends string
+        ? ReadonlyAccount<TAccountRegistryConfig>
+        : TAccountRegistryConfig,
       TAccountInstructionsSysvar extends string
         ? ReadonlyAccount<TAccountInstructionsSysvar>
         : TAccountInstructionsSysvar,
+      TAccountAgentRecord extends string
+        ? ReadonlyAccount<TAccountAgentRecord>
+        : TAccountAgentRecord,
+      TAccountAgentVerification extends string
+        ? ReadonlyAccount<TAccountAgentVerification>
+        : TAccountAgentVerification,
       TAccountAgentAta extends string
         ? ReadonlyAccount<TAccountAgentAta>
         : TAccountAgentAta,
@@ -103,6 +117,12 @@ export type CreateCompressedAttestationInstruction<
       TAccountClock extends string
         ? ReadonlyAccount<TAccountClock>
         : TAccountClock,
+      TAccountAgentParent extends string
+        ? ReadonlyAccount<TAccountAgentParent>
+        : TAccountAgentParent,
+      TAccountParentAta extends string
+        ? ReadonlyAccount<TAccountParentAta>
+        : TAccountParentAta,
       TAccountEventAuthority extends string
         ? ReadonlyAccount<TAccountEventAuthority>
         : TAccountEventAuthority,
@@ -175,12 +195,17 @@ export function getCreateCompressedAttestationInstructionDataCodec(): Codec<
 export type CreateCompressedAttestationAsyncInput<
   TAccountPayer extends string = string,
   TAccountSchemaConfig extends string = string,
+  TAccountRegistryConfig extends string = string,
   TAccountInstructionsSysvar extends string = string,
+  TAccountAgentRecord extends string = string,
+  TAccountAgentVerification extends string = string,
   TAccountAgentAta extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountDelegationAttestation extends string = string,
   TAccountSatiCredential extends string = string,
   TAccountClock extends string = string,
+  TAccountAgentParent extends string = string,
+  TAccountParentAta extends string = string,
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
@@ -188,8 +213,20 @@ export type CreateCompressedAttestationAsyncInput<
   payer: TransactionSigner<TAccountPayer>;
   /** Schema config PDA */
   schemaConfig: Address<TAccountSchemaConfig>;
+  /** Registry config - checked for the pause switch */
+  registryConfig?: Address<TAccountRegistryConfig>;
   /** Instructions sysvar for Ed25519 signature verification */
   instructionsSysvar?: Address<TAccountInstructionsSysvar>;
+  /**
+   * AgentRecord PDA for token_account (`["agent", mint]`).
+   * Required in every signature mode: proves the target is an active SATI agent.
+   */
+  agentRecord: Address<TAccountAgentRecord>;
+  /**
+   * AgentVerification PDA for token_account (`["verification", mint]`).
+   * Required when schema_config.min_verification_level > 0.
+   */
+  agentVerification?: Address<TAccountAgentVerification>;
   /**
    * Agent's ATA that holds the NFT - proves signer owns the agent identity.
    * Required for DualSignature and AgentOwnerSigned modes.
@@ -221,6 +258,16 @@ export type CreateCompressedAttestationAsyncInput<
    * Required when delegation_attestation is provided.
    */
   clock?: Address<TAccountClock>;
+  /**
+   * AgentParent link for token_account (`["parent", mint]`).
+   * Lets the parent agent's holder sign for the agent in AgentOwnerSigned mode.
+   */
+  agentParent?: Address<TAccountAgentParent>;
+  /**
+   * Parent holder's token account for the parent mint.
+   * Required when agent_parent is provided.
+   */
+  parentAta?: Address<TAccountParentAta>;
   eventAuthority?: Address<TAccountEventAuthority>;
   program: Address<TAccountProgram>;
   data: CreateCompressedAttestationInstructionDataArgs["data"];
@@ -232,12 +279,17 @@ export type CreateCompressedAttestationAsyncInput<
 export async function getCreateCompressedAttestationInstructionAsync<
   TAccountPayer extends string,
   TAccountSchemaConfig extends string,
+  TAccountRegistryConfig extends string,
   TAccountInstructionsSysvar extends string,
+  TAccountAgentRecord extends string,
+  TAccountAgentVerification extends string,
   TAccountAgentAta extends string,
   TAccountTokenProgram extends string,
   TAccountDelegationAttestation extends string,
   TAccountSatiCredential extends string,
   TAccountClock extends string,
+  TAccountAgentParent extends string,
+  TAccountParentAta extends string,
   TAccountEventAuthority extends string,
   TAccountProgram extends string,
   TProgramAddress extends Address = typeof SATI_PROGRAM_ADDRESS,
@@ -245,12 +297,17 @@ export async function getCreateCompressedAttestationInstructionAsync<
   input: CreateCompressedAttestationAsyncInput<
     TAccountPayer,
     TAccountSchemaConfig,
+    TAccountRegistryConfig,
     TAccountInstructionsSysvar,
+    TAccountAgentRecord,
+    TAccountAgentVerification,
     TAccountAgentAta,
     TAccountTokenProgram,
     TAccountDelegationAttestation,
     TAccountSatiCredential,
     TAccountClock,
+    TAccountAgentParent,
+    TAccountParentAta,
     TAccountEventAuthority,
     TAccountProgram
   >,
@@ -260,12 +317,17 @@ export async function getCreateCompressedAttestationInstructionAsync<
     TProgramAddress,
     TAccountPayer,
     TAccountSchemaConfig,
+    TAccountRegistryConfig,
     TAccountInstructionsSysvar,
+    TAccountAgentRecord,
+    TAccountAgentVerification,
     TAccountAgentAta,
     TAccountTokenProgram,
     TAccountDelegationAttestation,
     TAccountSatiCredential,
     TAccountClock,
+    TAccountAgentParent,
+    TAccountParentAta,
     TAccountEventAuthority,
     TAccountProgram
   >
@@ -277,10 +339,16 @@ export async function getCreateCompressedAttestationInstructionAsync<
   const originalAccounts = {
     payer: { value: input.payer ?? null, isWritable: true },
     schemaConfig: { value: input.schemaConfig ?? null, isWritable: false },
+    registryConfig: { value: input.registryConfig ?? null, isWritable: false },
     instructionsSysvar: {
       value: input.instructionsSysvar ?? null,
       isWritable: false,
     },
+    agentRecord: { value: input.agentRecord ?? null, isWritable: false },
+    agentVerification: {
+      value: input.agentVerification ?? null,
+      isWritable: false,
+    },
     agentAta: { value: input.agentAta ?? null, isWritable: false },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     delegationAttestation: {
@@ -289,6 +357,8 @@ export async function getCreateCompressedAttestationInstructionAsync<
     },
     satiCredential: { value: input.satiCredential ?? null, isWritable: false },
     clock: { value: input.clock ?? null, isWritable: false },
+    agentParent: { value: input.agentParent ?? null, isWritable: false },
+    parentAta: { value: input.parentAta ?? null, isWritable: false },
     eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
     program: { value: input.program ?? null, isWritable: false },
   };
@@ -301,6 +371,16 @@ export async function getCreateCompressedAttestationInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
+  if (!accounts.registryConfig.value) {
+    accounts.registryConfig.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([114, 101, 103, 105, 115, 116, 114, 121]),
+        ),
+      ],
+    });
+  }
   if (!accounts.instructionsSysvar.value) {
     accounts.instructionsSysvar.value =
       "Sysvar1nstructions1111111111111111111111111" as Address<"Sysvar1nstructions1111111111111111111111111">;
@@ -328,12 +408,17 @@ export async function getCreateCompressedAttestationInstructionAsync<
     accounts: [
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.schemaConfig),
+      getAccountMeta(accounts.registryConfig),
       getAccountMeta(accounts.instructionsSysvar),
+      getAccountMeta(accounts.agentRecord),
+      getAccountMeta(accounts.agentVerification),
       getAccountMeta(accounts.agentAta),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.delegationAttestation),
       getAccountMeta(accounts.satiCredential),
       getAccountMeta(accounts.clock),
+      getAccountMeta(accounts.agentParent),
+      getAccountMeta(accounts.parentAta),
       getAccountMeta(accounts.eventAuthority),
       getAccountMeta(accounts.program),
     ],
@@ -345,12 +430,17 @@ export async function getCreateCompressedAttestationInstructionAsync<
     TProgramAddress,
     TAccountPayer,
     TAccountSchemaConfig,
+    TAccountRegistryConfig,
     TAccountInstructionsSysvar,
+    TAccountAgentRecord,
+    TAccountAgentVerification,
     TAccountAgentAta,
     TAccountTokenProgram,
     TAccountDelegationAttestation,
     TAccountSatiCredential,
     TAccountClock,
+    TAccountAgentParent,
+    TAccountParentAta,
     TAccountEventAuthority,
     TAccountProgram
   >);
@@ -359,12 +449,17 @@ export async function getCreateCompressedAttestationInstructionAsync<
 export type CreateCompressedAttestationInput<
   TAccountPayer extends string = string,
   TAccountSchemaConfig extends string = string,
+  TAccountRegistryConfig extends string = string,
   TAccountInstructionsSysvar extends string = string,
+  TAccountAgentRecord extends string = string,
+  TAccountAgentVerification extends string = string,
   TAccountAgentAta extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountDelegationAttestation extends string = string,
   TAccountSatiCredential extends string = string,
   TAccountClock extends string = string,
+  TAccountAgentParent extends string = string,
+  TAccountParentAta extends string = string,
   TAccountEventAuthority extends string = string,
   TAccountProgram extends string = string,
 > = {
@@ -372,8 +467,20 @@ export type CreateCompressedAttestationInput<
   payer: TransactionSigner<TAccountPayer>;
   /** Schema config PDA */
   schemaConfig: Address<TAccountSchemaConfig>;
+  /** Registry config - checked for the pause switch */
+  registryConfig: Address<TAccountRegistryConfig>;
   /** Instructions sysvar for Ed25519 signature verification */
   instructionsSysvar?: Address<TAccountInstructionsSysvar>;
+  /**
+   * AgentRecord PDA for token_account (`["agent", mint]`).
+   * Required in every signature mode: proves the target is an active SATI agent.
+   */
+  agentRecord: Address<TAccountAgentRecord>;
+  /**
+   * AgentVerification PDA for token_account (`["verification", mint]`).
+   * Required when schema_config.min_verification_level > 0.
+   */
+  agentVerification?: Address<TAccountAgentVerification>;
   /**
    * Agent's ATA that holds the NFT - proves signer owns the agent identity.
    * Required for DualSignature and AgentOwnerSigned modes.
@@ -405,6 +512,16 @@ export type CreateCompressedAttestationInput<
    * Required when delegation_attestation is provided.
    */
   clock?: Address<TAccountClock>;
+  /**
+   * AgentParent link for token_account (`["parent", mint]`).
+   * Lets the parent agent's holder sign for the agent in AgentOwnerSigned mode.
+   */
+  agentParent?: Address<TAccountAgentParent>;
+  /**
+   * Parent holder's token account for the parent mint.
+   * Required when agent_parent is provided.
+   */
+  parentAta?: Address<TAccountParentAta>;
   eventAuthority: Address<TAccountEventAuthority>;
   program: Address<TAccountProgram>;
   data: CreateCompressedAttestationInstructionDataArgs["data"];
@@ -416,12 +533,17 @@ export type CreateCompressedAttestationInput<
 export function getCreateCompressedAttestationInstruction<
   TAccountPayer extends string,
   TAccountSchemaConfig extends string,
+  TAccountRegistryConfig extends string,
   TAccountInstructionsSysvar extends string,
+  TAccountAgentRecord extends string,
+  TAccountAgentVerification extends string,
   TAccountAgentAta extends string,
   TAccountTokenProgram extends string,
   TAccountDelegationAttestation extends string,
   TAccountSatiCredential extends string,
   TAccountClock extends string,
+  TAccountAgentParent extends string,
+  TAccountParentAta extends string,
   TAccountEventAuthority extends string,
   TAccountProgram extends string,
   TProgramAddress extends Address = typeof SATI_PROGRAM_ADDRESS,
@@ -429,12 +551,17 @@ export function getCreateCompressedAttestationInstruction<
   input: CreateCompressedAttestationInput<
     TAccountPayer,
     TAccountSchemaConfig,
+    TAccountRegistryConfig,
     TAccountInstructionsSysvar,
+    TAccountAgentRecord,
+    TAccountAgentVerification,
     TAccountAgentAta,
     TAccountTokenProgram,
     TAccountDelegationAttestation,
     TAccountSatiCredential,
     TAccountClock,
+    TAccountAgentParent,
+    TAccountParentAta,
     TAccountEventAuthority,
     TAccountProgram
   >,
@@ -443,12 +570,17 @@ export function getCreateCompressedAttestationInstruction<
   TProgramAddress,
   TAccountPayer,
   TAccountSchemaConfig,
+  TAccountRegistryConfig,
   TAccountInstructionsSysvar,
+  TAccountAgentRecord,
+  TAccountAgentVerification,
   TAccountAgentAta,
   TAccountTokenProgram,
   TAccountDelegationAttestation,
   TAccountSatiCredential,
   TAccountClock,
+  TAccountAgentParent,
+  TAccountParentAta,
   TAccountEventAuthority,
   TAccountProgram
 > {
@@ -459,10 +591,16 @@ export function getCreateCompressedAttestationInstruction<
   const originalAccounts = {
     payer: { value: input.payer ?? null, isWritable: true },
     schemaConfig: { value: input.schemaConfig ?? null, isWritable: false },
+    registryConfig: { value: input.registryConfig ?? null, isWritable: false },
     instructionsSysvar: {
       value: input.instructionsSysvar ?? null,
       isWritable: false,
     },
+    agentRecord: { value: input.agentRecord ?? null, isWritable: false },
+    agentVerification: {
+      value: input.agentVerification ?? null,
+      isWritable: false,
+    },
     agentAta: { value: input.agentAta ?? null, isWritable: false },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     delegationAttestation: {
@@ -471,6 +609,8 @@ export function getCreateCompressedAttestationInstruction<
     },
     satiCredential: { value: input.satiCredential ?? null, isWritable: false },
     clock: { value: input.clock ?? null, isWritable: false },
+    agentParent: { value: input.agentParent ?? null, isWritable: false },
+    parentAta: { value: input.parentAta ?? null, isWritable: false },
     eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
     program: { value: input.program ?? null, isWritable: false },
   };
@@ -497,12 +637,17 @@ export function getCreateCompressedAttestationInstruction<
     accounts: [
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.schemaConfig),
+      getAccountMeta(accounts.registryConfig),
       getAccountMeta(accounts.instructionsSysvar),
+      getAccountMeta(accounts.agentRecord),
+      getAccountMeta(accounts.agentVerification),
       getAccountMeta(accounts.agentAta),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.delegationAttestation),
       getAccountMeta(accounts.satiCredential),
       getAccountMeta(accounts.clock),
+      getAccountMeta(accounts.agentParent),
+      getAccountMeta(accounts.parentAta),
       getAccountMeta(accounts.eventAuthority),
       getAccountMeta(accounts.program),
     ],
@@ -514,12 +659,17 @@ export function getCreateCompressedAttestationInstruction<
     TProgramAddress,
     TAccountPayer,
     TAccountSchemaConfig,
+    TAccountRegistryConfig,
     TAccountInstructionsSysvar,
+    TAccountAgentRecord,
+    TAccountAgentVerification,
     TAccountAgentAta,
     TAccountTokenProgram,
     TAccountDelegationAttestation,
     TAccountSatiCredential,
     TAccountClock,
+    TAccountAgentParent,
+    TAccountParentAta,
     TAccountEventAuthority,
     TAccountProgram
   >);
@@ -535,8 +685,20 @@ export type ParsedCreateCompressedAttestationInstruction<
     payer: TAccountMetas[0];
     /** Schema config PDA */
     schemaConfig: TAccountMetas[1];
+    /** Registry config - checked for the pause switch */
+    registryConfig: TAccountMetas[2];
     /** Instructions sysvar for Ed25519 signature verification */
-    instructionsSysvar: TAccountMetas[2];
+    instructionsSysvar: TAccountMetas[3];
+    /**
+     * AgentRecord PDA for token_account (`["agent", mint]`).
+     * Required in every signature mode: proves the target is an active SATI agent.
+     */
+    agentRecord: TAccountMetas[4];
+    /**
+     * AgentVerification PDA for token_account (`["verification", mint]`).
+     * Required when schema_config.min_verification_level > 0.
+     */
+    agentVerification?: TAccountMetas[5] | undefined;
     /**
      * Agent's ATA that holds the NFT - proves signer owns the agent identity.
      * Required for DualSignature and AgentOwnerSigned modes.
@@ -546,30 +708,40 @@ export type ParsedCreateCompressedAttestationInstruction<
      * amount must be >= 1, and owner must match signatures[0].pubkey.
      * Note: token_account in data is the MINT address; this is the holder's ATA.
      */
-    agentAta?: TAccountMetas[3] | undefined;
+    agentAta?: TAccountMetas[6] | undefined;
     /**
      * Token-2022 program for ATA verification.
      * Required when agent_ata is provided.
      */
-    tokenProgram?: TAccountMetas[4] | undefined;
+    tokenProgram?: TAccountMetas[7] | undefined;
     /**
      * Delegation attestation (optional).
      * Required when signer != agent ATA owner for AgentOwnerSigned mode.
      * Must be a valid DelegateV1 SAS attestation proving the signer's delegation.
      */
-    delegationAttestation?: TAccountMetas[5] | undefined;
+    delegationAttestation?: TAccountMetas[8] | undefined;
     /**
      * SATI SAS credential for delegation PDA derivation.
      * Required when delegation_attestation is provided.
      */
-    satiCredential?: TAccountMetas[6] | undefined;
+    satiCredential?: TAccountMetas[9] | undefined;
     /**
      * Clock sysvar for delegation expiry verification.
      * Required when delegation_attestation is provided.
      */
-    clock?: TAccountMetas[7] | undefined;
-    eventAuthority: TAccountMetas[8];
-    program: TAccountMetas[9];
+    clock?: TAccountMetas[10] | undefined;
+    /**
+     * AgentParent link for token_account (`["parent", mint]`).
+     * Lets the parent agent's holder sign for the agent in AgentOwnerSigned mode.
+     */
+    agentParent?: TAccountMetas[11] | undefined;
+    /**
+     * Parent holder's token account for the parent mint.
+     * Required when agent_parent is provided.
+     */
+    parentAta?: TAccountMetas[12] | undefined;
+    eventAuthority: TAccountMetas[13];
+    program: TAccountMetas[14];
   };
   data: CreateCompressedAttestationInstructionData;
 };
@@ -582,7 +754,7 @@ export function parseCreateCompressedAttestationInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>,
 ): ParsedCreateCompressedAttestationInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 10) {
+  if (instruction.accounts.length < 15) {
     // TODO: Coded error.
     throw new Error("Not enough accounts");
   }
@@ -603,12 +775,17 @@ export function parseCreateCompressedAttestationInstruction<
     accounts: {
       payer: getNextAccount(),
       schemaConfig: getNextAccount(),
+      registryConfig: getNextAccount(),
       instructionsSysvar: getNextAccount(),
+      agentRecord: getNextAccount(),
+      agentVerification: getNextOptionalAccount(),
       agentAta: getNextOptionalAccount(),
       tokenProgram: getNextOptionalAccount(),
       delegationAttestation: getNextOptionalAccount(),
       satiCredential: getNextOptionalAccount(),
       clock: getNextOptionalAccount(),
+      agentParent: getNextOptionalAccount(),
+      parentAta: getNextOptionalAccount(),
       eventAuthority: getNextAccount(),
       program: getNextAccount(),
     },
@@ -54,17 +54,22 @@ export type CreateRegularAttestationInstruction<
   TProgram extends string = typeof SATI_PROGRAM_ADDRESS,
   TAccountPayer extends string | AccountMeta<string> = string,
   TAccountSchemaConfig extends string | AccountMeta<string> = string,
+  TAccountRegistryConfig extends string | AccountMeta<string> = string,
   TAccountSatiPda extends string | AccountMeta<string> = string,
   TAccountSatiCredential extends string | AccountMeta<string> = string,
   TAccountSasSchema extends string | AccountMeta<string> = string,
   TAccountAttestation extends string | AccountMeta<string> = string,
   TAccountInstructionsSysvar extends string | AccountMeta<string> =
     "Sysvar1nstructions1111111111111111111111111",
+  TAccountAgentRecord extends string | AccountMeta<string> = string,
+  TAccountAgentVerification extends string | AccountMeta<string> = string,
   TAccountAgentAta extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends string | AccountMeta<string> = string,
   TAccountDelegationAttestation extends string | AccountMeta<string> = string,
   TAccountClock extends string | AccountMeta<string> =
     "SysvarC1ock11111111111111111111111111111111",
+  TAccountAgentParent extends string | AccountMeta<string> = string,
+  TAccountParentAta extends string | AccountMeta<string> = string,
   TAccountSasProgram extends string | AccountMeta<string> =
     "22zoJMtdu4tQc2PzL74ZUT7FrwgB1Udec8DdW4yw4BdG",
   TAccountSystemProgram extends string | AccountMeta<string> =
@@ -83,6 +88,9 @@ export type CreateRegularAttestationInstruction<
       TAccountSchemaConfig extends string
         ? ReadonlyAccount<TAccountSchemaConfig>
         : TAccountSchemaConfig,
+      TAccountRegistryConfig extends string
+        ? ReadonlyAccount<TAccountRegistryConfig>
+        : TAccountRegistryConfig,
       TAccountSatiPda extends string
         ? ReadonlyAccount<TAccountSatiPda>
         : TAccountSatiPda,
@@ -98,6 +106,12 @@ export type CreateRegularAttestationInstruction<
       TAccountInstructionsSysvar extends string
         ? ReadonlyAccount<TAccountInstructionsSysvar>
         : TAccountInstructionsSysvar,
+      TAccountAgentRecord extends string
+        ? ReadonlyAccount<TAccountAgentRecord>
+        : TAccountAgentRecord,
+      TAccountAgentVerification extends string
+        ? ReadonlyAccount<TAccountAgentVerification>
+        : TAccountAgentVerification,
       TAccountAgentAta extends string
         ? ReadonlyAccount<TAccountAgentAta>
         : TAccountAgentAta,
@@ -110,6 +124,12 @@ export type CreateRegularAttestationInstruction<
       TAccountClock extends string
         ? ReadonlyAccount<TAccountClock>
         : TAccountClock,
+      TAccountAgentParent extends string
+        ? ReadonlyAccount<TAccountAgentParent>
+        : TAccountAgentParent,
+      TAccountParentAta extends string
+        ? ReadonlyAccount<TAccountParentAta>
+        : TAccountParentAta,
       TAccountSasProgram extends string
         ? ReadonlyAccount<TAccountSasProgram>
         : TAccountSasProgram,
@@ -176,15 +196,20 @@ export function getCreateRegularAttestationInstructionDataCodec(): Codec<
 export type CreateRegularAttestationAsyncInput<
   TAccountPayer extends string = string,
   TAccountSchemaConfig extends string = string,
+  TAccountRegistryConfig extends string = string,
   TAccountSatiPda extends string = string,
   TAccountSatiCredential extends string = string,
   TAccountSasSchema extends string = string,
   TAccountAttestation extends string = string,
   TAccountInstructionsSysvar extends string = string,
+  TAccountAgentRecord extends string = string,
+  TAccountAgentVerification extends string = string,
   TAccountAgentAta extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountDelegationAttestation extends string = string,
   TAccountClock extends string = string,
+  TAccountAgentParent extends string = string,
+  TAccountParentAta extends string = string,
   TAccountSasProgram extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountEventAuthority extends string = string,
@@ -194,6 +219,8 @@ export type CreateRegularAttestationAsyncInput<
   payer: TransactionSigner<TAccountPayer>;
   /** Schema config PDA */
   schemaConfig: Address<TAccountSchemaConfig>;
+  /** Registry config - checked for the pause switch */
+  registryConfig?: Address<TAccountRegistryConfig>;
   /** SATI Attestation Program PDA - authorized signer on SAS credential */
   satiPda?: Address<TAccountSatiPda>;
   /** SATI SAS credential account */
@@ -204,6 +231,16 @@ export type CreateRegularAttestationAsyncInput<
   attestation: Address<TAccountAttestation>;
   /** Instructions sysvar for Ed25519 signature verification */
   instructionsSysvar?: Address<TAccountInstructionsSysvar>;
+  /**
+   * AgentRecord PDA for token_account (`["agent", mint]`).
+   * Required in every signature mode: proves the target is an active SATI agent.
+   */
+  agentRecord: Address<TAccountAgentRecord>;
+  /**
+   * AgentVerification PDA for token_account (`["verification", mint]`).
+   * Required when schema_config.min_verification_level > 0.
+   */
+  agentVerification?: Address<TAccountAgentVerification>;
   /**
    * Agent's ATA that holds the NFT - proves signer owns the agent identity.
    * Required for AgentOwnerSigned mode (DelegateV1).
@@ -226,6 +263,16 @@ export type CreateRegularAttestationAsyncInput<
    * Required when delegation_attestation is provided.
    */
   clock?: Address<TAccountClock>;
+  /**
+   * AgentParent link for token_account (`["parent", mint]`).
+   * Lets the parent agent's holder sign for the agent in AgentOwnerSigned mode.
+   */
+  agentParent?: Address<TAccountAgentParent>;
+  /**
+   * Parent holder's token account for the parent mint.
+   * Required when agent_parent is provided.
+   */
+  parentAta?: Address<TAccountParentAta>;
   /** SAS program */
   sasProgram?: Address<TAccountSasProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
@@ -238,15 +285,20 @@ export type CreateRegularAttestationAsyncInput<
 export async function getCreateRegularAttestationInstructionAsync<
   TAccountPayer extends string,
   TAccountSchemaConfig extends string,
+  TAccountRegistryConfig extends string,
   TAccountSatiPda extends string,
   TAccountSatiCredential extends string,
   TAccountSasSchema extends string,
   TAccountAttestation extends string,
   TAccountInstructionsSysvar extends string,
+  TAccountAgentRecord extends string,
+  TAccountAgentVerification extends string,
   TAccountAgentAta extends string,
   TAccountTokenProgram extends string,
   TAccountDelegationAttestation extends string,
   TAccountClock extends string,
+  TAccountAgentParent extends string,
+  TAccountParentAta extends string,
   TAccountSasProgram extends string,
   TAccountSystemProgram extends string,
   TAccountEventAuthority extends string,
@@ -256,15 +308,20 @@ export async function getCreateRegularAttestationInstructionAsync<
   input: CreateRegularAttestationAsyncInput<
     TAccountPayer,
     TAccountSchemaConfig,
+    TAccountRegistryConfig,
     TAccountSatiPda,
     TAccountSatiCredential,
     TAccountSasSchema,
     TAccountAttestation,
     TAccountInstructionsSysvar,
+    TAccountAgentRecord,
+    TAccountAgentVerification,
     TAccountAgentAta,
     TAccountTokenProgram,
     TAccountDelegationAttestation,
     TAccountClock,
+    TAccountAgentParent,
+    TAccountParentAta,
     TAccountSasProgram,
     TAccountSystemProgram,
     TAccountEventAuthority,
@@ -276,15 +333,20 @@ export async function getCreateRegularAttestationInstructionAsync<
     TProgramAddress,
     TAccountPayer,
     TAccountSchemaConfig,
+    TAccountRegistryConfig,
     TAccountSatiPda,
     TAccountSatiCredential,
     TAccountSasSchema,
     TAccountAttestation,
     TAccountInstructionsSysvar,
+    TAccountAgentRecord,
+    TAccountAgentVerification,
     TAccountAgentAta,
     TAccountTokenProgram,
     TAccountDelegationAttestation,
     TAccountClock,
+    TAccountAgentParent,
+    TAccountParentAta,
     TAccountSasProgram,
     TAccountSystemProgram,
     TAccountEventAuthority,
@@ -298,6 +360,7 @@ export async function getCreateRegularAttestationInstructionAsync<
   const originalAccounts = {
     payer: { value: input.payer ?? null, isWritable: true },
     schemaConfig: { value: input.schemaConfig ?? null, isWritable: false },
+    registryConfig: { value: input.registryConfig ?? null, isWritable: false },
     satiPda: { value: input.satiPda ?? null, isWritable: false },
     satiCredential: { value: input.satiCredential ?? null, isWritable: false },
     sasSchema: { value: input.sasSchema ?? null, isWritable: false },
@@ -306,6 +369,11 @@ export async function getCreateRegularAttestationInstructionAsync<
       value: input.instructionsSysvar ?? null,
       isWritable: false,
     },
+    agentRecord: { value: input.agentRecord ?? null, isWritable: false },
+    agentVerification: {
+      value: input.agentVerification ?? null,
+      isWritable: false,
+    },
     agentAta: { value: input.agentAta ?? null, isWritable: false },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     delegationAttestation: {
@@ -313,6 +381,8 @@ export async function getCreateRegularAttestationInstructionAsync<
       isWritable: false,
     },
     clock: { value: input.clock ?? null, isWritable: false },
+    agentParent: { value: input.agentParent ?? null, isWritable: false },
+    parentAta: { value: input.parentAta ?? null, isWritable: false },
     sasProgram: { value: input.sasProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
@@ -327,6 +397,16 @@ export async function getCreateRegularAttestationInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
+  if (!accounts.registryConfig.value) {
+    accounts.registryConfig.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([114, 101, 103, 105, 115, 116, 114, 121]),
+        ),
+      ],
+    });
+  }
   if (!accounts.satiPda.value) {
     accounts.satiPda.value = await getProgramDerivedAddress({
       programAddress,
@@ -375,15 +455,20 @@ export async function getCreateRegularAttestationInstructionAsync<
     accounts: [
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.schemaConfig),
+      getAccountMeta(accounts.registryConfig),
       getAccountMeta(accounts.satiPda),
       getAccountMeta(accounts.satiCredential),
       getAccountMeta(accounts.sasSchema),
       getAccountMeta(accounts.attestation),
       getAccountMeta(accounts.instructionsSysvar),
+      getAccountMeta(accounts.agentRecord),
+      getAccountMeta(accounts.agentVerification),
       getAccountMeta(accounts.agentAta),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.delegationAttestation),
       getAccountMeta(accounts.clock),
+      getAccountMeta(accounts.agentParent),
+      getAccountMeta(accounts.parentAta),
       getAccountMeta(accounts.sasProgram),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.eventAuthority),
@@ -397,15 +482,20 @@ export async function getCreateRegularAttestationInstructionAsync<
     TProgramAddress,
     TAccountPayer,
     TAccountSchemaConfig,
+    TAccountRegistryConfig,
     TAccountSatiPda,
     TAccountSatiCredential,
     TAccountSasSchema,
     TAccountAttestation,
     TAccountInstructionsSysvar,
+    TAccountAgentRecord,
+    TAccountAgentVerification,
     TAccountAgentAta,
     TAccountTokenProgram,
     TAccountDelegationAttestation,
     TAccountClock,
+    TAccountAgentParent,
+    TAccountParentAta,
     TAccountSasProgram,
     TAccountSystemProgram,
     TAccountEventAuthority,
@@ -416,15 +506,20 @@ export async function getCreateRegularAttestationInstructionAsync<
 export type CreateRegularAttestationInput<
   TAccountPayer extends string = string,
   TAccountSchemaConfig extends string = string,
+  TAccountRegistryConfig extends string = string,
   TAccountSatiPda extends string = string,
   TAccountSatiCredential extends string = string,
   TAccountSasSchema extends string = string,
   TAccountAttestation extends string = string,
   TAccountInstructionsSysvar extends string = string,
+  TAccountAgentRecord extends string = string,
+  TAccountAgentVerification extends string = string,
   TAccountAgentAta extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountDelegationAttestation extends string = string,
   TAccountClock extends string = string,
+  TAccountAgentParent extends string = string,
+  TAccountParentAta extends string = string,
   TAccountSasProgram extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountEventAuthority extends string = string,
@@ -434,6 +529,8 @@ export type CreateRegularAttestationInput<
   payer: TransactionSigner<TAccountPayer>;
   /** Schema config PDA */
   schemaConfig: Address<TAccountSchemaConfig>;
+  /** Registry config - checked for the pause switch */
+  registryConfig: Address<TAccountRegistryConfig>;
   /** SATI Attestation Program PDA - authorized signer on SAS credential */
   satiPda: Address<TAccountSatiPda>;
   /** SATI SAS credential account */
@@ -444,6 +541,16 @@ export type CreateRegularAttestationInput<
   attestation: Address<TAccountAttestation>;
   /** Instructions sysvar for Ed25519 signature verification */
   instructionsSysvar?: Address<TAccountInstructionsSysvar>;
+  /**
+   * AgentRecord PDA for token_account (`["agent", mint]`).
+   * Required in every signature mode: proves the target is an active SATI agent.
+   */
+  agentRecord: Address<TAccountAgentRecord>;
+  /**
+   * AgentVerification PDA for token_account (`["verification", mint]`).
+   * Required when schema_config.min_verification_level > 0.
+   */
+  agentVerification?: Address<TAccountAgentVerification>;
   /**
    * Agent's ATA that holds the NFT - proves signer owns the agent identity.
    * Required for AgentOwnerSigned mode (DelegateV1).
@@ -466,6 +573,16 @@ export type CreateRegularAttestationInput<
    * Required when delegation_attestation is provided.
    */
   clock?: Address<TAccountClock>;
+  /**
+   * AgentParent link for token_account (`["parent", mint]`).
+   * Lets the parent agent's holder sign for the agent in AgentOwnerSigned mode.
+   */
+  agentParent?: Address<TAccountAgentParent>;
+  /**
+   * Parent holder's token account for the parent mint.
+   * Required when agent_parent is provided.
+   */
+  parentAta?: Address<TAccountParentAta>;
   /** SAS program */
   sasProgram?: Address<TAccountSasProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
@@ -478,15 +595,20 @@ export type CreateRegularAttestationInput<
 export function getCreateRegularAttestationInstruction<
   TAccountPayer extends string,
   TAccountSchemaConfig extends string,
+  TAccountRegistryConfig extends string,
   TAccountSatiPda extends string,
   TAccountSatiCredential extends string,
   TAccountSasSchema extends string,
   TAccountAttestation extends string,
   TAccountInstructionsSysvar extends string,
+  TAccountAgentRecord extends string,
+  TAccountAgentVerification extends string,
   TAccountAgentAta extends string,
   TAccountTokenProgram extends string,
   TAccountDelegationAttestation extends string,
   TAccountClock extends string,
+  TAccountAgentParent extends string,
+  TAccountParentAta extends string,
   TAccountSasProgram extends string,
   TAccountSystemProgram extends string,
   TAccountEventAuthority extends string,
@@ -496,15 +618,20 @@ export function getCreateRegularAttestationInstruction<
   input: CreateRegularAttestationInput<
     TAccountPayer,
     TAccountSchemaConfig,
+    TAccountRegistryConfig,
     TAccountSatiPda,
     TAccountSatiCredential,
     TAccountSasSchema,
     TAccountAttestation,
     TAccountInstructionsSysvar,
+    TAccountAgentRecord,
+    TAccountAgentVerification,
     TAccountAgentAta,
     TAccountTokenProgram,
     TAccountDelegationAttestation,
     TAccountClock,
+    TAccountAgentParent,
+    TAccountParentAta,
     TAccountSasProgram,
     TAccountSystemProgram,
     TAccountEventAuthority,
@@ -515,15 +642,20 @@ export function getCreateRegularAttestationInstruction<
   TProgramAddress,
   TAccountPayer,
   TAccountSchemaConfig,
+  TAccountRegistryConfig,
   TAccountSatiPda,
   TAccountSatiCredential,
   TAccountSasSchema,
   TAccountAttestation,
   TAccountInstructionsSysvar,
+  TAccountAgentRecord,
+  TAccountAgentVerification,
   TAccountAgentAta,
   TAccountTokenProgram,
   TAccountDelegationAttestation,
   TAccountClock,
+  TAccountAgentParent,
+  TAccountParentAta,
   TAccountSasProgram,
   TAccountSystemProgram,
   TAccountEventAuthority,
@@ -536,6 +668,7 @@ export function getCreateRegularAttestationInstruction<
   const originalAccounts = {
     payer: { value: input.payer ?? null, isWritable: true },
     schemaConfig: { value: input.schemaConfig ?? null, isWritable: false },
+    registryConfig: { value: input.registryConfig ?? null, isWritable: false },
     satiPda: { value: input.satiPda ?? null, isWritable: false },
     satiCredential: { value: input.satiCredential ?? null, isWritable: false },
     sasSchema: { value: input.sasSchema ?? null, isWritable: false },
@@ -544,6 +677,11 @@ export function getCreateRegularAttestationInstruction<
       value: input.instructionsSysvar ?? null,
       isWritable: false,
     },
+    agentRecord: { value: input.agentRecord ?? null, isWritable: false },
+    agentVerification: {
+      value: input.agentVerification ?? null,
+      isWritable: false,
+    },
     agentAta: { value: input.agentAta ?? null, isWritable: false },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     delegationAttestation: {
@@ -551,6 +689,8 @@ export function getCreateRegularAttestationInstruction<
       isWritable: false,
     },
     clock: { value: input.clock ?? null, isWritable: false },
+    agentParent: { value: input.agentParent ?? null, isWritable: false },
+    parentAta: { value: input.parentAta ?? null, isWritable: false },
     sasProgram: { value: input.sasProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
@@ -587,15 +727,20 @@ export function getCreateRegularAttestationInstruction<
     accounts: [
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.schemaConfig),
+      getAccountMeta(accounts.registryConfig),
       getAccountMeta(accounts.satiPda),
       getAccountMeta(accounts.satiCredential),
       getAccountMeta(accounts.sasSchema),
       getAccountMeta(accounts.attestation),
       getAccountMeta(accounts.instructionsSysvar),
+      getAccountMeta(accounts.agentRecord),
+      getAccountMeta(accounts.agentVerification),
       getAccountMeta(accounts.agentAta),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.delegationAttestation),
       getAccountMeta(accounts.clock),
+      getAccountMeta(accounts.agentParent),
+      getAccountMeta(accounts.parentAta),
       getAccountMeta(accounts.sasProgram),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.eventAuthority),
@@ -609,15 +754,20 @@ export function getCreateRegularAttestationInstruction<
     TProgramAddress,
     TAccountPayer,
     TAccountSchemaConfig,
+    TAccountRegistryConfig,
     TAccountSatiPda,
     TAccountSatiCredential,
     TAccountSasSchema,
     TAccountAttestation,
     TAccountInstructionsSysvar,
+    TAccountAgentRecord,
+    TAccountAgentVerification,
     TAccountAgentAta,
     TAccountTokenProgram,
     TAccountDelegationAttestation,
     TAccountClock,
+    TAccountAgentParent,
+    TAccountParentAta,
     TAccountSasProgram,
     TAccountSystemProgram,
     TAccountEventAuthority,
@@ -635,43 +785,65 @@ export type ParsedCreateRegularAttestationInstruction<
     payer: TAccountMetas[0];
     /** Schema config PDA */
     schemaConfig: TAccountMetas[1];
+    /** Registry config - checked for the pause switch */
+    registryConfig: TAccountMetas[2];
     /** SATI Attestation Program PDA - authorized signer on SAS credential */
-    satiPda: TAccountMetas[2];
+    satiPda: TAccountMetas[3];
     /** SATI SAS credential account */
-    satiCredential: TAccountMetas[3];
+    satiCredential: TAccountMetas[4];
     /** SAS schema account */
-    sasSchema: TAccountMetas[4];
+    sasSchema: TAccountMetas[5];
     /** Attestation PDA to be created */
-    attestation: TAccountMetas[5];
+    attestation: TAccountMetas[6];
     /** Instructions sysvar for Ed25519 signature verification */
-    instructionsSysvar: TAccountMetas[6];
+    instructionsSysvar: TAccountMetas[7];
+    /**
+     * AgentRecord PDA for token_account (`["agent", mint]`).
+     * Required in every signature mode: proves the target is an active SATI agent.
+     */
+    agentRecord: TAccountMetas[8];
+    /**
+     * AgentVerification PDA for token_account (`["verification", mint]`).
+     * Required when schema_config.min_verification_level > 0.
+     */
+    agentVerification?: TAccountMetas[9] | undefined;
     /**
      * Agent's ATA that holds the NFT - proves signer owns the agent identity.
      * Required for AgentOwnerSigned mode (DelegateV1).
      * Optional for CounterpartySigned mode (ReputationScore).
      */
-    agentAta?: TAccountMetas[7] | undefined;
+    agentAta?: TAccountMetas[10] | undefined;
     /**
      * Token-2022 program for ATA verification.
      * Required when agent_ata is provided.
      */
-    tokenProgram?: TAccountMetas[8] | undefined;
+    tokenProgram?: TAccountMetas[11] | undefined;
     /**
      * Delegation attestation (optional).
      * Required when signer != agent ATA owner for AgentOwnerSigned mode.
      * Must be a valid DelegateV1 SAS attestation proving the signer's delegation.
      */
-    delegationAttestation?: TAccountMetas[9] | undefined;
+    delegationAttestation?: TAccountMetas[12] | undefined;
     /**
      * Clock sysvar for delegation expiry verification.
      * Required when delegation_attestation is provided.
      */
-    clock?: TAccountMetas[10] | undefined;
+    clock?: TAccountMetas[13] | undefined;
+    /**
+     * AgentParent link for token_account (`["parent", mint]`).
+     * Lets the parent agent's holder sign for the agent in AgentOwnerSigned mode.
+     */
+    agentParent?: TAccountMetas[14] | undefined;
+    /**
+     * Parent holder's token account for the parent mint.
+     * Required when agent_parent is provided.
+     */
+    parentAta?: TAccountMetas[15] | undefined;
     /** SAS program */
-    sasProgram: TAccountMetas[11];
-    systemProgram: TAccountMetas[12];
-    eventAuthority: TAccountMetas[13];
-    program: TAccountMetas[14];
+    sasProgram: TAccountMetas[16];
+    systemProgram: TAccountMetas[17];
+    eventAuthority: TAccountMetas[18];
+    program: TAccountMetas[19];
   };
   data: CreateRegularAttestationInstructionData;
 };
@@ -684,7 +856,7 @@ export function parseCreateRegularAttestationInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>,
 ): ParsedCreateRegularAttestationInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 15) {
+  if (instruction.accounts.length < 20) {
     // TODO: Coded error.
     throw new Error("Not enough accounts");
   }
@@ -705,15 +877,20 @@ export function parseCreateRegularAttestationInstruction<
     accounts: {
       payer: getNextAccount(),
       schemaConfig: getNextAccount(),
+      registryConfig: getNextAccount(),
       satiPda: getNextAccount(),
       satiCredential: getNextAccount(),
       sasSchema: getNextAccount(),
       attestation: getNextAccount(),
       instructionsSysvar: getNextAccount(),
+      agentRecord: getNextAccount(),
+      agentVerification: getNextOptionalAccount(),
       agentAta: getNextOptionalAccount(),
       tokenProgram: getNextOptionalAccount(),
       delegationAttestation: getNextOptionalAccount(),
       clock: getNextOptionalAccount(),
+      agentParent: getNextOptionalAccount(),
+      parentAta: getNextOptionalAccount(),
       sasProgram: getNextAccount(),
       systemProgram: getNextAccount(),
       eventAuthority: getNextAccount(),
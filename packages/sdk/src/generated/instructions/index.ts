@@ -6,12 +6,49 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from "./acceptRegistryAuthority";
+export * from "./backfillAgentRecord";
+export * from "./cancelGovernanceAction";
+export * from "./cancelRegistryAuthorityTransfer";
+export * from "./claimAgentHandle";
 export * from "./closeCompressedAttestation";
 export * from "./closeRegularAttestation";
 export * from "./createCompressedAttestation";
 export * from "./createRegularAttestation";
+export * from "./detachParentAgent";
+export * from "./executeGovernanceAction";
 export * from "./initialize";
 export * from "./linkEvmAddress";
+export * from "./linkExternalIdentity";
+export * from "./linkSnsDomain";
+export * from "./migrateRegistryConfig";
+export * from "./migrateSchemaConfig";
+export * from "./pauseRegistry";
+export * from "./proposeRegistryAuthority";
+export * from "./queueGovernanceAction";
 export * from "./registerAgent";
+export * from "./registerAgentsBatch";
 export * from "./registerSchemaConfig";
+export * from "./releaseAgentHandle";
+export * from "./retireAgent";
+export * from "./revokeAgentVerification";
+export * from "./setAgentServices";
+export * from "./setAgentStatus";
+export * from "./setAgentVerification";
+export * from "./setParentAgent";
+export * from "./setRegistrationFee";
+export * from "./setSchemaMinVerification";
+export * from "./setSuccessor";
+export * from "./setTimelockDelay";
+export * from "./suspendAgent";
+export * from "./syncAgentAuthority";
+export * from "./unlinkEvmAddress";
+export * from "./unlinkSnsDomain";
+export * from "./unpauseRegistry";
+export * from "./unsuspendAgent";
+export * from "./updateAgentMetadata";
+export * from "./updateCompressedAttestation";
 export * from "./updateRegistryAuthority";
+export * from "./updateRegularAttestation";
+export * from "./updateRegularAttestationsBatch";
+export * from "./withdrawTreasury";
@@ -15,6 +15,10 @@ import {
   getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getI64Decoder,
+  getI64Encoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
@@ -34,10 +38,13 @@ import {
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
-  type ReadonlySignerAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
 } from "@solana/kit";
 import { SATI_PROGRAM_ADDRESS } from "../programs";
 import {
@@ -45,6 +52,12 @@ import {
   getAccountMetaFactory,
   type ResolvedAccount,
 } from "../shared";
+import {
+  getEvmLinkFormatDecoder,
+  getEvmLinkFormatEncoder,
+  type EvmLinkFormat,
+  type EvmLinkFormatArgs,
+} from "../types";
 
 export const LINK_EVM_ADDRESS_DISCRIMINATOR = new Uint8Array([
   156, 75, 131, 178, 64, 110, 236, 64,
@@ -59,26 +72,39 @@ export function getLinkEvmAddressDiscriminatorBytes() {
 export type LinkEvmAddressInstruction<
   TProgram extends string = typeof SATI_PROGRAM_ADDRESS,
   TAccountOwner extends string | AccountMeta<string> = string,
+  TAccountRegistryConfig extends string | AccountMeta<string> = string,
   TAccountAgentMint extends string | AccountMeta<string> = string,
   TAccountAta extends string | AccountMeta<string> = string,
+  TAccountEvmLink extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends string | AccountMeta<string> =
     "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+  TAccountSystemProgram extends string | AccountMeta<string> =
+    "11111111111111111111111111111111",
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
   InstructionWithAccounts<
     [
       TAccountOwner extends string
-        ? ReadonlySignerAccount<TAccountOwner> &
+        ? WritableSignerAccount<TAccountOwner> &
             AccountSignerMeta<TAccountOwner>
         : TAccountOwner,
+      TAccountRegistryConfig extends string
+        ? ReadonlyAccount<TAccountRegistryConfig>
+        : TAccountRegistryConfig,
       TAccountAgentMint extends string
         ? ReadonlyAccount<TAccountAgentMint>
         : TAccountAgentMint,
       TAccountAta extends string ? ReadonlyAccount<TAccountAta> : TAccountAta,
+      TAccountEvmLink extends string
+        ? WritableAccount<TAccountEvmLink>
+        : TAccountEvmLink,
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -87,23 +113,31 @@ export type LinkEvmAddressInstructionData = {
   discriminator: ReadonlyUint8Array;
   /** EVM address (20 bytes) */
   evmAddress: ReadonlyUint8Array;
-  /** CAIP-2 chain identifier (e.g., "eip155:1", "eip155:8453") */
+  /** CAIP-2 chain identifier, canonical `eip155:<decimal>` (e.g., "eip155:1", "eip155:8453") */
   chainId: string;
   /** secp256k1 signature (64 bytes: r || s) */
   signature: ReadonlyUint8Array;
-  /** Recovery ID (0 or 1) */
+  /** Recovery ID (0/1, or Ethereum v 27/28) */
   recoveryId: number;
+  /** Message format the wallet signed (raw digest, EIP-191 or EIP-712) */
+  format: EvmLinkFormat;
+  /** Unix timestamp after which the link is void (None = no expiry) */
+  expiresAt: Option<bigint>;
 };
 
 export type LinkEvmAddressInstructionDataArgs = {
   /** EVM address (20 bytes) */
   evmAddress: ReadonlyUint8Array;
-  /** CAIP-2 chain identifier (e.g., "eip155:1", "eip155:8453") */
+  /** CAIP-2 chain identifier, canonical `eip155:<decimal>` (e.g., "eip155:1", "eip155:8453") */
   chainId: string;
   /** secp256k1 signature (64 bytes: r || s) */
   signature: ReadonlyUint8Array;
-  /** Recovery ID (0 or 1) */
+  /** Recovery ID (0/1, or Ethereum v 27/28) */
   recoveryId: number;
+  /** Message format the wallet signed (raw digest, EIP-191 or EIP-712) */
+  format: EvmLinkFormatArgs;
+  /** Unix timestamp after which the link is void (None = no expiry) */
+  expiresAt: OptionOrNullable<number | bigint>;
 };
 
 export function getLinkEvmAddressInstructionDataEncoder(): Encoder<LinkEvmAddressInstructionDataArgs> {
@@ -114,6 +148,8 @@ export function getLinkEvmAddressInstructionDataEncoder(): Encoder<LinkEvmAddres
       ["chainId", addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
       ["signature", fixEncoderSize(getBytesEncoder(), 64)],
       ["recoveryId", getU8Encoder()],
+      ["format", getEvmLinkFormatEncoder()],
+      ["expiresAt", getOptionEncoder(getI64Encoder())],
     ]),
     (value) => ({ ...value, discriminator: LINK_EVM_ADDRESS_DISCRIMINATOR }),
   );
@@ -126,6 +162,8 @@ export function getLinkEvmAddressInstructionDataDecoder(): Decoder<LinkEvmAddres
     ["chainId", addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
     ["signature", fixDecoderSize(getBytesDecoder(), 64)],
     ["recoveryId", getU8Decoder()],
+    ["format", getEvmLinkFormatDecoder()],
+    ["expiresAt", getOptionDecoder(getI64Decoder())],
   ]);
 }
 
@@ -141,12 +179,17 @@ export function getLinkEvmAddressInstructionDataCodec(): Codec<
 
 export type LinkEvmAddressAsyncInput<
   TAccountOwner extends string = string,
+  TAccountRegistryConfig extends string = string,
   TAccountAgentMint extends string = string,
   TAccountAta extends string = string,
+  TAccountEvmLink extends string = string,
   TAccountTokenProgram extends string = string,
+  TAccountSystemProgram extends string = string,
 > = {
-  /** Agent owner (must sign) */
+  /** Agent owner (must sign, pays for the EvmLink) */
   owner: TransactionSigner<TAccountOwner>;
+  /** Registry config - checked for the pause switch */
+  registryConfig?: Address<TAccountRegistryConfig>;
   /** Agent mint account */
   agentMint: Address<TAccountAgentMint>;
   /**
@@ -154,35 +197,49 @@ export type LinkEvmAddressAsyncInput<
    * Validated to be correct ATA for the agent_mint and have balance > 0
    */
   ata?: Address<TAccountAta>;
+  /** Link PDA (must not exist; unlink first to replace a link) */
+  evmLink: Address<TAccountEvmLink>;
   /** Token-2022 program for ATA verification */
   tokenProgram?: Address<TAccountTokenProgram>;
+  systemProgram?: Address<TAccountSystemProgram>;
   evmAddress: LinkEvmAddressInstructionDataArgs["evmAddress"];
   chainId: LinkEvmAddressInstructionDataArgs["chainId"];
   signature: LinkEvmAddressInstructionDataArgs["signature"];
   recoveryId: LinkEvmAddressInstructionDataArgs["recoveryId"];
+  format: LinkEvmAddressInstructionDataArgs["format"];
+  expiresAt: LinkEvmAddressInstructionDataArgs["expiresAt"];
 };
 
 export async function getLinkEvmAddressInstructionAsync<
   TAccountOwner extends string,
+  TAccountRegistryConfig extends string,
   TAccountAgentMint extends string,
   TAccountAta extends string,
+  TAccountEvmLink extends string,
   TAccountTokenProgram extends string,
+  TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof SATI_PROGRAM_ADDRESS,
 >(
   input: LinkEvmAddressAsyncInput<
     TAccountOwner,
+    TAccountRegistryConfig,
     TAccountAgentMint,
     TAccountAta,
-    TAccountTokenProgram
+    TAccountEvmLink,
+    TAccountTokenProgram,
+    TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress },
 ): Promise<
   LinkEvmAddressInstruction<
     TProgramAddress,
     TAccountOwner,
+    TAccountRegistryConfig,
     TAccountAgentMint,
     TAccountAta,
-    TAccountTokenProgram
+    TAccountEvmLink,
+    TAccountTokenProgram,
+    TAccountSystemProgram
   >
 > {
   // Program address.
@@ -190,10 +247,13 @@ export async function getLinkEvmAddressInstructionAsync<
 
   // Original accounts.
   const originalAccounts = {
-    owner: { value: input.owner ?? null, isWritable: false },
+    owner: { value: input.owner ?? null, isWritable: true },
+    registryConfig: { value: input.registryConfig ?? null, isWritable: false },
     agentMint: { value: input.agentMint ?? null, isWritable: false },
     ata: { value: input.ata ?? null, isWritable: false },
+    evmLink: { value: input.evmLink ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -204,6 +264,16 @@ export async function getLinkEvmAddressInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
+  if (!accounts.registryConfig.value) {
+    accounts.registryConfig.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([114, 101, 103, 105, 115, 116, 114, 121]),
+        ),
+      ],
+    });
+  }
   if (!accounts.tokenProgram.value) {
     accounts.tokenProgram.value =
       "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA" as Address<"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA">;
@@ -219,14 +289,21 @@ export async function getLinkEvmAddressInstructionAsync<
       ],
     });
   }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      "11111111111111111111111111111111" as Address<"11111111111111111111111111111111">;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, "programId");
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.owner),
+      getAccountMeta(accounts.registryConfig),
       getAccountMeta(accounts.agentMint),
       getAccountMeta(accounts.ata),
+      getAccountMeta(accounts.evmLink),
       getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.systemProgram),
     ],
     data: getLinkEvmAddressInstructionDataEncoder().encode(
       args as LinkEvmAddressInstructionDataArgs,
@@ -235,20 +312,28 @@ export async function getLinkEvmAddressInstructionAsync<
   } as LinkEvmAddressInstruction<
     TProgramAddress,
     TAccountOwner,
+    TAccountRegistryConfig,
     TAccountAgentMint,
     TAccountAta,
-    TAccountTokenProgram
+    TAccountEvmLink,
+    TAccountTokenProgram,
+    TAccountSystemProgram
   >);
 }
 
 export type LinkEvmAddressInput<
   TAccountOwner extends string = string,
+  TAccountRegistryConfig extends string = string,
   TAccountAgentMint extends string = string,
   TAccountAta extends string = string,
+  TAccountEvmLink extends string = string,
   TAccountTokenProgram extends string = string,
+  TAccountSystemProgram extends string = string,
 > = {
-  /** Agent owner (must sign) */
+  /** Agent owner (must sign, pays for the EvmLink) */
   owner: TransactionSigner<TAccountOwner>;
+  /** Registry config - checked for the pause switch */
+  registryConfig: Address<TAccountRegistryConfig>;
   /** Agent mint account */
   agentMint: Address<TAccountAgentMint>;
   /**
@@ -256,44 +341,61 @@ export type LinkEvmAddressInput<
    * Validated to be correct ATA for the agent_mint and have balance > 0
    */
   ata: Address<TAccountAta>;
+  /** Link PDA (must not exist; unlink first to replace a link) */
+  evmLink: Address<TAccountEvmLink>;
   /** Token-2022 program for ATA verification */
   tokenProgram?: Address<TAccountTokenProgram>;
+  systemProgram?: Address<TAccountSystemProgram>;
   evmAddress: LinkEvmAddressInstructionDataArgs["evmAddress"];
   chainId: LinkEvmAddressInstructionDataArgs["chainId"];
   signature: LinkEvmAddressInstructionDataArgs["signature"];
   recoveryId: LinkEvmAddressInstructionDataArgs["recoveryId"];
+  format: LinkEvmAddressInstructionDataArgs["format"];
+  expiresAt: LinkEvmAddressInstructionDataArgs["expiresAt"];
 };
 
 export function getLinkEvmAddressInstruction<
   TAccountOwner extends string,
+  TAccountRegistryConfig extends string,
   TAccountAgentMint extends string,
   TAccountAta extends string,
+  TAccountEvmLink extends string,
   TAccountTokenProgram extends string,
+  TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof SATI_PROGRAM_ADDRESS,
 >(
   input: LinkEvmAddressInput<
     TAccountOwner,
+    TAccountRegistryConfig,
     TAccountAgentMint,
     TAccountAta,
-    TAccountTokenProgram
+    TAccountEvmLink,
+    TAccountTokenProgram,
+    TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress },
 ): LinkEvmAddressInstruction<
   TProgramAddress,
   TAccountOwner,
+  TAccountRegistryConfig,
   TAccountAgentMint,
   TAccountAta,
-  TAccountTokenProgram
+  TAccountEvmLink,
+  TAccountTokenProgram,
+  TAccountSystemProgram
 > {
   // Program address.
   const programAddress = config?.programAddress ?? SATI_PROGRAM_ADDRESS;
 
   // Original accounts.
   const originalAccounts = {
-    owner: { value: input.owner ?? null, isWritable: false },
+    owner: { value: input.owner ?? null, isWritable: true },
+    registryConfig: { value: input.registryConfig ?? null, isWritable: false },
     agentMint: { value: input.agentMint ?? null, isWritable: false },
     ata: { value: input.ata ?? null, isWritable: false },
+    evmLink: { value: input.evmLink ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -308,14 +410,21 @@ export function getLinkEvmAddressInstruction<
     accounts.tokenProgram.value =
       "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA" as Address<"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA">;
   }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      "11111111111111111111111111111111" as Address<"11111111111111111111111111111111">;
+  }
 
   const getAccountMeta = getAccountMetaFactory(programAddress, "programId");
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.owner),
+      getAccountMeta(accounts.registryConfig),
       getAccountMeta(accounts.agentMint),
       getAccountMeta(accounts.ata),
+      getAccountMeta(accounts.evmLink),
       getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.systemProgram),
     ],
     data: getLinkEvmAddressInstructionDataEncoder().encode(
       args as LinkEvmAddressInstructionDataArgs,
@@ -324,9 +433,12 @@ export function getLinkEvmAddressInstruction<
   } as LinkEvmAddressInstruction<
     TProgramAddress,
     TAccountOwner,
+    TAccountRegistryConfig,
     TAccountAgentMint,
     TAccountAta,
-    TAccountTokenProgram
+    TAccountEvmLink,
+    TAccountTokenProgram,
+    TAccountSystemProgram
   >);
 }
 
@@ -336,17 +448,22 @@ export type ParsedLinkEvmAddressInstruction<
 > = {
   programAddress: Address<TProgram>;
   accounts: {
-    /** Agent owner (must sign) */
+    /** Agent owner (must sign, pays for the EvmLink) */
     owner: TAccountMetas[0];
+    /** Registry config - checked for the pause switch */
+    registryConfig: TAccountMetas[1];
     /** Agent mint account */
-    agentMint: TAccountMetas[1];
+    agentMint: TAccountMetas[2];
     /**
      * Owner's associated token account for this mint
      * Validated to be correct ATA for the agent_mint and have balance > 0
      */
-    ata: TAccountMetas[2];
+    ata: TAccountMetas[3];
+    /** Link PDA (must not exist; unlink first to replace a link) */
+    evmLink: TAccountMetas[4];
     /** Token-2022 program for ATA verification */
-    tokenProgram: TAccountMetas[3];
+    tokenProgram: TAccountMetas[5];
+    systemProgram: TAccountMetas[6];
   };
   data: LinkEvmAddressInstructionData;
 };
@@ -359,7 +476,7 @@ export function parseLinkEvmAddressInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>,
 ): ParsedLinkEvmAddressInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 4) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error("Not enough accounts");
   }
@@ -373,9 +490,12 @@ export function parseLinkEvmAddressInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       owner: getNextAccount(),
+      registryConfig: getNextAccount(),
       agentMint: getNextAccount(),
       ata: getNextAccount(),
+      evmLink: getNextAccount(),
       tokenProgram: getNextAccount(),
+      systemProgram: getNextAccount(),
     },
     data: getLinkEvmAddressInstructionDataDecoder().decode(instruction.data),
   };
@@ -12,6 +12,7 @@ import {
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
+  getAddressEncoder,
   getArrayDecoder,
   getArrayEncoder,
   getBooleanDecoder,
@@ -46,7 +47,11 @@ import {
   type WritableSignerAccount,
 } from "@solana/kit";
 import { SATI_PROGRAM_ADDRESS } from "../programs";
-import { getAccountMetaFactory, type ResolvedAccount } from "../shared";
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from "../shared";
 import {
   getMetadataEntryDecoder,
   getMetadataEntryEncoder,
@@ -75,10 +80,16 @@ export type RegisterAgentInstruction<
   TAccountToken2022Program extends string | AccountMeta<string> =
     "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb",
   TAccountAgentIndex extends string | AccountMeta<string> = string,
+  TAccountAgentRecord extends string | AccountMeta<string> = string,
   TAccountAssociatedTokenProgram extends string | AccountMeta<string> =
     "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
   TAccountSystemProgram extends string | AccountMeta<string> =
     "11111111111111111111111111111111",
+  TAccountTreasury extends string | AccountMeta<string> = string,
+  TAccountFeeMint extends string | AccountMeta<string> = string,
+  TAccountPayerFeeAccount extends string | AccountMeta<string> = string,
+  TAccountTreasuryFeeAccount extends string | AccountMeta<string> = string,
+  TAccountFeeTokenProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -110,12 +121,30 @@ export type RegisterAgentInstruction<
       TAccountAgentIndex extends string
         ? WritableAccount<TAccountAgentIndex>
         : TAccountAgentIndex,
+      TAccountAgentRecord extends string
+        ? WritableAccount<TAccountAgentRecord>
+        : TAccountAgentRecord,
       TAccountAssociatedTokenProgram extends string
         ? ReadonlyAccount<TAccountAssociatedTokenProgram>
         : TAccountAssociatedTokenProgram,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountTreasury extends string
+        ? WritableAccount<TAccountTreasury>
+        : TAccountTreasury,
+      TAccountFeeMint extends string
+        ? ReadonlyAccount<TAccountFeeMint>
+        : TAccountFeeMint,
+      TAccountPayerFeeAccount extends string
+        ? WritableAccount<TAccountPayerFeeAccount>
+        : TAccountPayerFeeAccount,
+      TAccountTreasuryFeeAccount extends string
+        ? WritableAccount<TAccountTreasuryFeeAccount>
+        : TAccountTreasuryFeeAccount,
+      TAccountFeeTokenProgram extends string
+        ? ReadonlyAccount<TAccountFeeTokenProgram>
+        : TAccountFeeTokenProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -187,8 +216,14 @@ export type RegisterAgentAsyncInput<
   TAccountAgentTokenAccount extends string = string,
   TAccountToken2022Program extends string = string,
   TAccountAgentIndex extends string = string,
+  TAccountAgentRecord extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountTreasury extends string = string,
+  TAccountFeeMint extends string = string,
+  TAccountPayerFeeAccount extends string = string,
+  TAccountTreasuryFeeAccount extends string = string,
+  TAccountFeeTokenProgram extends string = string,
 > = {
   /** Pays for account creation */
   payer: TransactionSigner<TAccountPayer>;
@@ -208,8 +243,20 @@ export type RegisterAgentAsyncInput<
    * NOTE: Uses (current_count + 1) which equals the new total_agents after increment
    */
   agentIndex: Address<TAccountAgentIndex>;
+  /** Agent record PDA for reverse lookup (mint -> member_number) */
+  agentRecord?: Address<TAccountAgentRecord>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  /** Registry treasury PDA (lamport fees only; created on first payment) */
+  treasury?: Address<TAccountTreasury>;
+  /** Fee mint (SPL fees only, must equal registry_config.fee_mint) */
+  feeMint?: Address<TAccountFeeMint>;
+  /** Payer's token account for fee_mint (SPL fees only) */
+  payerFeeAccount?: Address<TAccountPayerFeeAccount>;
+  /** Treasury-owned token account for fee_mint (SPL fees only) */
+  treasuryFeeAccount?: Address<TAccountTreasuryFeeAccount>;
+  /** Token program of fee_mint (SPL fees only) */
+  feeTokenProgram?: Address<TAccountFeeTokenProgram>;
   name: RegisterAgentInstructionDataArgs["name"];
   symbol: RegisterAgentInstructionDataArgs["symbol"];
   uri: RegisterAgentInstructionDataArgs["uri"];
@@ -226,8 +273,14 @@ export async function getRegisterAgentInstructionAsync<
   TAccountAgentTokenAccount extends string,
   TAccountToken2022Program extends string,
   TAccountAgentIndex extends string,
+  TAccountAgentRecord extends string,
   TAccountAssociatedTokenProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountTreasury extends string,
+  TAccountFeeMint extends string,
+  TAccountPayerFeeAccount extends string,
+  TAccountTreasuryFeeAccount extends string,
+  TAccountFeeTokenProgram extends string,
   TProgramAddress extends Address = typeof SATI_PROGRAM_ADDRESS,
 >(
   input: RegisterAgentAsyncInput<
@@ -239,8 +292,14 @@ export async function getRegisterAgentInstructionAsync<
     TAccountAgentTokenAccount,
     TAccountToken2022Program,
     TAccountAgentIndex,
+    TAccountAgentRecord,
     TAccountAssociatedTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountTreasury,
+    TAccountFeeMint,
+    TAccountPayerFeeAccount,
+    TAccountTreasuryFeeAccount,
+    TAccountFeeTokenProgram
   >,
   config?: { programAddress?: TProgramAddress },
 ): Promise<
@@ -254,8 +313,14 @@ export async function getRegisterAgentInstructionAsync<
     TAccountAgentTokenAccount,
     TAccountToken2022Program,
     TAccountAgentIndex,
+    TAccountAgentRecord,
     TAccountAssociatedTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountTreasury,
+    TAccountFeeMint,
+    TAccountPayerFeeAccount,
+    TAccountTreasuryFeeAccount,
+    TAccountFeeTokenProgram
   >
 > {
   // Program address.
@@ -277,11 +342,23 @@ export async function getRegisterAgentInstructionAsync<
       isWritable: false,
     },
     agentIndex: { value: input.agentIndex ?? null, isWritable: true },
+    agentRecord: { value: input.agentRecord ?? null, isWritable: true },
     associatedTokenProgram: {
       value: input.associatedTokenProgram ?? null,
       isWritable: false,
     },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    treasury: { value: input.treasury ?? null, isWritable: true },
+    feeMint: { value: input.feeMint ?? null, isWritable: false },
+    payerFeeAccount: { value: input.payerFeeAccount ?? null, isWritable: true },
+    treasuryFeeAccount: {
+      value: input.treasuryFeeAccount ?? null,
+      isWritable: true,
+    },
+    feeTokenProgram: {
+      value: input.feeTokenProgram ?? null,
+      isWritable: false,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -306,6 +383,17 @@ export async function getRegisterAgentInstructionAsync<
     accounts.token2022Program.value =
       "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb" as Address<"TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb">;
   }
+  if (!accounts.agentRecord.value) {
+    accounts.agentRecord.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([97, 103, 101, 110, 116]),
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.agentMint.value)),
+      ],
+    });
+  }
   if (!accounts.associatedTokenProgram.value) {
     accounts.associatedTokenProgram.value =
       "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL" as Address<"ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL">;
@@ -326,8 +414,14 @@ export async function getRegisterAgentInstructionAsync<
       getAccountMeta(accounts.agentTokenAccount),
       getAccountMeta(accounts.token2022Program),
       getAccountMeta(accounts.agentIndex),
+      getAccountMeta(accounts.agentRecord),
       getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.treasury),
+      getAccountMeta(accounts.feeMint),
+      getAccountMeta(accounts.payerFeeAccount),
+      getAccountMeta(accounts.treasuryFeeAccount),
+      getAccountMeta(accounts.feeTokenProgram),
     ],
     data: getRegisterAgentInstructionDataEncoder().encode(
       args as RegisterAgentInstructionDataArgs,
@@ -343,8 +437,14 @@ export async function getRegisterAgentInstructionAsync<
     TAccountAgentTokenAccount,
     TAccountToken2022Program,
     TAccountAgentIndex,
+    TAccountAgentRecord,
     TAccountAssociatedTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountTreasury,
+    TAccountFeeMint,
+    TAccountPayerFeeAccount,
+    TAccountTreasuryFeeAccount,
+    TAccountFeeTokenProgram
   >);
 }
 
@@ -357,8 +457,14 @@ export type RegisterAgentInput<
   TAccountAgentTokenAccount extends string = string,
   TAccountToken2022Program extends string = string,
   TAccountAgentIndex extends string = string,
+  TAccountAgentRecord extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountTreasury extends string = string,
+  TAccountFeeMint extends string = string,
+  TAccountPayerFeeAccount extends string = string,
+  TAccountTreasuryFeeAccount extends string = string,
+  TAccountFeeTokenProgram extends string = string,
 > = {
   /** Pays for account creation */
   payer: TransactionSigner<TAccountPayer>;
@@ -378,8 +484,20 @@ export type RegisterAgentInput<
    * NOTE: Uses (current_count + 1) which equals the new total_agents after increment
    */
   agentIndex: Address<TAccountAgentIndex>;
+  /** Agent record PDA for reverse lookup (mint -> member_number) */
+  agentRecord: Address<TAccountAgentRecord>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  /** Registry treasury PDA (lamport fees only; created on first payment) */
+  treasury?: Address<TAccountTreasury>;
+  /** Fee mint (SPL fees only, must equal registry_config.fee_mint) */
+  feeMint?: Address<TAccountFeeMint>;
+  /** Payer's token account for fee_mint (SPL fees only) */
+  payerFeeAccount?: Address<TAccountPayerFeeAccount>;
+  /** Treasury-owned token account for fee_mint (SPL fees only) */
+  treasuryFeeAccount?: Address<TAccountTreasuryFeeAccount>;
+  /** Token program of fee_mint (SPL fees only) */
+  feeTokenProgram?: Address<TAccountFeeTokenProgram>;
   name: RegisterAgentInstructionDataArgs["name"];
   symbol: RegisterAgentInstructionDataArgs["symbol"];
   uri: RegisterAgentInstructionDataArgs["uri"];
@@ -396,8 +514,14 @@ export function getRegisterAgentInstruction<
   TAccountAgentTokenAccount extends string,
   TAccountToken2022Program extends string,
   TAccountAgentIndex extends string,
+  TAccountAgentRecord extends string,
   TAccountAssociatedTokenProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountTreasury extends string,
+  TAccountFeeMint extends string,
+  TAccountPayerFeeAccount extends string,
+  TAccountTreasuryFeeAccount extends string,
+  TAccountFeeTokenProgram extends string,
   TProgramAddress extends Address = typeof SATI_PROGRAM_ADDRESS,
 >(
   input: RegisterAgentInput<
@@ -409,8 +533,14 @@ export function getRegisterAgentInstruction<
     TAccountAgentTokenAccount,
     TAccountToken2022Program,
     TAccountAgentIndex,
+    TAccountAgentRecord,
     TAccountAssociatedTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountTreasury,
+    TAccountFeeMint,
+    TAccountPayerFeeAccount,
+    TAccountTreasuryFeeAccount,
+    TAccountFeeTokenProgram
   >,
   config?: { programAddress?: TProgramAddress },
 ): RegisterAgentInstruction<
@@ -423,8 +553,14 @@ export function getRegisterAgentInstruction<
   TAccountAgentTokenAccount,
   TAccountToken2022Program,
   TAccountAgentIndex,
+  TAccountAgentRecord,
   TAccountAssociatedTokenProgram,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountTreasury,
+  TAccountFeeMint,
+  TAccountPayerFeeAccount,
+  TAccountTreasuryFeeAccount,
+  TAccountFeeTokenProgram
 > {
   // Program address.
   const programAddress = config?.programAddress ?? SATI_PROGRAM_ADDRESS;
@@ -445,11 +581,23 @@ export function getRegisterAgentInstruction<
       isWritable: false,
     },
     agentIndex: { value: input.agentIndex ?? null, isWritable: true },
+    agentRecord: { value: input.agentRecord ?? null, isWritable: true },
     associatedTokenProgram: {
       value: input.associatedTokenProgram ?? null,
       isWritable: false,
     },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    treasury: { value: input.treasury ?? null, isWritable: true },
+    feeMint: { value: input.feeMint ?? null, isWritable: false },
+    payerFeeAccount: { value: input.payerFeeAccount ?? null, isWritable: true },
+    treasuryFeeAccount: {
+      value: input.treasuryFeeAccount ?? null,
+      isWritable: true,
+    },
+    feeTokenProgram: {
+      value: input.feeTokenProgram ?? null,
+      isWritable: false,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -484,8 +632,14 @@ export function getRegisterAgentInstruction<
       getAccountMeta(accounts.agentTokenAccount),
       getAccountMeta(accounts.token2022Program),
       getAccountMeta(accounts.agentIndex),
+      getAccountMeta(accounts.agentRecord),
       getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.treasury),
+      getAccountMeta(accounts.feeMint),
+      getAccountMeta(accounts.payerFeeAccount),
+      getAccountMeta(accounts.treasuryFeeAccount),
+      getAccountMeta(accounts.feeTokenProgram),
     ],
     data: getRegisterAgentInstructionDataEncoder().encode(
       args as RegisterAgentInstructionDataArgs,
@@ -501,8 +655,14 @@ export function getRegisterAgentInstruction<
     TAccountAgentTokenAccount,
     TAccountToken2022Program,
     TAccountAgentIndex,
+    TAccountAgentRecord,
     TAccountAssociatedTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountTreasury,
+    TAccountFeeMint,
+    TAccountPayerFeeAccount,
+    TAccountTreasuryFeeAccount,
+    TAccountFeeTokenProgram
   >);
 }
 
@@ -530,8 +690,20 @@ export type ParsedRegisterAgentInstruction<
      * NOTE: Uses (current_count + 1) which equals the new total_agents after increment
      */
     agentIndex: TAccountMetas[7];
-    associatedTokenProgram: TAccountMetas[8];
-    systemProgram: TAccountMetas[9];
+    /** Agent record PDA for reverse lookup (mint -> member_number) */
+    agentRecord: TAccountMetas[8];
+    associatedTokenProgram: TAccountMetas[9];
+    systemProgram: TAccountMetas[10];
+    /** Registry treasury PDA (lamport fees only; created on first payment) */
+    treasury?: TAccountMetas[11] | undefined;
+    /** Fee mint (SPL fees only, must equal registry_config.fee_mint) */
+    feeMint?: TAccountMetas[12] | undefined;
+    /** Payer's token account for fee_mint (SPL fees only) */
+    payerFeeAccount?: TAccountMetas[13] | undefined;
+    /** Treasury-owned token account for fee_mint (SPL fees only) */
+    treasuryFeeAccount?: TAccountMetas[14] | undefined;
+    /** Token program of fee_mint (SPL fees only) */
+    feeTokenProgram?: TAccountMetas[15] | undefined;
   };
   data: RegisterAgentInstructionData;
 };
@@ -544,7 +716,7 @@ export function parseRegisterAgentInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>,
 ): ParsedRegisterAgentInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 10) {
+  if (instruction.accounts.length < 16) {
     // TODO: Coded error.
     throw new Error("Not enough accounts");
   }
@@ -554,6 +726,12 @@ export function parseRegisterAgentInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === SATI_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -565,8 +743,14 @@ export function parseRegisterAgentInstruction<
       agentTokenAccount: getNextAccount(),
       token2022Program: getNextAccount(),
       agentIndex: getNextAccount(),
+      agentRecord: getNextAccount(),
       associatedTokenProgram: getNextAccount(),
       systemProgram: getNextAccount(),
+      treasury: getNextOptionalAccount(),
+      feeMint: getNextOptionalAccount(),
+      payerFeeAccount: getNextOptionalAccount(),
+      treasuryFeeAccount: getNextOptionalAccount(),
+      feeTokenProgram: getNextOptionalAccount(),
     },
     data: getRegisterAgentInstructionDataDecoder().decode(instruction.data),
   };
@@ -14,30 +14,87 @@ import {
   type ReadonlyUint8Array,
 } from "@solana/kit";
 import {
+  type ParsedAcceptRegistryAuthorityInstruction,
+  type ParsedBackfillAgentRecordInstruction,
+  type ParsedCancelGovernanceActionInstruction,
+  type ParsedCancelRegistryAuthorityTransferInstruction,
+  type ParsedClaimAgentHandleInstruction,
   type ParsedCloseCompressedAttestationInstruction,
   type ParsedCloseRegularAttestationInstruction,
   type ParsedCreateCompressedAttestationInstruction,
   type ParsedCreateRegularAttestationInstruction,
+  type ParsedDetachParentAgentInstruction,
+  type ParsedExecuteGovernanceActionInstruction,
   type ParsedInitializeInstruction,
   type ParsedLinkEvmAddressInstruction,
+  type ParsedLinkExternalIdentityInstruction,
+  type ParsedLinkSnsDomainInstruction,
+  type ParsedMigrateRegistryConfigInstruction,
+  type ParsedMigrateSchemaConfigInstruction,
+  type ParsedPauseRegistryInstruction,
+  type ParsedProposeRegistryAuthorityInstruction,
+  type ParsedQueueGovernanceActionInstruction,
   type ParsedRegisterAgentInstruction,
+  type ParsedRegisterAgentsBatchInstruction,
   type ParsedRegisterSchemaConfigInstruction,
+  type ParsedReleaseAgentHandleInstruction,
+  type ParsedRetireAgentInstruction,
+  type ParsedRevokeAgentVerificationInstruction,
+  type ParsedSetAgentServicesInstruction,
+  type ParsedSetAgentStatusInstruction,
+  type ParsedSetAgentVerificationInstruction,
+  type ParsedSetParentAgentInstruction,
+  type ParsedSetRegistrationFeeInstruction,
+  type ParsedSetSchemaMinVerificationInstruction,
+  type ParsedSetSuccessorInstruction,
+  type ParsedSetTimelockDelayInstruction,
+  type ParsedSuspendAgentInstruction,
+  type ParsedSyncAgentAuthorityInstruction,
+  type ParsedUnlinkEvmAddressInstruction,
+  type ParsedUnlinkSnsDomainInstruction,
+  type ParsedUnpauseRegistryInstruction,
+  type ParsedUnsuspendAgentInstruction,
+  type ParsedUpdateAgentMetadataInstruction,
+  type ParsedUpdateCompressedAttestationInstruction,
   type ParsedUpdateRegistryAuthorityInstruction,
+  type ParsedUpdateRegularAttestationInstruction,
+  type ParsedUpdateRegularAttestationsBatchInstruction,
+  type ParsedWithdrawTreasuryInstruction,
 } from "../instructions";
 
 export const SATI_PROGRAM_ADDRESS =
   "satiRkxEiwZ51cv8PRu8UMzuaqeaNU9jABo6oAFMsLe" as Address<"satiRkxEiwZ51cv8PRu8UMzuaqeaNU9jABo6oAFMsLe">;
 
 export enum SatiAccount {
+  AgentHandle,
   AgentIndex,
+  AgentParent,
+  AgentRecord,
+  AgentSuccessor,
+  AgentVerification,
+  EvmLink,
+  HandleClaim,
+  PendingAction,
   RegistryConfig,
   SchemaConfig,
+  SnsLink,
 }
 
 export function identifySatiAccount(
   account: { data: ReadonlyUint8Array } | ReadonlyUint8Array,
 ): SatiAccount {
   const data = "data" in account ? account.data : account;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([208, 138, 125, 150, 206, 142, 254, 192]),
+      ),
+      0,
+    )
+  ) {
+    return SatiAccount.AgentHandle;
+  }
   if (
     containsBytes(
       data,
@@ -49,6 +106,83 @@ export function identifySatiAccount(
   ) {
     return SatiAccount.AgentIndex;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([0, 95, 202, 178, 190, 29, 71, 67]),
+      ),
+      0,
+    )
+  ) {
+    return SatiAccount.AgentParent;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([4, 201, 129, 70, 197, 134, 47, 169]),
+      ),
+      0,
+    )
+  ) {
+    return SatiAccount.AgentRecord;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([104, 187, 139, 50, 183, 164, 220, 156]),
+      ),
+      0,
+    )
+  ) {
+    return SatiAccount.AgentSuccessor;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([128, 155, 95, 241, 66, 207, 166, 59]),
+      ),
+      0,
+    )
+  ) {
+    return SatiAccount.AgentVerification;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([181, 144, 100, 29, 9, 149, 212, 233]),
+      ),
+      0,
+    )
+  ) {
+    return SatiAccount.EvmLink;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([148, 215, 248, 53, 11, 234, 115, 190]),
+      ),
+      0,
+    )
+  ) {
+    return SatiAccount.HandleClaim;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([10, 76, 29, 155, 104, 63, 34, 51]),
+      ),
+      0,
+    )
+  ) {
+    return SatiAccount.PendingAction;
+  }
   if (
     containsBytes(
       data,
@@ -71,27 +205,130 @@ export function identifySatiAccount(
   ) {
     return SatiAccount.SchemaConfig;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([64, 176, 207, 190, 180, 79, 139, 45]),
+      ),
+      0,
+    )
+  ) {
+    return SatiAccount.SnsLink;
+  }
   throw new Error(
     "The provided account could not be identified as a sati account.",
   );
 }
 
 export enum SatiInstruction {
+  AcceptRegistryAuthority,
+  BackfillAgentRecord,
+  CancelGovernanceAction,
+  CancelRegistryAuthorityTransfer,
+  ClaimAgentHandle,
   CloseCompressedAttestation,
   CloseRegularAttestation,
   CreateCompressedAttestation,
   CreateRegularAttestation,
+  DetachParentAgent,
+  ExecuteGovernanceAction,
   Initialize,
   LinkEvmAddress,
+  LinkExternalIdentity,
+  LinkSnsDomain,
+  MigrateRegistryConfig,
+  MigrateSchemaConfig,
+  PauseRegistry,
+  ProposeRegistryAuthority,
+  QueueGovernanceAction,
   RegisterAgent,
+  RegisterAgentsBatch,
   RegisterSchemaConfig,
+  ReleaseAgentHandle,
+  RetireAgent,
+  RevokeAgentVerification,
+  SetAgentServices,
+  SetAgentStatus,
+  SetAgentVerification,
+  SetParentAgent,
+  SetRegistrationFee,
+  SetSchemaMinVerification,
+  SetSuccessor,
+  SetTimelockDelay,
+  SuspendAgent,
+  SyncAgentAuthority,
+  UnlinkEvmAddress,
+  UnlinkSnsDomain,
+  UnpauseRegistry,
+  UnsuspendAgent,
+  UpdateAgentMetadata,
+  UpdateCompressedAttestation,
   UpdateRegistryAuthority,
+  UpdateRegularAttestation,
+  UpdateRegularAttestationsBatch,
+  WithdrawTreasury,
 }
 
 export function identifySatiInstruction(
   instruction: { data: ReadonlyUint8Array } | ReadonlyUint8Array,
 ): SatiInstruction {
   const data = "data" in instruction ? instruction.data : instruction;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([228, 112, 232, 244, 155, 68, 83, 87]),
+      ),
+      0,
+    )
+  ) {
+    return SatiInstruction.AcceptRegistryAuthority;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([201, 122, 195, 212, 220, 141, 23, 150]),
+      ),
+      0,
+    )
+  ) {
+    return SatiInstruction.BackfillAgentRecord;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([14, 99, 9, 94, 27, 216, 83, 177]),
+      ),
+      0,
+    )
+  ) {
+    return SatiInstruction.CancelGovernanceAction;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([242, 87, 187, 35, 239, 151, 76, 115]),
+      ),
+      0,
+    )
+  ) {
+    return SatiInstruction.CancelRegistryAuthorityTransfer;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([25, 100, 14, 13, 39, 65, 247, 84]),
+      ),
+      0,
+    )
+  ) {
+    return SatiInstruction.ClaimAgentHandle;
+  }
   if (
     containsBytes(
       data,
@@ -136,6 +373,28 @@ export function identifySatiInstruction(
   ) {
     return SatiInstruction.CreateRegularAttestation;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([228, 69, 111, 135, 250, 242, 129, 246]),
+      ),
+      0,
+    )
+  ) {
+    return SatiInstruction.DetachParentAgent;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([45, 191, 143, 171, 141, 243, 56, 30]),
+      ),
+      0,
+    )
+  ) {
+    return SatiInstruction.ExecuteGovernanceAction;
+  }
   if (
     containsBytes(
       data,
@@ -158,6 +417,83 @@ export function identifySatiInstruction(
   ) {
     return SatiInstruction.LinkEvmAddress;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([247, 188, 204, 137, 28, 26, 220, 163]),
+      ),
+      0,
+    )
+  ) {
+    return SatiInstruction.LinkExternalIdentity;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([125, 192, 19, 125, 39, 94, 63, 144]),
+      ),
+      0,
+    )
+  ) {
+    return SatiInstruction.LinkSnsDomain;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([103, 181, 226, 54, 185, 93, 44, 69]),
+      ),
+      0,
+    )
+  ) {
+    return SatiInstruction.MigrateRegistryConfig;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([125, 9, 104, 128, 230, 116, 203, 133]),
+      ),
+      0,
+    )
+  ) {
+    return SatiInstruction.MigrateSchemaConfig;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([106, 147, 106, 131, 69, 218, 76, 249]),
+      ),
+      0,
+    )
+  ) {
+    return SatiInstruction.PauseRegistry;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([125, 119, 150, 86, 165, 76, 48, 8]),
+      ),
+      0,
+    )
+  ) {
+    return SatiInstruction.ProposeRegistryAuthority;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([137, 19, 227, 165, 255, 100, 116, 0]),
+      ),
+      0,
+    )
+  ) {
+    return SatiInstruction.QueueGovernanceAction;
+  }
   if (
     containsBytes(
       data,
@@ -169,6 +505,17 @@ export function identifySatiInstruction(
   ) {
     return SatiInstruction.RegisterAgent;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([166, 43, 136, 74, 251, 208, 78, 63]),
+      ),
+      0,
+    )
+  ) {
+    return SatiInstruction.RegisterAgentsBatch;
+  }
   if (
     containsBytes(
       data,
@@ -180,6 +527,215 @@ export function identifySatiInstruction(
   ) {
     return SatiInstruction.RegisterSchemaConfig;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([186, 104, 134, 175, 67, 110, 128, 52]),
+      ),
+      0,
+    )
+  ) {
+    return SatiInstruction.ReleaseAgentHandle;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([117, 73, 111, 93, 56, 245, 247, 0]),
+      ),
+      0,
+    )
+  ) {
+    return SatiInstruction.RetireAgent;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([241, 39, 84, 1, 9, 216, 243, 66]),
+      ),
+      0,
+    )
+  ) {
+    return SatiInstruction.RevokeAgentVerification;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([118, 48, 28, 22, 174, 179, 64, 222]),
+      ),
+      0,
+    )
+  ) {
+    return SatiInstruction.SetAgentServices;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([31, 193, 64, 189, 114, 155, 164, 218]),
+      ),
+      0,
+    )
+  ) {
+    return SatiInstruction.SetAgentStatus;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([25, 223, 0, 75, 157, 119, 77, 244]),
+      ),
+      0,
+    )
+  ) {
+    return SatiInstruction.SetAgentVerification;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([131, 222, 99, 252, 177, 7, 249, 202]),
+      ),
+      0,
+    )
+  ) {
+    return SatiInstruction.SetParentAgent;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([156, 222, 72, 107, 152, 183, 168, 199]),
+      ),
+      0,
+    )
+  ) {
+    return SatiInstruction.SetRegistrationFee;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([55, 140, 23, 195, 151, 95, 208, 239]),
+      ),
+      0,
+    )
+  ) {
+    return SatiInstruction.SetSchemaMinVerification;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([21, 205, 192, 121, 152, 86, 46, 77]),
+      ),
+      0,
+    )
+  ) {
+    return SatiInstruction.SetSuccessor;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([47, 45, 87, 12, 19, 247, 83, 95]),
+      ),
+      0,
+    )
+  ) {
+    return SatiInstruction.SetTimelockDelay;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([242, 28, 54, 59, 247, 20, 59, 110]),
+      ),
+      0,
+    )
+  ) {
+    return SatiInstruction.SuspendAgent;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([248, 62, 100, 88, 255, 115, 74, 249]),
+      ),
+      0,
+    )
+  ) {
+    return SatiInstruction.SyncAgentAuthority;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([250, 96, 149, 25, 109, 96, 217, 218]),
+      ),
+      0,
+    )
+  ) {
+    return SatiInstruction.UnlinkEvmAddress;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([188, 38, 1, 3, 163, 66, 239, 134]),
+      ),
+      0,
+    )
+  ) {
+    return SatiInstruction.UnlinkSnsDomain;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([106, 183, 71, 126, 188, 208, 197, 26]),
+      ),
+      0,
+    )
+  ) {
+    return SatiInstruction.UnpauseRegistry;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([79, 75, 53, 57, 177, 142, 131, 149]),
+      ),
+      0,
+    )
+  ) {
+    return SatiInstruction.UnsuspendAgent;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([80, 63, 141, 214, 125, 25, 174, 106]),
+      ),
+      0,
+    )
+  ) {
+    return SatiInstruction.UpdateAgentMetadata;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([13, 2, 215, 102, 210, 88, 100, 44]),
+      ),
+      0,
+    )
+  ) {
+    return SatiInstruction.UpdateCompressedAttestation;
+  }
   if (
     containsBytes(
       data,
@@ -191,6 +747,39 @@ export function identifySatiInstruction(
   ) {
     return SatiInstruction.UpdateRegistryAuthority;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([243, 79, 163, 32, 109, 1, 151, 124]),
+      ),
+      0,
+    )
+  ) {
+    return SatiInstruction.UpdateRegularAttestation;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([101, 74, 205, 114, 173, 3, 230, 212]),
+      ),
+      0,
+    )
+  ) {
+    return SatiInstruction.UpdateRegularAttestationsBatch;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([40, 63, 122, 158, 144, 216, 83, 96]),
+      ),
+      0,
+    )
+  ) {
+    return SatiInstruction.WithdrawTreasury;
+  }
   throw new Error(
     "The provided instruction could not be identified as a sati instruction.",
   );
@@ -199,6 +788,21 @@ export function identifySatiInstruction(
 export type ParsedSatiInstruction<
   TProgram extends string = "satiRkxEiwZ51cv8PRu8UMzuaqeaNU9jABo6oAFMsLe",
 > =
+  | ({
+      instructionType: SatiInstruction.AcceptRegistryAuthority;
+    } & ParsedAcceptRegistryAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: SatiInstruction.BackfillAgentRecord;
+    } & ParsedBackfillAgentRecordInstruction<TProgram>)
+  | ({
+      instructionType: SatiInstruction.CancelGovernanceAction;
+    } & ParsedCancelGovernanceActionInstruction<TProgram>)
+  | ({
+      instructionType: SatiInstruction.CancelRegistryAuthorityTransfer;
+    } & ParsedCancelRegistryAuthorityTransferInstruction<TProgram>)
+  | ({
+      instructionType: SatiInstruction.ClaimAgentHandle;
+    } & ParsedClaimAgentHandleInstruction<TProgram>)
   | ({
       instructionType: SatiInstruction.CloseCompressedAttestation;
     } & ParsedCloseCompressedAttestationInstruction<TProgram>)
@@ -211,18 +815,114 @@ export type ParsedSatiInstruction<
   | ({
       instructionType: SatiInstruction.CreateRegularAttestation;
     } & ParsedCreateRegularAttestationInstruction<TProgram>)
+  | ({
+      instructionType: SatiInstruction.DetachParentAgent;
+    } & ParsedDetachParentAgentInstruction<TProgram>)
+  | ({
+      instructionType: SatiInstruction.ExecuteGovernanceAction;
+    } & ParsedExecuteGovernanceActionInstruction<TProgram>)
   | ({
       instructionType: SatiInstruction.Initialize;
     } & ParsedInitializeInstruction<TProgram>)
   | ({
       instructionType: SatiInstruction.LinkEvmAddress;
     } & ParsedLinkEvmAddressInstruction<TProgram>)
+  | ({
+      instructionType: SatiInstruction.LinkExternalIdentity;
+    } & ParsedLinkExternalIdentityInstruction<TProgram>)
+  | ({
+      instructionType: SatiInstruction.LinkSnsDomain;
+    } & ParsedLinkSnsDomainInstruction<TProgram>)
+  | ({
+      instructionType: SatiInstruction.MigrateRegistryConfig;
+    } & ParsedMigrateRegistryConfigInstruction<TProgram>)
+  | ({
+      instructionType: SatiInstruction.MigrateSchemaConfig;
+    } & ParsedMigrateSchemaConfigInstruction<TProgram>)
+  | ({
+      instructionType: SatiInstruction.PauseRegistry;
+    } & ParsedPauseRegistryInstruction<TProgram>)
+  | ({
+      instructionType: SatiInstruction.ProposeRegistryAuthority;
+    } & ParsedProposeRegistryAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: SatiInstruction.QueueGovernanceAction;
+    } & ParsedQueueGovernanceActionInstruction<TProgram>)
   | ({
       instructionType: SatiInstruction.RegisterAgent;
     } & ParsedRegisterAgentInstruction<TProgram>)
+  | ({
+      instructionType: SatiInstruction.RegisterAgentsBatch;
+    } & ParsedRegisterAgentsBatchInstruction<TProgram>)
   | ({
       instructionType: SatiInstruction.RegisterSchemaConfig;
     } & ParsedRegisterSchemaConfigInstruction<TProgram>)
+  | ({
+      instructionType: SatiInstruction.ReleaseAgentHandle;
+    } & ParsedReleaseAgentHandleInstruction<TProgram>)
+  | ({
+      instructionType: SatiInstruction.RetireAgent;
+    } & ParsedRetireAgentInstruction<TProgram>)
+  | ({
+      instructionType: SatiInstruction.RevokeAgentVerification;
+    } & ParsedRevokeAgentVerificationInstruction<TProgram>)
+  | ({
+      instructionType: SatiInstruction.SetAgentServices;
+    } & ParsedSetAgentServicesInstruction<TProgram>)
+  | ({
+      instructionType: SatiInstruction.SetAgentStatus;
+    } & ParsedSetAgentStatusInstruction<TProgram>)
+  | ({
+      instructionType: SatiInstruction.SetAgentVerification;
+    } & ParsedSetAgentVerificationInstruction<TProgram>)
+  | ({
+      instructionType: SatiInstruction.SetParentAgent;
+    } & ParsedSetParentAgentInstruction<TProgram>)
+  | ({
+      instructionType: SatiInstruction.SetRegistrationFee;
+    } & ParsedSetRegistrationFeeInstruction<TProgram>)
+  | ({
+      instructionType: SatiInstruction.SetSchemaMinVerification;
+    } & ParsedSetSchemaMinVerificationInstruction<TProgram>)
+  | ({
+      instructionType: SatiInstruction.SetSuccessor;
+    } & ParsedSetSuccessorInstruction<TProgram>)
+  | ({
+      instructionType: SatiInstruction.SetTimelockDelay;
+    } & ParsedSetTimelockDelayInstruction<TProgram>)
+  | ({
+      instructionType: SatiInstruction.SuspendAgent;
+    } & ParsedSuspendAgentInstruction<TProgram>)
+  | ({
+      instructionType: SatiInstruction.SyncAgentAuthority;
+    } & ParsedSyncAgentAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: SatiInstruction.UnlinkEvmAddress;
+    } & ParsedUnlinkEvmAddressInstruction<TProgram>)
+  | ({
+      instructionType: SatiInstruction.UnlinkSnsDomain;
+    } & ParsedUnlinkSnsDomainInstruction<TProgram>)
+  | ({
+      instructionType: SatiInstruction.UnpauseRegistry;
+    } & ParsedUnpauseRegistryInstruction<TProgram>)
+  | ({
+      instructionType: SatiInstruction.UnsuspendAgent;
+    } & ParsedUnsuspendAgentInstruction<TProgram>)
+  | ({
+      instructionType: SatiInstruction.UpdateAgentMetadata;
+    } & ParsedUpdateAgentMetadataInstruction<TProgram>)
+  | ({
+      instructionType: SatiInstruction.UpdateCompressedAttestation;
+    } & ParsedUpdateCompressedAttestationInstruction<TProgram>)
   | ({
       instructionType: SatiInstruction.UpdateRegistryAuthority;
-    } & ParsedUpdateRegistryAuthorityInstruction<TProgram>);
+    } & ParsedUpdateRegistryAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: SatiInstruction.UpdateRegularAttestation;
+    } & ParsedUpdateRegularAttestationInstruction<TProgram>)
+  | ({
+      instructionType: SatiInstruction.UpdateRegularAttestationsBatch;
+    } & ParsedUpdateRegularAttestationsBatchInstruction<TProgram>)
+  | ({
+      instructionType: SatiInstruction.WithdrawTreasury;
+    } & ParsedWithdrawTreasuryInstruction<TProgram>);
@@ -18,6 +18,8 @@ import {
   getBytesEncoder,
   getI64Decoder,
   getI64Encoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getU32Decoder,
@@ -28,6 +30,8 @@ import {
   type Codec,
   type Decoder,
   type Encoder,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyUint8Array,
 } from "@solana/kit";
 
@@ -41,6 +45,8 @@ export type EvmAddressLinked = {
   chainId: string;
   /** Unix timestamp when linked */
   linkedAt: bigint;
+  /** Unix timestamp after which the link is void (None = no expiry) */
+  expiresAt: Option<bigint>;
 };
 
 export type EvmAddressLinkedArgs = {
@@ -52,6 +58,8 @@ export type EvmAddressLinkedArgs = {
   chainId: string;
   /** Unix timestamp when linked */
   linkedAt: number | bigint;
+  /** Unix timestamp after which the link is void (None = no expiry) */
+  expiresAt: OptionOrNullable<number | bigint>;
 };
 
 export function getEvmAddressLinkedEncoder(): Encoder<EvmAddressLinkedArgs> {
@@ -60,6 +68,7 @@ export function getEvmAddressLinkedEncoder(): Encoder<EvmAddressLinkedArgs> {
     ["evmAddress", fixEncoderSize(getBytesEncoder(), 20)],
     ["chainId", addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
     ["linkedAt", getI64Encoder()],
+    ["expiresAt", getOptionEncoder(getI64Encoder())],
   ]);
 }
 
@@ -69,6 +78,7 @@ export function getEvmAddressLinkedDecoder(): Decoder<EvmAddressLinked> {
     ["evmAddress", fixDecoderSize(getBytesDecoder(), 20)],
     ["chainId", addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
     ["linkedAt", getI64Decoder()],
+    ["expiresAt", getOptionDecoder(getI64Decoder())],
   ]);
 }
 
@@ -6,18 +6,56 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from "./agentAuthoritySynced";
+export * from "./agentHandleClaimed";
+export * from "./agentHandleReleased";
+export * from "./agentMetadataUpdated";
+export * from "./agentParentDetached";
+export * from "./agentParentSet";
 export * from "./agentRegistered";
+export * from "./agentRetired";
+export * from "./agentServicesUpdated";
+export * from "./agentStatus";
+export * from "./agentStatusChanged";
+export * from "./agentSuccessorSet";
+export * from "./agentVerificationRevoked";
+export * from "./agentVerificationSet";
 export * from "./attestationClosed";
 export * from "./attestationCreated";
+export * from "./attestationUpdated";
 export * from "./compressedAccountMeta";
 export * from "./compressedProof";
 export * from "./evmAddressLinked";
+export * from "./evmAddressUnlinked";
+export * from "./evmLinkFormat";
+export * from "./externalIdentityLinked";
+export * from "./externalIdentityProof";
+export * from "./externalIdentityType";
+export * from "./governanceAction";
+export * from "./governanceActionCancelled";
+export * from "./governanceActionExecuted";
+export * from "./governanceActionQueued";
 export * from "./metadataEntry";
 export * from "./packedAddressTreeInfo";
 export * from "./packedStateTreeInfo";
+export * from "./registerAgentParams";
+export * from "./registrationFeePaid";
+export * from "./registrationFeeUpdated";
+export * from "./registryAuthorityProposed";
+export * from "./registryAuthorityTransferCancelled";
 export * from "./registryAuthorityUpdated";
+export * from "./registryConfigMigrated";
 export * from "./registryInitialized";
+export * from "./registryPaused";
+export * from "./registryUnpaused";
+export * from "./schemaConfigMigrated";
 export * from "./schemaConfigRegistered";
+export * from "./schemaMinVerificationUpdated";
+export * from "./serviceEntry";
 export * from "./signatureMode";
+export * from "./snsDomainLinked";
+export * from "./snsDomainUnlinked";
 export * from "./storageType";
+export * from "./timelockDelayUpdated";
+export * from "./treasuryWithdrawn";
 export * from "./validityProof";
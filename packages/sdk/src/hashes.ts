@@ -24,7 +24,9 @@ import { type Address, getAddressEncoder } from "@solana/kit";
 
 // Domain separators - must match programs/sati/src/constants.rs
 const DOMAIN_INTERACTION = new TextEncoder().encode("SATI:interaction:v1");
-const DOMAIN_EVM_LINK = new TextEncoder().encode("SATI:evm_link:v1");
+const DOMAIN_REPUTATION_UPDATE = new TextEncoder().encode("SATI:reputation_update:v1");
+const DOMAIN_REPUTATION_BATCH = new TextEncoder().encode("SATI:reputation_batch:v2");
+const DOMAIN_EVM_LINK = new TextEncoder().encode("SATI:evm_link:v2");
 
 /**
  * Helper to convert Address to 32-byte Uint8Array
@@ -122,35 +124,245 @@ export function computeReputationNonce(provider: Address, tokenAccount: Address)
   return keccak_256(data);
 }
 
+/**
+ * Compute the hash a provider signs to rewrite a ReputationScore via update_regular_attestation.
+ * Domain: SATI:reputation_update:v1
+ *
+ * Binds the new data and expiry to the data being replaced and the attestation's
+ * current revision (read from its attestation_revision PDA, 0 if absent). Every
+ * write bumps the revision, so the signature cannot be replayed even if the score
+ * later returns to the same data.
+ *
+ * @param sasSchema - SAS schema address
+ * @param currentData - Schema data currently stored in the SAS attestation
+ * @param newData - Replacement schema data
+ * @param revision - Attestation's current revision
+ * @param expiry - Expiry timestamp for the rewritten attestation (0 = never)
+ * @returns 32-byte keccak256 hash
+ */
+export function computeReputationUpdateHash(
+  sasSchema: Address,
+  currentData: Uint8Array,
+  newData: Uint8Array,
+  revision: number,
+  expiry: bigint,
+): Uint8Array {
+  const data = new Uint8Array(DOMAIN_REPUTATION_UPDATE.length + 32 + 32 + 32 + 4 + 8); // domain + schema + hashes + revision + expiry
+  const view = new DataView(data.buffer);
+
+  let offset = 0;
+  data.set(DOMAIN_REPUTATION_UPDATE, offset);
+  offset += DOMAIN_REPUTATION_UPDATE.length;
+  data.set(addressToBytes(sasSchema), offset);
+  offset += 32;
+  data.set(keccak_256(currentData), offset);
+  offset += 32;
+  data.set(keccak_256(newData), offset);
+  offset += 32;
+  view.setUint32(offset, revision, true);
+  offset += 4;
+  view.setBigInt64(offset, expiry, true);
+
+  return keccak_256(data);
+}
+
+/**
+ * Compute the hash a provider signs once for update_regular_attestations_batch.
+ * Domain: SATI:reputation_batch:v2
+ *
+ * Each entry contributes keccak256(currentData), keccak256(newData) and its
+ * attestation revision (u32 LE), in remaining-accounts order. The current hash is
+ * zeroed for agents without a score yet. The revision is read from the entry's
+ * attestation_revision PDA (0 if absent); it survives closes, so a signature
+ * cannot recreate a score after the provider or agent closed it.
+ *
+ * @param sasSchema - SAS schema address
+ * @param entries - Current (null if absent) and new schema data plus revision per agent
+ * @param expiry - Expiry timestamp shared by every entry (0 = never)
+ * @returns 32-byte keccak256 hash
+ */
+export function computeReputationBatchHash(
+  sasSchema: Address,
+  entries: { currentData: Uint8Array | null; newData: Uint8Array; revision: number }[],
+  expiry: bigint,
+): Uint8Array {
+  const data = new Uint8Array(DOMAIN_REPUTATION_BATCH.length + 32 + 8 + entries.length * 68); // domain + schema + expiry + entries
+  const view = new DataView(data.buffer);
+
+  let offset = 0;
+  data.set(DOMAIN_REPUTATION_BATCH, offset);
+  offset += DOMAIN_REPUTATION_BATCH.length;
+  data.set(addressToBytes(sasSchema), offset);
+  offset += 32;
+  view.setBigInt64(offset, expiry, true);
+  offset += 8;
+  for (const { currentData, newData, revision } of entries) {
+    data.set(currentData ? keccak_256(currentData) : new Uint8Array(32), offset);
+    offset += 32;
+    data.set(keccak_256(newData), offset);
+    offset += 32;
+    view.setUint32(offset, revision, true);
+    offset += 4;
+  }
+
+  return keccak_256(data);
+}
+
 /**
  * Compute the hash for EVM address linking.
- * Domain: SATI:evm_link:v1
+ * Domain: SATI:evm_link:v2
+ *
+ * Binds the holder the link is recorded for and its expiry, so a signature
+ * cannot be replayed by a later holder or with a different lifetime.
  *
  * @param agentMint - Agent's mint address
+ * @param holder - Wallet that submits link_evm_address (current NFT holder)
  * @param evmAddress - 20-byte EVM address (without 0x prefix)
  * @param chainId - CAIP-2 chain identifier (e.g., "eip155:1")
+ * @param expiresAt - Unix timestamp the link expires at (null = never)
  * @returns 32-byte keccak256 hash
  */
-export function computeEvmLinkHash(agentMint: Address, evmAddress: Uint8Array, chainId: string): Uint8Array {
+export function computeEvmLinkHash(
+  agentMint: Address,
+  holder: Address,
+  evmAddress: Uint8Array,
+  chainId: string,
+  expiresAt: bigint | null = null,
+): Uint8Array {
   if (evmAddress.length !== 20) {
     throw new Error("evmAddress must be 20 bytes");
   }
 
   const chainIdBytes = new TextEncoder().encode(chainId);
-  const data = new Uint8Array(DOMAIN_EVM_LINK.length + 32 + 20 + chainIdBytes.length);
+  const data = new Uint8Array(DOMAIN_EVM_LINK.length + 32 + 32 + 20 + 8 + chainIdBytes.length);
 
   let offset = 0;
   data.set(DOMAIN_EVM_LINK, offset);
   offset += DOMAIN_EVM_LINK.length;
   data.set(addressToBytes(agentMint), offset);
   offset += 32;
+  data.set(addressToBytes(holder), offset);
+  offset += 32;
   data.set(evmAddress, offset);
   offset += 20;
+  new DataView(data.buffer).setBigInt64(offset, expiresAt ?? 0n, true);
+  offset += 8;
   data.set(chainIdBytes, offset);
 
   return keccak_256(data);
 }
 
+// EIP-712 type strings - must match programs/sati/src/constants.rs
+const EIP712_DOMAIN_TYPE = "EIP712Domain(string name,string version)";
+const EIP712_EVM_LINK_TYPE =
+  "EvmLink(bytes32 agentMint,bytes32 holder,address evmAddress,string chainId,int64 expiresAt)";
+
+function concatBytes(...parts: Uint8Array[]): Uint8Array {
+  const out = new Uint8Array(parts.reduce((sum, part) => sum + part.length, 0));
+  let offset = 0;
+  for (const part of parts) {
+    out.set(part, offset);
+    offset += part.length;
+  }
+  return out;
+}
+
+function utf8(text: string): Uint8Array {
+  return new TextEncoder().encode(text);
+}
+
+/**
+ * Build the human-readable message an EVM wallet signs with `personal_sign`
+ * (EvmLinkFormat.Eip191).
+ *
+ * MUST match build_evm_link_message() in programs/sati/src/signature.rs exactly.
+ *
+ * @param agentMint - Agent's mint address
+ * @param holder - Wallet that submits link_evm_address (current NFT holder)
+ * @param evmAddress - 20-byte EVM address
+ * @param chainId - CAIP-2 chain identifier (e.g., "eip155:1")
+ * @param expiresAt - Unix timestamp the link expires at (null = never)
+ * @returns Message text (pass to personal_sign as-is)
+ */
+export function buildEvmLinkMessage(
+  agentMint: Address,
+  holder: Address,
+  evmAddress: Uint8Array,
+  chainId: string,
+  expiresAt: bigint | null = null,
+): string {
+  if (evmAddress.length !== 20) {
+    throw new Error("evmAddress must be 20 bytes");
+  }
+  const addressHex = Array.from(evmAddress, (b) => b.toString(16).padStart(2, "0")).join("");
+  const expires = expiresAt === null ? "never" : expiresAt.toString();
+  return `SATI EVM Link\n\nAgent: ${agentMint}\nHolder: ${holder}\nAddress: 0x${addressHex}\nChain: ${chainId}\nExpires: ${expires}\n\nSign to link this address to the agent.`;
+}
+
+/**
+ * Compute the EIP-191 personal message digest ("\x19Ethereum Signed Message:\n" + length + message).
+ *
+ * @param message - Message bytes
+ * @returns 32-byte keccak256 hash
+ */
+export function computeEip191Hash(message: Uint8Array): Uint8Array {
+  return keccak_256(concatBytes(utf8(`\x19Ethereum Signed Message:\n${message.length}`), message));
+}
+
+/**
+ * Compute the EIP-712 domain separator for EVM address linking: { name: "SATI", version: "1" }.
+ */
+export function computeEvmLinkDomainSeparator(): Uint8Array {
+  return keccak_256(
+    concatBytes(keccak_256(utf8(EIP712_DOMAIN_TYPE)), keccak_256(utf8("SATI")), keccak_256(utf8("1"))),
+  );
+}
+
+/**
+ * Compute the EIP-712 digest for EVM address linking (EvmLinkFormat.Eip712).
+ *
+ * Wallets produce the same digest from eth_signTypedData_v4 with
+ * domain { name: "SATI", version: "1" } and primary type
+ * EvmLink(bytes32 agentMint,bytes32 holder,address evmAddress,string chainId,int64 expiresAt),
+ * where expiresAt is 0 for links that never expire.
+ *
+ * @param agentMint - Agent's mint address (encoded as bytes32)
+ * @param holder - Wallet that submits link_evm_address (encoded as bytes32)
+ * @param evmAddress - 20-byte EVM address
+ * @param chainId - CAIP-2 chain identifier (e.g., "eip155:1")
+ * @param expiresAt - Unix timestamp the link expires at (null = never)
+ * @returns 32-byte keccak256 hash
+ */
+export function computeEvmLinkEip712Hash(
+  agentMint: Address,
+  holder: Address,
+  evmAddress: Uint8Array,
+  chainId: string,
+  expiresAt: bigint | null = null,
+): Uint8Array {
+  if (evmAddress.length !== 20) {
+    throw new Error("evmAddress must be 20 bytes");
+  }
+  // int64 is sign-extended to 32 bytes
+  const expiresAtWord = new Uint8Array(32);
+  new DataView(expiresAtWord.buffer).setBigInt64(24, expiresAt ?? 0n, false);
+  if ((expiresAt ?? 0n) < 0n) {
+    expiresAtWord.fill(0xff, 0, 24);
+  }
+  const structHash = keccak_256(
+    concatBytes(
+      keccak_256(utf8(EIP712_EVM_LINK_TYPE)),
+      addressToBytes(agentMint),
+      addressToBytes(holder),
+      new Uint8Array(12), // address is left-padded to 32 bytes
+      evmAddress,
+      keccak_256(utf8(chainId)),
+      expiresAtWord,
+    ),
+  );
+  return keccak_256(concatBytes(new Uint8Array([0x19, 0x01]), computeEvmLinkDomainSeparator(), structHash));
+}
+
 // =============================================================================
 // Data Hash Helpers
 // =============================================================================
@@ -4,6 +4,7 @@
  * Browser-compatible utility functions for PDA derivation and constants.
  */
 
+import { keccak_256 } from "@noble/hashes/sha3.js";
 import { address, type Address, getProgramDerivedAddress, getAddressEncoder } from "@solana/kit";
 import { SATI_PROGRAM_ADDRESS } from "./generated";
 
@@ -57,20 +58,37 @@ export async function findSchemaConfigPda(sasSchema: Address): Promise<readonly
 //   const groupMint = registryConfig.data.groupMint;
 
 /**
- * Derive Associated Token Account address for Token-2022
+ * Derive the Registry Treasury PDA
+ *
+ * Seeds: ["treasury"]
+ *
+ * Receives lamport registration fees directly and owns the token accounts
+ * that receive SPL registration fees.
+ */
+export async function findTreasuryPda(): Promise<readonly [Address, number]> {
+  const encoder = new TextEncoder();
+  return getProgramDerivedAddress({
+    programAddress: SATI_PROGRAM_ADDRESS,
+    seeds: [encoder.encode("treasury")],
+  });
+}
+
+/**
+ * Derive Associated Token Account address (Token-2022 by default)
  *
  * @param mint - Token mint address
  * @param owner - Token account owner
+ * @param tokenProgram - Token program owning the mint (default: Token-2022)
  */
-export async function findAssociatedTokenAddress(mint: Address, owner: Address): Promise<readonly [Address, number]> {
+export async function findAssociatedTokenAddress(
+  mint: Address,
+  owner: Address,
+  tokenProgram: Address = TOKEN_2022_PROGRAM_ADDRESS,
+): Promise<readonly [Address, number]> {
   const addressEncoder = getAddressEncoder();
   return getProgramDerivedAddress({
     programAddress: ASSOCIATED_TOKEN_PROGRAM_ADDRESS,
-    seeds: [
-      addressEncoder.encode(owner),
-      addressEncoder.encode(TOKEN_2022_PROGRAM_ADDRESS),
-      addressEncoder.encode(mint),
-    ],
+    seeds: [addressEncoder.encode(owner), addressEncoder.encode(tokenProgram), addressEncoder.encode(mint)],
   });
 }
 
@@ -97,3 +115,49 @@ export async function findAgentIndexPda(memberNumber: bigint): Promise<readonly
     seeds: [encoder.encode("agent_index"), memberBytes],
   });
 }
+
+/**
+ * Derive the Agent Record PDA for an agent mint
+ *
+ * Seeds: ["agent", mint]
+ *
+ * Agent Record PDAs map a mint back to its member_number and hold the
+ * agent's lifecycle status.
+ *
+ * @param mint - The agent NFT mint address
+ * @returns PDA address and bump
+ */
+export async function findAgentRecordPda(mint: Address): Promise<readonly [Address, number]> {
+  const encoder = new TextEncoder();
+  const addressEncoder = getAddressEncoder();
+  return getProgramDerivedAddress({
+    programAddress: SATI_PROGRAM_ADDRESS,
+    seeds: [encoder.encode("agent"), addressEncoder.encode(mint)],
+  });
+}
+
+/**
+ * Derive the EVM Link PDA for an (agent, chain, EVM address) triple
+ *
+ * Seeds: ["evm_link", mint, keccak256(chain_id), evm_address]
+ *
+ * The chain id is hashed because CAIP-2 identifiers can exceed the 32-byte
+ * seed limit.
+ *
+ * @param mint - The agent NFT mint address
+ * @param chainId - CAIP-2 chain identifier (e.g., "eip155:1")
+ * @param evmAddress - 20-byte EVM address
+ * @returns PDA address and bump
+ */
+export async function findEvmLinkPda(
+  mint: Address,
+  chainId: string,
+  evmAddress: Uint8Array,
+): Promise<readonly [Address, number]> {
+  const encoder = new TextEncoder();
+  const addressEncoder = getAddressEncoder();
+  return getProgramDerivedAddress({
+    programAddress: SATI_PROGRAM_ADDRESS,
+    seeds: [encoder.encode("evm_link"), addressEncoder.encode(mint), keccak_256(encoder.encode(chainId)), evmAddress],
+  });
+}
@@ -198,6 +198,59 @@ export interface CounterpartyMessageParams {
  * ```
  */
 export function buildCounterpartyMessage(params: CounterpartyMessageParams): SigningMessage {
+  const text = `${buildCounterpartyFields(params)}
+
+Sign to create this attestation.`;
+
+  return {
+    messageBytes: new TextEncoder().encode(text),
+    text,
+  };
+}
+
+/**
+ * Parameters for building an amend message
+ */
+export interface CounterpartyAmendMessageParams extends CounterpartyMessageParams {
+  /** Revision the attestation will have after the amendment (current revision + 1) */
+  revision: number;
+}
+
+/**
+ * Build the SIWS message a counterparty signs to amend a compressed attestation.
+ *
+ * Same fields as {@link buildCounterpartyMessage} for the amended data, plus the
+ * new revision so an older amendment cannot be replayed.
+ *
+ * ## Message Format
+ * ```
+ * SATI {schema_name}
+ *
+ * Agent: {token_account}
+ * Task: {task_ref}
+ * Outcome: {Negative|Neutral|Positive}
+ * Details: {content}
+ * Revision: {revision}
+ *
+ * Sign to amend this attestation.
+ * ```
+ */
+export function buildCounterpartyAmendMessage(params: CounterpartyAmendMessageParams): SigningMessage {
+  const text = `${buildCounterpartyFields(params)}
+Revision: ${params.revision}
+
+Sign to amend this attestation.`;
+
+  return {
+    messageBytes: new TextEncoder().encode(text),
+    text,
+  };
+}
+
+/**
+ * Format the attestation fields shared by create and amend messages.
+ */
+function buildCounterpartyFields(params: CounterpartyMessageParams): string {
   const { schemaName, data } = params;
 
   if (data.length < OFFSETS.CONTENT) {
@@ -224,20 +277,12 @@ export function buildCounterpartyMessage(params: CounterpartyMessageParams): Sig
   const outcomeLabel = getOutcomeLabel(outcome);
   const detailsText = decodeContentForDisplay(content, contentType);
 
-  // Build SIWS-style message
-  const text = `SATI ${schemaName}
+  return `SATI ${schemaName}
 
 Agent: ${tokenAccountB58}
 Task: ${taskRefB58}
 Outcome: ${outcomeLabel}
-Details: ${detailsText}
-
-Sign to create this attestation.`;
-
-  return {
-    messageBytes: new TextEncoder().encode(text),
-    text,
-  };
+Details: ${detailsText}`;
 }
 
 /**
@@ -412,6 +412,8 @@ export interface CompressedAttestation {
   signature1: Uint8Array;
   /** Second signature (counterparty for DualSignature, zeroed for CounterpartySigned/AgentOwnerSigned) */
   signature2: Uint8Array;
+  /** Number of amendments (0 = as created) */
+  revision: number;
 }
 
 /**
@@ -221,7 +221,7 @@ export interface SignatureVerificationResult {
 export interface UpdateAgentMetadataParams {
   /** Payer for transaction fees */
   payer: KeyPairSigner;
-  /** Current owner (must be update authority) */
+  /** Current owner (must hold the agent NFT) */
   owner: KeyPairSigner;
   /** Agent NFT mint address */
   mint: Address;
@@ -0,0 +1,108 @@
+/**
+ * EVM Link Message Format Conformance Tests
+ *
+ * These tests verify that the TypeScript EVM link digests (raw, EIP-191 and
+ * EIP-712) match the shared test vectors. The same vectors are used by Rust
+ * tests to ensure cross-language consistency.
+ *
+ * If these tests fail after a format change, update the vectors file in
+ * programs/sati/tests/fixtures/evm-link-vectors.json and verify Rust tests also pass.
+ *
+ * Run: pnpm vitest run tests/unit/evm-link-conformance.test.ts
+ */
+
+import { describe, test, expect } from "vitest";
+import { readFileSync } from "node:fs";
+import { join } from "node:path";
+import { type Address, getAddressDecoder } from "@solana/kit";
+import {
+  buildEvmLinkMessage,
+  computeEip191Hash,
+  computeEvmLinkDomainSeparator,
+  computeEvmLinkEip712Hash,
+  computeEvmLinkHash,
+} from "../../src/hashes";
+
+// =============================================================================
+// Test Vector Types
+// =============================================================================
+
+interface Vector {
+  name: string;
+  agentMintHex: string;
+  holderHex: string;
+  privateKeyHex: string;
+  evmAddressHex: string;
+  chainId: string;
+  expiresAt: number | null;
+  expectedRawHashHex: string;
+  expectedEip191Message: string;
+  expectedEip191HashHex: string;
+  expectedEip712HashHex: string;
+}
+
+interface VectorsFile {
+  description: string;
+  eip712DomainSeparatorHex: string;
+  vectors: Vector[];
+}
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+function hexToBytes(hex: string): Uint8Array {
+  const bytes = new Uint8Array(hex.length / 2);
+  for (let i = 0; i < hex.length; i += 2) {
+    bytes[i / 2] = parseInt(hex.slice(i, i + 2), 16);
+  }
+  return bytes;
+}
+
+function bytesToHex(bytes: Uint8Array): string {
+  return Array.from(bytes, (b) => b.toString(16).padStart(2, "0")).join("");
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+describe("EVM Link Message Format Conformance", () => {
+  const vectorsPath = join(__dirname, "../../../../programs/sati/tests/fixtures/evm-link-vectors.json");
+  const vectorsFile: VectorsFile = JSON.parse(readFileSync(vectorsPath, "utf-8"));
+
+  test("vectors file is valid", () => {
+    expect(vectorsFile.vectors.length).toBeGreaterThan(0);
+  });
+
+  test("EIP-712 domain separator matches Rust implementation", () => {
+    expect(bytesToHex(computeEvmLinkDomainSeparator())).toBe(vectorsFile.eip712DomainSeparatorHex);
+  });
+
+  for (const vector of vectorsFile.vectors) {
+    describe(vector.name, () => {
+      const agentMint = getAddressDecoder().decode(hexToBytes(vector.agentMintHex)) as Address;
+      const holder = getAddressDecoder().decode(hexToBytes(vector.holderHex)) as Address;
+      const evmAddress = hexToBytes(vector.evmAddressHex);
+      const expiresAt = vector.expiresAt === null ? null : BigInt(vector.expiresAt);
+
+      test("raw hash", () => {
+        expect(bytesToHex(computeEvmLinkHash(agentMint, holder, evmAddress, vector.chainId, expiresAt))).toBe(
+          vector.expectedRawHashHex,
+        );
+      });
+
+      test("EIP-191 message and hash", () => {
+        const message = buildEvmLinkMessage(agentMint, holder, evmAddress, vector.chainId, expiresAt);
+        expect(message).toBe(vector.expectedEip191Message);
+        expect(bytesToHex(computeEip191Hash(new TextEncoder().encode(message)))).toBe(vector.expectedEip191HashHex);
+      });
+
+      test("EIP-712 hash", () => {
+        expect(bytesToHex(computeEvmLinkEip712Hash(agentMint, holder, evmAddress, vector.chainId, expiresAt))).toBe(
+          vector.expectedEip712HashHex,
+        );
+      });
+    });
+  }
+});
@@ -15,6 +15,8 @@ import {
   computeInteractionHash,
   computeAttestationNonce,
   computeReputationNonce,
+  computeReputationUpdateHash,
+  computeReputationBatchHash,
   computeEvmLinkHash,
   computeDataHash,
   computeDataHashFromHashes,
@@ -49,7 +51,7 @@ function randomBytes(length: number): Uint8Array {
 describe("Domain Separators", () => {
   test("domain separators have correct prefixes", () => {
     expect(new TextDecoder().decode(DOMAINS.INTERACTION)).toBe("SATI:interaction:v1");
-    expect(new TextDecoder().decode(DOMAINS.EVM_LINK)).toBe("SATI:evm_link:v1");
+    expect(new TextDecoder().decode(DOMAINS.EVM_LINK)).toBe("SATI:evm_link:v2");
   });
 
   test("domain separators are unique", () => {
@@ -217,6 +219,60 @@ describe("computeReputationNonce", () => {
   });
 });
 
+// =============================================================================
+// Tests: Reputation Update Hash
+// =============================================================================
+
+describe("computeReputationUpdateHash", () => {
+  test("binds the transition and revision", () => {
+    const sasSchema = randomAddress();
+    const dataA = randomBytes(131);
+    const dataB = randomBytes(131);
+
+    const base = computeReputationUpdateHash(sasSchema, dataA, dataB, 0, 0n);
+
+    expect(base.length).toBe(32);
+    expect(computeReputationUpdateHash(sasSchema, dataA, dataB, 0, 0n)).toEqual(base);
+    expect(computeReputationUpdateHash(sasSchema, dataA, dataB, 2, 0n)).not.toEqual(base);
+    expect(computeReputationUpdateHash(sasSchema, dataB, dataA, 0, 0n)).not.toEqual(base);
+    expect(computeReputationUpdateHash(sasSchema, dataA, dataB, 0, 1n)).not.toEqual(base);
+  });
+});
+
+// =============================================================================
+// Tests: Reputation Batch Hash
+// =============================================================================
+
+describe("computeReputationBatchHash", () => {
+  test("produces 32-byte hash", () => {
+    const hash = computeReputationBatchHash(
+      randomAddress(),
+      [{ currentData: null, newData: randomBytes(100), revision: 0 }],
+      0n,
+    );
+
+    expect(hash).toBeInstanceOf(Uint8Array);
+    expect(hash.length).toBe(32);
+  });
+
+  test("binds each entry's data and revision", () => {
+    const sasSchema = randomAddress();
+    const currentData = randomBytes(100);
+    const newData = randomBytes(100);
+
+    const base = computeReputationBatchHash(sasSchema, [{ currentData, newData, revision: 0 }], 0n);
+
+    expect(computeReputationBatchHash(sasSchema, [{ currentData, newData, revision: 0 }], 0n)).toEqual(base);
+    expect(computeReputationBatchHash(sasSchema, [{ currentData, newData, revision: 1 }], 0n)).not.toEqual(base);
+    expect(computeReputationBatchHash(sasSchema, [{ currentData: null, newData, revision: 0 }], 0n)).not.toEqual(
+      base,
+    );
+    expect(computeReputationBatchHash(randomAddress(), [{ currentData, newData, revision: 0 }], 0n)).not.toEqual(
+      base,
+    );
+  });
+});
+
 // =============================================================================
 // Tests: EVM Link Hash
 // =============================================================================
@@ -224,10 +280,11 @@ describe("computeReputationNonce", () => {
 describe("computeEvmLinkHash", () => {
   test("produces 32-byte hash", () => {
     const agentMint = randomAddress();
+    const holder = randomAddress();
     const evmAddress = randomBytes(20);
     const chainId = "eip155:1";
 
-    const hash = computeEvmLinkHash(agentMint, evmAddress, chainId);
+    const hash = computeEvmLinkHash(agentMint, holder, evmAddress, chainId);
 
     expect(hash).toBeInstanceOf(Uint8Array);
     expect(hash.length).toBe(32);
@@ -235,30 +292,47 @@ describe("computeEvmLinkHash", () => {
 
   test("is deterministic with same inputs", () => {
     const agentMint = randomAddress();
+    const holder = randomAddress();
     const evmAddress = randomBytes(20);
     const chainId = "eip155:1";
 
-    const hash1 = computeEvmLinkHash(agentMint, evmAddress, chainId);
-    const hash2 = computeEvmLinkHash(agentMint, evmAddress, chainId);
+    const hash1 = computeEvmLinkHash(agentMint, holder, evmAddress, chainId);
+    const hash2 = computeEvmLinkHash(agentMint, holder, evmAddress, chainId);
 
     expect(hash1).toEqual(hash2);
   });
 
   test("different chain IDs produce different hashes", () => {
     const agentMint = randomAddress();
+    const holder = randomAddress();
     const evmAddress = randomBytes(20);
 
-    const hash1 = computeEvmLinkHash(agentMint, evmAddress, "eip155:1");
-    const hash2 = computeEvmLinkHash(agentMint, evmAddress, "eip155:137");
+    const hash1 = computeEvmLinkHash(agentMint, holder, evmAddress, "eip155:1");
+    const hash2 = computeEvmLinkHash(agentMint, holder, evmAddress, "eip155:137");
 
     expect(hash1).not.toEqual(hash2);
   });
 
+  test("binds holder and expiry", () => {
+    const agentMint = randomAddress();
+    const holder = randomAddress();
+    const evmAddress = randomBytes(20);
+
+    const base = computeEvmLinkHash(agentMint, holder, evmAddress, "eip155:1");
+
+    expect(computeEvmLinkHash(agentMint, randomAddress(), evmAddress, "eip155:1")).not.toEqual(base);
+    expect(computeEvmLinkHash(agentMint, holder, evmAddress, "eip155:1", 1_700_000_000n)).not.toEqual(base);
+    expect(computeEvmLinkHash(agentMint, holder, evmAddress, "eip155:1", 0n)).toEqual(base);
+  });
+
   test("throws on invalid evmAddress length", () => {
     const agentMint = randomAddress();
+    const holder = randomAddress();
     const chainId = "eip155:1";
 
-    expect(() => computeEvmLinkHash(agentMint, randomBytes(32), chainId)).toThrow("evmAddress must be 20 bytes");
+    expect(() => computeEvmLinkHash(agentMint, holder, randomBytes(32), chainId)).toThrow(
+      "evmAddress must be 20 bytes",
+    );
   });
 });
 
@@ -477,14 +551,14 @@ describe("Hash Parity with Rust", () => {
 
   describe("computeEvmLinkHash parity", () => {
     test("vector 1: zeros with chain id", () => {
-      const hash = computeEvmLinkHash(TEST_ADDRESS_1, ZERO_BYTES_20, "eip155:1");
+      const hash = computeEvmLinkHash(TEST_ADDRESS_1, TEST_ADDRESS_2, ZERO_BYTES_20, "eip155:1");
 
       expect(hash.length).toBe(32);
     });
 
     test("vector 2: different chain id", () => {
-      const hash1 = computeEvmLinkHash(TEST_ADDRESS_1, ZERO_BYTES_20, "eip155:1");
-      const hash2 = computeEvmLinkHash(TEST_ADDRESS_1, ZERO_BYTES_20, "eip155:137");
+      const hash1 = computeEvmLinkHash(TEST_ADDRESS_1, TEST_ADDRESS_2, ZERO_BYTES_20, "eip155:1");
+      const hash2 = computeEvmLinkHash(TEST_ADDRESS_1, TEST_ADDRESS_2, ZERO_BYTES_20, "eip155:137");
 
       expect(hash1.length).toBe(32);
       expect(hash2.length).toBe(32);
@@ -493,11 +567,11 @@ describe("Hash Parity with Rust", () => {
 
     test("vector 3: different EVM address", () => {
       const evmAddress = new Uint8Array(20).fill(0xab);
-      const hash = computeEvmLinkHash(TEST_ADDRESS_2, evmAddress, "eip155:1");
+      const hash = computeEvmLinkHash(TEST_ADDRESS_2, TEST_ADDRESS_1, evmAddress, "eip155:1");
 
       expect(hash.length).toBe(32);
 
-      const hashZeros = computeEvmLinkHash(TEST_ADDRESS_2, ZERO_BYTES_20, "eip155:1");
+      const hashZeros = computeEvmLinkHash(TEST_ADDRESS_2, TEST_ADDRESS_1, ZERO_BYTES_20, "eip155:1");
       expect(hash).not.toEqual(hashZeros);
     });
   });
@@ -12,6 +12,9 @@ import { type Address, getAddressDecoder } from "@solana/kit";
 import {
   findRegistryConfigPda,
   findSchemaConfigPda,
+  findAgentRecordPda,
+  findTreasuryPda,
+  findEvmLinkPda,
   findAssociatedTokenAddress,
   TOKEN_2022_PROGRAM_ADDRESS,
   ASSOCIATED_TOKEN_PROGRAM_ADDRESS,
@@ -109,6 +112,89 @@ describe("findSchemaConfigPda", () => {
   });
 });
 
+// =============================================================================
+// Tests: Agent Record PDA Derivation
+// =============================================================================
+
+describe("findAgentRecordPda", () => {
+  test("is deterministic with same mint", async () => {
+    const mint = randomAddress();
+    const [pda1, bump1] = await findAgentRecordPda(mint);
+    const [pda2, bump2] = await findAgentRecordPda(mint);
+
+    expect(pda1).toBe(pda2);
+    expect(bump1).toBe(bump2);
+  });
+
+  test("different mints produce different PDAs", async () => {
+    const [pda1] = await findAgentRecordPda(randomAddress());
+    const [pda2] = await findAgentRecordPda(randomAddress());
+
+    expect(pda1).not.toBe(pda2);
+  });
+
+  test("differs from schema config PDA for the same key", async () => {
+    const key = randomAddress();
+    const [recordPda] = await findAgentRecordPda(key);
+    const [schemaPda] = await findSchemaConfigPda(key);
+
+    expect(recordPda).not.toBe(schemaPda);
+  });
+});
+
+// =============================================================================
+// Tests: Treasury PDA Derivation
+// =============================================================================
+
+describe("findTreasuryPda", () => {
+  test("is deterministic", async () => {
+    const [pda1, bump1] = await findTreasuryPda();
+    const [pda2, bump2] = await findTreasuryPda();
+
+    expect(pda1).toBe(pda2);
+    expect(bump1).toBe(bump2);
+  });
+
+  test("differs from registry config PDA", async () => {
+    const [treasuryPda] = await findTreasuryPda();
+    const [registryPda] = await findRegistryConfigPda();
+
+    expect(treasuryPda).not.toBe(registryPda);
+  });
+});
+
+// =============================================================================
+// Tests: EVM Link PDA Derivation
+// =============================================================================
+
+describe("findEvmLinkPda", () => {
+  const evmAddress = new Uint8Array(20).fill(0xab);
+
+  test("is deterministic", async () => {
+    const mint = randomAddress();
+    const [pda1, bump1] = await findEvmLinkPda(mint, "eip155:1", evmAddress);
+    const [pda2, bump2] = await findEvmLinkPda(mint, "eip155:1", evmAddress);
+
+    expect(pda1).toBe(pda2);
+    expect(bump1).toBe(bump2);
+  });
+
+  test("different chains produce different PDAs", async () => {
+    const mint = randomAddress();
+    const [mainnet] = await findEvmLinkPda(mint, "eip155:1", evmAddress);
+    const [base] = await findEvmLinkPda(mint, "eip155:8453", evmAddress);
+
+    expect(mainnet).not.toBe(base);
+  });
+
+  test("accepts chain ids longer than 32 bytes", async () => {
+    const longChainId = `eip155:${"9".repeat(40)}`;
+    const [pda] = await findEvmLinkPda(randomAddress(), longChainId, evmAddress);
+
+    expect(typeof pda).toBe("string");
+  });
+});
+
 // =============================================================================
 // Tests: Associated Token Address Derivation
 // =============================================================================
@@ -158,6 +244,19 @@ describe("findAssociatedTokenAddress", () => {
     expect(ata1).not.toBe(ata2);
   });
 
+  test("token program is part of the derivation", async () => {
+    const mint = randomAddress();
+    const owner = randomAddress();
+    const splTokenProgram = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA" as Address;
+
+    const [defaultAta] = await findAssociatedTokenAddress(mint, owner);
+    const [token2022Ata] = await findAssociatedTokenAddress(mint, owner, TOKEN_2022_PROGRAM_ADDRESS);
+    const [splAta] = await findAssociatedTokenAddress(mint, owner, splTokenProgram);
+
+    expect(defaultAta).toBe(token2022Ata);
+    expect(splAta).not.toBe(token2022Ata);
+  });
+
   test("ATA differs from registry and schema PDAs", async () => {
     const mint = randomAddress();
     const owner = randomAddress();
@@ -0,0 +1,192 @@
+//! CAIP-2 chain identifiers for EVM and external identity links.
+//!
+//! Any CAIP-2 id must have a `[-a-z0-9]{3,8}` namespace and a
+//! `[-_a-zA-Z0-9]{1,32}` reference. EVM links additionally accept only
+//! `eip155:<reference>` where the reference is the EIP-155 chain ID as a
+//! canonical decimal: non-zero, no sign, no leading zeros and at most 20 digits
+//! (fits in u64). Every chain therefore has exactly one spelling, so a
+//! signature for `eip155:1` cannot be replayed as `eip155:01` and each
+//! (chain, address) pair maps to a single EvmLink PDA.
+
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_CHAIN_ID_LENGTH;
+use crate::errors::SatiError;
+
+/// CAIP-2 namespace for EVM chains
+pub const EIP155_NAMESPACE: &str = "eip155";
+
+/// CAIP-2 namespace for Sui networks
+pub const SUI_NAMESPACE: &str = "sui";
+
+/// CAIP-2 namespace for Aptos networks
+pub const APTOS_NAMESPACE: &str = "aptos";
+
+/// CAIP-2 namespace for Cosmos chains
+pub const COSMOS_NAMESPACE: &str = "cosmos";
+
+/// CAIP-2 namespace for Bitcoin-derived chains (reference = genesis hash prefix)
+pub const BIP122_NAMESPACE: &str = "bip122";
+
+/// Maximum digits in an eip155 reference (u64::MAX has 20)
+pub const MAX_EIP155_REFERENCE_LENGTH: usize = 20;
+
+/// Maximum length of a CAIP-2 reference
+pub const MAX_REFERENCE_LENGTH: usize = 32;
+
+/// Split a CAIP-2 identifier into namespace and reference, checking the
+/// overall length and the namespace syntax.
+fn split_chain_id(chain_id: &str) -> Result<(&str, &str)> {
+    require!(
+        !chain_id.is_empty() && chain_id.len() <= MAX_CHAIN_ID_LENGTH,
+        SatiError::InvalidChainId
+    );
+    let (namespace, reference) = chain_id.split_once(':').ok_or(SatiError::InvalidChainId)?;
+
+    // CAIP-2 namespace: [-a-z0-9]{3,8}
+    require!(
+        (3..=8).contains(&namespace.len())
+            && namespace
+                .bytes()
+                .all(|b| b == b'-' || b.is_ascii_lowercase() || b.is_ascii_digit()),
+        SatiError::InvalidChainId
+    );
+    Ok((namespace, reference))
+}
+
+/// Validate a CAIP-2 chain identifier of any namespace and return its namespace.
+pub fn parse_chain_id(chain_id: &str) -> Result<&str> {
+    let (namespace, reference) = split_chain_id(chain_id)?;
+
+    // CAIP-2 reference: [-_a-zA-Z0-9]{1,32}
+    require!(
+        !reference.is_empty()
+            && reference.len() <= MAX_REFERENCE_LENGTH
+            && reference
+                .bytes()
+                .all(|b| b == b'-' || b == b'_' || b.is_ascii_alphanumeric()),
+        SatiError::InvalidChainReference
+    );
+    Ok(namespace)
+}
+
+/// Parse a CAIP-2 `eip155:<decimal>` chain identifier into its EIP-155 chain ID.
+pub fn parse_eip155_chain_id(chain_id: &str) -> Result<u64> {
+    let (namespace, reference) = split_chain_id(chain_id)?;
+    require!(
+        namespace == EIP155_NAMESPACE,
+        SatiError::UnsupportedChainNamespace
+    );
+
+    require!(
+        !reference.is_empty()
+            && reference.len() <= MAX_EIP155_REFERENCE_LENGTH
+            && reference.bytes().all(|b| b.is_ascii_digit())
+            && !reference.starts_with('0'),
+        SatiError::InvalidChainReference
+    );
+    reference
+        .parse::<u64>()
+        .map_err(|_| error!(SatiError::InvalidChainReference))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_err(chain_id: &str, expected: SatiError) {
+        assert_eq!(
+            parse_eip155_chain_id(chain_id).unwrap_err(),
+            expected.into(),
+            "{chain_id:?}"
+        );
+    }
+
+    #[test]
+    fn test_parse_eip155_chain_id_accepts_canonical() {
+        assert_eq!(parse_eip155_chain_id("eip155:1").unwrap(), 1);
+        assert_eq!(parse_eip155_chain_id("eip155:8453").unwrap(), 8453);
+        assert_eq!(parse_eip155_chain_id("eip155:11155111").unwrap(), 11155111);
+        assert_eq!(
+            parse_eip155_chain_id("eip155:18446744073709551615").unwrap(),
+            u64::MAX
+        );
+    }
+
+    #[test]
+    fn test_parse_eip155_chain_id_rejects_malformed() {
+        assert_err("", SatiError::InvalidChainId);
+        assert_err("eip155", SatiError::InvalidChainId);
+        assert_err("EIP155:1", SatiError::InvalidChainId);
+        assert_err("ab:1", SatiError::InvalidChainId);
+        assert_err(
+            &format!("eip155:{}", "1".repeat(35)),
+            SatiError::InvalidChainId,
+        );
+    }
+
+    #[test]
+    fn test_parse_eip155_chain_id_rejects_other_namespaces() {
+        assert_err(
+            "solana:5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp",
+            SatiError::UnsupportedChainNamespace,
+        );
+        assert_err("cosmos:cosmoshub-4", SatiError::UnsupportedChainNamespace);
+    }
+
+    #[test]
+    fn test_parse_eip155_chain_id_rejects_non_canonical_references() {
+        for chain_id in [
+            "eip155:",
+            "eip155:0",
+            "eip155:01",
+            "eip155:+1",
+            "eip155:-1",
+            "eip155:0x1",
+            "eip155:1:2",
+            "eip155: 1",
+            "eip155:18446744073709551616",
+            "eip155:123456789012345678901",
+        ] {
+            assert_err(chain_id, SatiError::InvalidChainReference);
+        }
+    }
+
+    #[test]
+    fn test_parse_chain_id_accepts_any_namespace() {
+        assert_eq!(parse_chain_id("eip155:01").unwrap(), "eip155");
+        assert_eq!(parse_chain_id("sui:mainnet").unwrap(), "sui");
+        assert_eq!(parse_chain_id("cosmos:cosmoshub-4").unwrap(), "cosmos");
+        assert_eq!(
+            parse_chain_id("bip122:000000000019d6689c085ae165831e93").unwrap(),
+            "bip122"
+        );
+        assert_eq!(
+            parse_chain_id("solana:5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp").unwrap(),
+            "solana"
+        );
+    }
+
+    #[test]
+    fn test_parse_chain_id_rejects_malformed() {
+        for (chain_id, expected) in [
+            ("", SatiError::InvalidChainId),
+            ("sui", SatiError::InvalidChainId),
+            ("Sui:mainnet", SatiError::InvalidChainId),
+            ("sui:", SatiError::InvalidChainReference),
+            ("sui:main net", SatiError::InvalidChainReference),
+            ("sui:main:net", SatiError::InvalidChainReference),
+            ("sui:a.b", SatiError::InvalidChainReference),
+            (
+                "sui:aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+                SatiError::InvalidChainReference,
+            ),
+        ] {
+            assert_eq!(
+                parse_chain_id(chain_id).unwrap_err(),
+                expected.into(),
+                "{chain_id:?}"
+            );
+        }
+    }
+}
@@ -20,6 +20,10 @@ pub const MAX_METADATA_KEY_LENGTH: usize = 32;
 /// Maximum length for metadata value (bytes)
 pub const MAX_METADATA_VALUE_LENGTH: usize = 200;
 
+/// Reserved TokenMetadata key committing to the registration file content
+/// (see crate::uri_hash)
+pub const URI_HASH_KEY: &str = "uri_hash";
+
 /// TLV overhead padding for Token-2022 extensions.
 ///
 /// Each extension adds ~8-12 bytes header (2-byte type + 2-byte length + alignment).
@@ -34,6 +38,76 @@ pub const TLV_OVERHEAD_PADDING: usize = 100;
 /// Beyond this, clients should request 400k CUs via SetComputeUnitLimit.
 pub const LARGE_METADATA_THRESHOLD: usize = 5;
 
+/// Pause flag: register_agent / register_agents_batch
+pub const PAUSE_REGISTRATION: u8 = 1 << 0;
+
+/// Pause flag: create_compressed_attestation / update_compressed_attestation
+pub const PAUSE_COMPRESSED_ATTESTATIONS: u8 = 1 << 1;
+
+/// Pause flag: create_regular_attestation / update_regular_attestation /
+/// update_regular_attestations_batch
+pub const PAUSE_REGULAR_ATTESTATIONS: u8 = 1 << 2;
+
+/// Pause flag: close_compressed_attestation / close_regular_attestation
+pub const PAUSE_CLOSES: u8 = 1 << 3;
+
+/// Pause flag: holder-driven agent changes (update_agent_metadata, retire_agent,
+/// set_agent_services, set_agent_status, set_successor, sync_agent_authority,
+/// claim_agent_handle, set_parent_agent, link_evm_address, link_external_identity,
+/// link_sns_domain). Removals (release_agent_handle, unlink_evm_address,
+/// unlink_sns_domain, detach_parent_agent) stay open so holders can always
+/// withdraw a claim or link.
+pub const PAUSE_AGENT_UPDATES: u8 = 1 << 4;
+
+/// All defined pause flags
+pub const PAUSE_ALL: u8 = PAUSE_REGISTRATION
+    | PAUSE_COMPRESSED_ATTESTATIONS
+    | PAUSE_REGULAR_ATTESTATIONS
+    | PAUSE_CLOSES
+    | PAUSE_AGENT_UPDATES;
+
+/// Maximum registry timelock delay (30 days, seconds).
+/// Bounds the delay so a mistaken value cannot lock governance indefinitely.
+pub const MAX_TIMELOCK_DELAY: i64 = 30 * 24 * 60 * 60;
+
+/// Maximum number of agents per register_agents_batch instruction.
+/// Each agent adds a mint signature and 5 accounts, so the 1232-byte transaction
+/// limit is usually reached first (typically 3-4 agents with short metadata).
+pub const MAX_BATCH_AGENTS: usize = 5;
+
+/// Remaining accounts per agent in register_agents_batch:
+/// owner, agent_mint, agent_token_account, agent_index, agent_record
+pub const BATCH_ACCOUNTS_PER_AGENT: usize = 5;
+
+/// Maximum number of entries per update_regular_attestations_batch instruction.
+/// Each entry carries at least 131 bytes of data plus 2-3 accounts, so the
+/// 1232-byte transaction limit is usually reached first.
+pub const MAX_BATCH_ATTESTATIONS: usize = 5;
+
+/// Minimum length for agent handles (bytes)
+pub const MIN_HANDLE_LENGTH: usize = 3;
+
+/// Maximum length for agent handles (bytes). Also bounds the PDA seed (max 32).
+pub const MAX_HANDLE_LENGTH: usize = 32;
+
+/// Maximum number of service entries in AgentServices
+pub const MAX_SERVICES: usize = 8;
+
+/// Maximum length for a service protocol name, e.g. "A2A", "MCP" (bytes)
+pub const MAX_SERVICE_PROTOCOL_LENGTH: usize = 32;
+
+/// Maximum length for a service endpoint (bytes)
+pub const MAX_SERVICE_ENDPOINT_LENGTH: usize = 200;
+
+/// Maximum length for a service version (bytes)
+pub const MAX_SERVICE_VERSION_LENGTH: usize = 32;
+
+/// Maximum length for a CAIP-2 chain identifier: namespace (8) + ":" + reference (32)
+pub const MAX_CHAIN_ID_LENGTH: usize = 41;
+
+/// Maximum length for a linked .sol domain label (bytes, without ".sol")
+pub const MAX_SNS_DOMAIN_LENGTH: usize = 63;
+
 // ============================================================================
 // Attestation Constants
 // ============================================================================
@@ -70,8 +144,48 @@ pub const DOMAIN_VALIDATION: &[u8] = b"SATI:validation:v1";
 /// Domain separator for reputation hash (provider signs).
 pub const DOMAIN_REPUTATION: &[u8] = b"SATI:reputation:v1";
 
+/// Domain separator for reputation rewrite hash (provider signs one transition).
+pub const DOMAIN_REPUTATION_UPDATE: &[u8] = b"SATI:reputation_update:v1";
+
+/// Domain separator for batch reputation hash (provider signs once per batch).
+pub const DOMAIN_REPUTATION_BATCH: &[u8] = b"SATI:reputation_batch:v2";
+
 /// Domain separator for EVM address linking.
-pub const DOMAIN_EVM_LINK: &[u8] = b"SATI:evm_link:v1";
+pub const DOMAIN_EVM_LINK: &[u8] = b"SATI:evm_link:v2";
+
+/// EIP-191 personal message prefix (followed by the decimal message length).
+pub const EIP191_PREFIX: &[u8] = b"\x19Ethereum Signed Message:\n";
+
+/// EIP-712 domain type for EVM address linking (no chainId: links may target any chain).
+pub const EIP712_DOMAIN_TYPE: &[u8] = b"EIP712Domain(string name,string version)";
+
+/// EIP-712 domain name for EVM address linking.
+pub const EIP712_DOMAIN_NAME: &[u8] = b"SATI";
+
+/// EIP-712 domain version for EVM address linking.
+pub const EIP712_DOMAIN_VERSION: &[u8] = b"1";
+
+/// EIP-712 primary type for EVM address linking.
+pub const EIP712_EVM_LINK_TYPE: &[u8] =
+    b"EvmLink(bytes32 agentMint,bytes32 holder,address evmAddress,string chainId,int64 expiresAt)";
+
+/// Domain separator for Sui/Aptos/Cosmos ed25519 identity linking.
+pub const DOMAIN_ED25519_LINK: &[u8] = b"SATI:ed25519_link:v2";
+
+/// Domain separator for secp256r1 passkey identity linking.
+pub const DOMAIN_SECP256R1_LINK: &[u8] = b"SATI:secp256r1_link:v2";
+
+/// Domain separator for Bitcoin BIP-137 identity linking.
+pub const DOMAIN_BITCOIN_LINK: &[u8] = b"SATI:bitcoin_link:v2";
+
+/// Bitcoin signed message prefix (followed by the varint message length).
+pub const BIP137_PREFIX: &[u8] = b"\x18Bitcoin Signed Message:\n";
+
+/// WebAuthn authenticator data: rpIdHash (32) + flags (1) + signCount (4).
+pub const MIN_AUTHENTICATOR_DATA_LENGTH: usize = 37;
+
+/// WebAuthn authenticator data flag: user present.
+pub const AUTHENTICATOR_FLAG_UP: u8 = 0x01;
 
 // ============================================================================
 // SAS (Solana Attestation Service) Layout Constants
@@ -90,6 +204,12 @@ pub const SAS_HEADER_SIZE: usize = 1 + 32 + 32 + 32 + 4; // 101 bytes
 /// Offset to the data payload in SAS attestation account.
 pub const SAS_DATA_OFFSET: usize = SAS_HEADER_SIZE;
 
+/// Offset of the credential field in SAS attestation account.
+pub const SAS_CREDENTIAL_OFFSET: usize = 1 + 32;
+
+/// Offset of the schema field in SAS attestation account.
+pub const SAS_SCHEMA_OFFSET: usize = SAS_CREDENTIAL_OFFSET + 32;
+
 // ============================================================================
 // SAS Attestation Tail Field Sizes (for delegation verification)
 // ============================================================================
@@ -160,4 +160,278 @@ pub enum SatiError {
 
     #[msg("Failed to extract EVM address from secp256k1 key recovery")]
     InvalidEvmAddressRecovery,
+
+    // ========================================================================
+    // Agent Metadata Errors
+    // ========================================================================
+    #[msg("Metadata update must change at least one field")]
+    EmptyMetadataUpdate,
+
+    #[msg("Agent mint does not contain valid TokenMetadata")]
+    InvalidAgentMetadata,
+
+    #[msg("Metadata key not found")]
+    MetadataKeyNotFound,
+
+    #[msg("Agent metadata authority is already held by the registry")]
+    AgentAuthorityAlreadySynced,
+
+    // ========================================================================
+    // Agent Lifecycle Errors
+    // ========================================================================
+    #[msg("Agent index does not reference this mint")]
+    AgentIndexMismatch,
+
+    #[msg("Agent record does not match token_account in attestation data")]
+    AgentMintAccountMismatch,
+
+    #[msg("Agent has been retired")]
+    AgentRetired,
+
+    #[msg("Agent is not registered in SATI (no AgentRecord for token_account)")]
+    AgentNotRegistered,
+
+    // ========================================================================
+    // Batch Registration Errors
+    // ========================================================================
+    #[msg("Batch must contain between 1 and 5 agents")]
+    InvalidBatchSize,
+
+    #[msg("Remaining accounts do not match the batch layout")]
+    InvalidBatchAccounts,
+
+    // ========================================================================
+    // Registry Governance Errors
+    // ========================================================================
+    #[msg("Direct authority transfer is disabled; use propose_registry_authority")]
+    AuthorityTransferRequiresAcceptance,
+
+    #[msg("Invalid pending authority")]
+    InvalidPendingAuthority,
+
+    #[msg("No authority transfer is pending")]
+    NoPendingAuthority,
+
+    #[msg("Registry config is already at the current version")]
+    RegistryAlreadyMigrated,
+
+    // ========================================================================
+    // Timelock Errors
+    // ========================================================================
+    #[msg("Registry is timelocked; queue this action with queue_governance_action")]
+    TimelockRequired,
+
+    #[msg("Timelock has not elapsed for this action")]
+    TimelockNotElapsed,
+
+    #[msg("Timelock delay must be between 0 and 30 days")]
+    InvalidTimelockDelay,
+
+    #[msg("Action was queued by a previous registry authority")]
+    StaleGovernanceAction,
+
+    #[msg("Schema config account required to execute this action")]
+    MissingSchemaConfigAccount,
+
+    // ========================================================================
+    // Pause Errors
+    // ========================================================================
+    #[msg("This operation is paused by the registry authority")]
+    RegistryPaused,
+
+    #[msg("Pause flags must be non-zero and only use defined bits")]
+    InvalidPauseFlags,
+
+    // ========================================================================
+    // Registration Fee Errors
+    // ========================================================================
+    #[msg(
+        "Registration fee accounts required (treasury for lamports, token accounts for SPL fees)"
+    )]
+    MissingFeeAccounts,
+
+    #[msg("Fee account does not match the registry treasury or fee mint")]
+    InvalidFeeAccount,
+
+    #[msg("Invalid registration fee mint")]
+    InvalidRegistrationFee,
+
+    #[msg("Treasury balance too low for withdrawal")]
+    InsufficientTreasuryBalance,
+
+    // ========================================================================
+    // Handle Errors
+    // ========================================================================
+    #[msg(
+        "Handle must be 3-32 ASCII letters, digits, '-' or '_', starting and ending alphanumeric"
+    )]
+    InvalidHandle,
+
+    #[msg("Handle (or a confusable variant) is already claimed")]
+    HandleTaken,
+
+    #[msg("Only the agent holder or registry authority can release a handle of an active agent")]
+    HandleReleaseNotAllowed,
+
+    // ========================================================================
+    // Verification Errors
+    // ========================================================================
+    #[msg("Schema requires a verified agent (missing or insufficient AgentVerification)")]
+    AgentNotVerified,
+
+    #[msg("Verification level must be non-zero (use revoke_agent_verification)")]
+    InvalidVerificationLevel,
+
+    #[msg("Schema config is already at the current layout")]
+    SchemaConfigAlreadyMigrated,
+
+    // ========================================================================
+    // Agent Status Errors
+    // ========================================================================
+    #[msg("Agent is suspended by the registry authority")]
+    AgentSuspended,
+
+    #[msg("Invalid agent status transition")]
+    InvalidAgentStatus,
+
+    // ========================================================================
+    // Successor Errors
+    // ========================================================================
+    #[msg("Successor must be a different agent that has not itself been succeeded")]
+    InvalidSuccessor,
+
+    // ========================================================================
+    // Service Errors
+    // ========================================================================
+    #[msg("Too many service entries (max 8)")]
+    TooManyServices,
+
+    #[msg("Service entry needs a protocol and endpoint within length limits")]
+    InvalidServiceEntry,
+
+    // ========================================================================
+    // URI Hash Errors
+    // ========================================================================
+    #[msg("uri_hash must be 'sha256:<64 hex>' or 'keccak256:<64 hex>' (lowercase)")]
+    InvalidUriHash,
+
+    // ========================================================================
+    // EVM Link Errors
+    // ========================================================================
+    #[msg("Chain ID must be a non-empty CAIP-2 identifier (max 41 bytes)")]
+    InvalidChainId,
+
+    #[msg("EVM link expiry must be in the future")]
+    InvalidEvmLinkExpiry,
+
+    #[msg("Only the current holder can unlink an active EVM link")]
+    EvmUnlinkNotAllowed,
+
+    #[msg("secp256k1 signature s value must be in the lower half of the curve order")]
+    Secp256k1HighS,
+
+    #[msg("secp256k1 recovery ID must be 0, 1, 27 or 28")]
+    InvalidRecoveryId,
+
+    #[msg("Only eip155 chain IDs are supported")]
+    UnsupportedChainNamespace,
+
+    #[msg("eip155 chain reference must be a canonical non-zero decimal (max 20 digits)")]
+    InvalidChainReference,
+
+    // ========================================================================
+    // External Identity Errors
+    // ========================================================================
+    #[msg("External identity proof is malformed")]
+    InvalidExternalIdentityProof,
+
+    #[msg("Chain ID namespace does not match the external identity type")]
+    ExternalIdentityChainMismatch,
+
+    #[msg("No precompile instruction verifies the external key's signature over the link message")]
+    ExternalSignatureNotFound,
+
+    #[msg("WebAuthn assertion must be a user-present webauthn.get over the link challenge")]
+    InvalidWebAuthnAssertion,
+
+    #[msg("Recovered key does not match the claimed external identity")]
+    ExternalIdentityMismatch,
+
+    // ========================================================================
+    // SNS Link Errors
+    // ========================================================================
+    #[msg("SNS domain must be a .sol label of 1-63 bytes without dots or whitespace")]
+    InvalidSnsDomain,
+
+    #[msg("Account is not the SNS name registry of the .sol domain")]
+    InvalidNameRegistry,
+
+    #[msg("Domain is not owned by the agent holder")]
+    SnsOwnerMismatch,
+
+    // ========================================================================
+    // Parent Agent Errors
+    // ========================================================================
+    #[msg("Parent must be a different active agent")]
+    InvalidParentAgent,
+
+    #[msg("Parent link does not belong to this agent")]
+    InvalidParentLink,
+
+    #[msg("Parent link was created by a previous holder of the agent")]
+    ParentLinkStale,
+
+    #[msg("Signer does not hold the parent agent")]
+    ParentHolderMismatch,
+
+    // ========================================================================
+    // Attestation Amendment Errors
+    // ========================================================================
+    #[msg("Only counterparty-signed attestations can be amended")]
+    AttestationNotAmendable,
+
+    #[msg("Amendment may only change the outcome, content type and content")]
+    ImmutableAttestationField,
+
+    // ========================================================================
+    // Batch Attestation Errors
+    // ========================================================================
+    #[msg("Batch must contain between 1 and 5 attestations")]
+    InvalidAttestationBatchSize,
+
+    #[msg("All batch entries must name the same provider as counterparty")]
+    BatchProviderMismatch,
+
+    // ========================================================================
+    // Regular Attestation Rewrite Errors
+    // ========================================================================
+    #[msg("SAS schema does not match the schema config")]
+    SasSchemaMismatch,
+
+    #[msg("Attestation was not issued under the SATI credential")]
+    SasCredentialMismatch,
+
+    #[msg("Remaining accounts do not match the attestation batch layout")]
+    InvalidAttestationBatchAccounts,
+
+    #[msg("Attestation revision account does not match the attestation")]
+    InvalidAttestationRevisionAccount,
+
+    // ========================================================================
+    // Renounce Errors
+    // ========================================================================
+    #[msg("Registry must be fully unpaused before renouncing authority")]
+    RenounceWhilePaused,
+
+    #[msg("Registration fee must be zero before renouncing authority")]
+    RenounceWithFee,
+
+    #[msg("External identity link expiry must be in the future")]
+    InvalidExternalLinkExpiry,
+
+    #[msg("Attestation batch contains the same attestation more than once")]
+    DuplicateBatchAttestation,
+
+    #[msg("Only the current holder can unlink an active SNS link")]
+    SnsUnlinkNotAllowed,
 }
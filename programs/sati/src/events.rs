@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{SignatureMode, StorageType};
+use crate::state::{
+    AgentStatus, ExternalIdentityType, GovernanceAction, MetadataEntry, ServiceEntry,
+    SignatureMode, StorageType,
+};
 
 // ============================================================================
 // Registry Events
@@ -22,12 +25,270 @@ pub struct AgentRegistered {
     pub non_transferable: bool,
 }
 
+/// Emitted when an agent's TokenMetadata is updated via SATI
+#[event]
+pub struct AgentMetadataUpdated {
+    /// Agent mint address
+    pub mint: Pubkey,
+    /// Holder who authorized the update
+    pub updated_by: Pubkey,
+    /// New name (None = unchanged)
+    pub name: Option<String>,
+    /// New URI (None = unchanged)
+    pub uri: Option<String>,
+    /// Additional metadata entries inserted or overwritten
+    pub additional_metadata: Vec<MetadataEntry>,
+    /// Additional metadata keys removed
+    pub removed_keys: Vec<String>,
+}
+
+/// Emitted when an agent's metadata authority is handed to the registry PDA
+#[event]
+pub struct AgentAuthoritySynced {
+    /// Agent mint address
+    pub mint: Pubkey,
+    /// Holder at the time of sync
+    pub holder: Pubkey,
+    /// Previous TokenMetadata update authority
+    pub previous_authority: Pubkey,
+}
+
+/// Emitted when an agent NFT is burned via retire_agent
+#[event]
+pub struct AgentRetired {
+    /// Agent mint address
+    pub mint: Pubkey,
+    /// Holder who retired the agent
+    pub holder: Pubkey,
+    /// Member number (its AgentIndex is closed; never reused)
+    pub member_number: u64,
+}
+
+/// Emitted when an agent's lifecycle status changes (holder or registry authority)
+#[event]
+pub struct AgentStatusChanged {
+    /// Agent mint address
+    pub mint: Pubkey,
+    /// Status before the change
+    pub old_status: AgentStatus,
+    /// Status after the change
+    pub new_status: AgentStatus,
+    /// Holder (Active/Deprecated) or registry authority (suspend/unsuspend)
+    pub changed_by: Pubkey,
+}
+
+/// Emitted when an agent is linked to its successor identity
+#[event]
+pub struct AgentSuccessorSet {
+    /// Previous agent mint
+    pub mint: Pubkey,
+    /// Successor agent mint
+    pub successor: Pubkey,
+    /// Holder of the previous agent
+    pub holder: Pubkey,
+    /// Holder of the successor agent
+    pub successor_holder: Pubkey,
+}
+
+/// Emitted when an agent is attached to a parent agent
+#[event]
+pub struct AgentParentSet {
+    /// Child agent mint
+    pub mint: Pubkey,
+    /// Parent agent mint
+    pub parent: Pubkey,
+    /// Holder of the child agent
+    pub holder: Pubkey,
+    /// Holder of the parent agent
+    pub parent_holder: Pubkey,
+}
+
+/// Emitted when an agent is detached from its parent agent
+#[event]
+pub struct AgentParentDetached {
+    /// Child agent mint
+    pub mint: Pubkey,
+    /// Parent agent mint
+    pub parent: Pubkey,
+    /// Holder of the child or parent agent, or anyone once the link is stale
+    pub detached_by: Pubkey,
+}
+
+/// Emitted when an agent's service endpoints are replaced (empty = removed)
+#[event]
+pub struct AgentServicesUpdated {
+    /// Agent mint address
+    pub mint: Pubkey,
+    /// Holder who made the update
+    pub updated_by: Pubkey,
+    /// Full list of service endpoints after the update
+    pub services: Vec<ServiceEntry>,
+}
+
+/// Emitted when an agent claims a handle
+#[event]
+pub struct AgentHandleClaimed {
+    /// Agent mint address
+    pub mint: Pubkey,
+    /// Normalized handle
+    pub handle: String,
+    /// Holder that claimed it
+    pub claimed_by: Pubkey,
+}
+
+/// Emitted when a handle is released and can be claimed again
+#[event]
+pub struct AgentHandleReleased {
+    /// Agent mint address
+    pub mint: Pubkey,
+    /// Normalized handle
+    pub handle: String,
+    /// Holder, registry authority, or anyone (retired agent)
+    pub released_by: Pubkey,
+}
+
+/// Emitted when the registry sets or updates an agent's verification badge
+#[event]
+pub struct AgentVerificationSet {
+    /// Agent mint address
+    pub mint: Pubkey,
+    /// Verification level
+    pub level: u8,
+    /// Issuing registry authority
+    pub issuer: Pubkey,
+}
+
+/// Emitted when the registry revokes an agent's verification badge
+#[event]
+pub struct AgentVerificationRevoked {
+    /// Agent mint address
+    pub mint: Pubkey,
+    /// Revoking registry authority
+    pub revoked_by: Pubkey,
+}
+
 #[event]
 pub struct RegistryAuthorityUpdated {
     pub old_authority: Pubkey,
     pub new_authority: Option<Pubkey>,
 }
 
+/// Emitted when the registry authority proposes a successor
+#[event]
+pub struct RegistryAuthorityProposed {
+    /// Current authority
+    pub authority: Pubkey,
+    /// Proposed authority (must call accept_registry_authority)
+    pub pending_authority: Pubkey,
+}
+
+/// Emitted when a pending authority transfer is cancelled
+#[event]
+pub struct RegistryAuthorityTransferCancelled {
+    /// Current authority
+    pub authority: Pubkey,
+    /// Proposal that was withdrawn
+    pub cancelled_authority: Pubkey,
+}
+
+/// Emitted when the registry timelock delay changes
+#[event]
+pub struct TimelockDelayUpdated {
+    /// Previous delay in seconds (0 = disabled)
+    pub old_delay: i64,
+    /// New delay in seconds (0 = disabled)
+    pub new_delay: i64,
+}
+
+/// Emitted when a governance action is queued behind the timelock
+#[event]
+pub struct GovernanceActionQueued {
+    /// PendingAction id
+    pub action_id: u64,
+    /// Authority that queued the action
+    pub authority: Pubkey,
+    /// Action to be performed
+    pub action: GovernanceAction,
+    /// Earliest unix timestamp at which the action can be executed
+    pub execute_after: i64,
+}
+
+/// Emitted when a queued governance action is executed
+#[event]
+pub struct GovernanceActionExecuted {
+    /// PendingAction id
+    pub action_id: u64,
+    /// Account that executed the action (anyone)
+    pub executed_by: Pubkey,
+}
+
+/// Emitted when a queued governance action is cancelled by the authority
+#[event]
+pub struct GovernanceActionCancelled {
+    /// PendingAction id
+    pub action_id: u64,
+    /// Authority that cancelled the action
+    pub authority: Pubkey,
+}
+
+/// Emitted when the authority pauses one or more flows
+#[event]
+pub struct RegistryPaused {
+    /// Flags set by this call (PAUSE_* bits)
+    pub flags: u8,
+    /// Resulting pause bitfield
+    pub paused: u8,
+}
+
+/// Emitted when the authority unpauses one or more flows
+#[event]
+pub struct RegistryUnpaused {
+    /// Flags cleared by this call (PAUSE_* bits)
+    pub flags: u8,
+    /// Resulting pause bitfield
+    pub paused: u8,
+}
+
+/// Emitted when the registration fee is changed
+#[event]
+pub struct RegistrationFeeUpdated {
+    /// Fee mint (None = lamports)
+    pub fee_mint: Option<Pubkey>,
+    /// Fee per agent (0 = no fee)
+    pub amount: u64,
+}
+
+/// Emitted when a registration fee is paid into the treasury
+#[event]
+pub struct RegistrationFeePaid {
+    /// Fee payer
+    pub payer: Pubkey,
+    /// Fee mint (None = lamports)
+    pub fee_mint: Option<Pubkey>,
+    /// Total amount paid (fee per agent x agents registered)
+    pub amount: u64,
+}
+
+/// Emitted when the authority withdraws from the treasury
+#[event]
+pub struct TreasuryWithdrawn {
+    /// Withdrawn mint (None = lamports)
+    pub mint: Option<Pubkey>,
+    /// Amount withdrawn
+    pub amount: u64,
+    /// Receiving account (wallet for lamports, token account for SPL)
+    pub destination: Pubkey,
+}
+
+/// Emitted when a RegistryConfig account is migrated to a newer layout
+#[event]
+pub struct RegistryConfigMigrated {
+    /// Layout version before migration
+    pub from_version: u8,
+    /// Layout version after migration
+    pub to_version: u8,
+}
+
 // ============================================================================
 // Attestation Events
 // ============================================================================
@@ -49,6 +310,22 @@ pub struct SchemaConfigRegistered {
     pub name: String,
 }
 
+/// Emitted when a schema's agent verification requirement changes
+#[event]
+pub struct SchemaMinVerificationUpdated {
+    /// SAS schema address
+    pub schema: Pubkey,
+    /// Minimum AgentVerification level (0 = none)
+    pub min_level: u8,
+}
+
+/// Emitted when a SchemaConfig account is grown to the current layout
+#[event]
+pub struct SchemaConfigMigrated {
+    /// SAS schema address
+    pub schema: Pubkey,
+}
+
 /// Emitted when an attestation is created (compressed or regular)
 #[event]
 pub struct AttestationCreated {
@@ -75,6 +352,27 @@ pub struct AttestationClosed {
     pub address: Pubkey,
 }
 
+/// Emitted when an attestation is amended in place
+#[event]
+pub struct AttestationUpdated {
+    /// SAS schema address
+    pub sas_schema: Pubkey,
+    /// Agent's MINT ADDRESS (stable identity). Named `token_account` for SAS compatibility.
+    pub token_account: Pubkey,
+    /// Counterparty that signed the amendment
+    pub counterparty: Pubkey,
+    /// Storage type of the attestation
+    pub storage_type: StorageType,
+    /// Attestation address (unchanged by the update)
+    pub address: Pubkey,
+    /// Outcome before the update
+    pub old_outcome: u8,
+    /// Outcome after the update
+    pub new_outcome: u8,
+    /// Revision after the update (always 0 for regular attestations, which are rewritten)
+    pub revision: u32,
+}
+
 // ============================================================================
 // EVM Linking Events
 // ============================================================================
@@ -90,4 +388,67 @@ pub struct EvmAddressLinked {
     pub chain_id: String,
     /// Unix timestamp when linked
     pub linked_at: i64,
+    /// Unix timestamp after which the link is void (None = no expiry)
+    pub expires_at: Option<i64>,
+}
+
+/// Emitted when an EvmLink is closed
+#[event]
+pub struct EvmAddressUnlinked {
+    /// Agent mint address
+    pub agent_mint: Pubkey,
+    /// EVM address (20 bytes)
+    pub evm_address: [u8; 20],
+    /// CAIP-2 chain identifier
+    pub chain_id: String,
+    /// Current holder, or whoever closed a stale link
+    pub unlinked_by: Pubkey,
+}
+
+/// Emitted when a non-EVM external identity is linked to an agent
+#[event]
+pub struct ExternalIdentityLinked {
+    /// Agent mint address
+    pub agent_mint: Pubkey,
+    /// Key type and address derivation of `identity`
+    pub identity_type: ExternalIdentityType,
+    /// CAIP-2 chain identifier (e.g., "sui:mainnet")
+    pub chain_id: String,
+    /// Chain address (Sui/Aptos 32 bytes, Cosmos/Bitcoin 20 bytes)
+    /// or compressed passkey public key (33 bytes)
+    pub identity: Vec<u8>,
+    /// NFT holder the proof was signed for; the link lapses when the NFT leaves it
+    pub holder: Pubkey,
+    /// Unix timestamp when linked
+    pub linked_at: i64,
+    /// Unix timestamp after which the link is void (None = no expiry)
+    pub expires_at: Option<i64>,
+}
+
+/// Emitted when a .sol domain is linked to an agent
+#[event]
+pub struct SnsDomainLinked {
+    /// Agent mint address
+    pub agent_mint: Pubkey,
+    /// SNS name registry account of the domain
+    pub name_account: Pubkey,
+    /// Domain label without ".sol"
+    pub domain: String,
+    /// Agent holder and domain owner
+    pub holder: Pubkey,
+    /// Unix timestamp when linked
+    pub linked_at: i64,
+}
+
+/// Emitted when an SnsLink is closed
+#[event]
+pub struct SnsDomainUnlinked {
+    /// Agent mint address
+    pub agent_mint: Pubkey,
+    /// SNS name registry account of the domain
+    pub name_account: Pubkey,
+    /// Domain label without ".sol"
+    pub domain: String,
+    /// Current holder, or whoever closed a stale link
+    pub unlinked_by: Pubkey,
 }
@@ -0,0 +1,415 @@
+//! Proofs and address derivations for `link_external_identity`.
+//!
+//! Every identity type signs the same shape of link hash,
+//! `keccak256(domain || agent_mint || holder || expires_at || chain_id)`, under
+//! its own domain separator, delivered the way its signer natively signs:
+//! - Ed25519 (Sui/Aptos/Cosmos): the raw 32-byte hash, checked by the Ed25519 precompile
+//! - secp256r1 passkeys: the hash as the WebAuthn challenge, checked by the secp256r1 precompile
+//! - Bitcoin: the lowercase hex of the hash as a BIP-137 `signmessage` text,
+//!   recovered with `secp256k1_recover`
+//!
+//! The linked identity is the address the key controls on its chain, derived
+//! with that chain's own rule.
+
+use anchor_lang::prelude::*;
+use blake2::{digest::consts::U32, Blake2b};
+use ripemd::Ripemd160;
+use sha3::{Digest, Keccak256, Sha3_256};
+use solana_program::hash::{hash as sha256, hashv as sha256v};
+use solana_program::secp256k1_recover::secp256k1_recover;
+
+use crate::constants::*;
+use crate::errors::SatiError;
+use crate::signature::check_secp256k1_signature_canonical;
+use crate::state::ExternalIdentityType;
+
+/// Sui signature scheme flag for ed25519 keys
+const SUI_ED25519_FLAG: u8 = 0x00;
+
+/// Aptos authentication scheme for single ed25519 keys
+const APTOS_ED25519_SCHEME: u8 = 0x00;
+
+/// Compute the link hash an external key signs.
+/// Domain: DOMAIN_ED25519_LINK, DOMAIN_SECP256R1_LINK or DOMAIN_BITCOIN_LINK
+///
+/// Binds the NFT holder and expiry (0 = none), so a proof signed for one
+/// holder cannot be submitted by the next one.
+pub fn compute_external_link_hash(
+    domain: &[u8],
+    agent_mint: &Pubkey,
+    holder: &Pubkey,
+    chain_id: &str,
+    expires_at: Option<i64>,
+) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    hasher.update(domain);
+    hasher.update(agent_mint.as_ref());
+    hasher.update(holder.as_ref());
+    hasher.update(expires_at.unwrap_or(0).to_le_bytes());
+    hasher.update(chain_id.as_bytes());
+    hasher.finalize().into()
+}
+
+// ============================================================================
+// Ed25519
+// ============================================================================
+
+/// Sui address: blake2b256(flag || public_key)
+pub fn sui_ed25519_address(public_key: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Blake2b::<U32>::new();
+    hasher.update([SUI_ED25519_FLAG]);
+    hasher.update(public_key);
+    hasher.finalize().into()
+}
+
+/// Aptos authentication key (initial account address): sha3_256(public_key || scheme)
+pub fn aptos_ed25519_address(public_key: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha3_256::new();
+    hasher.update(public_key);
+    hasher.update([APTOS_ED25519_SCHEME]);
+    hasher.finalize().into()
+}
+
+/// Cosmos ed25519 address: sha256(public_key)[..20]
+pub fn cosmos_ed25519_address(public_key: &[u8; 32]) -> [u8; 20] {
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&sha256(public_key).to_bytes()[..20]);
+    address
+}
+
+// ============================================================================
+// secp256r1 Passkeys (WebAuthn)
+// ============================================================================
+
+/// Check a WebAuthn assertion over `challenge` and return the bytes the passkey
+/// signed: `authenticator_data || sha256(client_data_json)`.
+///
+/// `client_data_json` must be a `webauthn.get` whose challenge is the base64url
+/// (unpadded) encoding of `challenge`, and the user-present flag must be set.
+pub fn build_webauthn_signed_message(
+    challenge: &[u8; 32],
+    authenticator_data: &[u8],
+    client_data_json: &[u8],
+) -> Result<Vec<u8>> {
+    require!(
+        authenticator_data.len() >= MIN_AUTHENTICATOR_DATA_LENGTH
+            && authenticator_data[32] & AUTHENTICATOR_FLAG_UP != 0,
+        SatiError::InvalidWebAuthnAssertion
+    );
+
+    let client_data =
+        core::str::from_utf8(client_data_json).map_err(|_| SatiError::InvalidWebAuthnAssertion)?;
+    let expected_challenge = format!("\"challenge\":\"{}\"", base64url_encode(challenge));
+    require!(
+        client_data.contains("\"type\":\"webauthn.get\"")
+            && client_data.contains(&expected_challenge),
+        SatiError::InvalidWebAuthnAssertion
+    );
+
+    let mut message = authenticator_data.to_vec();
+    message.extend_from_slice(&sha256(client_data_json).to_bytes());
+    Ok(message)
+}
+
+/// Unpadded base64url encoding (RFC 4648 §5), as used for WebAuthn challenges.
+fn base64url_encode(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, &b)| n | (b as u32) << (16 - 8 * i));
+        for i in 0..=chunk.len() {
+            out.push(ALPHABET[(n >> (18 - 6 * i)) as usize & 0x3f] as char);
+        }
+    }
+    out
+}
+
+// ============================================================================
+// Bitcoin (BIP-137)
+// ============================================================================
+
+/// Bitcoin hash160: ripemd160(sha256(data))
+pub fn hash160(data: &[u8]) -> [u8; 20] {
+    Ripemd160::digest(sha256(data).to_bytes()).into()
+}
+
+/// The `signmessage` text for a link hash: its lowercase hex encoding.
+pub fn build_bitcoin_link_message(link_hash: &[u8; 32]) -> String {
+    link_hash.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Compute the Bitcoin signed-message digest:
+/// sha256d(BIP137_PREFIX || varint(len) || message)
+pub fn compute_bip137_hash(message: &[u8]) -> [u8; 32] {
+    let len = message.len();
+    let varint: Vec<u8> = match len {
+        0..=0xfc => vec![len as u8],
+        0xfd..=0xffff => [&[0xfd][..], &(len as u16).to_le_bytes()].concat(),
+        _ => [&[0xfe][..], &(len as u32).to_le_bytes()].concat(),
+    };
+    let inner = sha256v(&[BIP137_PREFIX, &varint, message]);
+    sha256(inner.as_ref()).to_bytes()
+}
+
+/// Recover the address a BIP-137 signature (header || r || s) commits to.
+///
+/// The header selects the recovery ID and address type:
+/// 27-30 uncompressed P2PKH, 31-34 compressed P2PKH, 35-38 P2SH-P2WPKH,
+/// 39-42 P2WPKH. Only low-s signatures are accepted.
+pub fn recover_bip137_address(
+    message_hash: &[u8; 32],
+    signature: &[u8; 65],
+) -> Result<(ExternalIdentityType, [u8; 20])> {
+    let header = signature[0];
+    require!(
+        (27..=42).contains(&header),
+        SatiError::InvalidExternalIdentityProof
+    );
+    let recovery_id = (header - 27) % 4;
+    let rs: &[u8; 64] = signature[1..]
+        .try_into()
+        .map_err(|_| SatiError::InvalidExternalIdentityProof)?;
+    check_secp256k1_signature_canonical(rs)?;
+
+    let recovered = secp256k1_recover(message_hash, recovery_id, rs)
+        .map_err(|_| SatiError::Secp256k1RecoveryFailed)?
+        .to_bytes();
+
+    if header < 31 {
+        let mut uncompressed = [0u8; 65];
+        uncompressed[0] = 0x04;
+        uncompressed[1..].copy_from_slice(&recovered);
+        return Ok((ExternalIdentityType::BitcoinP2pkh, hash160(&uncompressed)));
+    }
+
+    let mut compressed = [0u8; 33];
+    compressed[0] = 0x02 | (recovered[63] & 1);
+    compressed[1..].copy_from_slice(&recovered[..32]);
+    let key_hash = hash160(&compressed);
+
+    Ok(match header {
+        31..=34 => (ExternalIdentityType::BitcoinP2pkh, key_hash),
+        35..=38 => {
+            // P2SH redeem script: OP_0 <20-byte key hash>
+            let redeem_script = [&[0x00, 0x14][..], &key_hash].concat();
+            (
+                ExternalIdentityType::BitcoinP2shP2wpkh,
+                hash160(&redeem_script),
+            )
+        }
+        _ => (ExternalIdentityType::BitcoinP2wpkh, key_hash),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k256::ecdsa::SigningKey;
+
+    /// RFC 8032 test 1 public key
+    const ED25519_PUBKEY: &str = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+
+    fn ed25519_pubkey() -> [u8; 32] {
+        hex::decode(ED25519_PUBKEY).unwrap().try_into().unwrap()
+    }
+
+    #[test]
+    fn test_ed25519_address_derivations() {
+        let public_key = ed25519_pubkey();
+        assert_eq!(
+            hex::encode(sui_ed25519_address(&public_key)),
+            "304af458e90e97c841685b8cbbc59b909f3e2cf150df590ada4c81452c29737d"
+        );
+        assert_eq!(
+            hex::encode(aptos_ed25519_address(&public_key)),
+            "63c5215e87770d17b9f4cd47c777e322f4eb152cfd2054c1080fd9d57c48913b"
+        );
+        assert_eq!(
+            hex::encode(cosmos_ed25519_address(&public_key)),
+            "21fe31dfa154a261626bf854046fd2271b7bed4b"
+        );
+    }
+
+    #[test]
+    fn test_link_hash_is_domain_separated() {
+        let mint = Pubkey::new_unique();
+        let holder = Pubkey::new_unique();
+        let hash = |domain, holder, chain_id, expires_at| {
+            compute_external_link_hash(domain, &mint, holder, chain_id, expires_at)
+        };
+        let ed25519 = hash(DOMAIN_ED25519_LINK, &holder, "sui:mainnet", None);
+        assert_ne!(
+            ed25519,
+            hash(DOMAIN_SECP256R1_LINK, &holder, "sui:mainnet", None)
+        );
+        assert_ne!(
+            ed25519,
+            hash(DOMAIN_ED25519_LINK, &holder, "sui:testnet", None)
+        );
+    }
+
+    #[test]
+    fn test_link_hash_binds_holder_and_expiry() {
+        let mint = Pubkey::new_unique();
+        let holder = Pubkey::new_unique();
+        let hash =
+            compute_external_link_hash(DOMAIN_BITCOIN_LINK, &mint, &holder, "bip122:x", None);
+        assert_ne!(
+            hash,
+            compute_external_link_hash(
+                DOMAIN_BITCOIN_LINK,
+                &mint,
+                &Pubkey::new_unique(),
+                "bip122:x",
+                None
+            )
+        );
+        assert_ne!(
+            hash,
+            compute_external_link_hash(DOMAIN_BITCOIN_LINK, &mint, &holder, "bip122:x", Some(1))
+        );
+    }
+
+    #[test]
+    fn test_base64url_encode() {
+        assert_eq!(base64url_encode(b""), "");
+        assert_eq!(base64url_encode(b"f"), "Zg");
+        assert_eq!(base64url_encode(b"fo"), "Zm8");
+        assert_eq!(base64url_encode(b"foo"), "Zm9v");
+        assert_eq!(base64url_encode(b"foob"), "Zm9vYg");
+        assert_eq!(
+            base64url_encode(&[0xfa, 0xfb, 0xfc, 0xfd, 0xfe, 0xff, 0xfb, 0xff]),
+            "-vv8_f7_-_8"
+        );
+    }
+
+    fn client_data(kind: &str, challenge: &[u8; 32]) -> Vec<u8> {
+        format!(
+            "{{\"type\":\"{kind}\",\"challenge\":\"{}\",\"origin\":\"https://example.com\"}}",
+            base64url_encode(challenge)
+        )
+        .into_bytes()
+    }
+
+    #[test]
+    fn test_webauthn_signed_message() {
+        let challenge = [7u8; 32];
+        let mut authenticator_data = vec![0u8; 37];
+        authenticator_data[32] = AUTHENTICATOR_FLAG_UP;
+        let client_data_json = client_data("webauthn.get", &challenge);
+
+        let message =
+            build_webauthn_signed_message(&challenge, &authenticator_data, &client_data_json)
+                .unwrap();
+        assert_eq!(&message[..37], &authenticator_data[..]);
+        assert_eq!(&message[37..], sha256(&client_data_json).as_ref());
+    }
+
+    #[test]
+    fn test_webauthn_rejects_bad_assertions() {
+        let challenge = [7u8; 32];
+        let mut authenticator_data = vec![0u8; 37];
+        authenticator_data[32] = AUTHENTICATOR_FLAG_UP;
+        let expected: anchor_lang::error::Error = SatiError::InvalidWebAuthnAssertion.into();
+
+        // Wrong challenge, wrong ceremony type, no user presence, short authenticator data
+        for (authenticator_data, client_data_json) in [
+            (
+                authenticator_data.clone(),
+                client_data("webauthn.get", &[8u8; 32]),
+            ),
+            (
+                authenticator_data.clone(),
+                client_data("webauthn.create", &challenge),
+            ),
+            (vec![0u8; 37], client_data("webauthn.get", &challenge)),
+            (
+                authenticator_data[..36].to_vec(),
+                client_data("webauthn.get", &challenge),
+            ),
+        ] {
+            assert_eq!(
+                build_webauthn_signed_message(&challenge, &authenticator_data, &client_data_json)
+                    .unwrap_err(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_bip137_hash() {
+        assert_eq!(
+            hex::encode(compute_bip137_hash(b"hello world")),
+            "0b6b6ce07bc55ee4aeba0098a5e5d2c8986cab228a54199723f9962316633733"
+        );
+        // 3-byte varint length
+        assert_eq!(
+            hex::encode(compute_bip137_hash(&[b'a'; 300])),
+            "3ec158a43b80359df647352dac1d37dbf26a94e5f06e5790760290c75cd11dc0"
+        );
+    }
+
+    /// Sign with private key 1 and prepend the BIP-137 header for `base`.
+    fn sign_bip137(message_hash: &[u8; 32], base: u8) -> [u8; 65] {
+        let mut key_bytes = [0u8; 32];
+        key_bytes[31] = 1;
+        let signing_key = SigningKey::from_slice(&key_bytes).unwrap();
+        let (signature, recovery_id) = signing_key.sign_prehash_recoverable(message_hash).unwrap();
+        let mut out = [0u8; 65];
+        out[0] = base + recovery_id.to_byte();
+        out[1..].copy_from_slice(&signature.to_bytes());
+        out
+    }
+
+    #[test]
+    fn test_recover_bip137_address_types() {
+        let message_hash = compute_bip137_hash(b"hello world");
+        for (base, expected_type, expected_hash) in [
+            (
+                27,
+                ExternalIdentityType::BitcoinP2pkh,
+                "91b24bf9f5288532960ac687abb035127b1d28a5",
+            ),
+            (
+                31,
+                ExternalIdentityType::BitcoinP2pkh,
+                "751e76e8199196d454941c45d1b3a323f1433bd6",
+            ),
+            (
+                35,
+                ExternalIdentityType::BitcoinP2shP2wpkh,
+                "bcfeb728b584253d5f3f70bcb780e9ef218a68f4",
+            ),
+            (
+                39,
+                ExternalIdentityType::BitcoinP2wpkh,
+                "751e76e8199196d454941c45d1b3a323f1433bd6",
+            ),
+        ] {
+            let (identity_type, address_hash) =
+                recover_bip137_address(&message_hash, &sign_bip137(&message_hash, base)).unwrap();
+            assert_eq!(identity_type, expected_type, "header base {base}");
+            assert_eq!(
+                hex::encode(address_hash),
+                expected_hash,
+                "header base {base}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_recover_bip137_rejects_bad_header() {
+        let message_hash = compute_bip137_hash(b"hello world");
+        let mut signature = sign_bip137(&message_hash, 31);
+        for header in [0, 26, 43, 255] {
+            signature[0] = header;
+            assert_eq!(
+                recover_bip137_address(&message_hash, &signature).unwrap_err(),
+                SatiError::InvalidExternalIdentityProof.into()
+            );
+        }
+    }
+}
@@ -0,0 +1,119 @@
+//! Agent handle normalization.
+//!
+//! Handles are unique, human-readable names resolved on-chain via the
+//! `["handle", handle_seed(handle)]` PDA. Two steps keep lookalikes from
+//! coexisting:
+//!
+//! 1. [`normalize_handle`] validates and case-folds the handle. Only ASCII
+//!    letters, digits, `-` and `_` are accepted, which rejects homoglyphs from
+//!    other scripts (Cyrillic `а`, fullwidth `Ａ`, zero-width characters, ...).
+//! 2. [`handle_seed`] maps the normalized handle to its confusable skeleton
+//!    (`0`→`o`, `1`→`l`, `rn`→`m`, `vv`→`w`, `_`→`-`). The skeleton is the PDA seed,
+//!    so `paypa1` and `paypal` derive the same address and only one can be claimed.
+//!
+//! Clients must apply the same rules to resolve a handle to its mint.
+
+use anchor_lang::prelude::*;
+
+use crate::constants::{MAX_HANDLE_LENGTH, MIN_HANDLE_LENGTH};
+use crate::errors::SatiError;
+
+/// Validate a handle and return its normalized (lowercase) form.
+pub fn normalize_handle(handle: &str) -> Result<String> {
+    require!(
+        (MIN_HANDLE_LENGTH..=MAX_HANDLE_LENGTH).contains(&handle.len()),
+        SatiError::InvalidHandle
+    );
+
+    let normalized = handle.to_ascii_lowercase();
+    let bytes = normalized.as_bytes();
+    let is_separator = |b: u8| b == b'-' || b == b'_';
+
+    require!(
+        bytes
+            .iter()
+            .all(|&b| b.is_ascii_lowercase() || b.is_ascii_digit() || is_separator(b)),
+        SatiError::InvalidHandle
+    );
+    require!(
+        !is_separator(bytes[0]) && !is_separator(bytes[bytes.len() - 1]),
+        SatiError::InvalidHandle
+    );
+    require!(
+        !bytes
+            .windows(2)
+            .any(|w| is_separator(w[0]) && is_separator(w[1])),
+        SatiError::InvalidHandle
+    );
+
+    Ok(normalized)
+}
+
+/// Confusable skeleton of a normalized handle, used as the PDA seed.
+pub fn handle_seed(normalized: &str) -> String {
+    normalized
+        .replace("rn", "m")
+        .replace("vv", "w")
+        .chars()
+        .map(|c| match c {
+            '0' => 'o',
+            '1' => 'l',
+            '_' => '-',
+            c => c,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_handle_case_folding() {
+        assert_eq!(normalize_handle("OpenAI").unwrap(), "openai");
+        assert_eq!(normalize_handle("my-Agent_2").unwrap(), "my-agent_2");
+    }
+
+    #[test]
+    fn test_normalize_handle_length() {
+        assert!(normalize_handle("ab").is_err());
+        assert!(normalize_handle("abc").is_ok());
+        assert!(normalize_handle(&"a".repeat(32)).is_ok());
+        assert!(normalize_handle(&"a".repeat(33)).is_err());
+    }
+
+    #[test]
+    fn test_normalize_handle_rejects_non_ascii() {
+        // Cyrillic 'а' (U+0430) looks like Latin 'a'
+        assert!(normalize_handle("p\u{0430}ypal").is_err());
+        // Fullwidth and zero-width characters
+        assert!(normalize_handle("\u{FF21}gent").is_err());
+        assert!(normalize_handle("agent\u{200B}").is_err());
+        assert!(normalize_handle("agent name").is_err());
+        assert!(normalize_handle("agent.sol").is_err());
+    }
+
+    #[test]
+    fn test_normalize_handle_separators() {
+        assert!(normalize_handle("-agent").is_err());
+        assert!(normalize_handle("agent_").is_err());
+        assert!(normalize_handle("my--agent").is_err());
+        assert!(normalize_handle("my-_agent").is_err());
+    }
+
+    #[test]
+    fn test_handle_seed_confusables_collide() {
+        assert_eq!(handle_seed("paypa1"), handle_seed("paypal"));
+        assert_eq!(handle_seed("g00gle"), handle_seed("google"));
+        assert_eq!(handle_seed("rnicrosoft"), handle_seed("microsoft"));
+        assert_eq!(handle_seed("vvallet"), handle_seed("wallet"));
+        assert_eq!(handle_seed("my_agent"), handle_seed("my-agent"));
+        assert_ne!(handle_seed("mail"), handle_seed("mall"));
+    }
+
+    #[test]
+    fn test_handle_seed_fits_pda_seed() {
+        let longest = normalize_handle(&"z".repeat(MAX_HANDLE_LENGTH)).unwrap();
+        assert!(handle_seed(&longest).len() <= 32);
+    }
+}
@@ -8,9 +8,10 @@ use light_sdk::{
     },
 };
 
+use crate::constants::PAUSE_CLOSES;
 use crate::errors::SatiError;
 use crate::events::AttestationClosed;
-use crate::state::{CloseParams, CompressedAttestation, SchemaConfig, StorageType};
+use crate::state::{CloseParams, CompressedAttestation, RegistryConfig, SchemaConfig, StorageType};
 use crate::ID;
 use crate::LIGHT_CPI_SIGNER;
 
@@ -31,6 +32,14 @@ pub struct CloseCompressedAttestation<'info> {
     )]
     pub schema_config: Account<'info, SchemaConfig>,
 
+    /// Registry config - checked for the pause switch
+    #[account(
+        seeds = [b"registry"],
+        bump = registry_config.bump,
+        constraint = !registry_config.is_paused(PAUSE_CLOSES) @ SatiError::RegistryPaused,
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
     /// Optional: Agent's ATA (required if signer is NFT owner, not counterparty).
     /// If provided, must hold the agent NFT (mint matches token_account from data).
     /// Note: token_account in data is the MINT address; this is the holder's ATA.
@@ -111,6 +120,7 @@ pub fn handler<'info>(
             num_signatures: params.num_signatures,
             signature1: params.signature1,
             signature2: params.signature2,
+            revision: params.revision.unwrap_or(0),
         },
     )?;
 
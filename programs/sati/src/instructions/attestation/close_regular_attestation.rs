@@ -2,10 +2,10 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{TokenAccount, TokenInterface};
 use solana_attestation_service_client::instructions::CloseAttestationCpiBuilder;
 
-use crate::constants::SAS_DATA_OFFSET;
+use crate::constants::{PAUSE_CLOSES, SAS_DATA_OFFSET};
 use crate::errors::SatiError;
 use crate::events::AttestationClosed;
-use crate::state::{SchemaConfig, StorageType};
+use crate::state::{RegistryConfig, SchemaConfig, StorageType};
 
 /// Accounts for close_regular_attestation instruction (SAS storage)
 #[event_cpi]
@@ -27,6 +27,14 @@ pub struct CloseRegularAttestation<'info> {
     )]
     pub schema_config: Account<'info, SchemaConfig>,
 
+    /// Registry config - checked for the pause switch
+    #[account(
+        seeds = [b"registry"],
+        bump = registry_config.bump,
+        constraint = !registry_config.is_paused(PAUSE_CLOSES) @ SatiError::RegistryPaused,
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
     /// SATI Attestation Program PDA - authorized signer on SAS credential
     /// CHECK: Seeds verified
     #[account(
@@ -19,7 +19,10 @@ use crate::signature::{
     compute_attestation_nonce, compute_interaction_hash, extract_ed25519_signatures,
     verify_agent_authorization,
 };
-use crate::state::{CompressedAttestation, CreateParams, SchemaConfig, SignatureMode, StorageType};
+use crate::state::{
+    AgentParent, AgentRecord, AgentVerification, CompressedAttestation, CreateParams,
+    RegistryConfig, SchemaConfig, SignatureMode, StorageType,
+};
 use crate::ID;
 use crate::LIGHT_CPI_SIGNER;
 
@@ -39,11 +42,29 @@ pub struct CreateCompressedAttestation<'info> {
     )]
     pub schema_config: Account<'info, SchemaConfig>,
 
+    /// Registry config - checked for the pause switch
+    #[account(
+        seeds = [b"registry"],
+        bump = registry_config.bump,
+        constraint = !registry_config.is_paused(PAUSE_COMPRESSED_ATTESTATIONS) @ SatiError::RegistryPaused,
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
     /// Instructions sysvar for Ed25519 signature verification
     /// CHECK: Verified in handler via address check
     #[account(address = instructions_sysvar::ID)]
     pub instructions_sysvar: AccountInfo<'info>,
 
+    /// AgentRecord PDA for token_account (`["agent", mint]`).
+    /// Required in every signature mode: proves the target is an active SATI agent.
+    /// CHECK: Validated in handler via AgentRecord::load_active
+    pub agent_record: AccountInfo<'info>,
+
+    /// AgentVerification PDA for token_account (`["verification", mint]`).
+    /// Required when schema_config.min_verification_level > 0.
+    /// CHECK: Validated in handler via AgentVerification::require_level
+    pub agent_verification: Option<AccountInfo<'info>>,
+
     /// Agent's ATA that holds the NFT - proves signer owns the agent identity.
     /// Required for DualSignature and AgentOwnerSigned modes.
     /// Optional for CounterpartySigned mode (not validated).
@@ -71,6 +92,14 @@ pub struct CreateCompressedAttestation<'info> {
     /// Clock sysvar for delegation expiry verification.
     /// Required when delegation_attestation is provided.
     pub clock: Option<Sysvar<'info, Clock>>,
+
+    /// AgentParent link for token_account (`["parent", mint]`).
+    /// Lets the parent agent's holder sign for the agent in AgentOwnerSigned mode.
+    pub agent_parent: Option<Account<'info, AgentParent>>,
+
+    /// Parent holder's token account for the parent mint.
+    /// Required when agent_parent is provided.
+    pub parent_ata: Option<InterfaceAccount<'info, TokenAccount>>,
     // Light Protocol accounts are passed via remaining_accounts
     // and parsed by CpiAccounts::new()
 }
@@ -120,6 +149,14 @@ pub fn handler<'info>(
         SatiError::SelfAttestationNotAllowed
     );
 
+    // 4b. Verify token_account is a registered, non-retired SATI agent
+    AgentRecord::load_active(&ctx.accounts.agent_record, &token_account_pubkey)?;
+    AgentVerification::require_level(
+        ctx.accounts.agent_verification.as_ref(),
+        &token_account_pubkey,
+        schema_config.min_verification_level,
+    )?;
+
     // 5. Determine expected pubkeys for signature extraction
     let expected_agent_pubkey = match schema_config.signature_mode {
         SignatureMode::DualSignature | SignatureMode::AgentOwnerSigned => {
@@ -158,8 +195,14 @@ pub fn handler<'info>(
         let agent_ata = ctx.accounts.agent_ata.as_ref().unwrap(); // Already validated above
         let signer_pubkey = &extracted_signatures[0].pubkey;
 
-        // Get clock for delegation verification (only if not owner)
-        let clock = if signer_pubkey != &agent_ata.owner {
+        let agent_parent = ctx
+            .accounts
+            .agent_parent
+            .as_deref()
+            .zip(ctx.accounts.parent_ata.as_deref());
+
+        // Get clock for delegation verification (only if not owner or parent holder)
+        let clock = if signer_pubkey != &agent_ata.owner && agent_parent.is_none() {
             ctx.accounts
                 .clock
                 .as_ref()
@@ -176,7 +219,7 @@ pub fn handler<'info>(
             .map(|c| c.key())
             .unwrap_or_default();
 
-        // Verify agent authorization (owner fast path or delegation)
+        // Verify agent authorization (owner fast path, parent agent or delegation)
         verify_agent_authorization(
             signer_pubkey,
             &token_account_pubkey,
@@ -184,6 +227,7 @@ pub fn handler<'info>(
             schema_config.delegation_schema.as_ref(),
             ctx.accounts.delegation_attestation.as_ref(),
             &sati_credential,
+            agent_parent,
             clock,
         )?;
     }
@@ -267,7 +311,7 @@ pub fn handler<'info>(
 
 /// Validate universal base layout fields at fixed offsets.
 /// All schemas share the same 130-byte universal layout.
-fn validate_universal_base(data: &[u8]) -> Result<()> {
+pub(crate) fn validate_universal_base(data: &[u8]) -> Result<()> {
     // Validate outcome at offset 96 (0-2 defined, 3-7 reserved)
     let outcome = data[offsets::OUTCOME];
     require!(outcome <= MAX_OUTCOME_VALUE, SatiError::InvalidOutcome);
@@ -342,6 +386,33 @@ fn build_expected_messages(
 /// Sign to create this attestation.
 /// ```
 fn build_siws_message(schema_name: &str, data: &[u8]) -> Result<Vec<u8>> {
+    let fields = build_siws_fields(schema_name, data)?;
+    Ok(format!("{fields}\n\nSign to create this attestation.").into_bytes())
+}
+
+/// Build the SIWS message the counterparty signs to amend an attestation.
+/// Must match the SDK's buildCounterpartyAmendMessage() exactly.
+///
+/// Same fields as the create message for the amended data, plus the new
+/// revision so an older amendment cannot be replayed:
+/// ```text
+/// ...
+/// Details: {content_text}
+/// Revision: {revision}
+///
+/// Sign to amend this attestation.
+/// ```
+pub(crate) fn build_siws_amend_message(
+    schema_name: &str,
+    data: &[u8],
+    revision: u32,
+) -> Result<Vec<u8>> {
+    let fields = build_siws_fields(schema_name, data)?;
+    Ok(format!("{fields}\nRevision: {revision}\n\nSign to amend this attestation.").into_bytes())
+}
+
+/// Format the attestation fields shared by the create and amend messages.
+fn build_siws_fields(schema_name: &str, data: &[u8]) -> Result<String> {
     use bs58;
 
     // Extract fields from universal layout
@@ -366,12 +437,10 @@ fn build_siws_message(schema_name: &str, data: &[u8]) -> Result<Vec<u8>> {
     // Decode content for display
     let details_text = decode_content_for_display(content, content_type);
 
-    // Build SIWS message (must match SDK exactly!)
-    let message = format!(
-        "SATI {schema_name}\n\nAgent: {token_account_b58}\nTask: {task_ref_b58}\nOutcome: {outcome_label}\nDetails: {details_text}\n\nSign to create this attestation."
-    );
-
-    Ok(message.into_bytes())
+    // Build SIWS fields (must match SDK exactly!)
+    Ok(format!(
+        "SATI {schema_name}\n\nAgent: {token_account_b58}\nTask: {task_ref_b58}\nOutcome: {outcome_label}\nDetails: {details_text}"
+    ))
 }
 
 /// Decode content bytes for human-readable display in SIWS message.
@@ -431,6 +500,7 @@ mod tests {
             closeable: false,
             name: "test".to_string(),
             bump: 255,
+            min_verification_level: 0,
         }
     }
 
@@ -622,4 +692,32 @@ mod tests {
         let result = validate_universal_base(&data);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_siws_amend_message_format() {
+        let mut data = vec![0u8; 136];
+        data[offsets::LAYOUT_VERSION] = CURRENT_LAYOUT_VERSION;
+        data[offsets::OUTCOME] = 0; // Negative
+        data[offsets::CONTENT_TYPE] = 2; // UTF-8
+        data[offsets::CONTENT..].copy_from_slice(b"fixed");
+
+        let create = String::from_utf8(build_siws_message("Feedback", &data).unwrap()).unwrap();
+        let amend =
+            String::from_utf8(build_siws_amend_message("Feedback", &data, 3).unwrap()).unwrap();
+
+        // Same fields as the create message, then the revision and amend footer
+        let fields = create
+            .strip_suffix("\n\nSign to create this attestation.")
+            .unwrap();
+        assert!(fields.ends_with("Outcome: Negative\nDetails: fixed"));
+        assert_eq!(
+            amend,
+            format!("{fields}\nRevision: 3\n\nSign to amend this attestation.")
+        );
+        assert_ne!(
+            build_siws_amend_message("Feedback", &data, 2).unwrap(),
+            amend.into_bytes(),
+            "Revision must be bound into the message"
+        );
+    }
 }
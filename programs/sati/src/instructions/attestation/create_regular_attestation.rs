@@ -12,7 +12,10 @@ use crate::signature::{
     compute_interaction_hash, compute_reputation_nonce, extract_ed25519_signatures,
     verify_agent_authorization,
 };
-use crate::state::{CreateRegularParams, SchemaConfig, SignatureMode, StorageType};
+use crate::state::{
+    AgentParent, AgentRecord, AgentVerification, CreateRegularParams, RegistryConfig, SchemaConfig,
+    SignatureMode, StorageType,
+};
 
 /// Accounts for create_regular_attestation instruction (SAS storage)
 #[event_cpi]
@@ -30,6 +33,14 @@ pub struct CreateRegularAttestation<'info> {
     )]
     pub schema_config: Account<'info, SchemaConfig>,
 
+    /// Registry config - checked for the pause switch
+    #[account(
+        seeds = [b"registry"],
+        bump = registry_config.bump,
+        constraint = !registry_config.is_paused(PAUSE_REGULAR_ATTESTATIONS) @ SatiError::RegistryPaused,
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
     /// SATI Attestation Program PDA - authorized signer on SAS credential
     /// CHECK: Seeds verified
     #[account(
@@ -56,6 +67,16 @@ pub struct CreateRegularAttestation<'info> {
     #[account(address = instructions_sysvar::ID)]
     pub instructions_sysvar: AccountInfo<'info>,
 
+    /// AgentRecord PDA for token_account (`["agent", mint]`).
+    /// Required in every signature mode: proves the target is an active SATI agent.
+    /// CHECK: Validated in handler via AgentRecord::load_active
+    pub agent_record: AccountInfo<'info>,
+
+    /// AgentVerification PDA for token_account (`["verification", mint]`).
+    /// Required when schema_config.min_verification_level > 0.
+    /// CHECK: Validated in handler via AgentVerification::require_level
+    pub agent_verification: Option<AccountInfo<'info>>,
+
     /// Agent's ATA that holds the NFT - proves signer owns the agent identity.
     /// Required for AgentOwnerSigned mode (DelegateV1).
     /// Optional for CounterpartySigned mode (ReputationScore).
@@ -75,6 +96,14 @@ pub struct CreateRegularAttestation<'info> {
     /// Required when delegation_attestation is provided.
     pub clock: Option<Sysvar<'info, Clock>>,
 
+    /// AgentParent link for token_account (`["parent", mint]`).
+    /// Lets the parent agent's holder sign for the agent in AgentOwnerSigned mode.
+    pub agent_parent: Option<Account<'info, AgentParent>>,
+
+    /// Parent holder's token account for the parent mint.
+    /// Required when agent_parent is provided.
+    pub parent_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
     /// SAS program
     /// CHECK: Program ID verified
     #[account(address = solana_attestation_service_client::programs::SOLANA_ATTESTATION_SERVICE_ID)]
@@ -126,6 +155,14 @@ pub fn handler<'info>(
         SatiError::SelfAttestationNotAllowed
     );
 
+    // 3b. Verify token_account is a registered, non-retired SATI agent
+    AgentRecord::load_active(&ctx.accounts.agent_record, &token_account_pubkey)?;
+    AgentVerification::require_level(
+        ctx.accounts.agent_verification.as_ref(),
+        &token_account_pubkey,
+        schema_config.min_verification_level,
+    )?;
+
     // 4. Determine expected pubkeys for signature extraction
     let expected_agent_pubkey = match schema_config.signature_mode {
         SignatureMode::AgentOwnerSigned => {
@@ -187,8 +224,14 @@ pub fn handler<'info>(
         let agent_ata = ctx.accounts.agent_ata.as_ref().unwrap(); // Already validated above
         let signer_pubkey = &extracted_signatures[0].pubkey;
 
-        // Get clock for delegation verification (only if not owner)
-        let clock = if signer_pubkey != &agent_ata.owner {
+        let agent_parent = ctx
+            .accounts
+            .agent_parent
+            .as_deref()
+            .zip(ctx.accounts.parent_ata.as_deref());
+
+        // Get clock for delegation verification (only if not owner or parent holder)
+        let clock = if signer_pubkey != &agent_ata.owner && agent_parent.is_none() {
             ctx.accounts
                 .clock
                 .as_ref()
@@ -198,7 +241,7 @@ pub fn handler<'info>(
             &Clock::default()
         };
 
-        // Verify agent authorization (owner fast path or delegation)
+        // Verify agent authorization (owner fast path, parent agent or delegation)
         verify_agent_authorization(
             signer_pubkey,
             &token_account_pubkey,
@@ -206,6 +249,7 @@ pub fn handler<'info>(
             schema_config.delegation_schema.as_ref(),
             ctx.accounts.delegation_attestation.as_ref(),
             &ctx.accounts.sati_credential.key(),
+            agent_parent,
             clock,
         )?;
     }
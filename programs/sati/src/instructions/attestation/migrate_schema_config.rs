@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::Discriminator;
+
+use crate::errors::SatiError;
+use crate::events::SchemaConfigMigrated;
+use crate::state::SchemaConfig;
+
+/// Accounts for migrate_schema_config instruction
+#[derive(Accounts)]
+#[instruction(sas_schema: Pubkey)]
+pub struct MigrateSchemaConfig<'info> {
+    /// Pays for the extra rent (anyone)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Schema config in an older, shorter layout.
+    /// CHECK: May not deserialize as SchemaConfig until migrated;
+    /// owner, seeds, discriminator and length are checked.
+    #[account(
+        mut,
+        seeds = [b"schema_config", sas_schema.as_ref()],
+        bump,
+        owner = crate::ID
+    )]
+    pub schema_config: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateSchemaConfig>, sas_schema: Pubkey) -> Result<()> {
+    let schema_info = ctx.accounts.schema_config.to_account_info();
+    let size = 8 + SchemaConfig::INIT_SPACE;
+
+    {
+        let data = schema_info.try_borrow_data()?;
+        require!(
+            data.len() >= 8 && &data[..8] == SchemaConfig::DISCRIMINATOR,
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        require!(data.len() < size, SatiError::SchemaConfigAlreadyMigrated);
+    }
+
+    // 1. Top up rent for the larger account
+    let required_lamports = Rent::get()?
+        .minimum_balance(size)
+        .saturating_sub(schema_info.lamports());
+    if required_lamports > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: schema_info.clone(),
+                },
+            ),
+            required_lamports,
+        )?;
+    }
+
+    // 2. Grow the account. Fields are borsh-packed, so the new zero bytes land
+    // after the serialized data and appended fields read as 0 (no requirement).
+    schema_info.resize(size)?;
+
+    emit!(SchemaConfigMigrated { schema: sas_schema });
+
+    Ok(())
+}
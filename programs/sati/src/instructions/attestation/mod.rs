@@ -2,10 +2,20 @@ pub mod close_compressed_attestation;
 pub mod close_regular_attestation;
 pub mod create_compressed_attestation;
 pub mod create_regular_attestation;
+pub mod migrate_schema_config;
 pub mod register_schema_config;
+pub mod set_schema_min_verification;
+pub mod update_compressed_attestation;
+pub mod update_regular_attestation;
+pub mod update_regular_attestations_batch;
 
 pub use close_compressed_attestation::*;
 pub use close_regular_attestation::*;
 pub use create_compressed_attestation::*;
 pub use create_regular_attestation::*;
+pub use migrate_schema_config::*;
 pub use register_schema_config::*;
+pub use set_schema_min_verification::*;
+pub use update_compressed_attestation::*;
+pub use update_regular_attestation::*;
+pub use update_regular_attestations_batch::*;
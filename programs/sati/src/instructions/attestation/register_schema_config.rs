@@ -18,6 +18,7 @@ pub struct RegisterSchemaConfig<'info> {
         bump = registry_config.bump,
         has_one = authority @ SatiError::InvalidAuthority,
         constraint = !registry_config.is_immutable() @ SatiError::ImmutableAuthority,
+        constraint = !registry_config.is_timelocked() @ SatiError::TimelockRequired,
     )]
     pub registry_config: Account<'info, RegistryConfig>,
 
@@ -55,6 +56,7 @@ pub fn handler(
     schema_config.closeable = closeable;
     schema_config.name = name.clone();
     schema_config.bump = ctx.bumps.schema_config;
+    schema_config.min_verification_level = 0;
 
     emit!(SchemaConfigRegistered {
         schema: sas_schema,
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::SatiError;
+use crate::events::SchemaMinVerificationUpdated;
+use crate::state::{RegistryConfig, SchemaConfig};
+
+/// Accounts for set_schema_min_verification instruction
+#[derive(Accounts)]
+pub struct SetSchemaMinVerification<'info> {
+    /// Current authority (must sign)
+    pub authority: Signer<'info>,
+
+    /// Registry config - validates authority and checks mutability
+    #[account(
+        seeds = [b"registry"],
+        bump = registry_config.bump,
+        has_one = authority @ SatiError::InvalidAuthority,
+        constraint = !registry_config.is_immutable() @ SatiError::ImmutableAuthority,
+        constraint = !registry_config.is_timelocked() @ SatiError::TimelockRequired,
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
+    /// Schema config to update
+    #[account(
+        mut,
+        seeds = [b"schema_config", schema_config.sas_schema.as_ref()],
+        bump = schema_config.bump,
+    )]
+    pub schema_config: Account<'info, SchemaConfig>,
+}
+
+pub fn handler(ctx: Context<SetSchemaMinVerification>, min_level: u8) -> Result<()> {
+    apply_schema_min_verification(&mut ctx.accounts.schema_config, min_level);
+    Ok(())
+}
+
+/// Store a schema's verification requirement (shared with execute_governance_action).
+pub(crate) fn apply_schema_min_verification(schema_config: &mut SchemaConfig, min_level: u8) {
+    schema_config.min_verification_level = min_level;
+
+    emit!(SchemaMinVerificationUpdated {
+        schema: schema_config.sas_schema,
+        min_level,
+    });
+}
@@ -0,0 +1,215 @@
+use anchor_lang::prelude::*;
+use light_sdk::{
+    account::LightAccount,
+    cpi::{
+        v1::{CpiAccounts, LightSystemProgramCpi},
+        InvokeLightSystemProgram, LightCpiInstruction,
+    },
+};
+use solana_program::sysvar::instructions as instructions_sysvar;
+
+use super::create_compressed_attestation::{build_siws_amend_message, validate_universal_base};
+use crate::constants::*;
+use crate::errors::SatiError;
+use crate::events::AttestationUpdated;
+use crate::signature::extract_ed25519_signatures;
+use crate::state::{
+    CompressedAttestation, RegistryConfig, SchemaConfig, SignatureMode, StorageType, UpdateParams,
+};
+use crate::ID;
+use crate::LIGHT_CPI_SIGNER;
+
+/// Accounts for update_compressed_attestation instruction (compressed storage)
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateCompressedAttestation<'info> {
+    /// Payer for transaction fees (the counterparty signs via Ed25519 instruction)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Schema config PDA
+    #[account(
+        seeds = [b"schema_config", schema_config.sas_schema.as_ref()],
+        bump = schema_config.bump,
+        constraint = schema_config.storage_type == StorageType::Compressed @ SatiError::StorageTypeMismatch,
+    )]
+    pub schema_config: Account<'info, SchemaConfig>,
+
+    /// Registry config - checked for the pause switch
+    #[account(
+        seeds = [b"registry"],
+        bump = registry_config.bump,
+        constraint = !registry_config.is_paused(PAUSE_COMPRESSED_ATTESTATIONS) @ SatiError::RegistryPaused,
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
+    /// Instructions sysvar for Ed25519 signature verification
+    /// CHECK: Verified in handler via address check
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+    // Light Protocol accounts are passed via remaining_accounts
+    // and parsed by CpiAccounts::new()
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateCompressedAttestation<'info>>,
+    params: UpdateParams,
+) -> Result<()> {
+    let schema_config = &ctx.accounts.schema_config;
+
+    // 1. Only attestations carrying a counterparty SIWS signature can be amended
+    require!(
+        matches!(
+            schema_config.signature_mode,
+            SignatureMode::DualSignature | SignatureMode::CounterpartySigned
+        ),
+        SatiError::AttestationNotAmendable
+    );
+
+    // 2. Verify data lengths
+    require!(
+        params.current_data.len() >= MIN_BASE_LAYOUT_SIZE
+            && params.new_data.len() >= MIN_BASE_LAYOUT_SIZE,
+        SatiError::AttestationDataTooSmall
+    );
+    require!(
+        params.new_data.len() <= MAX_ATTESTATION_DATA_SIZE,
+        SatiError::AttestationDataTooLarge
+    );
+
+    // 3. Keep the address binding and the agent's blind commitment intact
+    check_amendment(&params.current_data, &params.new_data)?;
+    validate_universal_base(&params.new_data)?;
+
+    let token_account_bytes: [u8; 32] = params.current_data
+        [offsets::TOKEN_ACCOUNT..offsets::COUNTERPARTY]
+        .try_into()
+        .map_err(|_| SatiError::InvalidSignature)?;
+    let counterparty_bytes: [u8; 32] = params.current_data[offsets::COUNTERPARTY..offsets::OUTCOME]
+        .try_into()
+        .map_err(|_| SatiError::InvalidSignature)?;
+    let token_account = Pubkey::new_from_array(token_account_bytes);
+    let counterparty = Pubkey::new_from_array(counterparty_bytes);
+
+    // 4. Verify the counterparty signed the amend message for the next revision
+    let revision = params.revision.checked_add(1).ok_or(SatiError::Overflow)?;
+    let amend_message = build_siws_amend_message(&schema_config.name, &params.new_data, revision)?;
+    let extracted_signatures = extract_ed25519_signatures(
+        &ctx.accounts.instructions_sysvar,
+        None,
+        &counterparty,
+        SignatureMode::CounterpartySigned,
+        &[amend_message],
+    )?;
+
+    // 5. Initialize Light Protocol CPI accounts
+    let light_cpi_accounts = CpiAccounts::new(
+        ctx.accounts.payer.as_ref(),
+        ctx.remaining_accounts,
+        LIGHT_CPI_SIGNER,
+    );
+
+    // 6. Reconstruct the current attestation (hash verified by the Light system program)
+    let mut attestation = LightAccount::<CompressedAttestation>::new_mut(
+        &ID,
+        &params.account_meta,
+        CompressedAttestation {
+            sas_schema: schema_config.sas_schema.to_bytes(),
+            token_account: token_account_bytes,
+            data: params.current_data.clone(),
+            num_signatures: params.num_signatures,
+            signature1: params.signature1,
+            signature2: params.signature2,
+            revision: params.revision,
+        },
+    )?;
+
+    // 7. Write the amended data; the counterparty's slot holds the amend signature
+    let old_outcome = params.current_data[offsets::OUTCOME];
+    let new_outcome = params.new_data[offsets::OUTCOME];
+    let counterparty_signature = extracted_signatures[0].sig;
+    attestation.data = params.new_data;
+    match schema_config.signature_mode {
+        SignatureMode::DualSignature => attestation.signature2 = counterparty_signature,
+        _ => attestation.signature1 = counterparty_signature,
+    }
+    attestation.revision = revision;
+
+    // 8. CPI to Light System Program to update
+    LightSystemProgramCpi::new_cpi(LIGHT_CPI_SIGNER, params.proof)
+        .with_light_account(attestation)?
+        .invoke(light_cpi_accounts)
+        .map_err(|_| SatiError::LightCpiInvocationFailed)?;
+
+    // 9. Emit event
+    emit_cpi!(AttestationUpdated {
+        sas_schema: schema_config.sas_schema,
+        token_account,
+        counterparty,
+        storage_type: StorageType::Compressed,
+        address: Pubkey::new_from_array(params.account_meta.address),
+        old_outcome,
+        new_outcome,
+        revision,
+    });
+
+    Ok(())
+}
+
+/// Require an amendment to leave every field except outcome, content type and
+/// content unchanged. task_ref, token_account and counterparty derive the
+/// address; data_hash is covered by the agent's interaction signature.
+fn check_amendment(current: &[u8], new: &[u8]) -> Result<()> {
+    require!(
+        current[..offsets::OUTCOME] == new[..offsets::OUTCOME]
+            && current[offsets::DATA_HASH..offsets::CONTENT_TYPE]
+                == new[offsets::DATA_HASH..offsets::CONTENT_TYPE],
+        SatiError::ImmutableAttestationField
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_data(outcome: u8, content: &[u8]) -> Vec<u8> {
+        let mut data = vec![0u8; MIN_BASE_LAYOUT_SIZE];
+        data[offsets::LAYOUT_VERSION] = CURRENT_LAYOUT_VERSION;
+        data[offsets::TASK_REF..offsets::TOKEN_ACCOUNT].fill(1);
+        data[offsets::TOKEN_ACCOUNT..offsets::COUNTERPARTY].fill(2);
+        data[offsets::COUNTERPARTY..offsets::OUTCOME].fill(3);
+        data[offsets::OUTCOME] = outcome;
+        data[offsets::DATA_HASH..offsets::CONTENT_TYPE].fill(4);
+        data[offsets::CONTENT_TYPE] = 2;
+        data.extend_from_slice(content);
+        data
+    }
+
+    #[test]
+    fn test_check_amendment_allows_outcome_and_content() {
+        let current = make_data(2, b"great srevice");
+        assert!(check_amendment(&current, &make_data(2, b"great service")).is_ok());
+        assert!(check_amendment(&current, &make_data(1, b"")).is_ok());
+    }
+
+    #[test]
+    fn test_check_amendment_rejects_bound_fields() {
+        let current = make_data(2, b"ok");
+        for (offset, field) in [
+            (offsets::LAYOUT_VERSION, "layout_version"),
+            (offsets::TASK_REF, "task_ref"),
+            (offsets::TOKEN_ACCOUNT, "token_account"),
+            (offsets::COUNTERPARTY, "counterparty"),
+            (offsets::DATA_HASH, "data_hash"),
+        ] {
+            let mut new = current.clone();
+            new[offset] ^= 0xff;
+            assert_eq!(
+                check_amendment(&current, &new).unwrap_err(),
+                SatiError::ImmutableAttestationField.into(),
+                "{field}"
+            );
+        }
+    }
+}
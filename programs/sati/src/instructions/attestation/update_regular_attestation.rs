@@ -0,0 +1,349 @@
+use anchor_lang::prelude::*;
+use solana_attestation_service_client::instructions::{
+    CloseAttestationCpiBuilder, CreateAttestationCpiBuilder,
+};
+use solana_attestation_service_client::programs::SOLANA_ATTESTATION_SERVICE_ID;
+use solana_program::sysvar::instructions as instructions_sysvar;
+
+use super::create_compressed_attestation::validate_universal_base;
+use crate::constants::*;
+use crate::errors::SatiError;
+use crate::events::AttestationUpdated;
+use crate::signature::{
+    compute_reputation_nonce, compute_reputation_update_hash, extract_ed25519_signatures,
+};
+use crate::state::{
+    AgentRecord, AgentVerification, AttestationRevision, RegistryConfig, SchemaConfig,
+    SignatureMode, StorageType, UpdateRegularParams,
+};
+use crate::utils::create_pda_account;
+
+/// Accounts for update_regular_attestation instruction (SAS storage)
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateRegularAttestation<'info> {
+    /// Payer for the recreated account; receives the old account's rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Schema config PDA
+    #[account(
+        seeds = [b"schema_config", schema_config.sas_schema.as_ref()],
+        bump = schema_config.bump,
+        constraint = schema_config.storage_type == StorageType::Regular @ SatiError::StorageTypeMismatch,
+    )]
+    pub schema_config: Account<'info, SchemaConfig>,
+
+    /// Registry config - checked for the pause switch
+    #[account(
+        seeds = [b"registry"],
+        bump = registry_config.bump,
+        constraint = !registry_config.is_paused(PAUSE_REGULAR_ATTESTATIONS) @ SatiError::RegistryPaused,
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
+    /// SATI Attestation Program PDA - authorized signer on SAS credential
+    /// CHECK: Seeds verified
+    #[account(
+        seeds = [b"sati_attestation"],
+        bump,
+    )]
+    pub sati_pda: AccountInfo<'info>,
+
+    /// SATI SAS credential account
+    /// CHECK: Validated by SAS program
+    pub sati_credential: AccountInfo<'info>,
+
+    /// SAS schema account (the one schema_config governs)
+    /// CHECK: Address verified; validated by SAS program
+    #[account(address = schema_config.sas_schema @ SatiError::SasSchemaMismatch)]
+    pub sas_schema: AccountInfo<'info>,
+
+    /// Attestation PDA to be rewritten (closed and recreated at the same address)
+    /// CHECK: Owner verified; schema and credential checked in handler
+    #[account(mut, owner = SOLANA_ATTESTATION_SERVICE_ID)]
+    pub attestation: AccountInfo<'info>,
+
+    /// Rewrite counter for `attestation` (created on the first rewrite)
+    /// CHECK: PDA verified via seeds; created or deserialized in handler
+    #[account(
+        mut,
+        seeds = [b"attestation_revision", attestation.key().as_ref()],
+        bump
+    )]
+    pub attestation_revision: UncheckedAccount<'info>,
+
+    /// Instructions sysvar for Ed25519 signature verification
+    /// CHECK: Verified via address
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    /// AgentRecord PDA for token_account (`["agent", mint]`).
+    /// CHECK: Validated in handler via AgentRecord::load_active
+    pub agent_record: AccountInfo<'info>,
+
+    /// AgentVerification PDA for token_account (`["verification", mint]`).
+    /// Required when schema_config.min_verification_level > 0.
+    /// CHECK: Validated in handler via AgentVerification::require_level
+    pub agent_verification: Option<AccountInfo<'info>>,
+
+    /// SAS program
+    /// CHECK: Program ID verified
+    #[account(address = SOLANA_ATTESTATION_SERVICE_ID)]
+    pub sas_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateRegularAttestation<'info>>,
+    params: UpdateRegularParams,
+) -> Result<()> {
+    let schema_config = &ctx.accounts.schema_config;
+
+    // 1. Only provider-signed scores can be rewritten by the provider
+    require!(
+        schema_config.signature_mode == SignatureMode::CounterpartySigned,
+        SatiError::AttestationNotAmendable
+    );
+
+    // 2. Verify new data length and layout
+    validate_regular_data(&params.data)?;
+
+    // 3. Read the current data from the SAS attestation, which must belong to
+    //    this schema and the SATI credential
+    // SAS layout: discriminator(1) + nonce(32) + credential(32) + schema(32) + data_len(4) + data
+    let current_data = {
+        let account_data = ctx.accounts.attestation.try_borrow_data()?;
+        require_sas_header(
+            &account_data,
+            &ctx.accounts.sati_credential.key(),
+            &schema_config.sas_schema,
+        )?;
+        read_sas_data(&account_data)?
+    };
+
+    // 4. The nonce binds (provider, agent), so neither may change
+    require!(
+        current_data[offsets::TOKEN_ACCOUNT..offsets::OUTCOME]
+            == params.data[offsets::TOKEN_ACCOUNT..offsets::OUTCOME],
+        SatiError::ImmutableAttestationField
+    );
+
+    let token_account_bytes: [u8; 32] = params.data[offsets::TOKEN_ACCOUNT..offsets::COUNTERPARTY]
+        .try_into()
+        .map_err(|_| SatiError::InvalidSignature)?;
+    let counterparty_bytes: [u8; 32] = params.data[offsets::COUNTERPARTY..offsets::OUTCOME]
+        .try_into()
+        .map_err(|_| SatiError::InvalidSignature)?;
+    let token_account_pubkey = Pubkey::new_from_array(token_account_bytes);
+    let counterparty_pubkey = Pubkey::new_from_array(counterparty_bytes);
+
+    // 5. Verify token_account is still a registered, non-retired SATI agent
+    AgentRecord::load_active(&ctx.accounts.agent_record, &token_account_pubkey)?;
+    AgentVerification::require_level(
+        ctx.accounts.agent_verification.as_ref(),
+        &token_account_pubkey,
+        schema_config.min_verification_level,
+    )?;
+
+    // 6. Verify the provider signed this exact transition at the current revision
+    let attestation_key = ctx.accounts.attestation.key();
+    let revision_info = ctx.accounts.attestation_revision.to_account_info();
+    let current_revision = AttestationRevision::load(&revision_info)?;
+    let expected_message = compute_reputation_update_hash(
+        &schema_config.sas_schema,
+        &current_data,
+        &params.data,
+        current_revision,
+        params.expiry,
+    );
+    extract_ed25519_signatures(
+        &ctx.accounts.instructions_sysvar,
+        None,
+        &counterparty_pubkey,
+        SignatureMode::CounterpartySigned,
+        &[expected_message.to_vec()],
+    )?;
+
+    // 7. SAS has no in-place update: close and recreate at the same nonce
+    let sati_pda_seeds: &[&[u8]] = &[b"sati_attestation", &[ctx.bumps.sati_pda]];
+
+    CloseAttestationCpiBuilder::new(&ctx.accounts.sas_program)
+        .payer(&ctx.accounts.payer)
+        .authority(&ctx.accounts.sati_pda)
+        .credential(&ctx.accounts.sati_credential)
+        .attestation(&ctx.accounts.attestation)
+        .invoke_signed(&[sati_pda_seeds])?;
+
+    let nonce = compute_reputation_nonce(&counterparty_pubkey, &token_account_pubkey);
+
+    CreateAttestationCpiBuilder::new(&ctx.accounts.sas_program)
+        .payer(&ctx.accounts.payer)
+        .authority(&ctx.accounts.sati_pda)
+        .credential(&ctx.accounts.sati_credential)
+        .schema(&ctx.accounts.sas_schema)
+        .attestation(&ctx.accounts.attestation)
+        .system_program(&ctx.accounts.system_program)
+        .nonce(Pubkey::new_from_array(nonce))
+        .data(params.data.clone())
+        .expiry(params.expiry)
+        .invoke_signed(&[sati_pda_seeds])?;
+
+    // 8. Bump the rewrite counter
+    let revision = current_revision.checked_add(1).ok_or(SatiError::Overflow)?;
+    store_attestation_revision(
+        &ctx.accounts.payer,
+        &revision_info,
+        &ctx.accounts.system_program,
+        &attestation_key,
+        ctx.bumps.attestation_revision,
+        revision,
+    )?;
+
+    // 9. Emit event
+    emit_cpi!(AttestationUpdated {
+        sas_schema: schema_config.sas_schema,
+        token_account: token_account_pubkey,
+        counterparty: counterparty_pubkey,
+        storage_type: StorageType::Regular,
+        address: attestation_key,
+        old_outcome: current_data[offsets::OUTCOME],
+        new_outcome: params.data[offsets::OUTCOME],
+        revision,
+    });
+
+    Ok(())
+}
+
+/// Validate replacement data against the universal base layout.
+pub(crate) fn validate_regular_data(data: &[u8]) -> Result<()> {
+    require!(
+        data.len() >= MIN_BASE_LAYOUT_SIZE,
+        SatiError::AttestationDataTooSmall
+    );
+    require!(
+        data.len() <= MAX_ATTESTATION_DATA_SIZE,
+        SatiError::AttestationDataTooLarge
+    );
+    require!(
+        data[offsets::LAYOUT_VERSION] == CURRENT_LAYOUT_VERSION,
+        SatiError::UnsupportedLayoutVersion
+    );
+    validate_universal_base(data)
+}
+
+/// Require a SAS attestation account to belong to `schema` and `credential`.
+pub(crate) fn require_sas_header(
+    account_data: &[u8],
+    credential: &Pubkey,
+    schema: &Pubkey,
+) -> Result<()> {
+    require!(
+        account_data.len() >= SAS_DATA_OFFSET,
+        SatiError::AttestationDataTooSmall
+    );
+    require!(
+        account_data[SAS_SCHEMA_OFFSET..SAS_SCHEMA_OFFSET + 32] == schema.to_bytes(),
+        SatiError::SasSchemaMismatch
+    );
+    require!(
+        account_data[SAS_CREDENTIAL_OFFSET..SAS_SCHEMA_OFFSET] == credential.to_bytes(),
+        SatiError::SasCredentialMismatch
+    );
+    Ok(())
+}
+
+/// Store `revision` in an attestation's counter PDA, creating it on the first rewrite.
+pub(crate) fn store_attestation_revision<'info>(
+    payer: &AccountInfo<'info>,
+    info: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    attestation: &Pubkey,
+    bump: u8,
+    revision: u32,
+) -> Result<()> {
+    if info.owner != &crate::ID {
+        create_pda_account(
+            payer,
+            info,
+            system_program,
+            AttestationRevision::SIZE,
+            &[b"attestation_revision", attestation.as_ref(), &[bump]],
+        )?;
+    }
+    AttestationRevision { revision, bump }.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])
+}
+
+/// Copy the schema data payload out of a SAS attestation account.
+pub(crate) fn read_sas_data(account_data: &[u8]) -> Result<Vec<u8>> {
+    require!(
+        account_data.len() >= SAS_DATA_OFFSET,
+        SatiError::AttestationDataTooSmall
+    );
+    let data_len = u32::from_le_bytes(
+        account_data[SAS_DATA_OFFSET - 4..SAS_DATA_OFFSET]
+            .try_into()
+            .map_err(|_| SatiError::AttestationDataTooSmall)?,
+    ) as usize;
+    let data = account_data
+        .get(SAS_DATA_OFFSET..SAS_DATA_OFFSET + data_len)
+        .ok_or(SatiError::AttestationDataTooSmall)?;
+    require!(
+        data.len() >= MIN_BASE_LAYOUT_SIZE,
+        SatiError::AttestationDataTooSmall
+    );
+    Ok(data.to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sas_account(data: &[u8]) -> Vec<u8> {
+        let mut account = vec![0u8; SAS_DATA_OFFSET - 4];
+        account.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        account.extend_from_slice(data);
+        account.extend_from_slice(&[9u8; SAS_TAIL_SIZE]);
+        account
+    }
+
+    fn sas_header(credential: &Pubkey, schema: &Pubkey) -> Vec<u8> {
+        let mut account = vec![0u8; SAS_DATA_OFFSET];
+        account[SAS_CREDENTIAL_OFFSET..SAS_SCHEMA_OFFSET].copy_from_slice(credential.as_ref());
+        account[SAS_SCHEMA_OFFSET..SAS_SCHEMA_OFFSET + 32].copy_from_slice(schema.as_ref());
+        account
+    }
+
+    #[test]
+    fn test_require_sas_header() {
+        let credential = Pubkey::new_unique();
+        let schema = Pubkey::new_unique();
+        let account = sas_header(&credential, &schema);
+
+        assert!(require_sas_header(&account, &credential, &schema).is_ok());
+        assert_eq!(
+            require_sas_header(&account, &credential, &Pubkey::new_unique()).unwrap_err(),
+            SatiError::SasSchemaMismatch.into()
+        );
+        assert_eq!(
+            require_sas_header(&account, &Pubkey::new_unique(), &schema).unwrap_err(),
+            SatiError::SasCredentialMismatch.into()
+        );
+        assert!(require_sas_header(&account[..SAS_SCHEMA_OFFSET], &credential, &schema).is_err());
+    }
+
+    #[test]
+    fn test_read_sas_data() {
+        let data = vec![7u8; MIN_BASE_LAYOUT_SIZE + 5];
+        assert_eq!(read_sas_data(&sas_account(&data)).unwrap(), data);
+    }
+
+    #[test]
+    fn test_read_sas_data_rejects_truncated() {
+        let data = vec![7u8; MIN_BASE_LAYOUT_SIZE];
+        let account = sas_account(&data);
+        assert!(read_sas_data(&account[..SAS_DATA_OFFSET + 10]).is_err());
+        assert!(read_sas_data(&sas_account(&data[..MIN_BASE_LAYOUT_SIZE - 1])).is_err());
+    }
+}
@@ -0,0 +1,305 @@
+use anchor_lang::prelude::*;
+use solana_attestation_service_client::instructions::{
+    CloseAttestationCpiBuilder, CreateAttestationCpiBuilder,
+};
+use solana_attestation_service_client::programs::SOLANA_ATTESTATION_SERVICE_ID;
+use solana_program::sysvar::instructions as instructions_sysvar;
+
+use super::update_regular_attestation::{
+    read_sas_data, require_sas_header, store_attestation_revision, validate_regular_data,
+};
+use crate::constants::*;
+use crate::errors::SatiError;
+use crate::events::{AttestationCreated, AttestationUpdated};
+use crate::signature::{
+    compute_reputation_batch_hash, compute_reputation_nonce, extract_ed25519_signatures,
+    ReputationBatchEntry,
+};
+use crate::state::{
+    AgentRecord, AgentVerification, AttestationRevision, RegistryConfig, SchemaConfig,
+    SignatureMode, StorageType, UpdateRegularBatchParams,
+};
+
+/// Accounts for update_regular_attestations_batch.
+///
+/// Per-entry accounts are passed via remaining_accounts, in the same order as
+/// `entries`:
+/// 0. attestation (writable) - SAS PDA for compute_reputation_nonce(provider, agent)
+/// 1. attestation_revision (writable) - PDA ["attestation_revision", attestation]
+/// 2. agent_record - PDA ["agent", token_account]
+/// 3. agent_verification - PDA ["verification", token_account]
+///    (only when schema_config.min_verification_level > 0)
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateRegularAttestationsBatch<'info> {
+    /// Payer for new accounts; receives the rent of rewritten ones
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Schema config PDA
+    #[account(
+        seeds = [b"schema_config", schema_config.sas_schema.as_ref()],
+        bump = schema_config.bump,
+        constraint = schema_config.storage_type == StorageType::Regular @ SatiError::StorageTypeMismatch,
+    )]
+    pub schema_config: Account<'info, SchemaConfig>,
+
+    /// Registry config - checked for the pause switch
+    #[account(
+        seeds = [b"registry"],
+        bump = registry_config.bump,
+        constraint = !registry_config.is_paused(PAUSE_REGULAR_ATTESTATIONS) @ SatiError::RegistryPaused,
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
+    /// SATI Attestation Program PDA - authorized signer on SAS credential
+    /// CHECK: Seeds verified
+    #[account(
+        seeds = [b"sati_attestation"],
+        bump,
+    )]
+    pub sati_pda: AccountInfo<'info>,
+
+    /// SATI SAS credential account
+    /// CHECK: Validated by SAS program
+    pub sati_credential: AccountInfo<'info>,
+
+    /// SAS schema account (the one schema_config governs)
+    /// CHECK: Address verified; validated by SAS program
+    #[account(address = schema_config.sas_schema @ SatiError::SasSchemaMismatch)]
+    pub sas_schema: AccountInfo<'info>,
+
+    /// Instructions sysvar for Ed25519 signature verification
+    /// CHECK: Verified via address
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    /// SAS program
+    /// CHECK: Program ID verified
+    #[account(address = SOLANA_ATTESTATION_SERVICE_ID)]
+    pub sas_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// State read for one entry before the provider signature is checked
+struct CurrentEntry {
+    attestation: Pubkey,
+    token_account: Pubkey,
+    current_data: Option<Vec<u8>>,
+    revision: u32,
+    revision_bump: u8,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateRegularAttestationsBatch<'info>>,
+    params: UpdateRegularBatchParams,
+) -> Result<()> {
+    let schema_config = &ctx.accounts.schema_config;
+
+    // === Input Validation ===
+    require!(
+        schema_config.signature_mode == SignatureMode::CounterpartySigned,
+        SatiError::AttestationNotAmendable
+    );
+    require!(
+        !params.entries.is_empty() && params.entries.len() <= MAX_BATCH_ATTESTATIONS,
+        SatiError::InvalidAttestationBatchSize
+    );
+    let accounts_per_entry = if schema_config.min_verification_level > 0 {
+        4
+    } else {
+        3
+    };
+    require!(
+        ctx.remaining_accounts.len() == params.entries.len() * accounts_per_entry,
+        SatiError::InvalidAttestationBatchAccounts
+    );
+
+    // === PHASE 1: Validate entries and read current state ===
+    validate_regular_data(&params.entries[0])?;
+    let provider = Pubkey::new_from_array(
+        params.entries[0][offsets::COUNTERPARTY..offsets::OUTCOME]
+            .try_into()
+            .map_err(|_| SatiError::InvalidSignature)?,
+    );
+
+    let mut current = Vec::with_capacity(params.entries.len());
+    for (data, accounts) in params
+        .entries
+        .iter()
+        .zip(ctx.remaining_accounts.chunks_exact(accounts_per_entry))
+    {
+        validate_regular_data(data)?;
+        require!(
+            data[offsets::COUNTERPARTY..offsets::OUTCOME] == provider.to_bytes(),
+            SatiError::BatchProviderMismatch
+        );
+        let token_account = Pubkey::new_from_array(
+            data[offsets::TOKEN_ACCOUNT..offsets::COUNTERPARTY]
+                .try_into()
+                .map_err(|_| SatiError::InvalidSignature)?,
+        );
+        require!(
+            token_account != provider,
+            SatiError::SelfAttestationNotAllowed
+        );
+
+        // A repeated entry would be signed against state its first write replaces
+        let attestation = &accounts[0];
+        require!(
+            current
+                .iter()
+                .all(|entry: &CurrentEntry| entry.token_account != token_account
+                    && entry.attestation != *attestation.key),
+            SatiError::DuplicateBatchAttestation
+        );
+
+        AgentRecord::load_active(&accounts[2], &token_account)?;
+        AgentVerification::require_level(
+            accounts.get(3),
+            &token_account,
+            schema_config.min_verification_level,
+        )?;
+
+        // Existing attestations are rewritten; the nonce binds (provider, agent)
+        let current_data = if *attestation.owner == SOLANA_ATTESTATION_SERVICE_ID
+            && !attestation.data_is_empty()
+        {
+            let account_data = attestation.try_borrow_data()?;
+            require_sas_header(
+                &account_data,
+                &ctx.accounts.sati_credential.key(),
+                &schema_config.sas_schema,
+            )?;
+            let current_data = read_sas_data(&account_data)?;
+            require!(
+                current_data[offsets::TOKEN_ACCOUNT..offsets::OUTCOME]
+                    == data[offsets::TOKEN_ACCOUNT..offsets::OUTCOME],
+                SatiError::ImmutableAttestationField
+            );
+            Some(current_data)
+        } else {
+            None
+        };
+
+        // The revision survives close, so it doubles as the replay nonce
+        let (revision_key, revision_bump) = Pubkey::find_program_address(
+            &[b"attestation_revision", attestation.key.as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            accounts[1].key(),
+            revision_key,
+            SatiError::InvalidAttestationRevisionAccount
+        );
+        let revision = AttestationRevision::load(&accounts[1])?;
+
+        current.push(CurrentEntry {
+            attestation: attestation.key(),
+            token_account,
+            current_data,
+            revision,
+            revision_bump,
+        });
+    }
+
+    // === PHASE 2: Verify the provider signed the whole batch ===
+    let signed_entries: Vec<ReputationBatchEntry> = current
+        .iter()
+        .zip(&params.entries)
+        .map(|(entry, data)| {
+            (
+                entry.current_data.as_deref(),
+                data.as_slice(),
+                entry.revision,
+            )
+        })
+        .collect();
+    let expected_message =
+        compute_reputation_batch_hash(&schema_config.sas_schema, &signed_entries, params.expiry);
+    extract_ed25519_signatures(
+        &ctx.accounts.instructions_sysvar,
+        None,
+        &provider,
+        SignatureMode::CounterpartySigned,
+        &[expected_message.to_vec()],
+    )?;
+
+    // === PHASE 3: Write each attestation ===
+    let sas_schema = schema_config.sas_schema;
+    let sati_pda_seeds: &[&[u8]] = &[b"sati_attestation", &[ctx.bumps.sati_pda]];
+
+    for ((data, entry), accounts) in params
+        .entries
+        .into_iter()
+        .zip(current)
+        .zip(ctx.remaining_accounts.chunks_exact(accounts_per_entry))
+    {
+        let CurrentEntry {
+            token_account,
+            current_data,
+            revision,
+            revision_bump,
+            ..
+        } = entry;
+        let attestation = &accounts[0];
+
+        // SAS has no in-place update: close before recreating at the same nonce
+        if current_data.is_some() {
+            CloseAttestationCpiBuilder::new(&ctx.accounts.sas_program)
+                .payer(&ctx.accounts.payer)
+                .authority(&ctx.accounts.sati_pda)
+                .credential(&ctx.accounts.sati_credential)
+                .attestation(attestation)
+                .invoke_signed(&[sati_pda_seeds])?;
+        }
+
+        let nonce = compute_reputation_nonce(&provider, &token_account);
+        let new_outcome = data[offsets::OUTCOME];
+
+        CreateAttestationCpiBuilder::new(&ctx.accounts.sas_program)
+            .payer(&ctx.accounts.payer)
+            .authority(&ctx.accounts.sati_pda)
+            .credential(&ctx.accounts.sati_credential)
+            .schema(&ctx.accounts.sas_schema)
+            .attestation(attestation)
+            .system_program(&ctx.accounts.system_program)
+            .nonce(Pubkey::new_from_array(nonce))
+            .data(data)
+            .expiry(params.expiry)
+            .invoke_signed(&[sati_pda_seeds])?;
+
+        let revision = revision.checked_add(1).ok_or(SatiError::Overflow)?;
+        store_attestation_revision(
+            &ctx.accounts.payer,
+            &accounts[1],
+            &ctx.accounts.system_program,
+            attestation.key,
+            revision_bump,
+            revision,
+        )?;
+
+        match current_data {
+            Some(current_data) => emit_cpi!(AttestationUpdated {
+                sas_schema,
+                token_account,
+                counterparty: provider,
+                storage_type: StorageType::Regular,
+                address: attestation.key(),
+                old_outcome: current_data[offsets::OUTCOME],
+                new_outcome,
+                revision,
+            }),
+            None => emit_cpi!(AttestationCreated {
+                sas_schema,
+                token_account,
+                counterparty: provider,
+                storage_type: StorageType::Regular,
+                address: attestation.key(),
+            }),
+        }
+    }
+
+    Ok(())
+}
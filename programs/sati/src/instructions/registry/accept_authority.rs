@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::SatiError;
+use crate::events::RegistryAuthorityUpdated;
+use crate::state::RegistryConfig;
+
+#[derive(Accounts)]
+pub struct AcceptRegistryAuthority<'info> {
+    /// Proposed authority (must sign to prove control of the key)
+    pub new_authority: Signer<'info>,
+
+    /// Registry configuration
+    #[account(
+        mut,
+        seeds = [b"registry"],
+        bump = registry_config.bump,
+        constraint = registry_config.has_pending_authority() @ SatiError::NoPendingAuthority,
+        constraint = registry_config.pending_authority == new_authority.key() @ SatiError::InvalidPendingAuthority
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+}
+
+pub fn handler(ctx: Context<AcceptRegistryAuthority>) -> Result<()> {
+    let registry = &mut ctx.accounts.registry_config;
+    let old_authority = registry.authority;
+
+    registry.authority = ctx.accounts.new_authority.key();
+    registry.pending_authority = Pubkey::default();
+
+    emit!(RegistryAuthorityUpdated {
+        old_authority,
+        new_authority: Some(registry.authority),
+    });
+
+    Ok(())
+}
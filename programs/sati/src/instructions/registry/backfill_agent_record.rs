@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::{non_transferable::NonTransferable, BaseStateWithExtensions, StateWithExtensions},
+    state::Mint as Token2022Mint,
+};
+
+use crate::errors::SatiError;
+use crate::state::{AgentIndex, AgentRecord, AgentStatus};
+
+#[derive(Accounts)]
+#[instruction(member_number: u64)]
+pub struct BackfillAgentRecord<'info> {
+    /// Pays for the AgentRecord account (anyone)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Agent mint account
+    /// CHECK: Owner verified as Token-2022; bound to agent_index below
+    #[account(owner = anchor_spl::token_2022::ID)]
+    pub agent_mint: UncheckedAccount<'info>,
+
+    /// Agent index PDA - proves the mint was registered through SATI
+    #[account(
+        seeds = [b"agent_index", member_number.to_le_bytes().as_ref()],
+        bump = agent_index.bump,
+        constraint = agent_index.mint == agent_mint.key() @ SatiError::AgentIndexMismatch,
+    )]
+    pub agent_index: Account<'info, AgentIndex>,
+
+    /// Agent record PDA to create
+    #[account(
+        init,
+        payer = payer,
+        space = AgentRecord::SIZE,
+        seeds = [b"agent", agent_mint.key().as_ref()],
+        bump
+    )]
+    pub agent_record: Account<'info, AgentRecord>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<BackfillAgentRecord>, member_number: u64) -> Result<()> {
+    // Derive flags from the mint itself (registration time is not recoverable)
+    let (non_transferable, retired) = {
+        let mint_data = ctx.accounts.agent_mint.try_borrow_data()?;
+        let mint_state = StateWithExtensions::<Token2022Mint>::unpack(&mint_data)
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        (
+            mint_state.get_extension::<NonTransferable>().is_ok(),
+            mint_state.base.supply == 0,
+        )
+    };
+
+    let agent_record = &mut ctx.accounts.agent_record;
+    agent_record.mint = ctx.accounts.agent_mint.key();
+    agent_record.member_number = member_number;
+    agent_record.registered_slot = 0;
+    agent_record.registered_at = 0;
+    agent_record.non_transferable = non_transferable;
+    agent_record.status = if retired {
+        AgentStatus::Retired
+    } else {
+        AgentStatus::Active
+    };
+    agent_record.bump = ctx.bumps.agent_record;
+    agent_record.pre_suspend_status = AgentStatus::Active;
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::SatiError;
+use crate::events::RegistryAuthorityTransferCancelled;
+use crate::state::RegistryConfig;
+
+#[derive(Accounts)]
+pub struct CancelRegistryAuthorityTransfer<'info> {
+    /// Current authority (must sign)
+    pub authority: Signer<'info>,
+
+    /// Registry configuration
+    #[account(
+        mut,
+        seeds = [b"registry"],
+        bump = registry_config.bump,
+        has_one = authority @ SatiError::InvalidAuthority,
+        constraint = registry_config.has_pending_authority() @ SatiError::NoPendingAuthority
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+}
+
+pub fn handler(ctx: Context<CancelRegistryAuthorityTransfer>) -> Result<()> {
+    let registry = &mut ctx.accounts.registry_config;
+    let cancelled_authority = registry.pending_authority;
+
+    registry.pending_authority = Pubkey::default();
+
+    emit!(RegistryAuthorityTransferCancelled {
+        authority: registry.authority,
+        cancelled_authority,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::SatiError;
+use crate::events::GovernanceActionCancelled;
+use crate::state::{PendingAction, RegistryConfig};
+
+#[derive(Accounts)]
+pub struct CancelGovernanceAction<'info> {
+    /// Current authority (must sign)
+    pub authority: Signer<'info>,
+
+    /// Registry configuration
+    #[account(
+        seeds = [b"registry"],
+        bump = registry_config.bump,
+        has_one = authority @ SatiError::InvalidAuthority
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
+    /// Pending action to cancel (closed, rent to rent_recipient)
+    #[account(
+        mut,
+        seeds = [b"pending_action", pending_action.action_id.to_le_bytes().as_ref()],
+        bump = pending_action.bump,
+        has_one = rent_recipient,
+        close = rent_recipient
+    )]
+    pub pending_action: Account<'info, PendingAction>,
+
+    /// CHECK: Validated via has_one on pending_action
+    #[account(mut)]
+    pub rent_recipient: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CancelGovernanceAction>) -> Result<()> {
+    emit!(GovernanceActionCancelled {
+        action_id: ctx.accounts.pending_action.action_id,
+        authority: ctx.accounts.authority.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::constants::PAUSE_AGENT_UPDATES;
+use crate::errors::SatiError;
+use crate::events::AgentHandleClaimed;
+use crate::handle::{handle_seed, normalize_handle};
+use crate::state::{AgentHandle, AgentRecord, HandleClaim, RegistryConfig};
+use crate::utils::create_pda_account;
+
+#[derive(Accounts)]
+pub struct ClaimAgentHandle<'info> {
+    /// Agent holder (must sign). Pays rent for both handle accounts.
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// Registry config - checked for the pause switch
+    #[account(
+        seeds = [b"registry"],
+        bump = registry_config.bump,
+        constraint = !registry_config.is_paused(PAUSE_AGENT_UPDATES) @ SatiError::RegistryPaused
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
+    /// Agent mint
+    #[account(mint::token_program = token_program)]
+    pub agent_mint: InterfaceAccount<'info, Mint>,
+
+    /// Holder's associated token account for this mint
+    /// Validated to be correct ATA for the agent_mint and have balance > 0
+    #[account(
+        associated_token::mint = agent_mint,
+        associated_token::authority = holder,
+        associated_token::token_program = token_program,
+    )]
+    pub ata: InterfaceAccount<'info, TokenAccount>,
+
+    /// Agent record PDA - proves the mint is an active SATI agent
+    #[account(
+        seeds = [b"agent", agent_mint.key().as_ref()],
+        bump = agent_record.bump,
+        constraint = !agent_record.is_retired() @ SatiError::AgentRetired
+    )]
+    pub agent_record: Account<'info, AgentRecord>,
+
+    /// Handle PDA: ["handle", handle_seed(handle)]
+    /// CHECK: Seed depends on the normalized handle; PDA verified and account
+    /// created in handler
+    #[account(mut)]
+    pub agent_handle: UncheckedAccount<'info>,
+
+    /// Reverse lookup PDA (fails if this agent already has a handle)
+    #[account(
+        init,
+        payer = holder,
+        space = HandleClaim::SIZE,
+        seeds = [b"agent_handle", agent_mint.key().as_ref()],
+        bump
+    )]
+    pub handle_claim: Account<'info, HandleClaim>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ClaimAgentHandle>, handle: String) -> Result<()> {
+    // Verify holder owns the agent NFT (balance check)
+    require!(ctx.accounts.ata.amount > 0, SatiError::InvalidAuthority);
+
+    let handle = normalize_handle(&handle)?;
+    let seed = handle_seed(&handle);
+    let (expected, bump) = Pubkey::find_program_address(&[b"handle", seed.as_bytes()], &crate::ID);
+    require_keys_eq!(
+        ctx.accounts.agent_handle.key(),
+        expected,
+        SatiError::InvalidHandle
+    );
+    // Confusable variants share the seed, so this also rejects lookalikes
+    require!(
+        ctx.accounts.agent_handle.owner != &crate::ID,
+        SatiError::HandleTaken
+    );
+
+    let agent_handle_info = ctx.accounts.agent_handle.to_account_info();
+    create_pda_account(
+        &ctx.accounts.holder.to_account_info(),
+        &agent_handle_info,
+        &ctx.accounts.system_program.to_account_info(),
+        AgentHandle::SIZE,
+        &[b"handle", seed.as_bytes(), &[bump]],
+    )?;
+    AgentHandle {
+        mint: ctx.accounts.agent_mint.key(),
+        handle: handle.clone(),
+        rent_recipient: ctx.accounts.holder.key(),
+        claimed_at: Clock::get()?.unix_timestamp,
+        bump,
+    }
+    .try_serialize(&mut &mut agent_handle_info.try_borrow_mut_data()?[..])?;
+
+    let handle_claim = &mut ctx.accounts.handle_claim;
+    handle_claim.agent_handle = expected;
+    handle_claim.bump = ctx.bumps.handle_claim;
+
+    emit!(AgentHandleClaimed {
+        mint: ctx.accounts.agent_mint.key(),
+        handle,
+        claimed_by: ctx.accounts.holder.key(),
+    });
+
+    Ok(())
+}
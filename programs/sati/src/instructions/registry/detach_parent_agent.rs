@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::SatiError;
+use crate::events::AgentParentDetached;
+use crate::state::AgentParent;
+
+#[derive(Accounts)]
+pub struct DetachParentAgent<'info> {
+    /// Child or parent holder, or anyone once the link is stale
+    pub signer: Signer<'info>,
+
+    /// Child agent mint (pins the token program used to derive `holder_ata`)
+    #[account(
+        address = agent_parent.mint,
+        mint::token_program = token_program,
+    )]
+    pub agent_mint: InterfaceAccount<'info, Mint>,
+
+    /// Link to remove (closed, rent to the holder that created it)
+    #[account(
+        mut,
+        seeds = [b"parent", agent_parent.mint.as_ref()],
+        bump = agent_parent.bump,
+        has_one = holder,
+        close = holder
+    )]
+    pub agent_parent: Account<'info, AgentParent>,
+
+    /// CHECK: Validated via has_one on agent_parent
+    #[account(mut)]
+    pub holder: UncheckedAccount<'info>,
+
+    /// CHECK: Address checked against the linking holder's ATA in the handler.
+    /// May be closed; an empty or missing account means the holder changed.
+    pub holder_ata: UncheckedAccount<'info>,
+
+    /// Signer's token account for the child or parent mint
+    pub signer_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Token program that owns the agent mint
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<DetachParentAgent>) -> Result<()> {
+    let signer = ctx.accounts.signer.key();
+    let agent_parent = &ctx.accounts.agent_parent;
+    let token_program = ctx.accounts.token_program.key();
+
+    let expected_ata = spl_associated_token_account::get_associated_token_address_with_program_id(
+        &agent_parent.holder,
+        &agent_parent.mint,
+        &token_program,
+    );
+    require!(
+        ctx.accounts.holder_ata.key() == expected_ata,
+        SatiError::InvalidAuthority
+    );
+
+    // Either side of the hierarchy may detach
+    let is_holder = ctx.accounts.signer_ata.as_ref().is_some_and(|ata| {
+        ata.owner == signer
+            && (ata.mint == agent_parent.mint || ata.mint == agent_parent.parent)
+            && ata.amount > 0
+    });
+
+    // Links lapse when the child NFT leaves the linking holder;
+    // stale links can be closed by anyone
+    let holder_ata = ctx.accounts.holder_ata.to_account_info();
+    let holder_balance = if holder_ata.owner == &token_program && !holder_ata.data_is_empty() {
+        TokenAccount::try_deserialize(&mut &holder_ata.try_borrow_data()?[..])?.amount
+    } else {
+        0
+    };
+
+    require!(
+        is_holder || holder_balance == 0,
+        SatiError::InvalidAuthority
+    );
+
+    emit!(AgentParentDetached {
+        mint: agent_parent.mint,
+        parent: agent_parent.parent,
+        detached_by: signer,
+    });
+
+    Ok(())
+}
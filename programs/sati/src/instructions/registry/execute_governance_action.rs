@@ -0,0 +1,168 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::SatiError;
+use crate::events::{GovernanceActionExecuted, RegistryAuthorityProposed, SchemaConfigRegistered};
+use crate::instructions::attestation::set_schema_min_verification::apply_schema_min_verification;
+use crate::instructions::registry::set_registration_fee::apply_registration_fee;
+use crate::instructions::registry::set_timelock_delay::apply_timelock_delay;
+use crate::instructions::registry::update_authority::apply_renounce_authority;
+use crate::state::{GovernanceAction, PendingAction, RegistryConfig, SchemaConfig};
+use crate::utils::create_pda_account;
+
+#[derive(Accounts)]
+pub struct ExecuteGovernanceAction<'info> {
+    /// Anyone can execute once the timelock has elapsed.
+    /// Pays for the SchemaConfig account when executing RegisterSchemaConfig.
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    /// Registry configuration
+    #[account(
+        mut,
+        seeds = [b"registry"],
+        bump = registry_config.bump,
+        constraint = !registry_config.is_immutable() @ SatiError::ImmutableAuthority
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
+    /// Pending action to execute (closed, rent to rent_recipient)
+    #[account(
+        mut,
+        seeds = [b"pending_action", pending_action.action_id.to_le_bytes().as_ref()],
+        bump = pending_action.bump,
+        has_one = rent_recipient,
+        close = rent_recipient
+    )]
+    pub pending_action: Account<'info, PendingAction>,
+
+    /// CHECK: Validated via has_one on pending_action
+    #[account(mut)]
+    pub rent_recipient: UncheckedAccount<'info>,
+
+    /// Schema config PDA (RegisterSchemaConfig creates it, SetSchemaMinVerification updates it)
+    /// CHECK: PDA verified and account created in handler
+    #[account(mut)]
+    pub schema_config: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ExecuteGovernanceAction>) -> Result<()> {
+    let pending_action = &ctx.accounts.pending_action;
+
+    // === Validation ===
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now >= pending_action.execute_after,
+        SatiError::TimelockNotElapsed
+    );
+    // Actions queued by a previous authority must not outlive a handover
+    require_keys_eq!(
+        pending_action.authority,
+        ctx.accounts.registry_config.authority,
+        SatiError::StaleGovernanceAction
+    );
+
+    let action_id = pending_action.action_id;
+    let action = pending_action.action.clone();
+
+    // === Apply action ===
+    match action {
+        GovernanceAction::RegisterSchemaConfig {
+            sas_schema,
+            signature_mode,
+            storage_type,
+            delegation_schema,
+            closeable,
+            name,
+        } => {
+            let schema_config = ctx
+                .accounts
+                .schema_config
+                .as_ref()
+                .ok_or(SatiError::MissingSchemaConfigAccount)?;
+            let (expected, bump) =
+                Pubkey::find_program_address(&[b"schema_config", sas_schema.as_ref()], &crate::ID);
+            require_keys_eq!(
+                schema_config.key(),
+                expected,
+                SatiError::MissingSchemaConfigAccount
+            );
+
+            create_pda_account(
+                &ctx.accounts.executor.to_account_info(),
+                &schema_config.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                8 + SchemaConfig::INIT_SPACE,
+                &[b"schema_config", sas_schema.as_ref(), &[bump]],
+            )?;
+            SchemaConfig {
+                sas_schema,
+                signature_mode,
+                storage_type,
+                delegation_schema,
+                closeable,
+                name: name.clone(),
+                bump,
+                min_verification_level: 0,
+            }
+            .try_serialize(&mut &mut schema_config.try_borrow_mut_data()?[..])?;
+
+            emit!(SchemaConfigRegistered {
+                schema: sas_schema,
+                signature_mode,
+                storage_type,
+                delegation_schema,
+                closeable,
+                name,
+            });
+        }
+        GovernanceAction::ProposeAuthority { new_authority } => {
+            let registry = &mut ctx.accounts.registry_config;
+            registry.pending_authority = new_authority;
+
+            emit!(RegistryAuthorityProposed {
+                authority: registry.authority,
+                pending_authority: new_authority,
+            });
+        }
+        GovernanceAction::RenounceAuthority => {
+            apply_renounce_authority(&mut ctx.accounts.registry_config)?;
+        }
+        GovernanceAction::SetTimelockDelay { delay } => {
+            apply_timelock_delay(&mut ctx.accounts.registry_config, delay)?;
+        }
+        GovernanceAction::SetRegistrationFee { fee_mint, amount } => {
+            apply_registration_fee(&mut ctx.accounts.registry_config, fee_mint, amount)?;
+        }
+        GovernanceAction::SetSchemaMinVerification {
+            sas_schema,
+            min_level,
+        } => {
+            let schema_config = ctx
+                .accounts
+                .schema_config
+                .as_ref()
+                .ok_or(SatiError::MissingSchemaConfigAccount)?;
+            let (expected, _) =
+                Pubkey::find_program_address(&[b"schema_config", sas_schema.as_ref()], &crate::ID);
+            require_keys_eq!(
+                schema_config.key(),
+                expected,
+                SatiError::MissingSchemaConfigAccount
+            );
+
+            let mut config =
+                SchemaConfig::try_deserialize(&mut &schema_config.try_borrow_data()?[..])?;
+            apply_schema_min_verification(&mut config, min_level);
+            config.try_serialize(&mut &mut schema_config.try_borrow_mut_data()?[..])?;
+        }
+    }
+
+    emit!(GovernanceActionExecuted {
+        action_id,
+        executed_by: ctx.accounts.executor.key(),
+    });
+
+    Ok(())
+}
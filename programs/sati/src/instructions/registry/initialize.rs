@@ -88,6 +88,13 @@ pub fn handler(ctx: Context<Initialize>) -> Result<()> {
     registry.group_mint = group_mint_key;
     registry.total_agents = 0;
     registry.bump = registry_bump;
+    registry.version = RegistryConfig::CURRENT_VERSION;
+    registry.pending_authority = Pubkey::default();
+    registry.timelock_delay = 0;
+    registry.next_action_id = 0;
+    registry.paused = 0;
+    registry.fee_mint = Pubkey::default();
+    registry.fee_amount = 0;
 
     emit!(RegistryInitialized {
         authority: authority_key,
@@ -1,28 +1,45 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{TokenAccount, TokenInterface};
 
+use crate::caip2::parse_eip155_chain_id;
+use crate::constants::PAUSE_AGENT_UPDATES;
 use crate::errors::SatiError;
 use crate::events::EvmAddressLinked;
-use crate::signature::{compute_evm_link_hash, verify_secp256k1_signature};
+use crate::signature::{compute_evm_link_digest, verify_secp256k1_signature};
+use crate::state::{EvmLink, EvmLinkFormat, RegistryConfig};
 
 /// Parameters for linking an EVM address to a SATI agent.
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct LinkEvmAddressParams {
     /// EVM address (20 bytes)
     pub evm_address: [u8; 20],
-    /// CAIP-2 chain identifier (e.g., "eip155:1", "eip155:8453")
+    /// CAIP-2 chain identifier, canonical `eip155:<decimal>` (e.g., "eip155:1", "eip155:8453")
     pub chain_id: String,
     /// secp256k1 signature (64 bytes: r || s)
     pub signature: [u8; 64],
-    /// Recovery ID (0 or 1)
+    /// Recovery ID (0/1, or Ethereum v 27/28)
     pub recovery_id: u8,
+    /// Message format the wallet signed (raw digest, EIP-191 or EIP-712)
+    pub format: EvmLinkFormat,
+    /// Unix timestamp after which the link is void (None = no expiry)
+    pub expires_at: Option<i64>,
 }
 
 #[derive(Accounts)]
+#[instruction(params: LinkEvmAddressParams)]
 pub struct LinkEvmAddress<'info> {
-    /// Agent owner (must sign)
+    /// Agent owner (must sign, pays for the EvmLink)
+    #[account(mut)]
     pub owner: Signer<'info>,
 
+    /// Registry config - checked for the pause switch
+    #[account(
+        seeds = [b"registry"],
+        bump = registry_config.bump,
+        constraint = !registry_config.is_paused(PAUSE_AGENT_UPDATES) @ SatiError::RegistryPaused
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
     /// Agent mint account
     /// CHECK: Validated by checking owner has ATA with balance
     pub agent_mint: UncheckedAccount<'info>,
@@ -36,8 +53,25 @@ pub struct LinkEvmAddress<'info> {
     )]
     pub ata: InterfaceAccount<'info, TokenAccount>,
 
+    /// Link PDA (must not exist; unlink first to replace a link)
+    #[account(
+        init,
+        payer = owner,
+        space = EvmLink::SIZE,
+        seeds = [
+            b"evm_link",
+            agent_mint.key().as_ref(),
+            &EvmLink::chain_seed(&params.chain_id),
+            &params.evm_address,
+        ],
+        bump
+    )]
+    pub evm_link: Account<'info, EvmLink>,
+
     /// Token-2022 program for ATA verification
     pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
 }
 
 pub fn handler(ctx: Context<LinkEvmAddress>, params: LinkEvmAddressParams) -> Result<()> {
@@ -47,8 +81,27 @@ pub fn handler(ctx: Context<LinkEvmAddress>, params: LinkEvmAddressParams) -> Re
     // The ATA constraint already verified it's the correct ATA
     require!(ctx.accounts.ata.amount > 0, SatiError::InvalidAuthority);
 
-    // Compute the message hash
-    let message_hash = compute_evm_link_hash(&agent_mint, &params.evm_address, &params.chain_id);
+    // Canonical eip155 ids only, so each chain has one link PDA per address
+    parse_eip155_chain_id(&params.chain_id)?;
+
+    let clock = Clock::get()?;
+    if let Some(expires_at) = params.expires_at {
+        require!(
+            expires_at > clock.unix_timestamp,
+            SatiError::InvalidEvmLinkExpiry
+        );
+    }
+
+    // Compute the digest the wallet signed (bound to this holder and expiry)
+    let holder = ctx.accounts.owner.key();
+    let message_hash = compute_evm_link_digest(
+        params.format,
+        &agent_mint,
+        &holder,
+        &params.evm_address,
+        &params.chain_id,
+        params.expires_at,
+    );
 
     // Verify secp256k1 signature
     verify_secp256k1_signature(
@@ -58,13 +111,22 @@ pub fn handler(ctx: Context<LinkEvmAddress>, params: LinkEvmAddressParams) -> Re
         &params.evm_address,
     )?;
 
-    // Emit event as proof of verification
-    let clock = Clock::get()?;
+    // Persist the link; it lapses when the NFT leaves `holder` or at `expires_at`
+    let evm_link = &mut ctx.accounts.evm_link;
+    evm_link.agent_mint = agent_mint;
+    evm_link.evm_address = params.evm_address;
+    evm_link.chain_id = params.chain_id.clone();
+    evm_link.holder = holder;
+    evm_link.linked_at = clock.unix_timestamp;
+    evm_link.expires_at = params.expires_at;
+    evm_link.bump = ctx.bumps.evm_link;
+
     emit!(EvmAddressLinked {
         agent_mint,
         evm_address: params.evm_address,
         chain_id: params.chain_id,
         linked_at: clock.unix_timestamp,
+        expires_at: params.expires_at,
     });
 
     Ok(())
@@ -0,0 +1,167 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
+use solana_program::{ed25519_program, sysvar::instructions as instructions_sysvar};
+
+use crate::caip2::parse_chain_id;
+use crate::constants::{
+    DOMAIN_BITCOIN_LINK, DOMAIN_ED25519_LINK, DOMAIN_SECP256R1_LINK, PAUSE_AGENT_UPDATES,
+};
+use crate::errors::SatiError;
+use crate::events::ExternalIdentityLinked;
+use crate::external_identity::{
+    aptos_ed25519_address, build_bitcoin_link_message, build_webauthn_signed_message,
+    compute_bip137_hash, compute_external_link_hash, cosmos_ed25519_address,
+    recover_bip137_address, sui_ed25519_address,
+};
+use crate::signature::{verify_precompile_signature, SECP256R1_PROGRAM_ID};
+use crate::state::{ExternalIdentityProof, ExternalIdentityType, RegistryConfig};
+
+/// Parameters for linking a non-EVM identity to a SATI agent.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct LinkExternalIdentityParams {
+    /// CAIP-2 chain identifier; namespace must match the proof type
+    /// (sui, aptos, cosmos, bip122; any chain for passkeys)
+    pub chain_id: String,
+    /// Key type specific proof over the link hash
+    pub proof: ExternalIdentityProof,
+    /// Unix timestamp after which the link is void (None = no expiry)
+    pub expires_at: Option<i64>,
+}
+
+#[derive(Accounts)]
+pub struct LinkExternalIdentity<'info> {
+    /// Agent owner (must sign)
+    pub owner: Signer<'info>,
+
+    /// Registry config - checked for the pause switch
+    #[account(
+        seeds = [b"registry"],
+        bump = registry_config.bump,
+        constraint = !registry_config.is_paused(PAUSE_AGENT_UPDATES) @ SatiError::RegistryPaused
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
+    /// Agent mint account
+    /// CHECK: Validated by checking owner has ATA with balance
+    pub agent_mint: UncheckedAccount<'info>,
+
+    /// Owner's associated token account for this mint
+    /// Validated to be correct ATA for the agent_mint and have balance > 0
+    #[account(
+        associated_token::mint = agent_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub ata: InterfaceAccount<'info, TokenAccount>,
+
+    /// Instructions sysvar for Ed25519 / secp256r1 precompile lookup
+    /// CHECK: Verified via address check
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    /// Token-2022 program for ATA verification
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(
+    ctx: Context<LinkExternalIdentity>,
+    params: LinkExternalIdentityParams,
+) -> Result<()> {
+    let agent_mint = ctx.accounts.agent_mint.key();
+
+    // Verify owner holds the agent NFT (balance check)
+    // The ATA constraint already verified it's the correct ATA
+    require!(ctx.accounts.ata.amount > 0, SatiError::InvalidAuthority);
+
+    let namespace = parse_chain_id(&params.chain_id)?;
+    if let Some(expected) = params.proof.chain_namespace() {
+        require!(
+            namespace == expected,
+            SatiError::ExternalIdentityChainMismatch
+        );
+    }
+
+    let clock = Clock::get()?;
+    if let Some(expires_at) = params.expires_at {
+        require!(
+            expires_at > clock.unix_timestamp,
+            SatiError::InvalidExternalLinkExpiry
+        );
+    }
+
+    // Every proof signs a link hash bound to this holder and expiry
+    let holder = ctx.accounts.owner.key();
+    let link_hash_for = |domain| {
+        compute_external_link_hash(
+            domain,
+            &agent_mint,
+            &holder,
+            &params.chain_id,
+            params.expires_at,
+        )
+    };
+
+    let sysvar = &ctx.accounts.instructions_sysvar;
+    let (identity_type, identity) = match &params.proof {
+        ExternalIdentityProof::SuiEd25519 { public_key }
+        | ExternalIdentityProof::AptosEd25519 { public_key }
+        | ExternalIdentityProof::CosmosEd25519 { public_key } => {
+            let link_hash = link_hash_for(DOMAIN_ED25519_LINK);
+            verify_precompile_signature(sysvar, &ed25519_program::ID, public_key, &link_hash)?;
+
+            match params.proof {
+                ExternalIdentityProof::SuiEd25519 { .. } => (
+                    ExternalIdentityType::SuiEd25519,
+                    sui_ed25519_address(public_key).to_vec(),
+                ),
+                ExternalIdentityProof::AptosEd25519 { .. } => (
+                    ExternalIdentityType::AptosEd25519,
+                    aptos_ed25519_address(public_key).to_vec(),
+                ),
+                _ => (
+                    ExternalIdentityType::CosmosEd25519,
+                    cosmos_ed25519_address(public_key).to_vec(),
+                ),
+            }
+        }
+        ExternalIdentityProof::Secp256r1Passkey {
+            public_key,
+            authenticator_data,
+            client_data_json,
+        } => {
+            let challenge = link_hash_for(DOMAIN_SECP256R1_LINK);
+            let message =
+                build_webauthn_signed_message(&challenge, authenticator_data, client_data_json)?;
+            verify_precompile_signature(sysvar, &SECP256R1_PROGRAM_ID, public_key, &message)?;
+
+            (ExternalIdentityType::Secp256r1Passkey, public_key.to_vec())
+        }
+        ExternalIdentityProof::BitcoinBip137 {
+            signature,
+            address_hash,
+        } => {
+            let link_hash = link_hash_for(DOMAIN_BITCOIN_LINK);
+            let message_hash =
+                compute_bip137_hash(build_bitcoin_link_message(&link_hash).as_bytes());
+            let (identity_type, recovered) = recover_bip137_address(&message_hash, signature)?;
+            require!(
+                recovered == *address_hash,
+                SatiError::ExternalIdentityMismatch
+            );
+
+            (identity_type, recovered.to_vec())
+        }
+    };
+
+    emit!(ExternalIdentityLinked {
+        agent_mint,
+        identity_type,
+        chain_id: params.chain_id,
+        identity,
+        holder,
+        linked_at: clock.unix_timestamp,
+        expires_at: params.expires_at,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
+
+use crate::constants::PAUSE_AGENT_UPDATES;
+use crate::errors::SatiError;
+use crate::events::SnsDomainLinked;
+use crate::sns::{
+    parse_sol_domain_owner, sol_domain_name_account, validate_sns_domain, NAME_SERVICE_PROGRAM_ID,
+};
+use crate::state::{RegistryConfig, SnsLink};
+
+#[derive(Accounts)]
+pub struct LinkSnsDomain<'info> {
+    /// Agent holder and domain owner (must sign, pays for the SnsLink)
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// Registry config - checked for the pause switch
+    #[account(
+        seeds = [b"registry"],
+        bump = registry_config.bump,
+        constraint = !registry_config.is_paused(PAUSE_AGENT_UPDATES) @ SatiError::RegistryPaused
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
+    /// Agent mint account
+    /// CHECK: Validated by checking owner has ATA with balance
+    pub agent_mint: UncheckedAccount<'info>,
+
+    /// Owner's associated token account for this mint
+    /// Validated to be correct ATA for the agent_mint and have balance > 0
+    #[account(
+        associated_token::mint = agent_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub ata: InterfaceAccount<'info, TokenAccount>,
+
+    /// SNS name registry of `<domain>.sol`
+    /// CHECK: Address derived from the domain and header parsed in handler
+    #[account(owner = NAME_SERVICE_PROGRAM_ID @ SatiError::InvalidNameRegistry)]
+    pub name_account: UncheckedAccount<'info>,
+
+    /// Link PDA (must not exist)
+    #[account(
+        init,
+        payer = owner,
+        space = SnsLink::SIZE,
+        seeds = [b"sns_link", agent_mint.key().as_ref(), name_account.key().as_ref()],
+        bump
+    )]
+    pub sns_link: Account<'info, SnsLink>,
+
+    /// Token-2022 program for ATA verification
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<LinkSnsDomain>, domain: String) -> Result<()> {
+    let agent_mint = ctx.accounts.agent_mint.key();
+    let owner = ctx.accounts.owner.key();
+
+    // Verify owner holds the agent NFT (balance check)
+    // The ATA constraint already verified it's the correct ATA
+    require!(ctx.accounts.ata.amount > 0, SatiError::InvalidAuthority);
+
+    // The registry must be the one SNS derives for this label
+    validate_sns_domain(&domain)?;
+    let name_account = ctx.accounts.name_account.key();
+    require!(
+        name_account == sol_domain_name_account(&domain),
+        SatiError::InvalidNameRegistry
+    );
+
+    let domain_owner = parse_sol_domain_owner(&ctx.accounts.name_account.try_borrow_data()?)?;
+    require!(domain_owner == owner, SatiError::SnsOwnerMismatch);
+
+    let linked_at = Clock::get()?.unix_timestamp;
+    let sns_link = &mut ctx.accounts.sns_link;
+    sns_link.agent_mint = agent_mint;
+    sns_link.name_account = name_account;
+    sns_link.domain = domain.clone();
+    sns_link.holder = owner;
+    sns_link.linked_at = linked_at;
+    sns_link.bump = ctx.bumps.sns_link;
+
+    emit!(SnsDomainLinked {
+        agent_mint,
+        name_account,
+        domain,
+        holder: owner,
+        linked_at,
+    });
+
+    Ok(())
+}
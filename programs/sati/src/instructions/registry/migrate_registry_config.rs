@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::Discriminator;
+
+use crate::errors::SatiError;
+use crate::events::RegistryConfigMigrated;
+use crate::state::RegistryConfig;
+
+#[derive(Accounts)]
+pub struct MigrateRegistryConfig<'info> {
+    /// Pays for the extra rent (anyone)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Registry configuration in an older layout.
+    /// CHECK: Cannot be deserialized as RegistryConfig until migrated;
+    /// owner, seeds, discriminator and length are checked in the handler.
+    #[account(
+        mut,
+        seeds = [b"registry"],
+        bump,
+        owner = crate::ID
+    )]
+    pub registry_config: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateRegistryConfig>) -> Result<()> {
+    let registry_info = ctx.accounts.registry_config.to_account_info();
+
+    let from_version = {
+        let data = registry_info.try_borrow_data()?;
+        require!(
+            data.len() >= 8 && &data[..8] == RegistryConfig::DISCRIMINATOR,
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        // Every layout change appends fields, so older versions are shorter
+        require!(
+            data.len() >= RegistryConfig::LEGACY_SIZE && data.len() < RegistryConfig::SIZE,
+            SatiError::RegistryAlreadyMigrated
+        );
+        // Version 0 accounts predate the version byte
+        if data.len() == RegistryConfig::LEGACY_SIZE {
+            0
+        } else {
+            data[RegistryConfig::LEGACY_SIZE]
+        }
+    };
+
+    // 1. Top up rent for the larger account
+    let required_lamports = Rent::get()?
+        .minimum_balance(RegistryConfig::SIZE)
+        .saturating_sub(registry_info.lamports());
+    if required_lamports > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: registry_info.clone(),
+                },
+            ),
+            required_lamports,
+        )?;
+    }
+
+    // 2. Grow the account. New bytes are zeroed, which is the correct default for
+    // every appended field (no pending authority, timelock disabled, action id 0,
+    // nothing paused, no registration fee).
+    registry_info.resize(RegistryConfig::SIZE)?;
+
+    // 3. Stamp the layout version (first byte after the legacy fields)
+    registry_info.try_borrow_mut_data()?[RegistryConfig::LEGACY_SIZE] =
+        RegistryConfig::CURRENT_VERSION;
+
+    emit!(RegistryConfigMigrated {
+        from_version,
+        to_version: RegistryConfig::CURRENT_VERSION,
+    });
+
+    Ok(())
+}
@@ -1,9 +1,73 @@
+pub mod accept_authority;
+pub mod backfill_agent_record;
+pub mod cancel_authority_transfer;
+pub mod cancel_governance_action;
+pub mod claim_agent_handle;
+pub mod detach_parent_agent;
+pub mod execute_governance_action;
 pub mod initialize;
 pub mod link_evm_address;
+pub mod link_external_identity;
+pub mod link_sns_domain;
+pub mod migrate_registry_config;
+pub mod pause_registry;
+pub mod propose_authority;
+pub mod queue_governance_action;
 pub mod register_agent;
+pub mod register_agents_batch;
+pub mod release_agent_handle;
+pub mod retire_agent;
+pub mod revoke_agent_verification;
+pub mod set_agent_services;
+pub mod set_agent_status;
+pub mod set_agent_verification;
+pub mod set_parent_agent;
+pub mod set_registration_fee;
+pub mod set_successor;
+pub mod set_timelock_delay;
+pub mod suspend_agent;
+pub mod sync_agent_authority;
+pub mod unlink_evm_address;
+pub mod unlink_sns_domain;
+pub mod unpause_registry;
+pub mod unsuspend_agent;
+pub mod update_agent_metadata;
 pub mod update_authority;
+pub mod withdraw_treasury;
 
+pub use accept_authority::*;
+pub use backfill_agent_record::*;
+pub use cancel_authority_transfer::*;
+pub use cancel_governance_action::*;
+pub use claim_agent_handle::*;
+pub use detach_parent_agent::*;
+pub use execute_governance_action::*;
 pub use initialize::*;
 pub use link_evm_address::*;
+pub use link_external_identity::*;
+pub use link_sns_domain::*;
+pub use migrate_registry_config::*;
+pub use pause_registry::*;
+pub use propose_authority::*;
+pub use queue_governance_action::*;
 pub use register_agent::*;
+pub use register_agents_batch::*;
+pub use release_agent_handle::*;
+pub use retire_agent::*;
+pub use revoke_agent_verification::*;
+pub use set_agent_services::*;
+pub use set_agent_status::*;
+pub use set_agent_verification::*;
+pub use set_parent_agent::*;
+pub use set_registration_fee::*;
+pub use set_successor::*;
+pub use set_timelock_delay::*;
+pub use suspend_agent::*;
+pub use sync_agent_authority::*;
+pub use unlink_evm_address::*;
+pub use unlink_sns_domain::*;
+pub use unpause_registry::*;
+pub use unsuspend_agent::*;
+pub use update_agent_metadata::*;
 pub use update_authority::*;
+pub use withdraw_treasury::*;
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::PAUSE_ALL;
+use crate::errors::SatiError;
+use crate::events::RegistryPaused;
+use crate::state::RegistryConfig;
+
+#[derive(Accounts)]
+pub struct PauseRegistry<'info> {
+    /// Current authority (must sign)
+    pub authority: Signer<'info>,
+
+    /// Registry configuration. Pausing is an emergency switch and is
+    /// deliberately not subject to the governance timelock.
+    #[account(
+        mut,
+        seeds = [b"registry"],
+        bump = registry_config.bump,
+        has_one = authority @ SatiError::InvalidAuthority,
+        constraint = !registry_config.is_immutable() @ SatiError::ImmutableAuthority
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+}
+
+pub fn handler(ctx: Context<PauseRegistry>, flags: u8) -> Result<()> {
+    require!(
+        flags != 0 && flags & !PAUSE_ALL == 0,
+        SatiError::InvalidPauseFlags
+    );
+
+    let registry = &mut ctx.accounts.registry_config;
+    registry.paused |= flags;
+
+    emit!(RegistryPaused {
+        flags,
+        paused: registry.paused,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::SatiError;
+use crate::events::RegistryAuthorityProposed;
+use crate::state::RegistryConfig;
+
+#[derive(Accounts)]
+pub struct ProposeRegistryAuthority<'info> {
+    /// Current authority (must sign)
+    pub authority: Signer<'info>,
+
+    /// Registry configuration
+    #[account(
+        mut,
+        seeds = [b"registry"],
+        bump = registry_config.bump,
+        has_one = authority @ SatiError::InvalidAuthority,
+        constraint = !registry_config.is_immutable() @ SatiError::ImmutableAuthority,
+        constraint = !registry_config.is_timelocked() @ SatiError::TimelockRequired
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+}
+
+pub fn handler(ctx: Context<ProposeRegistryAuthority>, new_authority: Pubkey) -> Result<()> {
+    // Renouncing is a separate, explicit action (update_registry_authority(None))
+    require!(
+        new_authority != Pubkey::default(),
+        SatiError::InvalidPendingAuthority
+    );
+
+    // A new proposal replaces any previous one
+    let registry = &mut ctx.accounts.registry_config;
+    registry.pending_authority = new_authority;
+
+    emit!(RegistryAuthorityProposed {
+        authority: registry.authority,
+        pending_authority: new_authority,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MAX_NAME_LENGTH, MAX_TIMELOCK_DELAY};
+use crate::errors::SatiError;
+use crate::events::GovernanceActionQueued;
+use crate::instructions::registry::set_registration_fee::validate_registration_fee;
+use crate::state::{GovernanceAction, PendingAction, RegistryConfig};
+
+#[derive(Accounts)]
+pub struct QueueGovernanceAction<'info> {
+    /// Pays for the PendingAction account (refunded on execute/cancel)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Current authority (must sign)
+    pub authority: Signer<'info>,
+
+    /// Registry configuration
+    #[account(
+        mut,
+        seeds = [b"registry"],
+        bump = registry_config.bump,
+        has_one = authority @ SatiError::InvalidAuthority,
+        constraint = !registry_config.is_immutable() @ SatiError::ImmutableAuthority
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
+    /// Pending action PDA (id = registry_config.next_action_id)
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + PendingAction::INIT_SPACE,
+        seeds = [b"pending_action", registry_config.next_action_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub pending_action: Account<'info, PendingAction>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<QueueGovernanceAction>, action: GovernanceAction) -> Result<()> {
+    // Reject actions that could never execute
+    match &action {
+        GovernanceAction::RegisterSchemaConfig { name, .. } => {
+            require!(name.len() <= MAX_NAME_LENGTH, SatiError::NameTooLong);
+        }
+        GovernanceAction::ProposeAuthority { new_authority } => {
+            require!(
+                *new_authority != Pubkey::default(),
+                SatiError::InvalidPendingAuthority
+            );
+        }
+        GovernanceAction::RenounceAuthority => {}
+        GovernanceAction::SetTimelockDelay { delay } => {
+            require!(
+                (0..=MAX_TIMELOCK_DELAY).contains(delay),
+                SatiError::InvalidTimelockDelay
+            );
+        }
+        GovernanceAction::SetRegistrationFee { fee_mint, .. } => {
+            validate_registration_fee(*fee_mint)?;
+        }
+        GovernanceAction::SetSchemaMinVerification { .. } => {}
+    }
+
+    let clock = Clock::get()?;
+    let registry = &mut ctx.accounts.registry_config;
+    let action_id = registry.next_action_id;
+    let execute_after = clock
+        .unix_timestamp
+        .checked_add(registry.timelock_delay)
+        .ok_or(SatiError::Overflow)?;
+    registry.next_action_id = action_id.checked_add(1).ok_or(SatiError::Overflow)?;
+
+    let pending_action = &mut ctx.accounts.pending_action;
+    pending_action.action_id = action_id;
+    pending_action.authority = registry.authority;
+    pending_action.rent_recipient = ctx.accounts.payer.key();
+    pending_action.action = action.clone();
+    pending_action.queued_at = clock.unix_timestamp;
+    pending_action.execute_after = execute_after;
+    pending_action.bump = ctx.bumps.pending_action;
+
+    emit!(GovernanceActionQueued {
+        action_id,
+        authority: registry.authority,
+        action,
+        execute_after,
+    });
+
+    Ok(())
+}
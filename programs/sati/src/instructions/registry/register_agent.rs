@@ -6,17 +6,22 @@ use anchor_spl::token_2022::spl_token_2022::{
     instruction::{initialize_mint2, mint_to, set_authority, AuthorityType},
     state::Mint as Token2022Mint,
 };
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
 use spl_token_group_interface::instruction::initialize_member;
 use spl_token_metadata_interface::instruction::initialize as initialize_metadata;
 
 use crate::constants::{
     LARGE_METADATA_THRESHOLD, MAX_METADATA_ENTRIES, MAX_METADATA_KEY_LENGTH,
     MAX_METADATA_VALUE_LENGTH, MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_URI_LENGTH,
-    TLV_OVERHEAD_PADDING,
+    PAUSE_REGISTRATION, TLV_OVERHEAD_PADDING,
 };
 use crate::errors::SatiError;
-use crate::events::AgentRegistered;
-use crate::state::{AgentIndex, MetadataEntry, RegistryConfig};
+use crate::events::{AgentRegistered, RegistrationFeePaid};
+use crate::state::{AgentIndex, AgentRecord, AgentStatus, MetadataEntry, RegistryConfig};
+use crate::uri_hash::validate_uri_hash_entries;
+use crate::utils::create_pda_account;
 
 #[derive(Accounts)]
 #[instruction(name: String, symbol: String, uri: String)]
@@ -33,7 +38,8 @@ pub struct RegisterAgent<'info> {
     #[account(
         mut,
         seeds = [b"registry"],
-        bump = registry_config.bump
+        bump = registry_config.bump,
+        constraint = !registry_config.is_paused(PAUSE_REGISTRATION) @ SatiError::RegistryPaused
     )]
     pub registry_config: Account<'info, RegistryConfig>,
 
@@ -69,8 +75,37 @@ pub struct RegisterAgent<'info> {
     )]
     pub agent_index: Account<'info, AgentIndex>,
 
+    /// Agent record PDA for reverse lookup (mint -> member_number)
+    #[account(
+        init,
+        payer = payer,
+        space = AgentRecord::SIZE,
+        seeds = [b"agent", agent_mint.key().as_ref()],
+        bump
+    )]
+    pub agent_record: Account<'info, AgentRecord>,
+
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
+
+    /// Registry treasury PDA (lamport fees only; created on first payment)
+    /// CHECK: Address verified in collect_registration_fee
+    #[account(mut)]
+    pub treasury: Option<UncheckedAccount<'info>>,
+
+    /// Fee mint (SPL fees only, must equal registry_config.fee_mint)
+    pub fee_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    /// Payer's token account for fee_mint (SPL fees only)
+    #[account(mut)]
+    pub payer_fee_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Treasury-owned token account for fee_mint (SPL fees only)
+    #[account(mut)]
+    pub treasury_fee_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Token program of fee_mint (SPL fees only)
+    pub fee_token_program: Option<Interface<'info, TokenInterface>>,
 }
 
 pub fn handler(
@@ -82,11 +117,204 @@ pub fn handler(
     non_transferable: bool,
 ) -> Result<()> {
     // === Input Validation ===
+    validate_agent_metadata(&name, &symbol, &uri, additional_metadata.as_deref())?;
+
+    // === PHASE 0: Collect the registration fee (no-op when fee_amount == 0) ===
+    collect_registration_fee(
+        &ctx.accounts.registry_config,
+        &RegistrationFeeAccounts {
+            payer: &ctx.accounts.payer,
+            treasury: ctx.accounts.treasury.as_ref(),
+            fee_mint: ctx.accounts.fee_mint.as_deref(),
+            payer_fee_account: ctx.accounts.payer_fee_account.as_deref(),
+            treasury_fee_account: ctx.accounts.treasury_fee_account.as_deref(),
+            fee_token_program: ctx.accounts.fee_token_program.as_ref(),
+            system_program: &ctx.accounts.system_program,
+        },
+        1,
+    )?;
+
+    // === PHASE 1: Read state and prepare CPI parameters ===
+    let (registry_bump, current_count) = {
+        let registry = &ctx.accounts.registry_config;
+        (registry.bump, registry.total_agents)
+    };
+    // Borrow is now dropped - safe to make CPIs
+
+    // === PHASE 2: Execute all CPIs ===
+    let accs = AgentMintAccounts {
+        payer: ctx.accounts.payer.to_account_info(),
+        owner: ctx.accounts.owner.to_account_info(),
+        registry_config: ctx.accounts.registry_config.to_account_info(),
+        group_mint: ctx.accounts.group_mint.to_account_info(),
+        agent_mint: ctx.accounts.agent_mint.to_account_info(),
+        agent_token_account: ctx.accounts.agent_token_account.to_account_info(),
+        token_2022_program: ctx.accounts.token_2022_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+    };
+    create_agent_mint(
+        &accs,
+        registry_bump,
+        &name,
+        &symbol,
+        &uri,
+        additional_metadata.as_deref(),
+        non_transferable,
+    )?;
+
+    // === PHASE 3: Write state after CPIs succeed ===
+    let registry = &mut ctx.accounts.registry_config;
+    registry.total_agents = current_count.checked_add(1).ok_or(SatiError::Overflow)?;
+
+    // Initialize AgentIndex for enumeration
+    let agent_index = &mut ctx.accounts.agent_index;
+    agent_index.mint = ctx.accounts.agent_mint.key();
+    agent_index.bump = ctx.bumps.agent_index;
+
+    // Initialize AgentRecord for reverse lookup
+    let clock = Clock::get()?;
+    let agent_record = &mut ctx.accounts.agent_record;
+    agent_record.mint = ctx.accounts.agent_mint.key();
+    agent_record.member_number = registry.total_agents;
+    agent_record.registered_slot = clock.slot;
+    agent_record.registered_at = clock.unix_timestamp;
+    agent_record.non_transferable = non_transferable;
+    agent_record.status = AgentStatus::Active;
+    agent_record.bump = ctx.bumps.agent_record;
+    agent_record.pre_suspend_status = AgentStatus::Active;
+
+    // === Emit Event ===
+    emit!(AgentRegistered {
+        mint: ctx.accounts.agent_mint.key(),
+        owner: ctx.accounts.owner.key(),
+        member_number: registry.total_agents,
+        name,
+        uri,
+        non_transferable,
+    });
+
+    Ok(())
+}
+
+/// Account infos needed to mint one agent NFT (shared by register_agent and
+/// register_agents_batch).
+pub(crate) struct AgentMintAccounts<'info> {
+    pub payer: AccountInfo<'info>,
+    pub owner: AccountInfo<'info>,
+    pub registry_config: AccountInfo<'info>,
+    pub group_mint: AccountInfo<'info>,
+    pub agent_mint: AccountInfo<'info>,
+    pub agent_token_account: AccountInfo<'info>,
+    pub token_2022_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+/// Accounts used to pay the registration fee (shared by register_agent and
+/// register_agents_batch). Only the ones matching the fee kind are required.
+pub(crate) struct RegistrationFeeAccounts<'a, 'info> {
+    pub payer: &'a Signer<'info>,
+    pub treasury: Option<&'a UncheckedAccount<'info>>,
+    pub fee_mint: Option<&'a InterfaceAccount<'info, Mint>>,
+    pub payer_fee_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    pub treasury_fee_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    pub fee_token_program: Option<&'a Interface<'info, TokenInterface>>,
+    pub system_program: &'a Program<'info, System>,
+}
+
+/// Transfer `fee_amount * agent_count` from the payer into the registry treasury.
+///
+/// Lamport fees go to the treasury PDA itself, which is created (program-owned,
+/// zero data) on first payment so withdrawals can debit it directly. SPL fees go
+/// to any token account of `fee_mint` owned by the treasury PDA.
+pub(crate) fn collect_registration_fee(
+    registry: &RegistryConfig,
+    accs: &RegistrationFeeAccounts,
+    agent_count: u64,
+) -> Result<()> {
+    if registry.fee_amount == 0 {
+        return Ok(());
+    }
+    let amount = registry
+        .fee_amount
+        .checked_mul(agent_count)
+        .ok_or(SatiError::Overflow)?;
+    let (treasury_key, treasury_bump) = Pubkey::find_program_address(&[b"treasury"], &crate::ID);
+
+    match registry.fee_mint() {
+        None => {
+            let treasury = accs.treasury.ok_or(SatiError::MissingFeeAccounts)?;
+            require_keys_eq!(treasury.key(), treasury_key, SatiError::InvalidFeeAccount);
+
+            if treasury.owner != &crate::ID {
+                create_pda_account(
+                    &accs.payer.to_account_info(),
+                    &treasury.to_account_info(),
+                    &accs.system_program.to_account_info(),
+                    0,
+                    &[b"treasury", &[treasury_bump]],
+                )?;
+            }
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    accs.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: accs.payer.to_account_info(),
+                        to: treasury.to_account_info(),
+                    },
+                ),
+                amount,
+            )?;
+        }
+        Some(fee_mint_key) => {
+            let (Some(fee_mint), Some(from), Some(to), Some(token_program)) = (
+                accs.fee_mint,
+                accs.payer_fee_account,
+                accs.treasury_fee_account,
+                accs.fee_token_program,
+            ) else {
+                return Err(SatiError::MissingFeeAccounts.into());
+            };
+            require_keys_eq!(fee_mint.key(), fee_mint_key, SatiError::InvalidFeeAccount);
+            require_keys_eq!(to.owner, treasury_key, SatiError::InvalidFeeAccount);
+            require_keys_eq!(to.mint, fee_mint_key, SatiError::InvalidFeeAccount);
+
+            transfer_checked(
+                CpiContext::new(
+                    token_program.to_account_info(),
+                    TransferChecked {
+                        from: from.to_account_info(),
+                        mint: fee_mint.to_account_info(),
+                        to: to.to_account_info(),
+                        authority: accs.payer.to_account_info(),
+                    },
+                ),
+                amount,
+                fee_mint.decimals,
+            )?;
+        }
+    }
+
+    emit!(RegistrationFeePaid {
+        payer: accs.payer.key(),
+        fee_mint: registry.fee_mint(),
+        amount,
+    });
+
+    Ok(())
+}
+
+/// Validate name, symbol, uri and additional metadata against the registry limits.
+pub(crate) fn validate_agent_metadata(
+    name: &str,
+    symbol: &str,
+    uri: &str,
+    additional_metadata: Option<&[MetadataEntry]>,
+) -> Result<()> {
     require!(name.len() <= MAX_NAME_LENGTH, SatiError::NameTooLong);
     require!(symbol.len() <= MAX_SYMBOL_LENGTH, SatiError::SymbolTooLong);
     require!(uri.len() <= MAX_URI_LENGTH, SatiError::UriTooLong);
 
-    if let Some(ref metadata) = additional_metadata {
+    if let Some(metadata) = additional_metadata {
         require!(
             metadata.len() <= MAX_METADATA_ENTRIES,
             SatiError::TooManyMetadataEntries
@@ -101,17 +329,23 @@ pub fn handler(
                 SatiError::MetadataValueTooLong
             );
         }
+        validate_uri_hash_entries(metadata)?;
     }
 
-    // === PHASE 1: Read state and prepare CPI parameters ===
-    let (_group_mint, registry_bump, current_count) = {
-        let registry = &ctx.accounts.registry_config;
-        (registry.group_mint, registry.bump, registry.total_agents)
-    };
-    // Borrow is now dropped - safe to make CPIs
-
-    // === PHASE 2: Execute all CPIs ===
+    Ok(())
+}
 
+/// Create the agent mint with all extensions, add it to the SATI group, mint 1 token
+/// to the owner's ATA and renounce the mint authority.
+pub(crate) fn create_agent_mint(
+    accs: &AgentMintAccounts<'_>,
+    registry_bump: u8,
+    name: &str,
+    symbol: &str,
+    uri: &str,
+    additional_metadata: Option<&[MetadataEntry]>,
+    non_transferable: bool,
+) -> Result<()> {
     // 2a. Determine extensions and calculate space
     let mut extensions = vec![
         ExtensionType::MetadataPointer,
@@ -140,7 +374,7 @@ pub fn handler(
         .checked_add(uri.len())
         .ok_or(SatiError::Overflow)?;
 
-    if let Some(ref metadata) = additional_metadata {
+    if let Some(metadata) = additional_metadata {
         for entry in metadata {
             // Each entry: 4 bytes (key len) + key + 4 bytes (value len) + value
             let entry_size = 4_usize
@@ -176,16 +410,16 @@ pub fn handler(
 
     anchor_lang::solana_program::program::invoke(
         &anchor_lang::solana_program::system_instruction::create_account(
-            &ctx.accounts.payer.key(),
-            &ctx.accounts.agent_mint.key(),
+            &accs.payer.key(),
+            &accs.agent_mint.key(),
             lamports,
             mint_len as u64, // Use exact size for pointer extensions; metadata/group will reallocate
             &anchor_spl::token_2022::ID,
         ),
         &[
-            ctx.accounts.payer.to_account_info(),
-            ctx.accounts.agent_mint.to_account_info(),
-            ctx.accounts.system_program.to_account_info(),
+            accs.payer.clone(),
+            accs.agent_mint.clone(),
+            accs.system_program.clone(),
         ],
     )?;
 
@@ -193,28 +427,28 @@ pub fn handler(
     let init_metadata_pointer_ix =
         spl_token_2022::extension::metadata_pointer::instruction::initialize(
             &anchor_spl::token_2022::ID,
-            &ctx.accounts.agent_mint.key(),
-            Some(ctx.accounts.owner.key()), // authority is the owner
-            Some(ctx.accounts.agent_mint.key()), // metadata address is the mint itself
+            &accs.agent_mint.key(),
+            Some(accs.registry_config.key()), // authority is registry PDA
+            Some(accs.agent_mint.key()),      // metadata address is the mint itself
         )?;
 
     anchor_lang::solana_program::program::invoke(
         &init_metadata_pointer_ix,
-        &[ctx.accounts.agent_mint.to_account_info()],
+        std::slice::from_ref(&accs.agent_mint),
     )?;
 
     // 2c. Initialize GroupMemberPointer (points to self)
     let init_group_member_pointer_ix =
         spl_token_2022::extension::group_member_pointer::instruction::initialize(
             &anchor_spl::token_2022::ID,
-            &ctx.accounts.agent_mint.key(),
-            Some(ctx.accounts.registry_config.key()), // authority is registry PDA
-            Some(ctx.accounts.agent_mint.key()),      // member address is the mint itself
+            &accs.agent_mint.key(),
+            Some(accs.registry_config.key()), // authority is registry PDA
+            Some(accs.agent_mint.key()),      // member address is the mint itself
         )?;
 
     anchor_lang::solana_program::program::invoke(
         &init_group_member_pointer_ix,
-        &[ctx.accounts.agent_mint.to_account_info()],
+        std::slice::from_ref(&accs.agent_mint),
     )?;
 
     // 2d. Initialize NonTransferable if requested
@@ -222,48 +456,52 @@ pub fn handler(
         let init_non_transferable_ix =
             spl_token_2022::instruction::initialize_non_transferable_mint(
                 &anchor_spl::token_2022::ID,
-                &ctx.accounts.agent_mint.key(),
+                &accs.agent_mint.key(),
             )?;
 
         anchor_lang::solana_program::program::invoke(
             &init_non_transferable_ix,
-            &[ctx.accounts.agent_mint.to_account_info()],
+            std::slice::from_ref(&accs.agent_mint),
         )?;
     }
 
     // 2e. Initialize the mint
     let init_mint_ix = initialize_mint2(
         &anchor_spl::token_2022::ID,
-        &ctx.accounts.agent_mint.key(),
-        &ctx.accounts.payer.key(), // mint authority = payer (temporary, will renounce)
-        None,                      // no freeze authority
-        0,                         // decimals = 0 for NFT
+        &accs.agent_mint.key(),
+        &accs.payer.key(), // mint authority = payer (temporary, will renounce)
+        None,              // no freeze authority
+        0,                 // decimals = 0 for NFT
     )?;
 
     anchor_lang::solana_program::program::invoke(
         &init_mint_ix,
-        &[ctx.accounts.agent_mint.to_account_info()],
+        std::slice::from_ref(&accs.agent_mint),
     )?;
 
     // 2f. Initialize TokenMetadata
+    // Update authority is the registry PDA so metadata edits are always routed
+    // through SATI with a holder check (survives NFT transfers).
+    let registry_seeds: &[&[u8]] = &[b"registry", &[registry_bump]];
+
     let init_token_metadata_ix = initialize_metadata(
         &anchor_spl::token_2022::ID,
-        &ctx.accounts.agent_mint.key(), // metadata account
-        &ctx.accounts.owner.key(),      // update authority
-        &ctx.accounts.agent_mint.key(), // mint
-        &ctx.accounts.payer.key(),      // mint authority
-        name.clone(),
-        symbol.clone(),
-        uri.clone(),
+        &accs.agent_mint.key(),      // metadata account
+        &accs.registry_config.key(), // update authority
+        &accs.agent_mint.key(),      // mint
+        &accs.payer.key(),           // mint authority
+        name.to_string(),
+        symbol.to_string(),
+        uri.to_string(),
     );
 
     anchor_lang::solana_program::program::invoke(
         &init_token_metadata_ix,
         &[
-            ctx.accounts.agent_mint.to_account_info(),
-            ctx.accounts.owner.to_account_info(),
-            ctx.accounts.agent_mint.to_account_info(),
-            ctx.accounts.payer.to_account_info(),
+            accs.agent_mint.clone(),
+            accs.registry_config.clone(),
+            accs.agent_mint.clone(),
+            accs.payer.clone(),
         ],
     )?;
 
@@ -271,7 +509,7 @@ pub fn handler(
     // NOTE: Each metadata entry adds one CPI call (~5-10k compute units).
     // For >5 entries, clients should request 400k CUs via SetComputeUnitLimit.
     // See SDK documentation for compute budget examples.
-    if let Some(ref metadata) = additional_metadata {
+    if let Some(metadata) = additional_metadata {
         if metadata.len() > LARGE_METADATA_THRESHOLD {
             msg!(
                 "Large metadata ({} entries): ensure 400k compute units requested",
@@ -281,42 +519,38 @@ pub fn handler(
         for entry in metadata {
             let update_field_ix = spl_token_metadata_interface::instruction::update_field(
                 &anchor_spl::token_2022::ID,
-                &ctx.accounts.agent_mint.key(),
-                &ctx.accounts.owner.key(),
+                &accs.agent_mint.key(),
+                &accs.registry_config.key(),
                 spl_token_metadata_interface::state::Field::Key(entry.key.clone()),
                 entry.value.clone(),
             );
 
-            anchor_lang::solana_program::program::invoke(
+            invoke_signed(
                 &update_field_ix,
-                &[
-                    ctx.accounts.agent_mint.to_account_info(),
-                    ctx.accounts.owner.to_account_info(),
-                ],
+                &[accs.agent_mint.clone(), accs.registry_config.clone()],
+                &[registry_seeds],
             )?;
         }
     }
 
     // 2h. Initialize GroupMember (registry PDA signs as update_authority)
-    let registry_seeds: &[&[u8]] = &[b"registry", &[registry_bump]];
-
     let init_member_ix = initialize_member(
         &anchor_spl::token_2022::ID,
-        &ctx.accounts.agent_mint.key(),      // member (mint)
-        &ctx.accounts.agent_mint.key(),      // member mint
-        &ctx.accounts.payer.key(),           // member mint authority
-        &ctx.accounts.group_mint.key(),      // group
-        &ctx.accounts.registry_config.key(), // group update authority
+        &accs.agent_mint.key(),      // member (mint)
+        &accs.agent_mint.key(),      // member mint
+        &accs.payer.key(),           // member mint authority
+        &accs.group_mint.key(),      // group
+        &accs.registry_config.key(), // group update authority
     );
 
     invoke_signed(
         &init_member_ix,
         &[
-            ctx.accounts.agent_mint.to_account_info(),
-            ctx.accounts.agent_mint.to_account_info(),
-            ctx.accounts.payer.to_account_info(),
-            ctx.accounts.group_mint.to_account_info(),
-            ctx.accounts.registry_config.to_account_info(),
+            accs.agent_mint.clone(),
+            accs.agent_mint.clone(),
+            accs.payer.clone(),
+            accs.group_mint.clone(),
+            accs.registry_config.clone(),
         ],
         &[registry_seeds],
     )?;
@@ -324,38 +558,38 @@ pub fn handler(
     // 2i. Create owner's ATA
     anchor_lang::solana_program::program::invoke(
         &spl_associated_token_account::instruction::create_associated_token_account(
-            &ctx.accounts.payer.key(),
-            &ctx.accounts.owner.key(),
-            &ctx.accounts.agent_mint.key(),
+            &accs.payer.key(),
+            &accs.owner.key(),
+            &accs.agent_mint.key(),
             &anchor_spl::token_2022::ID,
         ),
         &[
-            ctx.accounts.payer.to_account_info(),
-            ctx.accounts.agent_token_account.to_account_info(),
-            ctx.accounts.owner.to_account_info(),
-            ctx.accounts.agent_mint.to_account_info(),
-            ctx.accounts.system_program.to_account_info(),
-            ctx.accounts.token_2022_program.to_account_info(),
+            accs.payer.clone(),
+            accs.agent_token_account.clone(),
+            accs.owner.clone(),
+            accs.agent_mint.clone(),
+            accs.system_program.clone(),
+            accs.token_2022_program.clone(),
         ],
     )?;
 
     // Verify ATA was properly created with correct address
     let expected_ata = spl_associated_token_account::get_associated_token_address_with_program_id(
-        ctx.accounts.owner.key,
-        &ctx.accounts.agent_mint.key(),
+        accs.owner.key,
+        &accs.agent_mint.key(),
         &anchor_spl::token_2022::ID,
     );
     require!(
-        ctx.accounts.agent_token_account.key() == expected_ata,
+        accs.agent_token_account.key() == expected_ata,
         SatiError::InvalidAuthority
     );
 
     // 2j. Mint exactly 1 token to owner's ATA
     let mint_to_ix = mint_to(
         &anchor_spl::token_2022::ID,
-        &ctx.accounts.agent_mint.key(),
-        &ctx.accounts.agent_token_account.key(),
-        &ctx.accounts.payer.key(), // mint authority
+        &accs.agent_mint.key(),
+        &accs.agent_token_account.key(),
+        &accs.payer.key(), // mint authority
         &[],
         1, // exactly 1 NFT
     )?;
@@ -363,34 +597,31 @@ pub fn handler(
     anchor_lang::solana_program::program::invoke(
         &mint_to_ix,
         &[
-            ctx.accounts.agent_mint.to_account_info(),
-            ctx.accounts.agent_token_account.to_account_info(),
-            ctx.accounts.payer.to_account_info(),
+            accs.agent_mint.clone(),
+            accs.agent_token_account.clone(),
+            accs.payer.clone(),
         ],
     )?;
 
     // 2k. Renounce mint authority (supply=1 forever)
     let set_authority_ix = set_authority(
         &anchor_spl::token_2022::ID,
-        &ctx.accounts.agent_mint.key(),
+        &accs.agent_mint.key(),
         None, // new authority = None (renounce)
         AuthorityType::MintTokens,
-        &ctx.accounts.payer.key(),
+        &accs.payer.key(),
         &[],
     )?;
 
     anchor_lang::solana_program::program::invoke(
         &set_authority_ix,
-        &[
-            ctx.accounts.agent_mint.to_account_info(),
-            ctx.accounts.payer.to_account_info(),
-        ],
+        &[accs.agent_mint.clone(), accs.payer.clone()],
     )?;
 
     // 2l. Verify mint authority was successfully renounced (defense-in-depth)
     // This ensures the supply=1 guarantee is enforced
     {
-        let mint_data = ctx.accounts.agent_mint.try_borrow_data()?;
+        let mint_data = accs.agent_mint.try_borrow_data()?;
         let mint_state = StateWithExtensions::<Token2022Mint>::unpack(&mint_data)
             .map_err(|_| SatiError::MintAuthorityNotRenounced)?;
         require!(
@@ -399,24 +630,5 @@ pub fn handler(
         );
     }
 
-    // === PHASE 3: Write state after CPIs succeed ===
-    let registry = &mut ctx.accounts.registry_config;
-    registry.total_agents = current_count.checked_add(1).ok_or(SatiError::Overflow)?;
-
-    // Initialize AgentIndex for enumeration
-    let agent_index = &mut ctx.accounts.agent_index;
-    agent_index.mint = ctx.accounts.agent_mint.key();
-    agent_index.bump = ctx.bumps.agent_index;
-
-    // === Emit Event ===
-    emit!(AgentRegistered {
-        mint: ctx.accounts.agent_mint.key(),
-        owner: ctx.accounts.owner.key(),
-        member_number: registry.total_agents,
-        name,
-        uri,
-        non_transferable,
-    });
-
     Ok(())
 }
@@ -0,0 +1,233 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::{BATCH_ACCOUNTS_PER_AGENT, MAX_BATCH_AGENTS, PAUSE_REGISTRATION};
+use crate::errors::SatiError;
+use crate::events::AgentRegistered;
+use crate::instructions::registry::register_agent::{
+    collect_registration_fee, create_agent_mint, validate_agent_metadata, AgentMintAccounts,
+    RegistrationFeeAccounts,
+};
+use crate::state::{AgentIndex, AgentRecord, AgentStatus, MetadataEntry, RegistryConfig};
+use crate::utils::create_pda_account;
+
+/// Parameters for a single agent in a batch registration.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RegisterAgentParams {
+    /// Agent name (max 32 bytes)
+    pub name: String,
+    /// Vestigial Token-2022 symbol (max 10 bytes, usually empty)
+    pub symbol: String,
+    /// Registration file URI (max 200 bytes)
+    pub uri: String,
+    /// Additional metadata entries (max 10)
+    pub additional_metadata: Option<Vec<MetadataEntry>>,
+    /// Set the NonTransferable extension (soulbound)
+    pub non_transferable: bool,
+}
+
+/// Accounts for register_agents_batch.
+///
+/// Per-agent accounts are passed via remaining_accounts in groups of
+/// BATCH_ACCOUNTS_PER_AGENT, in the same order as `agents`:
+/// 0. owner (any pubkey)
+/// 1. agent_mint (signer, writable) - new mint keypair
+/// 2. agent_token_account (writable) - owner's ATA
+/// 3. agent_index (writable) - PDA ["agent_index", total_agents + i + 1]
+/// 4. agent_record (writable) - PDA ["agent", agent_mint]
+#[derive(Accounts)]
+pub struct RegisterAgentsBatch<'info> {
+    /// Pays for all account creation
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Registry configuration
+    #[account(
+        mut,
+        seeds = [b"registry"],
+        bump = registry_config.bump,
+        constraint = !registry_config.is_paused(PAUSE_REGISTRATION) @ SatiError::RegistryPaused
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
+    /// TokenGroup mint (for membership)
+    /// CHECK: Validated against registry_config.group_mint
+    #[account(
+        mut,
+        address = registry_config.group_mint
+    )]
+    pub group_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Token-2022 program
+    #[account(address = anchor_spl::token_2022::ID)]
+    pub token_2022_program: UncheckedAccount<'info>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+
+    /// Registry treasury PDA (lamport fees only; created on first payment)
+    /// CHECK: Address verified in collect_registration_fee
+    #[account(mut)]
+    pub treasury: Option<UncheckedAccount<'info>>,
+
+    /// Fee mint (SPL fees only, must equal registry_config.fee_mint)
+    pub fee_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    /// Payer's token account for fee_mint (SPL fees only)
+    #[account(mut)]
+    pub payer_fee_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Treasury-owned token account for fee_mint (SPL fees only)
+    #[account(mut)]
+    pub treasury_fee_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Token program of fee_mint (SPL fees only)
+    pub fee_token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, RegisterAgentsBatch<'info>>,
+    agents: Vec<RegisterAgentParams>,
+) -> Result<()> {
+    // === Input Validation ===
+    require!(
+        !agents.is_empty() && agents.len() <= MAX_BATCH_AGENTS,
+        SatiError::InvalidBatchSize
+    );
+    require!(
+        ctx.remaining_accounts.len() == agents.len() * BATCH_ACCOUNTS_PER_AGENT,
+        SatiError::InvalidBatchAccounts
+    );
+    for agent in &agents {
+        validate_agent_metadata(
+            &agent.name,
+            &agent.symbol,
+            &agent.uri,
+            agent.additional_metadata.as_deref(),
+        )?;
+    }
+
+    // === PHASE 0: Collect the registration fee for every agent ===
+    collect_registration_fee(
+        &ctx.accounts.registry_config,
+        &RegistrationFeeAccounts {
+            payer: &ctx.accounts.payer,
+            treasury: ctx.accounts.treasury.as_ref(),
+            fee_mint: ctx.accounts.fee_mint.as_deref(),
+            payer_fee_account: ctx.accounts.payer_fee_account.as_deref(),
+            treasury_fee_account: ctx.accounts.treasury_fee_account.as_deref(),
+            fee_token_program: ctx.accounts.fee_token_program.as_ref(),
+            system_program: &ctx.accounts.system_program,
+        },
+        agents.len() as u64,
+    )?;
+
+    // === PHASE 1: Read state ===
+    let (registry_bump, current_count) = {
+        let registry = &ctx.accounts.registry_config;
+        (registry.bump, registry.total_agents)
+    };
+    let clock = Clock::get()?;
+
+    let payer = ctx.accounts.payer.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+
+    // === PHASE 2: Register each agent ===
+    let mut member_number = current_count;
+    for (agent, accounts) in agents.into_iter().zip(
+        ctx.remaining_accounts
+            .chunks_exact(BATCH_ACCOUNTS_PER_AGENT),
+    ) {
+        let [owner, agent_mint, agent_token_account, agent_index, agent_record] = accounts else {
+            return Err(SatiError::InvalidBatchAccounts.into());
+        };
+        member_number = member_number.checked_add(1).ok_or(SatiError::Overflow)?;
+
+        // Verify PDA addresses before creating anything
+        let member_bytes = member_number.to_le_bytes();
+        let (expected_index, index_bump) =
+            Pubkey::find_program_address(&[b"agent_index", &member_bytes], &crate::ID);
+        require_keys_eq!(
+            agent_index.key(),
+            expected_index,
+            SatiError::InvalidBatchAccounts
+        );
+        let (expected_record, record_bump) =
+            Pubkey::find_program_address(&[b"agent", agent_mint.key.as_ref()], &crate::ID);
+        require_keys_eq!(
+            agent_record.key(),
+            expected_record,
+            SatiError::InvalidBatchAccounts
+        );
+
+        // 2a. Mint the agent NFT (same path as register_agent)
+        let accs = AgentMintAccounts {
+            payer: payer.clone(),
+            owner: owner.clone(),
+            registry_config: ctx.accounts.registry_config.to_account_info(),
+            group_mint: ctx.accounts.group_mint.to_account_info(),
+            agent_mint: agent_mint.clone(),
+            agent_token_account: agent_token_account.clone(),
+            token_2022_program: ctx.accounts.token_2022_program.to_account_info(),
+            system_program: system_program.clone(),
+        };
+        create_agent_mint(
+            &accs,
+            registry_bump,
+            &agent.name,
+            &agent.symbol,
+            &agent.uri,
+            agent.additional_metadata.as_deref(),
+            agent.non_transferable,
+        )?;
+
+        // 2b. Create AgentIndex
+        create_pda_account(
+            &payer,
+            agent_index,
+            &system_program,
+            AgentIndex::SIZE,
+            &[b"agent_index", &member_bytes, &[index_bump]],
+        )?;
+        AgentIndex {
+            mint: agent_mint.key(),
+            bump: index_bump,
+        }
+        .try_serialize(&mut &mut agent_index.try_borrow_mut_data()?[..])?;
+
+        // 2c. Create AgentRecord
+        create_pda_account(
+            &payer,
+            agent_record,
+            &system_program,
+            AgentRecord::SIZE,
+            &[b"agent", agent_mint.key.as_ref(), &[record_bump]],
+        )?;
+        AgentRecord {
+            mint: agent_mint.key(),
+            member_number,
+            registered_slot: clock.slot,
+            registered_at: clock.unix_timestamp,
+            non_transferable: agent.non_transferable,
+            status: AgentStatus::Active,
+            bump: record_bump,
+            pre_suspend_status: AgentStatus::Active,
+        }
+        .try_serialize(&mut &mut agent_record.try_borrow_mut_data()?[..])?;
+
+        emit!(AgentRegistered {
+            mint: agent_mint.key(),
+            owner: owner.key(),
+            member_number,
+            name: agent.name,
+            uri: agent.uri,
+            non_transferable: agent.non_transferable,
+        });
+    }
+
+    // === PHASE 3: Single counter update for the whole batch ===
+    ctx.accounts.registry_config.total_agents = member_number;
+
+    Ok(())
+}
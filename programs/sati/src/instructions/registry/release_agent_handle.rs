@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::errors::SatiError;
+use crate::events::AgentHandleReleased;
+use crate::state::{AgentHandle, AgentRecord, HandleClaim, RegistryConfig};
+
+#[derive(Accounts)]
+pub struct ReleaseAgentHandle<'info> {
+    /// Agent holder, registry authority, or anyone once the agent is retired
+    pub signer: Signer<'info>,
+
+    /// Registry configuration (authority may release handles for moderation)
+    #[account(
+        seeds = [b"registry"],
+        bump = registry_config.bump
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
+    /// Handle to release (closed, rent to rent_recipient)
+    #[account(
+        mut,
+        has_one = rent_recipient,
+        close = rent_recipient
+    )]
+    pub agent_handle: Account<'info, AgentHandle>,
+
+    /// Reverse lookup PDA (closed, rent to rent_recipient)
+    #[account(
+        mut,
+        seeds = [b"agent_handle", agent_handle.mint.as_ref()],
+        bump = handle_claim.bump,
+        constraint = handle_claim.agent_handle == agent_handle.key() @ SatiError::InvalidHandle,
+        close = rent_recipient
+    )]
+    pub handle_claim: Account<'info, HandleClaim>,
+
+    /// Agent record PDA (retired agents' handles can be released by anyone)
+    #[account(
+        seeds = [b"agent", agent_handle.mint.as_ref()],
+        bump = agent_record.bump
+    )]
+    pub agent_record: Account<'info, AgentRecord>,
+
+    /// Signer's token account for the agent mint (holder releases only)
+    pub ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Validated via has_one on agent_handle
+    #[account(mut)]
+    pub rent_recipient: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<ReleaseAgentHandle>) -> Result<()> {
+    let signer = ctx.accounts.signer.key();
+    let agent_handle = &ctx.accounts.agent_handle;
+    let registry = &ctx.accounts.registry_config;
+
+    // Handles follow the agent NFT: whoever currently holds it may release
+    let is_holder =
+        ctx.accounts.ata.as_ref().is_some_and(|ata| {
+            ata.owner == signer && ata.mint == agent_handle.mint && ata.amount > 0
+        });
+    let is_authority = !registry.is_immutable() && registry.authority == signer;
+    require!(
+        is_holder || is_authority || ctx.accounts.agent_record.is_retired(),
+        SatiError::HandleReleaseNotAllowed
+    );
+
+    emit!(AgentHandleReleased {
+        mint: agent_handle.mint,
+        handle: agent_handle.handle.clone(),
+        released_by: signer,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::{burn, close_account, Burn, CloseAccount, Token2022};
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::constants::PAUSE_AGENT_UPDATES;
+use crate::errors::SatiError;
+use crate::events::AgentRetired;
+use crate::state::{AgentIndex, AgentRecord, AgentStatus, RegistryConfig};
+
+#[derive(Accounts)]
+pub struct RetireAgent<'info> {
+    /// Agent holder (must sign). Receives the ATA and AgentIndex rent.
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// Registry config - checked for the pause switch
+    #[account(
+        seeds = [b"registry"],
+        bump = registry_config.bump,
+        constraint = !registry_config.is_paused(PAUSE_AGENT_UPDATES) @ SatiError::RegistryPaused
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
+    /// Agent mint (supply drops to 0, which marks the agent as retired)
+    #[account(
+        mut,
+        mint::token_program = token_program,
+    )]
+    pub agent_mint: InterfaceAccount<'info, Mint>,
+
+    /// Holder's associated token account for this mint (closed after burn)
+    #[account(
+        mut,
+        associated_token::mint = agent_mint,
+        associated_token::authority = holder,
+        associated_token::token_program = token_program,
+    )]
+    pub ata: InterfaceAccount<'info, TokenAccount>,
+
+    /// Agent record PDA - proves the mint is a SATI agent; marked Retired and
+    /// kept as the tombstone for its member_number.
+    #[account(
+        mut,
+        seeds = [b"agent", agent_mint.key().as_ref()],
+        bump = agent_record.bump,
+    )]
+    pub agent_record: Account<'info, AgentRecord>,
+
+    /// Agent index PDA for the record's member_number (closed, rent to holder)
+    #[account(
+        mut,
+        close = holder,
+        seeds = [b"agent_index", agent_record.member_number.to_le_bytes().as_ref()],
+        bump = agent_index.bump,
+        constraint = agent_index.mint == agent_mint.key() @ SatiError::AgentIndexMismatch,
+    )]
+    pub agent_index: Account<'info, AgentIndex>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+pub fn handler(ctx: Context<RetireAgent>) -> Result<()> {
+    // Verify holder owns the agent NFT (balance check)
+    // The ATA constraint already verified it's the correct ATA
+    let amount = ctx.accounts.ata.amount;
+    require!(amount > 0, SatiError::InvalidAuthority);
+
+    // 1. Burn the agent NFT (supply 1 -> 0; mint authority is renounced so it can never be re-minted)
+    burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.agent_mint.to_account_info(),
+                from: ctx.accounts.ata.to_account_info(),
+                authority: ctx.accounts.holder.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    // 2. Close the now-empty ATA, returning rent to the holder
+    close_account(CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.ata.to_account_info(),
+            destination: ctx.accounts.holder.to_account_info(),
+            authority: ctx.accounts.holder.to_account_info(),
+        },
+    ))?;
+
+    // 3. Mark the record retired (the AgentIndex is closed by the account constraint)
+    // NOTE: RegistryConfig.total_agents is intentionally not decremented, so
+    // member_numbers are never reused even though the AgentIndex slot is freed.
+    let agent_record = &mut ctx.accounts.agent_record;
+    agent_record.status = AgentStatus::Retired;
+
+    emit!(AgentRetired {
+        mint: ctx.accounts.agent_mint.key(),
+        holder: ctx.accounts.holder.key(),
+        member_number: agent_record.member_number,
+    });
+
+    Ok(())
+}
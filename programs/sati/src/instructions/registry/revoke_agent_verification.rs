@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::SatiError;
+use crate::events::AgentVerificationRevoked;
+use crate::state::{AgentVerification, RegistryConfig};
+
+#[derive(Accounts)]
+pub struct RevokeAgentVerification<'info> {
+    /// Current authority (must sign). Receives the badge rent.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Registry configuration
+    #[account(
+        seeds = [b"registry"],
+        bump = registry_config.bump,
+        has_one = authority @ SatiError::InvalidAuthority,
+        constraint = !registry_config.is_immutable() @ SatiError::ImmutableAuthority
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
+    /// Verification PDA to close
+    #[account(
+        mut,
+        seeds = [b"verification", agent_verification.mint.as_ref()],
+        bump = agent_verification.bump,
+        close = authority
+    )]
+    pub agent_verification: Account<'info, AgentVerification>,
+}
+
+pub fn handler(ctx: Context<RevokeAgentVerification>) -> Result<()> {
+    emit!(AgentVerificationRevoked {
+        mint: ctx.accounts.agent_verification.mint,
+        revoked_by: ctx.accounts.authority.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,179 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::constants::{
+    MAX_SERVICES, MAX_SERVICE_ENDPOINT_LENGTH, MAX_SERVICE_PROTOCOL_LENGTH,
+    MAX_SERVICE_VERSION_LENGTH, PAUSE_AGENT_UPDATES,
+};
+use crate::errors::SatiError;
+use crate::events::AgentServicesUpdated;
+use crate::state::{AgentRecord, AgentServices, RegistryConfig, ServiceEntry};
+use crate::utils::{close_program_account, create_pda_account, resize_program_account};
+
+#[derive(Accounts)]
+pub struct SetAgentServices<'info> {
+    /// Agent holder (must sign). Pays for rent growth and receives refunds on shrink.
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// Registry config - checked for the pause switch
+    #[account(
+        seeds = [b"registry"],
+        bump = registry_config.bump,
+        constraint = !registry_config.is_paused(PAUSE_AGENT_UPDATES) @ SatiError::RegistryPaused
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
+    /// Agent mint
+    #[account(mint::token_program = token_program)]
+    pub agent_mint: InterfaceAccount<'info, Mint>,
+
+    /// Holder's associated token account for this mint
+    /// Validated to be correct ATA for the agent_mint and have balance > 0
+    #[account(
+        associated_token::mint = agent_mint,
+        associated_token::authority = holder,
+        associated_token::token_program = token_program,
+    )]
+    pub ata: InterfaceAccount<'info, TokenAccount>,
+
+    /// Agent record PDA - proves the mint is a SATI agent
+    #[account(
+        seeds = [b"agent", agent_mint.key().as_ref()],
+        bump = agent_record.bump,
+        constraint = !agent_record.is_retired() @ SatiError::AgentRetired
+    )]
+    pub agent_record: Account<'info, AgentRecord>,
+
+    /// Services PDA (created, resized or closed to match the new list)
+    /// CHECK: PDA verified via seeds; created or resized in handler
+    #[account(
+        mut,
+        seeds = [b"services", agent_mint.key().as_ref()],
+        bump
+    )]
+    pub agent_services: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetAgentServices>, services: Vec<ServiceEntry>) -> Result<()> {
+    // Verify holder owns the agent NFT (balance check)
+    require!(ctx.accounts.ata.amount > 0, SatiError::InvalidAuthority);
+    validate_services(&services)?;
+
+    let mint = ctx.accounts.agent_mint.key();
+    let holder = ctx.accounts.holder.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let services_info = ctx.accounts.agent_services.to_account_info();
+    let exists = services_info.owner == &crate::ID;
+
+    if services.is_empty() {
+        // Empty list removes the record and refunds the holder
+        if exists {
+            close_program_account(&services_info, &holder)?;
+        }
+    } else {
+        let space = AgentServices::space(&services);
+        let bump = ctx.bumps.agent_services;
+        if exists {
+            resize_program_account(&holder, &services_info, &system_program, space)?;
+        } else {
+            create_pda_account(
+                &holder,
+                &services_info,
+                &system_program,
+                space,
+                &[b"services", mint.as_ref(), &[bump]],
+            )?;
+        }
+
+        AgentServices {
+            mint,
+            services: services.clone(),
+            updated_at: Clock::get()?.unix_timestamp,
+            bump,
+        }
+        .try_serialize(&mut &mut services_info.try_borrow_mut_data()?[..])?;
+    }
+
+    emit!(AgentServicesUpdated {
+        mint,
+        updated_by: holder.key(),
+        services,
+    });
+
+    Ok(())
+}
+
+/// Validate the service list against count and length limits.
+fn validate_services(services: &[ServiceEntry]) -> Result<()> {
+    require!(services.len() <= MAX_SERVICES, SatiError::TooManyServices);
+    for service in services {
+        require!(
+            (1..=MAX_SERVICE_PROTOCOL_LENGTH).contains(&service.protocol.len())
+                && (1..=MAX_SERVICE_ENDPOINT_LENGTH).contains(&service.endpoint.len())
+                && service.version.len() <= MAX_SERVICE_VERSION_LENGTH,
+            SatiError::InvalidServiceEntry
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn service(protocol: &str, endpoint: &str, version: &str) -> ServiceEntry {
+        ServiceEntry {
+            protocol: protocol.to_string(),
+            endpoint: endpoint.to_string(),
+            version: version.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_validate_services_limits() {
+        assert!(validate_services(&[]).is_ok());
+        assert!(validate_services(&[service("MCP", "https://mcp.example/", "")]).is_ok());
+
+        let too_many = vec![service("A2A", "https://a2a.example/", "0.3.0"); MAX_SERVICES + 1];
+        assert_eq!(
+            validate_services(&too_many).unwrap_err(),
+            SatiError::TooManyServices.into()
+        );
+
+        for bad in [
+            service("", "https://x.example/", ""),
+            service("MCP", "", ""),
+            service(&"p".repeat(33), "https://x.example/", ""),
+            service("MCP", &"e".repeat(201), ""),
+            service("MCP", "https://x.example/", &"v".repeat(33)),
+        ] {
+            assert_eq!(
+                validate_services(&[bad]).unwrap_err(),
+                SatiError::InvalidServiceEntry.into()
+            );
+        }
+    }
+
+    #[test]
+    fn test_agent_services_space_matches_serialization() {
+        let services = vec![
+            service("A2A", "https://agent.example/agent-card.json", "0.3.0"),
+            service("agentWallet", "solana:5eykt4:7S3P4", ""),
+        ];
+        let account = AgentServices {
+            mint: Pubkey::default(),
+            services: services.clone(),
+            updated_at: 0,
+            bump: 0,
+        };
+        assert_eq!(
+            borsh::to_vec(&account).unwrap().len() + 8,
+            AgentServices::space(&services)
+        );
+    }
+}
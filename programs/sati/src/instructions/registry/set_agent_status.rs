@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::constants::PAUSE_AGENT_UPDATES;
+use crate::errors::SatiError;
+use crate::events::AgentStatusChanged;
+use crate::state::{AgentRecord, AgentStatus, RegistryConfig};
+
+#[derive(Accounts)]
+pub struct SetAgentStatus<'info> {
+    /// Agent holder (must sign)
+    pub holder: Signer<'info>,
+
+    /// Registry config - checked for the pause switch
+    #[account(
+        seeds = [b"registry"],
+        bump = registry_config.bump,
+        constraint = !registry_config.is_paused(PAUSE_AGENT_UPDATES) @ SatiError::RegistryPaused
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
+    /// Agent mint
+    #[account(mint::token_program = token_program)]
+    pub agent_mint: InterfaceAccount<'info, Mint>,
+
+    /// Holder's associated token account for this mint
+    /// Validated to be correct ATA for the agent_mint and have balance > 0
+    #[account(
+        associated_token::mint = agent_mint,
+        associated_token::authority = holder,
+        associated_token::token_program = token_program,
+    )]
+    pub ata: InterfaceAccount<'info, TokenAccount>,
+
+    /// Agent record PDA holding the status
+    #[account(
+        mut,
+        seeds = [b"agent", agent_mint.key().as_ref()],
+        bump = agent_record.bump,
+        constraint = !agent_record.is_retired() @ SatiError::AgentRetired,
+        constraint = !agent_record.is_suspended() @ SatiError::AgentSuspended
+    )]
+    pub agent_record: Account<'info, AgentRecord>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+pub fn handler(ctx: Context<SetAgentStatus>, status: AgentStatus) -> Result<()> {
+    // Verify holder owns the agent NFT (balance check)
+    require!(ctx.accounts.ata.amount > 0, SatiError::InvalidAuthority);
+
+    // Holders toggle Active <-> Deprecated; Retired and Suspended have their own instructions
+    require!(
+        matches!(status, AgentStatus::Active | AgentStatus::Deprecated),
+        SatiError::InvalidAgentStatus
+    );
+
+    let agent_record = &mut ctx.accounts.agent_record;
+    let old_status = agent_record.status;
+    require!(old_status != status, SatiError::InvalidAgentStatus);
+    agent_record.status = status;
+
+    emit!(AgentStatusChanged {
+        mint: agent_record.mint,
+        old_status,
+        new_status: status,
+        changed_by: ctx.accounts.holder.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::SatiError;
+use crate::events::AgentVerificationSet;
+use crate::state::{AgentRecord, AgentVerification, RegistryConfig};
+use crate::utils::create_pda_account;
+
+#[derive(Accounts)]
+pub struct SetAgentVerification<'info> {
+    /// Current authority (must sign). Pays for the badge account on first issue.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Registry configuration
+    #[account(
+        seeds = [b"registry"],
+        bump = registry_config.bump,
+        has_one = authority @ SatiError::InvalidAuthority,
+        constraint = !registry_config.is_immutable() @ SatiError::ImmutableAuthority
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
+    /// Agent record PDA - proves the mint is an active SATI agent
+    #[account(
+        seeds = [b"agent", agent_record.mint.as_ref()],
+        bump = agent_record.bump,
+        constraint = !agent_record.is_retired() @ SatiError::AgentRetired
+    )]
+    pub agent_record: Account<'info, AgentRecord>,
+
+    /// Verification PDA (created on first issue, updated afterwards)
+    /// CHECK: PDA verified via seeds; created or deserialized in handler
+    #[account(
+        mut,
+        seeds = [b"verification", agent_record.mint.as_ref()],
+        bump
+    )]
+    pub agent_verification: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetAgentVerification>, level: u8) -> Result<()> {
+    require!(level > 0, SatiError::InvalidVerificationLevel);
+
+    let mint = ctx.accounts.agent_record.mint;
+    let bump = ctx.bumps.agent_verification;
+    let verification_info = ctx.accounts.agent_verification.to_account_info();
+
+    if verification_info.owner != &crate::ID {
+        create_pda_account(
+            &ctx.accounts.authority.to_account_info(),
+            &verification_info,
+            &ctx.accounts.system_program.to_account_info(),
+            AgentVerification::SIZE,
+            &[b"verification", mint.as_ref(), &[bump]],
+        )?;
+    }
+
+    let issuer = ctx.accounts.authority.key();
+    AgentVerification {
+        mint,
+        level,
+        issuer,
+        verified_at: Clock::get()?.unix_timestamp,
+        bump,
+    }
+    .try_serialize(&mut &mut verification_info.try_borrow_mut_data()?[..])?;
+
+    emit!(AgentVerificationSet {
+        mint,
+        level,
+        issuer,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::constants::PAUSE_AGENT_UPDATES;
+use crate::errors::SatiError;
+use crate::events::AgentParentSet;
+use crate::state::{AgentParent, AgentRecord, RegistryConfig};
+
+#[derive(Accounts)]
+pub struct SetParentAgent<'info> {
+    /// Holder of the child agent (must sign). Pays rent for the link.
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// Holder of the parent agent (must sign; may be the same key)
+    pub parent_holder: Signer<'info>,
+
+    /// Registry config - checked for the pause switch
+    #[account(
+        seeds = [b"registry"],
+        bump = registry_config.bump,
+        constraint = !registry_config.is_paused(PAUSE_AGENT_UPDATES) @ SatiError::RegistryPaused
+    )]
+    pub registry_config: Box<Account<'info, RegistryConfig>>,
+
+    /// Child agent mint
+    #[account(mint::token_program = token_program)]
+    pub agent_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Holder's associated token account for agent_mint
+    #[account(
+        associated_token::mint = agent_mint,
+        associated_token::authority = holder,
+        associated_token::token_program = token_program,
+    )]
+    pub ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Child agent record - proves the child is an active SATI agent
+    #[account(
+        seeds = [b"agent", agent_mint.key().as_ref()],
+        bump = agent_record.bump,
+        constraint = !agent_record.is_retired() @ SatiError::AgentRetired,
+        constraint = !agent_record.is_suspended() @ SatiError::AgentSuspended
+    )]
+    pub agent_record: Box<Account<'info, AgentRecord>>,
+
+    /// Parent agent mint
+    #[account(mint::token_program = token_program)]
+    pub parent_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Parent holder's associated token account for parent_mint
+    #[account(
+        associated_token::mint = parent_mint,
+        associated_token::authority = parent_holder,
+        associated_token::token_program = token_program,
+    )]
+    pub parent_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Parent agent record - proves the parent is an active SATI agent
+    #[account(
+        seeds = [b"agent", parent_mint.key().as_ref()],
+        bump = parent_record.bump,
+        constraint = !parent_record.is_retired() @ SatiError::AgentRetired,
+        constraint = !parent_record.is_suspended() @ SatiError::AgentSuspended
+    )]
+    pub parent_record: Box<Account<'info, AgentRecord>>,
+
+    /// Link PDA (fails if this agent already has a parent)
+    #[account(
+        init,
+        payer = holder,
+        space = AgentParent::SIZE,
+        seeds = [b"parent", agent_mint.key().as_ref()],
+        bump
+    )]
+    pub agent_parent: Account<'info, AgentParent>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetParentAgent>) -> Result<()> {
+    // Verify both holders own their agent NFTs (balance check)
+    require!(ctx.accounts.ata.amount > 0, SatiError::InvalidAuthority);
+    require!(
+        ctx.accounts.parent_ata.amount > 0,
+        SatiError::InvalidAuthority
+    );
+
+    let mint = ctx.accounts.agent_mint.key();
+    let parent = ctx.accounts.parent_mint.key();
+    require_keys_neq!(mint, parent, SatiError::InvalidParentAgent);
+
+    let holder = ctx.accounts.holder.key();
+    let agent_parent = &mut ctx.accounts.agent_parent;
+    agent_parent.mint = mint;
+    agent_parent.parent = parent;
+    agent_parent.holder = holder;
+    agent_parent.linked_at = Clock::get()?.unix_timestamp;
+    agent_parent.bump = ctx.bumps.agent_parent;
+
+    emit!(AgentParentSet {
+        mint,
+        parent,
+        holder,
+        parent_holder: ctx.accounts.parent_holder.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::SatiError;
+use crate::events::RegistrationFeeUpdated;
+use crate::state::RegistryConfig;
+
+#[derive(Accounts)]
+pub struct SetRegistrationFee<'info> {
+    /// Current authority (must sign)
+    pub authority: Signer<'info>,
+
+    /// Registry configuration
+    #[account(
+        mut,
+        seeds = [b"registry"],
+        bump = registry_config.bump,
+        has_one = authority @ SatiError::InvalidAuthority,
+        constraint = !registry_config.is_immutable() @ SatiError::ImmutableAuthority,
+        constraint = !registry_config.is_timelocked() @ SatiError::TimelockRequired
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+}
+
+pub fn handler(
+    ctx: Context<SetRegistrationFee>,
+    fee_mint: Option<Pubkey>,
+    amount: u64,
+) -> Result<()> {
+    apply_registration_fee(&mut ctx.accounts.registry_config, fee_mint, amount)
+}
+
+/// Validate and store a new registration fee (shared with execute_governance_action).
+pub(crate) fn apply_registration_fee(
+    registry: &mut RegistryConfig,
+    fee_mint: Option<Pubkey>,
+    amount: u64,
+) -> Result<()> {
+    validate_registration_fee(fee_mint)?;
+
+    registry.fee_mint = fee_mint.unwrap_or_default();
+    registry.fee_amount = amount;
+
+    emit!(RegistrationFeeUpdated { fee_mint, amount });
+
+    Ok(())
+}
+
+/// Pubkey::default() is reserved to mean lamports, so it can't be a fee mint.
+pub(crate) fn validate_registration_fee(fee_mint: Option<Pubkey>) -> Result<()> {
+    require!(
+        fee_mint != Some(Pubkey::default()),
+        SatiError::InvalidRegistrationFee
+    );
+    Ok(())
+}
@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::constants::PAUSE_AGENT_UPDATES;
+use crate::errors::SatiError;
+use crate::events::{AgentStatusChanged, AgentSuccessorSet};
+use crate::state::{AgentRecord, AgentStatus, AgentSuccessor, RegistryConfig};
+
+#[derive(Accounts)]
+pub struct SetSuccessor<'info> {
+    /// Holder of the previous agent (must sign). Pays rent for the link.
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// Holder of the successor agent (must sign; may be the same key)
+    pub successor_holder: Signer<'info>,
+
+    /// Registry config - checked for the pause switch
+    #[account(
+        seeds = [b"registry"],
+        bump = registry_config.bump,
+        constraint = !registry_config.is_paused(PAUSE_AGENT_UPDATES) @ SatiError::RegistryPaused
+    )]
+    pub registry_config: Box<Account<'info, RegistryConfig>>,
+
+    /// Previous agent mint
+    #[account(mint::token_program = token_program)]
+    pub agent_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Holder's associated token account for agent_mint
+    #[account(
+        associated_token::mint = agent_mint,
+        associated_token::authority = holder,
+        associated_token::token_program = token_program,
+    )]
+    pub ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Previous agent record (marked Deprecated)
+    #[account(
+        mut,
+        seeds = [b"agent", agent_mint.key().as_ref()],
+        bump = agent_record.bump,
+        constraint = !agent_record.is_retired() @ SatiError::AgentRetired,
+        constraint = !agent_record.is_suspended() @ SatiError::AgentSuspended
+    )]
+    pub agent_record: Box<Account<'info, AgentRecord>>,
+
+    /// Successor agent mint
+    #[account(mint::token_program = token_program)]
+    pub successor_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Successor holder's associated token account for successor_mint
+    #[account(
+        associated_token::mint = successor_mint,
+        associated_token::authority = successor_holder,
+        associated_token::token_program = token_program,
+    )]
+    pub successor_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Successor agent record - proves the successor is an active SATI agent
+    #[account(
+        seeds = [b"agent", successor_mint.key().as_ref()],
+        bump = successor_record.bump,
+        constraint = !successor_record.is_retired() @ SatiError::AgentRetired,
+        constraint = !successor_record.is_suspended() @ SatiError::AgentSuspended
+    )]
+    pub successor_record: Box<Account<'info, AgentRecord>>,
+
+    /// Link PDA (fails if this agent already has a successor)
+    #[account(
+        init,
+        payer = holder,
+        space = AgentSuccessor::SIZE,
+        seeds = [b"successor", agent_mint.key().as_ref()],
+        bump
+    )]
+    pub agent_successor: Account<'info, AgentSuccessor>,
+
+    /// The successor's own link PDA, which must not exist (keeps chains acyclic)
+    /// CHECK: PDA verified via seeds; only its existence is checked
+    #[account(
+        seeds = [b"successor", successor_mint.key().as_ref()],
+        bump
+    )]
+    pub successor_link: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetSuccessor>) -> Result<()> {
+    // Verify both holders own their agent NFTs (balance check)
+    require!(ctx.accounts.ata.amount > 0, SatiError::InvalidAuthority);
+    require!(
+        ctx.accounts.successor_ata.amount > 0,
+        SatiError::InvalidAuthority
+    );
+
+    let mint = ctx.accounts.agent_mint.key();
+    let successor = ctx.accounts.successor_mint.key();
+    require_keys_neq!(mint, successor, SatiError::InvalidSuccessor);
+    require!(
+        ctx.accounts.successor_link.owner != &crate::ID,
+        SatiError::InvalidSuccessor
+    );
+
+    let agent_successor = &mut ctx.accounts.agent_successor;
+    agent_successor.mint = mint;
+    agent_successor.successor = successor;
+    agent_successor.linked_at = Clock::get()?.unix_timestamp;
+    agent_successor.bump = ctx.bumps.agent_successor;
+
+    // The previous identity is superseded; flag it for clients
+    let holder = ctx.accounts.holder.key();
+    let agent_record = &mut ctx.accounts.agent_record;
+    if agent_record.status != AgentStatus::Deprecated {
+        emit!(AgentStatusChanged {
+            mint,
+            old_status: agent_record.status,
+            new_status: AgentStatus::Deprecated,
+            changed_by: holder,
+        });
+        agent_record.status = AgentStatus::Deprecated;
+    }
+
+    emit!(AgentSuccessorSet {
+        mint,
+        successor,
+        holder,
+        successor_holder: ctx.accounts.successor_holder.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_TIMELOCK_DELAY;
+use crate::errors::SatiError;
+use crate::events::TimelockDelayUpdated;
+use crate::state::RegistryConfig;
+
+#[derive(Accounts)]
+pub struct SetTimelockDelay<'info> {
+    /// Current authority (must sign)
+    pub authority: Signer<'info>,
+
+    /// Registry configuration. Once a timelock is active, the delay itself can
+    /// only be changed through a queued SetTimelockDelay action.
+    #[account(
+        mut,
+        seeds = [b"registry"],
+        bump = registry_config.bump,
+        has_one = authority @ SatiError::InvalidAuthority,
+        constraint = !registry_config.is_immutable() @ SatiError::ImmutableAuthority,
+        constraint = !registry_config.is_timelocked() @ SatiError::TimelockRequired
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+}
+
+pub fn handler(ctx: Context<SetTimelockDelay>, delay: i64) -> Result<()> {
+    apply_timelock_delay(&mut ctx.accounts.registry_config, delay)
+}
+
+/// Validate and store a new timelock delay (shared with execute_governance_action).
+pub(crate) fn apply_timelock_delay(registry: &mut RegistryConfig, delay: i64) -> Result<()> {
+    require!(
+        (0..=MAX_TIMELOCK_DELAY).contains(&delay),
+        SatiError::InvalidTimelockDelay
+    );
+
+    let old_delay = registry.timelock_delay;
+    registry.timelock_delay = delay;
+
+    emit!(TimelockDelayUpdated {
+        old_delay,
+        new_delay: delay,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::SatiError;
+use crate::events::AgentStatusChanged;
+use crate::state::{AgentRecord, AgentStatus, RegistryConfig};
+
+#[derive(Accounts)]
+pub struct SuspendAgent<'info> {
+    /// Current authority (must sign)
+    pub authority: Signer<'info>,
+
+    /// Registry configuration. Suspension is moderation and is
+    /// deliberately not subject to the governance timelock.
+    #[account(
+        seeds = [b"registry"],
+        bump = registry_config.bump,
+        has_one = authority @ SatiError::InvalidAuthority,
+        constraint = !registry_config.is_immutable() @ SatiError::ImmutableAuthority
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
+    /// Agent record PDA to suspend
+    #[account(
+        mut,
+        seeds = [b"agent", agent_record.mint.as_ref()],
+        bump = agent_record.bump,
+        constraint = !agent_record.is_retired() @ SatiError::AgentRetired,
+        constraint = !agent_record.is_suspended() @ SatiError::InvalidAgentStatus
+    )]
+    pub agent_record: Account<'info, AgentRecord>,
+}
+
+pub fn handler(ctx: Context<SuspendAgent>) -> Result<()> {
+    let agent_record = &mut ctx.accounts.agent_record;
+    let old_status = agent_record.status;
+    agent_record.pre_suspend_status = old_status;
+    agent_record.status = AgentStatus::Suspended;
+
+    emit!(AgentStatusChanged {
+        mint: agent_record.mint,
+        old_status,
+        new_status: AgentStatus::Suspended,
+        changed_by: ctx.accounts.authority.key(),
+    });
+
+    Ok(())
+}
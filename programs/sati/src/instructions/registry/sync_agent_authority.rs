@@ -0,0 +1,148 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::{
+    spl_token_2022::{
+        extension::{
+            metadata_pointer::MetadataPointer, BaseStateWithExtensions, StateWithExtensions,
+        },
+        instruction::{set_authority, AuthorityType},
+        state::Mint as Token2022Mint,
+    },
+    Token2022,
+};
+use anchor_spl::token_interface::TokenAccount;
+use spl_token_metadata_interface::{instruction::update_authority, state::TokenMetadata};
+
+use crate::constants::PAUSE_AGENT_UPDATES;
+use crate::errors::SatiError;
+use crate::events::AgentAuthoritySynced;
+use crate::state::RegistryConfig;
+
+#[derive(Accounts)]
+pub struct SyncAgentAuthority<'info> {
+    /// Current agent holder (must sign)
+    pub holder: Signer<'info>,
+
+    /// Current TokenMetadata update authority, when it is not the holder.
+    /// Omit once the legacy owner has handed the update authority to the holder;
+    /// Token-2022 only lets the recorded update authority move it, so a legacy
+    /// owner who kept it must still co-sign.
+    pub current_authority: Option<Signer<'info>>,
+
+    /// Registry config - checked for the pause switch; becomes the permanent
+    /// metadata authority
+    #[account(
+        seeds = [b"registry"],
+        bump = registry_config.bump,
+        constraint = !registry_config.is_paused(PAUSE_AGENT_UPDATES) @ SatiError::RegistryPaused
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
+    /// Agent mint account
+    /// CHECK: Owner verified as Token-2022; extensions parsed in handler
+    #[account(mut, owner = anchor_spl::token_2022::ID)]
+    pub agent_mint: UncheckedAccount<'info>,
+
+    /// Holder's associated token account for this mint
+    /// Validated to be correct ATA for the agent_mint and have balance > 0
+    #[account(
+        associated_token::mint = agent_mint,
+        associated_token::authority = holder,
+        associated_token::token_program = token_program,
+    )]
+    pub ata: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+pub fn handler(ctx: Context<SyncAgentAuthority>) -> Result<()> {
+    // Verify holder owns the agent NFT (balance check)
+    // The ATA constraint already verified it's the correct ATA
+    require!(ctx.accounts.ata.amount > 0, SatiError::InvalidAuthority);
+
+    let agent_mint = ctx.accounts.agent_mint.key();
+    let registry_key = ctx.accounts.registry_config.key();
+    let authority_info = match &ctx.accounts.current_authority {
+        Some(current_authority) => current_authority.to_account_info(),
+        None => ctx.accounts.holder.to_account_info(),
+    };
+    let current_authority = authority_info.key();
+
+    // === PHASE 1: Read current authorities ===
+    let (metadata_authority, pointer_authority) = {
+        let mint_data = ctx.accounts.agent_mint.try_borrow_data()?;
+        let mint_state = StateWithExtensions::<Token2022Mint>::unpack(&mint_data)
+            .map_err(|_| SatiError::InvalidAgentMetadata)?;
+        let metadata = mint_state
+            .get_variable_len_extension::<TokenMetadata>()
+            .map_err(|_| SatiError::InvalidAgentMetadata)?;
+        require!(metadata.mint == agent_mint, SatiError::InvalidAgentMetadata);
+        let pointer = mint_state
+            .get_extension::<MetadataPointer>()
+            .map_err(|_| SatiError::InvalidAgentMetadata)?;
+
+        let metadata_authority: Option<Pubkey> = metadata.update_authority.into();
+        let pointer_authority: Option<Pubkey> = pointer.authority.into();
+        (metadata_authority, pointer_authority)
+    };
+    // Borrow is now dropped - safe to make CPIs
+
+    require!(
+        metadata_authority != Some(registry_key),
+        SatiError::AgentAuthorityAlreadySynced
+    );
+    require!(
+        metadata_authority == Some(current_authority),
+        SatiError::InvalidAuthority
+    );
+    // A pointer authority held by anyone else could repoint metadata after the sync
+    require!(
+        pointer_authority == Some(current_authority) || pointer_authority == Some(registry_key),
+        SatiError::InvalidAuthority
+    );
+
+    // === PHASE 2: Hand TokenMetadata update authority to the registry PDA ===
+    let update_authority_ix = update_authority(
+        &anchor_spl::token_2022::ID,
+        &agent_mint,
+        &current_authority,
+        Some(registry_key).try_into()?,
+    );
+
+    anchor_lang::solana_program::program::invoke(
+        &update_authority_ix,
+        &[
+            ctx.accounts.agent_mint.to_account_info(),
+            authority_info.clone(),
+        ],
+    )?;
+
+    // === PHASE 3: Hand MetadataPointer authority to the registry PDA ===
+    // Prevents the previous owner from repointing metadata to another account.
+    // Skipped when the registry already holds it (checked above).
+    if pointer_authority == Some(current_authority) {
+        let set_authority_ix = set_authority(
+            &anchor_spl::token_2022::ID,
+            &agent_mint,
+            Some(&registry_key),
+            AuthorityType::MetadataPointer,
+            &current_authority,
+            &[],
+        )?;
+
+        anchor_lang::solana_program::program::invoke(
+            &set_authority_ix,
+            &[
+                ctx.accounts.agent_mint.to_account_info(),
+                authority_info.clone(),
+            ],
+        )?;
+    }
+
+    emit!(AgentAuthoritySynced {
+        mint: agent_mint,
+        holder: ctx.accounts.holder.key(),
+        previous_authority: current_authority,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::SatiError;
+use crate::events::EvmAddressUnlinked;
+use crate::state::EvmLink;
+
+#[derive(Accounts)]
+pub struct UnlinkEvmAddress<'info> {
+    /// Current holder, or anyone once the link is stale
+    pub signer: Signer<'info>,
+
+    /// Agent mint (pins the token program used to derive `holder_ata`)
+    #[account(
+        address = evm_link.agent_mint,
+        mint::token_program = token_program,
+    )]
+    pub agent_mint: InterfaceAccount<'info, Mint>,
+
+    /// Link to remove (closed, rent to the holder that created it)
+    #[account(
+        mut,
+        seeds = [
+            b"evm_link",
+            evm_link.agent_mint.as_ref(),
+            &EvmLink::chain_seed(&evm_link.chain_id),
+            &evm_link.evm_address,
+        ],
+        bump = evm_link.bump,
+        has_one = holder,
+        close = holder
+    )]
+    pub evm_link: Account<'info, EvmLink>,
+
+    /// CHECK: Validated via has_one on evm_link
+    #[account(mut)]
+    pub holder: UncheckedAccount<'info>,
+
+    /// CHECK: Address checked against the linking holder's ATA in the handler.
+    /// May be closed; an empty or missing account means the holder changed.
+    pub holder_ata: UncheckedAccount<'info>,
+
+    /// Signer's token account for the agent mint (current holder unlinks)
+    pub signer_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Token program that owns the agent mint
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<UnlinkEvmAddress>) -> Result<()> {
+    let signer = ctx.accounts.signer.key();
+    let evm_link = &ctx.accounts.evm_link;
+    let token_program = ctx.accounts.token_program.key();
+
+    let expected_ata = spl_associated_token_account::get_associated_token_address_with_program_id(
+        &evm_link.holder,
+        &evm_link.agent_mint,
+        &token_program,
+    );
+    require!(
+        ctx.accounts.holder_ata.key() == expected_ata,
+        SatiError::InvalidAuthority
+    );
+
+    let is_holder = ctx.accounts.signer_ata.as_ref().is_some_and(|ata| {
+        ata.owner == signer && ata.mint == evm_link.agent_mint && ata.amount > 0
+    });
+
+    // Links lapse when the NFT leaves the linking holder or the expiry passes;
+    // stale links can be closed by anyone
+    let holder_balance = holder_ata_balance(&ctx.accounts.holder_ata, &token_program)?;
+    let is_stale = holder_balance == 0 || evm_link.is_expired(Clock::get()?.unix_timestamp);
+
+    require!(is_holder || is_stale, SatiError::EvmUnlinkNotAllowed);
+
+    emit!(EvmAddressUnlinked {
+        agent_mint: evm_link.agent_mint,
+        evm_address: evm_link.evm_address,
+        chain_id: evm_link.chain_id.clone(),
+        unlinked_by: signer,
+    });
+
+    Ok(())
+}
+
+/// Agent NFT balance of a linking holder's ATA; 0 if the account was closed.
+/// Shared with unlink_sns_domain.
+pub(crate) fn holder_ata_balance(holder_ata: &AccountInfo, token_program: &Pubkey) -> Result<u64> {
+    if holder_ata.owner == token_program && !holder_ata.data_is_empty() {
+        Ok(TokenAccount::try_deserialize(&mut &holder_ata.try_borrow_data()?[..])?.amount)
+    } else {
+        Ok(0)
+    }
+}
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::SatiError;
+use crate::events::SnsDomainUnlinked;
+use crate::instructions::registry::unlink_evm_address::holder_ata_balance;
+use crate::sns::{parse_sol_domain_owner, NAME_SERVICE_PROGRAM_ID};
+use crate::state::SnsLink;
+
+#[derive(Accounts)]
+pub struct UnlinkSnsDomain<'info> {
+    /// Current holder, or anyone once the link is stale
+    pub signer: Signer<'info>,
+
+    /// Agent mint (pins the token program used to derive `holder_ata`)
+    #[account(
+        address = sns_link.agent_mint,
+        mint::token_program = token_program,
+    )]
+    pub agent_mint: InterfaceAccount<'info, Mint>,
+
+    /// Link to remove (closed, rent to the holder that created it)
+    #[account(
+        mut,
+        seeds = [b"sns_link", sns_link.agent_mint.as_ref(), sns_link.name_account.as_ref()],
+        bump = sns_link.bump,
+        has_one = holder,
+        close = holder
+    )]
+    pub sns_link: Account<'info, SnsLink>,
+
+    /// CHECK: Validated via has_one on sns_link
+    #[account(mut)]
+    pub holder: UncheckedAccount<'info>,
+
+    /// CHECK: Address checked against the linking holder's ATA in the handler.
+    /// May be closed; an empty or missing account means the holder changed.
+    pub holder_ata: UncheckedAccount<'info>,
+
+    /// SNS name registry of the linked domain
+    /// CHECK: Address pinned to the link; the owner is read in the handler.
+    /// May be deleted or transferred, which makes the link stale.
+    #[account(address = sns_link.name_account)]
+    pub name_account: UncheckedAccount<'info>,
+
+    /// Signer's token account for the agent mint (current holder unlinks)
+    pub signer_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Token program that owns the agent mint
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<UnlinkSnsDomain>) -> Result<()> {
+    let signer = ctx.accounts.signer.key();
+    let sns_link = &ctx.accounts.sns_link;
+    let token_program = ctx.accounts.token_program.key();
+
+    let expected_ata = spl_associated_token_account::get_associated_token_address_with_program_id(
+        &sns_link.holder,
+        &sns_link.agent_mint,
+        &token_program,
+    );
+    require!(
+        ctx.accounts.holder_ata.key() == expected_ata,
+        SatiError::InvalidAuthority
+    );
+
+    let is_holder = ctx.accounts.signer_ata.as_ref().is_some_and(|ata| {
+        ata.owner == signer && ata.mint == sns_link.agent_mint && ata.amount > 0
+    });
+
+    // Links lapse when the NFT leaves the linking holder or the domain changes
+    // owner; stale links can be closed by anyone
+    let name_account = &ctx.accounts.name_account;
+    let domain_owner = if name_account.owner == &NAME_SERVICE_PROGRAM_ID {
+        parse_sol_domain_owner(&name_account.try_borrow_data()?).ok()
+    } else {
+        None
+    };
+    let holder_balance = holder_ata_balance(&ctx.accounts.holder_ata, &token_program)?;
+    let is_stale = holder_balance == 0 || domain_owner != Some(sns_link.holder);
+
+    require!(is_holder || is_stale, SatiError::SnsUnlinkNotAllowed);
+
+    emit!(SnsDomainUnlinked {
+        agent_mint: sns_link.agent_mint,
+        name_account: sns_link.name_account,
+        domain: sns_link.domain.clone(),
+        unlinked_by: signer,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::SatiError;
+use crate::events::AgentStatusChanged;
+use crate::state::{AgentRecord, AgentStatus, RegistryConfig};
+
+#[derive(Accounts)]
+pub struct UnsuspendAgent<'info> {
+    /// Current authority (must sign)
+    pub authority: Signer<'info>,
+
+    /// Registry configuration
+    #[account(
+        seeds = [b"registry"],
+        bump = registry_config.bump,
+        has_one = authority @ SatiError::InvalidAuthority,
+        constraint = !registry_config.is_immutable() @ SatiError::ImmutableAuthority
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
+    /// Suspended agent record PDA
+    #[account(
+        mut,
+        seeds = [b"agent", agent_record.mint.as_ref()],
+        bump = agent_record.bump,
+        constraint = agent_record.is_suspended() @ SatiError::InvalidAgentStatus
+    )]
+    pub agent_record: Account<'info, AgentRecord>,
+}
+
+pub fn handler(ctx: Context<UnsuspendAgent>) -> Result<()> {
+    // Restore whatever the agent was before suspension (Active or Deprecated)
+    let agent_record = &mut ctx.accounts.agent_record;
+    let new_status = agent_record.pre_suspend_status;
+    agent_record.status = new_status;
+
+    emit!(AgentStatusChanged {
+        mint: agent_record.mint,
+        old_status: AgentStatus::Suspended,
+        new_status,
+        changed_by: ctx.accounts.authority.key(),
+    });
+
+    Ok(())
+}
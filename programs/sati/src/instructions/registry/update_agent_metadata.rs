@@ -0,0 +1,399 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token_2022::{
+    spl_token_2022::{
+        extension::{BaseStateWithExtensions, StateWithExtensions},
+        state::Mint as Token2022Mint,
+    },
+    Token2022,
+};
+use anchor_spl::token_interface::TokenAccount;
+use spl_token_metadata_interface::{
+    instruction::{remove_key, update_field},
+    state::{Field, TokenMetadata},
+};
+
+use crate::constants::{
+    LARGE_METADATA_THRESHOLD, MAX_METADATA_ENTRIES, MAX_METADATA_KEY_LENGTH,
+    MAX_METADATA_VALUE_LENGTH, MAX_NAME_LENGTH, MAX_URI_LENGTH, PAUSE_AGENT_UPDATES,
+};
+use crate::errors::SatiError;
+use crate::events::AgentMetadataUpdated;
+use crate::state::{MetadataEntry, RegistryConfig};
+use crate::uri_hash::validate_uri_hash_entries;
+
+/// Parameters for updating an agent's TokenMetadata.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UpdateAgentMetadataParams {
+    /// New agent name (None = unchanged)
+    pub name: Option<String>,
+    /// New registration file URI (None = unchanged)
+    pub uri: Option<String>,
+    /// Additional metadata entries to insert or overwrite
+    pub additional_metadata: Vec<MetadataEntry>,
+    /// Additional metadata keys to remove (applied before inserts)
+    pub remove_keys: Vec<String>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateAgentMetadata<'info> {
+    /// Agent owner (must sign). Pays for any rent top-up caused by metadata growth.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// Registry configuration (TokenMetadata update authority for synced agents)
+    #[account(
+        seeds = [b"registry"],
+        bump = registry_config.bump,
+        constraint = !registry_config.is_paused(PAUSE_AGENT_UPDATES) @ SatiError::RegistryPaused
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
+    /// Agent mint account (TokenMetadata lives on the mint itself)
+    /// CHECK: Owner verified as Token-2022; metadata parsed in handler
+    #[account(mut, owner = anchor_spl::token_2022::ID)]
+    pub agent_mint: UncheckedAccount<'info>,
+
+    /// Owner's associated token account for this mint
+    /// Validated to be correct ATA for the agent_mint and have balance > 0
+    #[account(
+        associated_token::mint = agent_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub ata: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<UpdateAgentMetadata>, params: UpdateAgentMetadataParams) -> Result<()> {
+    // Verify owner holds the agent NFT (balance check)
+    // The ATA constraint already verified it's the correct ATA
+    require!(ctx.accounts.ata.amount > 0, SatiError::InvalidAuthority);
+
+    require!(
+        params.name.is_some()
+            || params.uri.is_some()
+            || !params.additional_metadata.is_empty()
+            || !params.remove_keys.is_empty(),
+        SatiError::EmptyMetadataUpdate
+    );
+
+    let agent_mint = ctx.accounts.agent_mint.key();
+
+    // === PHASE 1: Apply the update in memory to validate limits and size ===
+    let (current_len, old_tlv_len, new_tlv_len, update_authority) = {
+        let mint_data = ctx.accounts.agent_mint.try_borrow_data()?;
+        let mint_state = StateWithExtensions::<Token2022Mint>::unpack(&mint_data)
+            .map_err(|_| SatiError::InvalidAgentMetadata)?;
+        let mut metadata = mint_state
+            .get_variable_len_extension::<TokenMetadata>()
+            .map_err(|_| SatiError::InvalidAgentMetadata)?;
+        require!(metadata.mint == agent_mint, SatiError::InvalidAgentMetadata);
+        let update_authority: Option<Pubkey> = metadata.update_authority.into();
+
+        let old_tlv_len = metadata.tlv_size_of()?;
+        apply_metadata_update(&mut metadata, &params)?;
+        let new_tlv_len = metadata.tlv_size_of()?;
+
+        (mint_data.len(), old_tlv_len, new_tlv_len, update_authority)
+    };
+    // Borrow is now dropped - safe to make CPIs
+
+    // Synced agents are edited by the registry PDA on behalf of the holder.
+    // Legacy agents (registered before sync_agent_authority) still require the
+    // holder to be the TokenMetadata update authority.
+    let registry_key = ctx.accounts.registry_config.key();
+    let owner_key = ctx.accounts.owner.key();
+    let authority = match update_authority {
+        Some(authority) if authority == registry_key || authority == owner_key => authority,
+        _ => return Err(SatiError::InvalidAuthority.into()),
+    };
+
+    // === PHASE 2: Top up rent so Token-2022 can reallocate the mint ===
+    let new_len = current_len
+        .checked_sub(old_tlv_len)
+        .ok_or(SatiError::Overflow)?
+        .checked_add(new_tlv_len)
+        .ok_or(SatiError::Overflow)?;
+    let required_lamports = Rent::get()?.minimum_balance(new_len);
+    let current_lamports = ctx.accounts.agent_mint.lamports();
+
+    if required_lamports > current_lamports {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.owner.to_account_info(),
+                    to: ctx.accounts.agent_mint.to_account_info(),
+                },
+            ),
+            required_lamports - current_lamports,
+        )?;
+    }
+
+    // === PHASE 3: Execute Token-2022 metadata CPIs ===
+    // NOTE: Each field adds one CPI call (~5-10k compute units).
+    let total_fields = params.remove_keys.len() + params.additional_metadata.len();
+    if total_fields > LARGE_METADATA_THRESHOLD {
+        msg!(
+            "Large metadata update ({} fields): ensure 400k compute units requested",
+            total_fields
+        );
+    }
+
+    for key in &params.remove_keys {
+        // Key presence already verified in apply_metadata_update
+        let remove_key_ix = remove_key(
+            &anchor_spl::token_2022::ID,
+            &agent_mint,
+            &authority,
+            key.clone(),
+            true, // idempotent
+        );
+        invoke_metadata_cpi(&ctx, &remove_key_ix)?;
+    }
+
+    let mut fields: Vec<(Field, String)> = Vec::new();
+    if let Some(ref name) = params.name {
+        fields.push((Field::Name, name.clone()));
+    }
+    if let Some(ref uri) = params.uri {
+        fields.push((Field::Uri, uri.clone()));
+    }
+    for entry in &params.additional_metadata {
+        fields.push((Field::Key(entry.key.clone()), entry.value.clone()));
+    }
+
+    for (field, value) in fields {
+        let update_field_ix = update_field(
+            &anchor_spl::token_2022::ID,
+            &agent_mint,
+            &authority,
+            field,
+            value,
+        );
+        invoke_metadata_cpi(&ctx, &update_field_ix)?;
+    }
+
+    // === Emit Event ===
+    emit!(AgentMetadataUpdated {
+        mint: agent_mint,
+        updated_by: ctx.accounts.owner.key(),
+        name: params.name,
+        uri: params.uri,
+        additional_metadata: params.additional_metadata,
+        removed_keys: params.remove_keys,
+    });
+
+    Ok(())
+}
+
+/// Invoke a TokenMetadata instruction, signing with the registry PDA when it
+/// is the update authority (otherwise the owner's signature is forwarded).
+fn invoke_metadata_cpi(
+    ctx: &Context<UpdateAgentMetadata>,
+    ix: &anchor_lang::solana_program::instruction::Instruction,
+) -> Result<()> {
+    let registry_seeds: &[&[u8]] = &[b"registry", &[ctx.accounts.registry_config.bump]];
+
+    invoke_signed(
+        ix,
+        &[
+            ctx.accounts.agent_mint.to_account_info(),
+            ctx.accounts.owner.to_account_info(),
+            ctx.accounts.registry_config.to_account_info(),
+        ],
+        &[registry_seeds],
+    )?;
+    Ok(())
+}
+
+/// Validate the update against registry limits and apply it to `metadata`.
+///
+/// Removals are applied first, then name/uri, then key inserts/overwrites,
+/// mirroring the order of the Token-2022 CPIs in the handler.
+fn apply_metadata_update(
+    metadata: &mut TokenMetadata,
+    params: &UpdateAgentMetadataParams,
+) -> Result<()> {
+    if let Some(ref name) = params.name {
+        require!(name.len() <= MAX_NAME_LENGTH, SatiError::NameTooLong);
+    }
+    if let Some(ref uri) = params.uri {
+        require!(uri.len() <= MAX_URI_LENGTH, SatiError::UriTooLong);
+    }
+    require!(
+        params.additional_metadata.len() <= MAX_METADATA_ENTRIES,
+        SatiError::TooManyMetadataEntries
+    );
+    for entry in &params.additional_metadata {
+        require!(
+            entry.key.len() <= MAX_METADATA_KEY_LENGTH,
+            SatiError::MetadataKeyTooLong
+        );
+        require!(
+            entry.value.len() <= MAX_METADATA_VALUE_LENGTH,
+            SatiError::MetadataValueTooLong
+        );
+    }
+    validate_uri_hash_entries(&params.additional_metadata)?;
+
+    for key in &params.remove_keys {
+        require!(metadata.remove_key(key), SatiError::MetadataKeyNotFound);
+    }
+
+    if let Some(ref name) = params.name {
+        metadata.update(Field::Name, name.clone());
+    }
+    if let Some(ref uri) = params.uri {
+        metadata.update(Field::Uri, uri.clone());
+    }
+    for entry in &params.additional_metadata {
+        metadata.set_key_value(entry.key.clone(), entry.value.clone());
+    }
+
+    require!(
+        metadata.additional_metadata.len() <= MAX_METADATA_ENTRIES,
+        SatiError::TooManyMetadataEntries
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_metadata(entries: &[(&str, &str)]) -> TokenMetadata {
+        TokenMetadata {
+            name: "Agent".to_string(),
+            symbol: String::new(),
+            uri: "https://example.com/agent.json".to_string(),
+            additional_metadata: entries
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn make_params() -> UpdateAgentMetadataParams {
+        UpdateAgentMetadataParams {
+            name: None,
+            uri: None,
+            additional_metadata: vec![],
+            remove_keys: vec![],
+        }
+    }
+
+    fn entry(key: &str, value: &str) -> MetadataEntry {
+        MetadataEntry {
+            key: key.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_apply_metadata_update_name_and_uri() {
+        let mut metadata = make_metadata(&[]);
+        let mut params = make_params();
+        params.name = Some("Renamed".to_string());
+        params.uri = Some("ipfs://new".to_string());
+
+        apply_metadata_update(&mut metadata, &params).unwrap();
+
+        assert_eq!(metadata.name, "Renamed");
+        assert_eq!(metadata.uri, "ipfs://new");
+    }
+
+    #[test]
+    fn test_apply_metadata_update_overwrites_and_removes_keys() {
+        let mut metadata = make_metadata(&[("mcp", "https://old"), ("a2a", "https://a2a")]);
+        let mut params = make_params();
+        params.additional_metadata = vec![entry("mcp", "https://new"), entry("did", "did:x")];
+        params.remove_keys = vec!["a2a".to_string()];
+
+        apply_metadata_update(&mut metadata, &params).unwrap();
+
+        assert_eq!(
+            metadata.additional_metadata,
+            vec![
+                ("mcp".to_string(), "https://new".to_string()),
+                ("did".to_string(), "did:x".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_apply_metadata_update_missing_key_fails() {
+        let mut metadata = make_metadata(&[("mcp", "https://old")]);
+        let mut params = make_params();
+        params.remove_keys = vec!["a2a".to_string()];
+
+        let result = apply_metadata_update(&mut metadata, &params);
+        assert_eq!(result.unwrap_err(), SatiError::MetadataKeyNotFound.into());
+    }
+
+    #[test]
+    fn test_apply_metadata_update_enforces_length_limits() {
+        let mut params = make_params();
+        params.name = Some("A".repeat(MAX_NAME_LENGTH + 1));
+        let result = apply_metadata_update(&mut make_metadata(&[]), &params);
+        assert_eq!(result.unwrap_err(), SatiError::NameTooLong.into());
+
+        let mut params = make_params();
+        params.uri = Some("u".repeat(MAX_URI_LENGTH + 1));
+        let result = apply_metadata_update(&mut make_metadata(&[]), &params);
+        assert_eq!(result.unwrap_err(), SatiError::UriTooLong.into());
+
+        let mut params = make_params();
+        params.additional_metadata = vec![entry(&"k".repeat(MAX_METADATA_KEY_LENGTH + 1), "v")];
+        let result = apply_metadata_update(&mut make_metadata(&[]), &params);
+        assert_eq!(result.unwrap_err(), SatiError::MetadataKeyTooLong.into());
+
+        let mut params = make_params();
+        params.additional_metadata = vec![entry("k", &"v".repeat(MAX_METADATA_VALUE_LENGTH + 1))];
+        let result = apply_metadata_update(&mut make_metadata(&[]), &params);
+        assert_eq!(result.unwrap_err(), SatiError::MetadataValueTooLong.into());
+    }
+
+    #[test]
+    fn test_apply_metadata_update_validates_uri_hash() {
+        let digest = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+
+        let mut metadata = make_metadata(&[]);
+        let mut params = make_params();
+        params.additional_metadata = vec![entry("uri_hash", &format!("sha256:{digest}"))];
+        apply_metadata_update(&mut metadata, &params).unwrap();
+
+        let mut params = make_params();
+        params.additional_metadata = vec![entry("uri_hash", digest)];
+        let result = apply_metadata_update(&mut metadata, &params);
+        assert_eq!(result.unwrap_err(), SatiError::InvalidUriHash.into());
+    }
+
+    #[test]
+    fn test_apply_metadata_update_enforces_total_entry_limit() {
+        let existing: Vec<(String, String)> = (0..MAX_METADATA_ENTRIES)
+            .map(|i| (format!("key{i}"), "v".to_string()))
+            .collect();
+        let mut metadata = make_metadata(&[]);
+        metadata.additional_metadata = existing;
+
+        // Adding a new key beyond the limit fails
+        let mut params = make_params();
+        params.additional_metadata = vec![entry("extra", "v")];
+        let result = apply_metadata_update(&mut metadata.clone(), &params);
+        assert_eq!(
+            result.unwrap_err(),
+            SatiError::TooManyMetadataEntries.into()
+        );
+
+        // Replacing a removed key stays within the limit
+        params.remove_keys = vec!["key0".to_string()];
+        assert!(apply_metadata_update(&mut metadata, &params).is_ok());
+    }
+}
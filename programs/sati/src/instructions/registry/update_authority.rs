@@ -15,21 +15,39 @@ pub struct UpdateRegistryAuthority<'info> {
         seeds = [b"registry"],
         bump = registry_config.bump,
         has_one = authority @ SatiError::InvalidAuthority,
-        constraint = !registry_config.is_immutable() @ SatiError::ImmutableAuthority
+        constraint = !registry_config.is_immutable() @ SatiError::ImmutableAuthority,
+        constraint = !registry_config.is_timelocked() @ SatiError::TimelockRequired
     )]
     pub registry_config: Account<'info, RegistryConfig>,
 }
 
 pub fn handler(ctx: Context<UpdateRegistryAuthority>, new_authority: Option<Pubkey>) -> Result<()> {
-    let registry = &mut ctx.accounts.registry_config;
+    // Transfers go through propose_registry_authority / accept_registry_authority
+    // so a mistyped key can't brick governance. Only renounce remains here.
+    require!(
+        new_authority.is_none(),
+        SatiError::AuthorityTransferRequiresAcceptance
+    );
+
+    apply_renounce_authority(&mut ctx.accounts.registry_config)
+}
+
+/// Renounce registry authority (shared with execute_governance_action).
+/// Nobody could unpause or withdraw the treasury afterwards, so the registry must
+/// be fully unpaused and registration free.
+pub(crate) fn apply_renounce_authority(registry: &mut RegistryConfig) -> Result<()> {
+    require!(registry.paused == 0, SatiError::RenounceWhilePaused);
+    require!(registry.fee_amount == 0, SatiError::RenounceWithFee);
+
     let old_authority = registry.authority;
 
-    // None = renounce (set to default pubkey = immutable)
-    registry.authority = new_authority.unwrap_or_default();
+    // Default pubkey = immutable; also clears any pending transfer
+    registry.authority = Pubkey::default();
+    registry.pending_authority = Pubkey::default();
 
     emit!(RegistryAuthorityUpdated {
         old_authority,
-        new_authority,
+        new_authority: None,
     });
 
     Ok(())
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::errors::SatiError;
+use crate::events::TreasuryWithdrawn;
+use crate::state::RegistryConfig;
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    /// Current authority (must sign)
+    pub authority: Signer<'info>,
+
+    /// Registry configuration
+    #[account(
+        seeds = [b"registry"],
+        bump = registry_config.bump,
+        has_one = authority @ SatiError::InvalidAuthority,
+        constraint = !registry_config.is_immutable() @ SatiError::ImmutableAuthority
+    )]
+    pub registry_config: Account<'info, RegistryConfig>,
+
+    /// Registry treasury PDA (holds lamport fees, owns fee token accounts)
+    /// CHECK: PDA verified via seeds
+    #[account(mut, seeds = [b"treasury"], bump)]
+    pub treasury: UncheckedAccount<'info>,
+
+    /// Receives the funds: a wallet for lamports, a token account of `mint` for SPL
+    /// CHECK: Any account chosen by the authority
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+
+    /// Mint to withdraw (omit to withdraw lamports)
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    /// Treasury-owned token account for `mint`
+    #[account(mut)]
+    pub treasury_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Token program of `mint`
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub fn handler(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+    let treasury = &ctx.accounts.treasury;
+
+    match ctx.accounts.mint.as_deref() {
+        None => {
+            // Lamport fees: the treasury is program-owned, so debit it directly
+            // and keep it rent-exempt.
+            require!(
+                treasury.owner == &crate::ID,
+                SatiError::InsufficientTreasuryBalance
+            );
+            let available = treasury
+                .lamports()
+                .saturating_sub(Rent::get()?.minimum_balance(0));
+            require!(amount <= available, SatiError::InsufficientTreasuryBalance);
+
+            treasury.sub_lamports(amount)?;
+            ctx.accounts.destination.add_lamports(amount)?;
+        }
+        Some(mint) => {
+            let (Some(from), Some(token_program)) = (
+                ctx.accounts.treasury_token_account.as_deref(),
+                ctx.accounts.token_program.as_ref(),
+            ) else {
+                return Err(SatiError::MissingFeeAccounts.into());
+            };
+            require_keys_eq!(from.owner, treasury.key(), SatiError::InvalidFeeAccount);
+            require!(
+                amount <= from.amount,
+                SatiError::InsufficientTreasuryBalance
+            );
+
+            transfer_checked(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    TransferChecked {
+                        from: from.to_account_info(),
+                        mint: mint.to_account_info(),
+                        to: ctx.accounts.destination.to_account_info(),
+                        authority: treasury.to_account_info(),
+                    },
+                    &[&[b"treasury", &[ctx.bumps.treasury]]],
+                ),
+                amount,
+                mint.decimals,
+            )?;
+        }
+    }
+
+    emit!(TreasuryWithdrawn {
+        mint: ctx.accounts.mint.as_ref().map(|mint| mint.key()),
+        amount,
+        destination: ctx.accounts.destination.key(),
+    });
+
+    Ok(())
+}
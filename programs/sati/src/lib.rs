@@ -2,12 +2,18 @@ use anchor_lang::prelude::*;
 use light_sdk::{cpi::CpiSigner, derive_light_cpi_signer};
 use solana_security_txt::security_txt;
 
+pub mod caip2;
 pub mod constants;
 pub mod errors;
 pub mod events;
+pub mod external_identity;
+pub mod handle;
 pub mod instructions;
 pub mod signature;
+pub mod sns;
 pub mod state;
+pub mod uri_hash;
+pub mod utils;
 
 use instructions::*;
 use state::*;
@@ -42,6 +48,15 @@ pub mod sati {
         instructions::registry::initialize::handler(ctx)
     }
 
+    /// Create the AgentRecord PDA for an agent registered before AgentRecord existed.
+    /// Permissionless; membership is proven by the agent's AgentIndex PDA.
+    pub fn backfill_agent_record(
+        ctx: Context<BackfillAgentRecord>,
+        member_number: u64,
+    ) -> Result<()> {
+        instructions::registry::backfill_agent_record::handler(ctx, member_number)
+    }
+
     /// Register a new agent in the SATI registry.
     /// Creates a Token-2022 NFT with TokenMetadata and TokenGroupMember extensions.
     pub fn register_agent(
@@ -62,8 +77,106 @@ pub mod sati {
         )
     }
 
-    /// Update or renounce registry authority.
-    /// Pass None to renounce (makes registry immutable).
+    /// Register several agents in one transaction.
+    /// Per-agent accounts go in remaining_accounts; total_agents is incremented once.
+    pub fn register_agents_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, RegisterAgentsBatch<'info>>,
+        agents: Vec<RegisterAgentParams>,
+    ) -> Result<()> {
+        instructions::registry::register_agents_batch::handler(ctx, agents)
+    }
+
+    /// Retire an agent by burning its NFT and closing the holder's ATA.
+    /// Closes the AgentIndex PDA; the AgentRecord stays as a `Retired` tombstone.
+    pub fn retire_agent(ctx: Context<RetireAgent>) -> Result<()> {
+        instructions::registry::retire_agent::handler(ctx)
+    }
+
+    /// Replace an agent's on-chain service endpoints. Holder only (verified via ATA).
+    /// The AgentServices PDA is resized to fit; an empty list closes it.
+    pub fn set_agent_services(
+        ctx: Context<SetAgentServices>,
+        services: Vec<ServiceEntry>,
+    ) -> Result<()> {
+        instructions::registry::set_agent_services::handler(ctx, services)
+    }
+
+    /// Set an agent's status to Active or Deprecated. Holder only (verified via ATA).
+    /// Deprecated agents still accept attestations; suspended agents cannot change status.
+    pub fn set_agent_status(ctx: Context<SetAgentStatus>, status: AgentStatus) -> Result<()> {
+        instructions::registry::set_agent_status::handler(ctx, status)
+    }
+
+    /// Link an agent to its successor identity (one-way, permanent). Both holders sign.
+    /// The previous agent is marked Deprecated so indexers can merge histories.
+    pub fn set_successor(ctx: Context<SetSuccessor>) -> Result<()> {
+        instructions::registry::set_successor::handler(ctx)
+    }
+
+    /// Attach an agent to a parent agent. Both holders sign.
+    /// The parent's holder can then sign for the child wherever delegation is allowed.
+    pub fn set_parent_agent(ctx: Context<SetParentAgent>) -> Result<()> {
+        instructions::registry::set_parent_agent::handler(ctx)
+    }
+
+    /// Detach an agent from its parent. Child or parent holder, or anyone once
+    /// the child has changed hands. Rent returns to the linking holder.
+    pub fn detach_parent_agent(ctx: Context<DetachParentAgent>) -> Result<()> {
+        instructions::registry::detach_parent_agent::handler(ctx)
+    }
+
+    /// Suspend an agent. Authority only, not timelocked.
+    /// Suspended agents cannot receive new attestations.
+    pub fn suspend_agent(ctx: Context<SuspendAgent>) -> Result<()> {
+        instructions::registry::suspend_agent::handler(ctx)
+    }
+
+    /// Lift a suspension, returning the agent to Active. Authority only.
+    pub fn unsuspend_agent(ctx: Context<UnsuspendAgent>) -> Result<()> {
+        instructions::registry::unsuspend_agent::handler(ctx)
+    }
+
+    /// Hand an agent's metadata authority to the registry PDA.
+    /// Afterwards only the current holder can edit metadata (via update_agent_metadata).
+    pub fn sync_agent_authority(ctx: Context<SyncAgentAuthority>) -> Result<()> {
+        instructions::registry::sync_agent_authority::handler(ctx)
+    }
+
+    /// Update an agent's TokenMetadata (name, uri, additional metadata).
+    /// Only the current holder of the agent NFT can update.
+    pub fn update_agent_metadata(
+        ctx: Context<UpdateAgentMetadata>,
+        params: UpdateAgentMetadataParams,
+    ) -> Result<()> {
+        instructions::registry::update_agent_metadata::handler(ctx, params)
+    }
+
+    /// Claim a unique handle for an agent. Holder only, one handle per agent.
+    /// Handles are case-folded and confusable variants collide (see `handle` module).
+    pub fn claim_agent_handle(ctx: Context<ClaimAgentHandle>, handle: String) -> Result<()> {
+        instructions::registry::claim_agent_handle::handler(ctx, handle)
+    }
+
+    /// Release an agent handle so it can be claimed again.
+    /// Holder or registry authority; anyone once the agent is retired.
+    pub fn release_agent_handle(ctx: Context<ReleaseAgentHandle>) -> Result<()> {
+        instructions::registry::release_agent_handle::handler(ctx)
+    }
+
+    /// Issue or update an agent's verification badge. Authority only.
+    /// Schemas with `min_verification_level > 0` only accept agents at or above that level.
+    pub fn set_agent_verification(ctx: Context<SetAgentVerification>, level: u8) -> Result<()> {
+        instructions::registry::set_agent_verification::handler(ctx, level)
+    }
+
+    /// Revoke an agent's verification badge. Authority only.
+    /// Closes the AgentVerification PDA, returning rent to the authority.
+    pub fn revoke_agent_verification(ctx: Context<RevokeAgentVerification>) -> Result<()> {
+        instructions::registry::revoke_agent_verification::handler(ctx)
+    }
+
+    /// Renounce registry authority (makes registry immutable).
+    /// Only None is accepted; transfers use propose/accept_registry_authority.
     pub fn update_registry_authority(
         ctx: Context<UpdateRegistryAuthority>,
         new_authority: Option<Pubkey>,
@@ -71,8 +184,92 @@ pub mod sati {
         instructions::registry::update_authority::handler(ctx, new_authority)
     }
 
+    /// Propose a new registry authority (step 1 of 2).
+    /// Replaces any existing proposal; authority is unchanged until accepted.
+    pub fn propose_registry_authority(
+        ctx: Context<ProposeRegistryAuthority>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::registry::propose_authority::handler(ctx, new_authority)
+    }
+
+    /// Accept a proposed registry authority transfer (step 2 of 2).
+    /// Must be signed by the pending authority.
+    pub fn accept_registry_authority(ctx: Context<AcceptRegistryAuthority>) -> Result<()> {
+        instructions::registry::accept_authority::handler(ctx)
+    }
+
+    /// Cancel a pending registry authority transfer.
+    /// Current authority only.
+    pub fn cancel_registry_authority_transfer(
+        ctx: Context<CancelRegistryAuthorityTransfer>,
+    ) -> Result<()> {
+        instructions::registry::cancel_authority_transfer::handler(ctx)
+    }
+
+    /// Set the governance timelock delay in seconds (0 = disabled).
+    /// Only callable directly while no timelock is active; otherwise queue SetTimelockDelay.
+    pub fn set_timelock_delay(ctx: Context<SetTimelockDelay>, delay: i64) -> Result<()> {
+        instructions::registry::set_timelock_delay::handler(ctx, delay)
+    }
+
+    /// Queue a governance action behind the timelock. Authority only.
+    /// Creates a PendingAction PDA executable after `timelock_delay` seconds.
+    pub fn queue_governance_action(
+        ctx: Context<QueueGovernanceAction>,
+        action: GovernanceAction,
+    ) -> Result<()> {
+        instructions::registry::queue_governance_action::handler(ctx, action)
+    }
+
+    /// Execute a queued governance action once its timelock has elapsed.
+    /// Permissionless; closes the PendingAction.
+    pub fn execute_governance_action(ctx: Context<ExecuteGovernanceAction>) -> Result<()> {
+        instructions::registry::execute_governance_action::handler(ctx)
+    }
+
+    /// Cancel a queued governance action. Authority only.
+    /// Closes the PendingAction, returning rent to whoever paid for it.
+    pub fn cancel_governance_action(ctx: Context<CancelGovernanceAction>) -> Result<()> {
+        instructions::registry::cancel_governance_action::handler(ctx)
+    }
+
+    /// Pause one or more flows (PAUSE_* bitfield). Authority only.
+    /// Takes effect immediately; not subject to the governance timelock.
+    pub fn pause_registry(ctx: Context<PauseRegistry>, flags: u8) -> Result<()> {
+        instructions::registry::pause_registry::handler(ctx, flags)
+    }
+
+    /// Unpause one or more flows (PAUSE_* bitfield). Authority only.
+    /// Takes effect immediately; not subject to the governance timelock.
+    pub fn unpause_registry(ctx: Context<UnpauseRegistry>, flags: u8) -> Result<()> {
+        instructions::registry::unpause_registry::handler(ctx, flags)
+    }
+
+    /// Set the per-agent registration fee (None = lamports, 0 = no fee). Authority only.
+    /// Only callable directly while no timelock is active; otherwise queue SetRegistrationFee.
+    pub fn set_registration_fee(
+        ctx: Context<SetRegistrationFee>,
+        fee_mint: Option<Pubkey>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::registry::set_registration_fee::handler(ctx, fee_mint, amount)
+    }
+
+    /// Withdraw lamports or SPL tokens from the registry treasury. Authority only.
+    /// Lamport withdrawals keep the treasury rent-exempt.
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+        instructions::registry::withdraw_treasury::handler(ctx, amount)
+    }
+
+    /// Migrate an older RegistryConfig to the current layout.
+    /// Permissionless; grows the account and zero-initializes new fields.
+    pub fn migrate_registry_config(ctx: Context<MigrateRegistryConfig>) -> Result<()> {
+        instructions::registry::migrate_registry_config::handler(ctx)
+    }
+
     /// Link an EVM address to an agent via secp256k1 signature verification.
-    /// Proves the agent owner controls the specified EVM address.
+    /// Proves the agent owner controls the specified EVM address and stores an EvmLink PDA.
     pub fn link_evm_address(
         ctx: Context<LinkEvmAddress>,
         params: LinkEvmAddressParams,
@@ -80,6 +277,33 @@ pub mod sati {
         instructions::registry::link_evm_address::handler(ctx, params)
     }
 
+    /// Close an EvmLink, refunding the holder that created it.
+    /// Current holder only, or anyone once the link is expired or the holder changed.
+    pub fn unlink_evm_address(ctx: Context<UnlinkEvmAddress>) -> Result<()> {
+        instructions::registry::unlink_evm_address::handler(ctx)
+    }
+
+    /// Link a non-EVM identity (Sui/Aptos/Cosmos ed25519, passkey, Bitcoin) to an agent.
+    /// Verifies the external key signed the link hash and emits ExternalIdentityLinked.
+    pub fn link_external_identity(
+        ctx: Context<LinkExternalIdentity>,
+        params: LinkExternalIdentityParams,
+    ) -> Result<()> {
+        instructions::registry::link_external_identity::handler(ctx, params)
+    }
+
+    /// Link a .sol domain owned by the agent holder and store an SnsLink PDA.
+    /// Reads the SNS name registry owner; tokenized domains are not supported.
+    pub fn link_sns_domain(ctx: Context<LinkSnsDomain>, domain: String) -> Result<()> {
+        instructions::registry::link_sns_domain::handler(ctx, domain)
+    }
+
+    /// Close an SnsLink, refunding the holder that created it.
+    /// Current holder only, or anyone once the NFT or the domain changed hands.
+    pub fn unlink_sns_domain(ctx: Context<UnlinkSnsDomain>) -> Result<()> {
+        instructions::registry::unlink_sns_domain::handler(ctx)
+    }
+
     // =========================================================================
     // Attestation Instructions
     // =========================================================================
@@ -106,6 +330,24 @@ pub mod sati {
         )
     }
 
+    /// Require agents attested under a schema to hold a verification badge. Authority only.
+    /// Only callable directly while no timelock is active; otherwise queue SetSchemaMinVerification.
+    pub fn set_schema_min_verification(
+        ctx: Context<SetSchemaMinVerification>,
+        min_level: u8,
+    ) -> Result<()> {
+        instructions::attestation::set_schema_min_verification::handler(ctx, min_level)
+    }
+
+    /// Migrate an older SchemaConfig to the current layout.
+    /// Permissionless; only needed when the old account has no spare bytes.
+    pub fn migrate_schema_config(
+        ctx: Context<MigrateSchemaConfig>,
+        sas_schema: Pubkey,
+    ) -> Result<()> {
+        instructions::attestation::migrate_schema_config::handler(ctx, sas_schema)
+    }
+
     /// Create a compressed attestation via Light Protocol.
     /// Verifies Ed25519 signatures via instruction introspection.
     pub fn create_compressed_attestation<'info>(
@@ -133,6 +375,15 @@ pub mod sati {
         instructions::attestation::close_compressed_attestation::handler(ctx, params)
     }
 
+    /// Amend a compressed attestation in place (outcome and content only).
+    /// The counterparty signs a SIWS amend message bound to the next revision.
+    pub fn update_compressed_attestation<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateCompressedAttestation<'info>>,
+        params: UpdateParams,
+    ) -> Result<()> {
+        instructions::attestation::update_compressed_attestation::handler(ctx, params)
+    }
+
     /// Close a regular (SAS) attestation.
     /// Only allowed if schema config has closeable=true.
     pub fn close_regular_attestation<'info>(
@@ -140,4 +391,22 @@ pub mod sati {
     ) -> Result<()> {
         instructions::attestation::close_regular_attestation::handler(ctx)
     }
+
+    /// Rewrite a regular (SAS) attestation with new provider-signed data.
+    /// Closes and recreates the attestation at the same address in one instruction.
+    pub fn update_regular_attestation<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateRegularAttestation<'info>>,
+        params: UpdateRegularParams,
+    ) -> Result<()> {
+        instructions::attestation::update_regular_attestation::handler(ctx, params)
+    }
+
+    /// Create or rewrite several ReputationScores for one provider atomically.
+    /// A single provider Ed25519 signature covers every entry in the batch.
+    pub fn update_regular_attestations_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateRegularAttestationsBatch<'info>>,
+        params: UpdateRegularBatchParams,
+    ) -> Result<()> {
+        instructions::attestation::update_regular_attestations_batch::handler(ctx, params)
+    }
 }
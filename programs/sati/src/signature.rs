@@ -14,6 +14,7 @@
 //! - Hash functions include the mint address (stable identity); signatures come from the NFT owner.
 
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
 use sha3::{Digest, Keccak256};
 use solana_program::{
     clock::Clock,
@@ -24,11 +25,29 @@ use solana_program::{
 
 use crate::constants::*;
 use crate::errors::SatiError;
-use crate::state::SignatureMode;
+use crate::state::{AgentParent, EvmLinkFormat, SignatureMode};
 
 /// Size of Ed25519 signature offset structure (7 u16 fields = 14 bytes)
 const ED25519_OFFSETS_SIZE: usize = 14;
 
+/// secp256k1 curve order n (big-endian)
+const SECP256K1_N: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe,
+    0xba, 0xae, 0xdc, 0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c, 0xd0, 0x36, 0x41, 0x41,
+];
+
+/// n / 2 (big-endian): the largest s accepted (EIP-2 low-s rule)
+const SECP256K1_HALF_N: [u8; 32] = [
+    0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0x5d, 0x57, 0x6e, 0x73, 0x57, 0xa4, 0x50, 0x1d, 0xdf, 0xe9, 0x2f, 0x46, 0x68, 0x1b, 0x20, 0xa0,
+];
+
+/// secp256r1 signature verification precompile (SIMD-0075)
+pub const SECP256R1_PROGRAM_ID: Pubkey = pubkey!("Secp256r1SigVerify1111111111111111111111111");
+
+/// Precompile offsets value meaning "this instruction"
+const CURRENT_INSTRUCTION: u16 = u16::MAX;
+
 /// Signature data extracted from Ed25519 instruction.
 /// Contains the pubkey, signature bytes, and the message that was signed.
 #[derive(Clone, Debug)]
@@ -224,6 +243,59 @@ pub fn extract_ed25519_signatures(
     Ok(result)
 }
 
+/// Check that an Ed25519 or secp256r1 precompile instruction in this transaction
+/// verified a signature by `public_key` over exactly `message`.
+///
+/// Both precompiles share the 14-byte offsets layout. Only entries whose
+/// signature, key and message all live in the precompile instruction itself are
+/// considered, so the bytes compared here are the bytes the runtime verified.
+pub fn verify_precompile_signature(
+    instructions_sysvar: &AccountInfo,
+    precompile_id: &Pubkey,
+    public_key: &[u8],
+    message: &[u8],
+) -> Result<()> {
+    require!(
+        instructions_sysvar.key == &SYSVAR_INSTRUCTIONS_ID,
+        SatiError::InvalidInstructionsSysvar
+    );
+
+    let read_u16 = |data: &[u8], pos: usize| -> Option<u16> {
+        Some(u16::from_le_bytes(data.get(pos..pos + 2)?.try_into().ok()?))
+    };
+    let matches = |data: &[u8], entry: usize| -> Option<bool> {
+        let sig_ix = read_u16(data, entry + 2)?;
+        let pubkey_offset = read_u16(data, entry + 4)? as usize;
+        let pubkey_ix = read_u16(data, entry + 6)?;
+        let msg_offset = read_u16(data, entry + 8)? as usize;
+        let msg_size = read_u16(data, entry + 10)? as usize;
+        let msg_ix = read_u16(data, entry + 12)?;
+        if [sig_ix, pubkey_ix, msg_ix] != [CURRENT_INSTRUCTION; 3] {
+            return Some(false);
+        }
+        Some(
+            data.get(pubkey_offset..pubkey_offset + public_key.len())? == public_key
+                && data.get(msg_offset..msg_offset + msg_size)? == message,
+        )
+    };
+
+    let mut index = 0;
+    while let Ok(instruction) = load_instruction_at_checked(index, instructions_sysvar) {
+        if instruction.program_id == *precompile_id {
+            let data = &instruction.data;
+            let num_signatures = data.first().copied().unwrap_or(0) as usize;
+            for i in 0..num_signatures {
+                if matches(data, 2 + i * ED25519_OFFSETS_SIZE) == Some(true) {
+                    return Ok(());
+                }
+            }
+        }
+        index += 1;
+    }
+
+    err!(SatiError::ExternalSignatureNotFound)
+}
+
 /// Compute the interaction hash that the agent signs (blind to outcome).
 /// Domain: SATI:interaction:v1
 ///
@@ -273,6 +345,60 @@ pub fn compute_reputation_nonce(provider: &Pubkey, token_account: &Pubkey) -> [u
     hasher.finalize().into()
 }
 
+/// Compute the hash a provider signs to rewrite a regular attestation.
+/// Domain: SATI:reputation_update:v1
+/// Binds the new data and expiry to the data being replaced and the attestation's
+/// current revision. Every write bumps the revision, so a signature cannot be
+/// replayed even if the attestation later returns to the same data.
+pub fn compute_reputation_update_hash(
+    sas_schema: &Pubkey,
+    current_data: &[u8],
+    new_data: &[u8],
+    revision: u32,
+    expiry: i64,
+) -> [u8; 32] {
+    let current_hash: [u8; 32] = Keccak256::digest(current_data).into();
+    let new_hash: [u8; 32] = Keccak256::digest(new_data).into();
+
+    let mut hasher = Keccak256::new();
+    hasher.update(DOMAIN_REPUTATION_UPDATE);
+    hasher.update(sas_schema.as_ref());
+    hasher.update(current_hash);
+    hasher.update(new_hash);
+    hasher.update(revision.to_le_bytes());
+    hasher.update(expiry.to_le_bytes());
+    hasher.finalize().into()
+}
+
+/// One batch entry as signed: current data (None if the attestation doesn't exist),
+/// new data and the attestation's current revision.
+pub type ReputationBatchEntry<'a> = (Option<&'a [u8]>, &'a [u8], u32);
+
+/// Compute the hash a provider signs for update_regular_attestations_batch.
+/// Domain: SATI:reputation_batch:v2
+/// Each entry contributes keccak256(current_data) (zeroed for attestations that
+/// don't exist yet), keccak256(new_data) and the attestation's current revision.
+/// Every write bumps the revision, so the signature cannot be replayed, even after
+/// the attestation is closed.
+pub fn compute_reputation_batch_hash(
+    sas_schema: &Pubkey,
+    entries: &[ReputationBatchEntry],
+    expiry: i64,
+) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    hasher.update(DOMAIN_REPUTATION_BATCH);
+    hasher.update(sas_schema.as_ref());
+    hasher.update(expiry.to_le_bytes());
+    for (current_data, new_data, revision) in entries {
+        let current_hash: [u8; 32] =
+            current_data.map_or([0u8; 32], |d| Keccak256::digest(d).into());
+        hasher.update(current_hash);
+        hasher.update(Keccak256::digest(new_data));
+        hasher.update(revision.to_le_bytes());
+    }
+    hasher.finalize().into()
+}
+
 /// Compute the deterministic nonce for delegation attestation.
 /// One delegation per (schema, delegate, agent) tuple.
 /// Uses schema pubkey as domain separator to enable future delegation versions.
@@ -289,21 +415,145 @@ pub fn compute_delegation_nonce(
 }
 
 /// Compute the hash for EVM address linking.
-/// Domain: SATI:evm_link:v1
+/// Domain: SATI:evm_link:v2
+///
+/// Binds the holder the link is recorded for and its expiry (`0` = never), so a
+/// signature cannot be replayed by a later holder or with a different lifetime.
 pub fn compute_evm_link_hash(
     agent_mint: &Pubkey,
+    holder: &Pubkey,
     evm_address: &[u8; 20],
     chain_id: &str,
+    expires_at: Option<i64>,
 ) -> [u8; 32] {
     let mut hasher = Keccak256::new();
     hasher.update(DOMAIN_EVM_LINK);
     hasher.update(agent_mint.as_ref());
+    hasher.update(holder.as_ref());
     hasher.update(evm_address);
+    hasher.update(expires_at.unwrap_or(0).to_le_bytes());
     hasher.update(chain_id.as_bytes());
     hasher.finalize().into()
 }
 
+/// Build the human-readable message an EVM wallet signs with `personal_sign`.
+/// MUST match the SDK's buildEvmLinkMessage() exactly (see tests/fixtures/evm-link-vectors.json).
+pub fn build_evm_link_message(
+    agent_mint: &Pubkey,
+    holder: &Pubkey,
+    evm_address: &[u8; 20],
+    chain_id: &str,
+    expires_at: Option<i64>,
+) -> String {
+    let address_hex: String = evm_address.iter().map(|b| format!("{b:02x}")).collect();
+    let expires = expires_at.map_or_else(|| "never".to_string(), |t| t.to_string());
+    format!(
+        "SATI EVM Link\n\nAgent: {agent_mint}\nHolder: {holder}\nAddress: 0x{address_hex}\nChain: {chain_id}\nExpires: {expires}\n\nSign to link this address to the agent."
+    )
+}
+
+/// Compute the EIP-191 (version 0x45) digest of a personal message.
+pub fn compute_eip191_hash(message: &[u8]) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    hasher.update(EIP191_PREFIX);
+    hasher.update(message.len().to_string().as_bytes());
+    hasher.update(message);
+    hasher.finalize().into()
+}
+
+/// Compute the EIP-712 domain separator for EVM address linking.
+pub fn compute_evm_link_domain_separator() -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    hasher.update(Keccak256::digest(EIP712_DOMAIN_TYPE));
+    hasher.update(Keccak256::digest(EIP712_DOMAIN_NAME));
+    hasher.update(Keccak256::digest(EIP712_DOMAIN_VERSION));
+    hasher.finalize().into()
+}
+
+/// Compute the EIP-712 digest of
+/// `EvmLink(bytes32 agentMint,bytes32 holder,address evmAddress,string chainId,int64 expiresAt)`.
+pub fn compute_evm_link_eip712_hash(
+    agent_mint: &Pubkey,
+    holder: &Pubkey,
+    evm_address: &[u8; 20],
+    chain_id: &str,
+    expires_at: Option<i64>,
+) -> [u8; 32] {
+    let expires_at = expires_at.unwrap_or(0);
+    let mut struct_hasher = Keccak256::new();
+    struct_hasher.update(Keccak256::digest(EIP712_EVM_LINK_TYPE));
+    struct_hasher.update(agent_mint.as_ref());
+    struct_hasher.update(holder.as_ref());
+    struct_hasher.update([0u8; 12]); // address is left-padded to 32 bytes
+    struct_hasher.update(evm_address);
+    struct_hasher.update(Keccak256::digest(chain_id.as_bytes()));
+    // int64 is sign-extended to 32 bytes
+    struct_hasher.update(if expires_at < 0 {
+        [0xff; 24]
+    } else {
+        [0u8; 24]
+    });
+    struct_hasher.update(expires_at.to_be_bytes());
+    let struct_hash: [u8; 32] = struct_hasher.finalize().into();
+
+    let mut hasher = Keccak256::new();
+    hasher.update([0x19, 0x01]);
+    hasher.update(compute_evm_link_domain_separator());
+    hasher.update(struct_hash);
+    hasher.finalize().into()
+}
+
+/// Compute the digest the EVM wallet signed for a link in the given format.
+pub fn compute_evm_link_digest(
+    format: EvmLinkFormat,
+    agent_mint: &Pubkey,
+    holder: &Pubkey,
+    evm_address: &[u8; 20],
+    chain_id: &str,
+    expires_at: Option<i64>,
+) -> [u8; 32] {
+    match format {
+        EvmLinkFormat::Raw => {
+            compute_evm_link_hash(agent_mint, holder, evm_address, chain_id, expires_at)
+        }
+        EvmLinkFormat::Eip191 => compute_eip191_hash(
+            build_evm_link_message(agent_mint, holder, evm_address, chain_id, expires_at)
+                .as_bytes(),
+        ),
+        EvmLinkFormat::Eip712 => {
+            compute_evm_link_eip712_hash(agent_mint, holder, evm_address, chain_id, expires_at)
+        }
+    }
+}
+
+/// Map an Ethereum `v` (27/28) or raw recovery ID (0/1) to 0/1.
+/// Recovery IDs 2/3 (r overflow) never occur for Ethereum signatures and are rejected.
+pub fn normalize_recovery_id(recovery_id: u8) -> Result<u8> {
+    match recovery_id {
+        0 | 1 => Ok(recovery_id),
+        27 | 28 => Ok(recovery_id - 27),
+        _ => err!(SatiError::InvalidRecoveryId),
+    }
+}
+
+/// Reject non-canonical `(r, s)`: zero or out-of-range scalars, and high-s
+/// signatures (the malleable twin `(r, n - s)` of a valid signature).
+pub fn check_secp256k1_signature_canonical(signature: &[u8; 64]) -> Result<()> {
+    let (r, s) = signature.split_at(32);
+    require!(
+        r.iter().any(|&b| b != 0) && r < &SECP256K1_N[..],
+        SatiError::InvalidSecp256k1Signature
+    );
+    require!(
+        s.iter().any(|&b| b != 0),
+        SatiError::InvalidSecp256k1Signature
+    );
+    require!(s <= &SECP256K1_HALF_N[..], SatiError::Secp256k1HighS);
+    Ok(())
+}
+
 /// Verify secp256k1 signature and check recovered address matches expected.
+/// Only canonical low-s signatures are accepted; `recovery_id` may be 0/1 or 27/28.
 /// Returns Ok(()) if signature is valid and recovered address matches.
 pub fn verify_secp256k1_signature(
     message_hash: &[u8; 32],
@@ -311,6 +561,9 @@ pub fn verify_secp256k1_signature(
     recovery_id: u8,
     expected_evm_address: &[u8; 20],
 ) -> Result<()> {
+    let recovery_id = normalize_recovery_id(recovery_id)?;
+    check_secp256k1_signature_canonical(signature)?;
+
     // Recover public key from signature
     let recovered_pubkey = secp256k1_recover(message_hash, recovery_id, signature)
         .map_err(|_| SatiError::Secp256k1RecoveryFailed)?;
@@ -418,9 +671,12 @@ pub fn parse_delegation_attestation(data: &[u8]) -> Result<ParsedDelegation> {
 
 /// Verify agent authorization for attestation signing.
 ///
-/// Implements the dual-path authorization model:
+/// Implements the three-path authorization model:
 /// 1. **Owner Fast Path (~100 CU)**: If signer == agent ATA owner → authorize
-/// 2. **Delegation Path (~5-10k CU)**: Verify delegation attestation
+/// 2. **Parent Path (~200 CU)**: If an AgentParent link is provided, signer must hold the parent agent
+/// 3. **Delegation Path (~5-10k CU)**: Verify delegation attestation
+///
+/// Parent and delegation paths both require the schema to allow delegation.
 ///
 /// # Arguments
 /// * `signer` - The pubkey that signed the attestation
@@ -429,11 +685,13 @@ pub fn parse_delegation_attestation(data: &[u8]) -> Result<ParsedDelegation> {
 /// * `delegation_schema` - Schema for delegation verification (None = owner only)
 /// * `delegation_attestation` - Optional delegation attestation account
 /// * `sati_credential` - SATI SAS credential for PDA derivation
+/// * `agent_parent` - Optional AgentParent link and the parent holder's token account
 /// * `clock` - Current clock for expiry verification
 ///
 /// # Returns
 /// * `Ok(())` if authorization passes
 /// * `Err` with appropriate delegation error if authorization fails
+#[allow(clippy::too_many_arguments)]
 pub fn verify_agent_authorization(
     signer: &Pubkey,
     agent_mint: &Pubkey,
@@ -441,6 +699,7 @@ pub fn verify_agent_authorization(
     delegation_schema: Option<&Pubkey>,
     delegation_attestation: Option<&AccountInfo>,
     sati_credential: &Pubkey,
+    agent_parent: Option<(&AgentParent, &TokenAccount)>,
     clock: &Clock,
 ) -> Result<()> {
     // 1. Owner Fast Path: If signer is the agent ATA owner, authorize immediately
@@ -451,11 +710,16 @@ pub fn verify_agent_authorization(
     // 2. Schema Check: If delegation_schema is None, only owner can sign
     let delegate_schema = delegation_schema.ok_or(SatiError::OwnerOnly)?;
 
-    // 3. Delegation Required: Must provide delegation attestation
+    // 3. Parent Path: the parent agent's holder acts for the child like a delegate
+    if let Some((link, parent_ata)) = agent_parent {
+        return verify_parent_authorization(signer, agent_mint, agent_ata_owner, link, parent_ata);
+    }
+
+    // 4. Delegation Required: Must provide delegation attestation
     let delegation_account =
         delegation_attestation.ok_or(SatiError::DelegationAttestationRequired)?;
 
-    // 4. PDA Verification: Derive expected PDA and verify it matches
+    // 5. PDA Verification: Derive expected PDA and verify it matches
     let nonce = compute_delegation_nonce(delegate_schema, signer, agent_mint);
 
     let (expected_pda, _bump) = Pubkey::find_program_address(
@@ -473,27 +737,27 @@ pub fn verify_agent_authorization(
         SatiError::InvalidDelegationPDA
     );
 
-    // 5. Parse delegation attestation
+    // 6. Parse delegation attestation
     let delegation_data = delegation_account.try_borrow_data()?;
     let parsed = parse_delegation_attestation(&delegation_data)?;
     drop(delegation_data);
 
-    // 6. Delegate Binding: attestation.counterparty == signer
+    // 7. Delegate Binding: attestation.counterparty == signer
     require!(parsed.delegate == *signer, SatiError::DelegateMismatch);
 
-    // 7. Agent Binding: attestation.token_account == agent_mint
+    // 8. Agent Binding: attestation.token_account == agent_mint
     require!(
         parsed.agent_mint == *agent_mint,
         SatiError::AgentMintMismatch
     );
 
-    // 8. Owner Binding: attestation.data_hash == current_owner (transfer safety)
+    // 9. Owner Binding: attestation.data_hash == current_owner (transfer safety)
     require!(
         parsed.delegator == *agent_ata_owner,
         SatiError::DelegationOwnerMismatch
     );
 
-    // 9. Expiration Check: expiry == 0 OR expiry > current_timestamp
+    // 10. Expiration Check: expiry == 0 OR expiry > current_timestamp
     if parsed.expiry != 0 {
         require!(
             parsed.expiry > clock.unix_timestamp,
@@ -504,6 +768,26 @@ pub fn verify_agent_authorization(
     Ok(())
 }
 
+/// Verify that `signer` holds the parent agent linked to `agent_mint`.
+///
+/// The link only counts while the child is still held by the holder that
+/// co-signed it, so a transferred child never inherits the old hierarchy.
+pub fn verify_parent_authorization(
+    signer: &Pubkey,
+    agent_mint: &Pubkey,
+    agent_ata_owner: &Pubkey,
+    link: &AgentParent,
+    parent_ata: &TokenAccount,
+) -> Result<()> {
+    require!(link.mint == *agent_mint, SatiError::InvalidParentLink);
+    require!(link.holder == *agent_ata_owner, SatiError::ParentLinkStale);
+    require!(
+        parent_ata.mint == link.parent && parent_ata.owner == *signer && parent_ata.amount > 0,
+        SatiError::ParentHolderMismatch
+    );
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -650,15 +934,33 @@ mod tests {
     #[test]
     fn test_evm_link_hash_parity_vector1() {
         // Vector 1: zeros with chain id
-        let hash = compute_evm_link_hash(&TEST_ADDRESS_1, &[0u8; 20], "eip155:1");
+        let hash = compute_evm_link_hash(
+            &TEST_ADDRESS_1,
+            &TEST_ADDRESS_2,
+            &[0u8; 20],
+            "eip155:1",
+            None,
+        );
         assert_eq!(hash.len(), 32);
     }
 
     #[test]
     fn test_evm_link_hash_parity_vector2() {
         // Vector 2: different chain id
-        let hash1 = compute_evm_link_hash(&TEST_ADDRESS_1, &[0u8; 20], "eip155:1");
-        let hash2 = compute_evm_link_hash(&TEST_ADDRESS_1, &[0u8; 20], "eip155:137");
+        let hash1 = compute_evm_link_hash(
+            &TEST_ADDRESS_1,
+            &TEST_ADDRESS_2,
+            &[0u8; 20],
+            "eip155:1",
+            None,
+        );
+        let hash2 = compute_evm_link_hash(
+            &TEST_ADDRESS_1,
+            &TEST_ADDRESS_2,
+            &[0u8; 20],
+            "eip155:137",
+            None,
+        );
 
         assert_eq!(hash1.len(), 32);
         assert_eq!(hash2.len(), 32);
@@ -668,13 +970,294 @@ mod tests {
     #[test]
     fn test_evm_link_hash_parity_vector3() {
         // Vector 3: different EVM address
-        let hash = compute_evm_link_hash(&TEST_ADDRESS_2, &[0xab; 20], "eip155:1");
+        let hash = compute_evm_link_hash(
+            &TEST_ADDRESS_2,
+            &TEST_ADDRESS_1,
+            &[0xab; 20],
+            "eip155:1",
+            None,
+        );
         assert_eq!(hash.len(), 32);
 
-        let hash_zeros = compute_evm_link_hash(&TEST_ADDRESS_2, &[0u8; 20], "eip155:1");
+        let hash_zeros = compute_evm_link_hash(
+            &TEST_ADDRESS_2,
+            &TEST_ADDRESS_1,
+            &[0u8; 20],
+            "eip155:1",
+            None,
+        );
         assert_ne!(hash, hash_zeros);
     }
 
+    #[test]
+    fn test_evm_link_hash_binds_holder_and_expiry() {
+        let base = compute_evm_link_hash(
+            &TEST_ADDRESS_1,
+            &TEST_ADDRESS_2,
+            &[0xab; 20],
+            "eip155:1",
+            None,
+        );
+        assert_ne!(
+            base,
+            compute_evm_link_hash(
+                &TEST_ADDRESS_1,
+                &TEST_ADDRESS_3,
+                &[0xab; 20],
+                "eip155:1",
+                None
+            )
+        );
+        assert_ne!(
+            base,
+            compute_evm_link_hash(
+                &TEST_ADDRESS_1,
+                &TEST_ADDRESS_2,
+                &[0xab; 20],
+                "eip155:1",
+                Some(1_700_000_000)
+            )
+        );
+    }
+
+    #[test]
+    fn test_normalize_recovery_id() {
+        assert_eq!(normalize_recovery_id(0).unwrap(), 0);
+        assert_eq!(normalize_recovery_id(1).unwrap(), 1);
+        assert_eq!(normalize_recovery_id(27).unwrap(), 0);
+        assert_eq!(normalize_recovery_id(28).unwrap(), 1);
+        for invalid in [2, 3, 26, 29, 35, 255] {
+            assert_eq!(
+                normalize_recovery_id(invalid).unwrap_err(),
+                SatiError::InvalidRecoveryId.into()
+            );
+        }
+    }
+
+    #[test]
+    fn test_signature_canonical_low_s() {
+        let mut signature = [0u8; 64];
+        signature[31] = 1;
+        signature[32..].copy_from_slice(&SECP256K1_HALF_N);
+        assert!(check_secp256k1_signature_canonical(&signature).is_ok());
+
+        // n/2 + 1 is the smallest high s
+        signature[63] += 1;
+        assert_eq!(
+            check_secp256k1_signature_canonical(&signature).unwrap_err(),
+            SatiError::Secp256k1HighS.into()
+        );
+    }
+
+    #[test]
+    fn test_signature_canonical_rejects_out_of_range() {
+        let mut valid = [0u8; 64];
+        valid[31] = 1;
+        valid[63] = 1;
+        assert!(check_secp256k1_signature_canonical(&valid).is_ok());
+
+        let mut zero_r = valid;
+        zero_r[31] = 0;
+        let mut zero_s = valid;
+        zero_s[63] = 0;
+        let mut r_is_n = valid;
+        r_is_n[..32].copy_from_slice(&SECP256K1_N);
+        for signature in [zero_r, zero_s, r_is_n] {
+            assert_eq!(
+                check_secp256k1_signature_canonical(&signature).unwrap_err(),
+                SatiError::InvalidSecp256k1Signature.into()
+            );
+        }
+    }
+
+    #[test]
+    fn test_eip191_hash_known_vector() {
+        // hashMessage("hello world") from ethers/viem
+        let hash = compute_eip191_hash(b"hello world");
+        assert_eq!(
+            hash.iter().map(|b| format!("{b:02x}")).collect::<String>(),
+            "d9eba16ed0ecae432b71fe008c98cc872bb4cc214d3220a36f365326cf807d68"
+        );
+    }
+
+    #[test]
+    fn test_evm_link_message_format() {
+        let message = build_evm_link_message(
+            &TEST_ADDRESS_1,
+            &TEST_ADDRESS_2,
+            &[0xab; 20],
+            "eip155:8453",
+            Some(1_767_225_600),
+        );
+        assert_eq!(
+            message,
+            format!(
+                "SATI EVM Link\n\nAgent: {}\nHolder: {}\nAddress: 0x{}\nChain: eip155:8453\nExpires: 1767225600\n\nSign to link this address to the agent.",
+                TEST_ADDRESS_1,
+                TEST_ADDRESS_2,
+                "ab".repeat(20)
+            )
+        );
+        assert!(build_evm_link_message(
+            &TEST_ADDRESS_1,
+            &TEST_ADDRESS_2,
+            &[0xab; 20],
+            "eip155:8453",
+            None
+        )
+        .contains("\nExpires: never\n"));
+    }
+
+    #[test]
+    fn test_evm_link_digest_differs_by_format() {
+        let digests = [
+            EvmLinkFormat::Raw,
+            EvmLinkFormat::Eip191,
+            EvmLinkFormat::Eip712,
+        ]
+        .map(|format| {
+            compute_evm_link_digest(
+                format,
+                &TEST_ADDRESS_1,
+                &TEST_ADDRESS_2,
+                &[0xab; 20],
+                "eip155:1",
+                None,
+            )
+        });
+        assert_ne!(digests[0], digests[1]);
+        assert_ne!(digests[0], digests[2]);
+        assert_ne!(digests[1], digests[2]);
+    }
+
+    #[test]
+    fn test_evm_link_eip712_hash_binds_fields() {
+        let hash = |mint, holder, address: [u8; 20], chain_id, expires_at| {
+            compute_evm_link_eip712_hash(mint, holder, &address, chain_id, expires_at)
+        };
+        let base = hash(
+            &TEST_ADDRESS_1,
+            &TEST_ADDRESS_2,
+            [0xab; 20],
+            "eip155:1",
+            None,
+        );
+        assert_ne!(
+            base,
+            hash(
+                &TEST_ADDRESS_3,
+                &TEST_ADDRESS_2,
+                [0xab; 20],
+                "eip155:1",
+                None
+            )
+        );
+        assert_ne!(
+            base,
+            hash(
+                &TEST_ADDRESS_1,
+                &TEST_ADDRESS_3,
+                [0xab; 20],
+                "eip155:1",
+                None
+            )
+        );
+        assert_ne!(
+            base,
+            hash(
+                &TEST_ADDRESS_1,
+                &TEST_ADDRESS_2,
+                [0xcd; 20],
+                "eip155:1",
+                None
+            )
+        );
+        assert_ne!(
+            base,
+            hash(
+                &TEST_ADDRESS_1,
+                &TEST_ADDRESS_2,
+                [0xab; 20],
+                "eip155:10",
+                None
+            )
+        );
+        assert_ne!(
+            base,
+            hash(
+                &TEST_ADDRESS_1,
+                &TEST_ADDRESS_2,
+                [0xab; 20],
+                "eip155:1",
+                Some(1)
+            )
+        );
+    }
+
+    #[test]
+    fn test_reputation_update_hash_binds_fields() {
+        let schema = Pubkey::new_unique();
+        let current = vec![1u8; 131];
+        let new = vec![2u8; 131];
+        let hash = compute_reputation_update_hash(&schema, &current, &new, 0, 0);
+
+        assert_ne!(
+            hash,
+            compute_reputation_update_hash(&Pubkey::new_unique(), &current, &new, 0, 0)
+        );
+        assert_ne!(
+            hash,
+            compute_reputation_update_hash(&schema, &new, &new, 0, 0)
+        );
+        assert_ne!(
+            hash,
+            compute_reputation_update_hash(&schema, &current, &current, 0, 0)
+        );
+        assert_ne!(
+            hash,
+            compute_reputation_update_hash(&schema, &current, &new, 2, 0),
+            "the same transition at a later revision signs a different hash"
+        );
+        assert_ne!(
+            hash,
+            compute_reputation_update_hash(&schema, &current, &new, 0, 1)
+        );
+    }
+
+    #[test]
+    fn test_reputation_batch_hash_binds_entries() {
+        let schema = Pubkey::new_unique();
+        let entries: [ReputationBatchEntry; 2] =
+            [(None, &[1u8; 131], 0), (Some(&[2u8; 131]), &[3u8; 131], 4)];
+        let hash = compute_reputation_batch_hash(&schema, &entries, 0);
+
+        assert_ne!(
+            hash,
+            compute_reputation_batch_hash(&schema, &entries[..1], 0),
+            "dropping an entry changes the hash"
+        );
+        assert_ne!(
+            hash,
+            compute_reputation_batch_hash(&schema, &[entries[1], entries[0]], 0),
+            "reordering entries changes the hash"
+        );
+        assert_ne!(
+            hash,
+            compute_reputation_batch_hash(
+                &schema,
+                &[(Some(&[0u8; 131]), entries[0].1, 0), entries[1]],
+                0
+            ),
+            "creating and rewriting sign different hashes"
+        );
+        assert_ne!(
+            hash,
+            compute_reputation_batch_hash(&schema, &[(None, entries[0].1, 1), entries[1]], 0),
+            "a recreated attestation signs a different hash"
+        );
+        assert_ne!(hash, compute_reputation_batch_hash(&schema, &entries, 1));
+    }
+
     // =========================================================================
     // Delegation Nonce Tests
     // =========================================================================
@@ -722,4 +1305,117 @@ mod tests {
         let n2 = compute_delegation_nonce(&schema, &delegate, &Pubkey::new_unique());
         assert_ne!(n1, n2, "Different agents should produce different nonces");
     }
+
+    fn parent_ata(mint: Pubkey, owner: Pubkey, amount: u64) -> TokenAccount {
+        use solana_program::program_pack::Pack;
+        let account = spl_token_2022::state::Account {
+            mint,
+            owner,
+            amount,
+            state: spl_token_2022::state::AccountState::Initialized,
+            ..Default::default()
+        };
+        let mut data = [0u8; spl_token_2022::state::Account::LEN];
+        account.pack_into_slice(&mut data);
+        TokenAccount::try_deserialize_unchecked(&mut &data[..]).unwrap()
+    }
+
+    #[test]
+    fn test_verify_parent_authorization() {
+        let child = Pubkey::new_unique();
+        let parent = Pubkey::new_unique();
+        let child_holder = Pubkey::new_unique();
+        let parent_holder = Pubkey::new_unique();
+        let link = AgentParent {
+            mint: child,
+            parent,
+            holder: child_holder,
+            linked_at: 0,
+            bump: 0,
+        };
+        let ata = parent_ata(parent, parent_holder, 1);
+
+        assert!(
+            verify_parent_authorization(&parent_holder, &child, &child_holder, &link, &ata).is_ok()
+        );
+
+        // Link for a different child
+        assert_eq!(
+            verify_parent_authorization(
+                &parent_holder,
+                &Pubkey::new_unique(),
+                &child_holder,
+                &link,
+                &ata
+            )
+            .unwrap_err(),
+            SatiError::InvalidParentLink.into()
+        );
+
+        // Child transferred since the link was made
+        assert_eq!(
+            verify_parent_authorization(&parent_holder, &child, &Pubkey::new_unique(), &link, &ata)
+                .unwrap_err(),
+            SatiError::ParentLinkStale.into()
+        );
+
+        // Signer does not hold the parent: wrong owner, wrong mint, empty account
+        for ata in [
+            parent_ata(parent, Pubkey::new_unique(), 1),
+            parent_ata(Pubkey::new_unique(), parent_holder, 1),
+            parent_ata(parent, parent_holder, 0),
+        ] {
+            assert_eq!(
+                verify_parent_authorization(&parent_holder, &child, &child_holder, &link, &ata)
+                    .unwrap_err(),
+                SatiError::ParentHolderMismatch.into()
+            );
+        }
+    }
+
+    #[test]
+    fn test_agent_authorization_parent_path() {
+        let child = Pubkey::new_unique();
+        let parent = Pubkey::new_unique();
+        let child_holder = Pubkey::new_unique();
+        let parent_holder = Pubkey::new_unique();
+        let delegation_schema = Pubkey::new_unique();
+        let link = AgentParent {
+            mint: child,
+            parent,
+            holder: child_holder,
+            linked_at: 0,
+            bump: 0,
+        };
+        let ata = parent_ata(parent, parent_holder, 1);
+
+        // Parent holder signs like a delegate, without a delegation attestation
+        assert!(verify_agent_authorization(
+            &parent_holder,
+            &child,
+            &child_holder,
+            Some(&delegation_schema),
+            None,
+            &Pubkey::default(),
+            Some((&link, &ata)),
+            &Clock::default(),
+        )
+        .is_ok());
+
+        // Owner-only schemas (e.g. DelegateV1) stay owner-only
+        assert_eq!(
+            verify_agent_authorization(
+                &parent_holder,
+                &child,
+                &child_holder,
+                None,
+                None,
+                &Pubkey::default(),
+                Some((&link, &ata)),
+                &Clock::default(),
+            )
+            .unwrap_err(),
+            SatiError::OwnerOnly.into()
+        );
+    }
 }
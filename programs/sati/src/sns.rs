@@ -0,0 +1,115 @@
+//! Solana Name Service (.sol) name registry lookups for `link_sns_domain`.
+//!
+//! A `.sol` domain `<label>.sol` lives in a name registry account owned by the
+//! SPL Name Service program at
+//! `PDA([sha256("SPL Name Service" || label), class, parent], name_program)`
+//! with a zero class and the `.sol` TLD account as parent. The account starts
+//! with a 96-byte header: parent_name (32) + owner (32) + class (32).
+//!
+//! Only direct owners are recognized: tokenized domains (owned by the SNS
+//! tokenizer) and subdomains are not supported.
+
+use anchor_lang::prelude::*;
+use solana_program::hash::hashv;
+
+use crate::constants::MAX_SNS_DOMAIN_LENGTH;
+use crate::errors::SatiError;
+
+/// SPL Name Service program
+pub const NAME_SERVICE_PROGRAM_ID: Pubkey = pubkey!("namesLPneVptA9Z5rqUDD9tMTWEJwofgaYwp8cawRkX");
+
+/// `.sol` TLD name account (parent of every second-level .sol domain)
+pub const SOL_TLD_AUTHORITY: Pubkey = pubkey!("58PwtjSDuFHuUkYjH9BYnnQKHfwo9reZhC2zMJv9JPkx");
+
+/// Prefix hashed with a name to derive its registry address
+pub const NAME_HASH_PREFIX: &[u8] = b"SPL Name Service";
+
+/// Name registry header: parent_name (32) + owner (32) + class (32)
+pub const NAME_REGISTRY_HEADER_SIZE: usize = 96;
+
+/// Validate a .sol domain label (without the `.sol` suffix).
+pub fn validate_sns_domain(domain: &str) -> Result<()> {
+    require!(
+        !domain.is_empty()
+            && domain.len() <= MAX_SNS_DOMAIN_LENGTH
+            && !domain.contains('.')
+            && !domain.chars().any(char::is_whitespace),
+        SatiError::InvalidSnsDomain
+    );
+    Ok(())
+}
+
+/// Derive the name registry address of `<domain>.sol`.
+pub fn sol_domain_name_account(domain: &str) -> Pubkey {
+    let hashed_name = hashv(&[NAME_HASH_PREFIX, domain.as_bytes()]);
+    Pubkey::find_program_address(
+        &[
+            hashed_name.as_ref(),
+            &[0u8; 32], // no name class
+            SOL_TLD_AUTHORITY.as_ref(),
+        ],
+        &NAME_SERVICE_PROGRAM_ID,
+    )
+    .0
+}
+
+/// Parse a .sol name registry header and return the domain owner.
+pub fn parse_sol_domain_owner(data: &[u8]) -> Result<Pubkey> {
+    require!(
+        data.len() >= NAME_REGISTRY_HEADER_SIZE,
+        SatiError::InvalidNameRegistry
+    );
+    let parent = Pubkey::try_from(&data[0..32]).map_err(|_| SatiError::InvalidNameRegistry)?;
+    require!(parent == SOL_TLD_AUTHORITY, SatiError::InvalidNameRegistry);
+    Pubkey::try_from(&data[32..64]).map_err(|_| error!(SatiError::InvalidNameRegistry))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sol_domain_name_account() {
+        // bonfida.sol
+        assert_eq!(
+            sol_domain_name_account("bonfida"),
+            pubkey!("Crf8hzfthWGbGbLTVCiqRqV5MVnbpHB1L9KQMd6gsinb")
+        );
+    }
+
+    #[test]
+    fn test_validate_sns_domain() {
+        assert!(validate_sns_domain("bonfida").is_ok());
+        assert!(validate_sns_domain("agent-007").is_ok());
+        for domain in ["", "bonfida.sol", "sub.bonfida", "my agent"] {
+            assert_eq!(
+                validate_sns_domain(domain).unwrap_err(),
+                SatiError::InvalidSnsDomain.into(),
+                "{domain:?}"
+            );
+        }
+        assert!(validate_sns_domain(&"a".repeat(MAX_SNS_DOMAIN_LENGTH + 1)).is_err());
+    }
+
+    #[test]
+    fn test_parse_sol_domain_owner() {
+        let owner = Pubkey::new_unique();
+        let mut data = vec![0u8; NAME_REGISTRY_HEADER_SIZE + 8];
+        data[0..32].copy_from_slice(SOL_TLD_AUTHORITY.as_ref());
+        data[32..64].copy_from_slice(owner.as_ref());
+        assert_eq!(parse_sol_domain_owner(&data).unwrap(), owner);
+
+        // Truncated header
+        assert_eq!(
+            parse_sol_domain_owner(&data[..95]).unwrap_err(),
+            SatiError::InvalidNameRegistry.into()
+        );
+
+        // Not a .sol domain (e.g. a subdomain or another TLD)
+        data[0..32].copy_from_slice(Pubkey::new_unique().as_ref());
+        assert_eq!(
+            parse_sol_domain_owner(&data).unwrap_err(),
+            SatiError::InvalidNameRegistry.into()
+        );
+    }
+}
@@ -4,6 +4,11 @@ use light_sdk::instruction::{
     account_meta::CompressedAccountMeta, PackedAddressTreeInfo, ValidityProof,
 };
 use light_sdk::{LightDiscriminator, LightHasher};
+use sha3::{Digest, Keccak256};
+
+use crate::caip2::{APTOS_NAMESPACE, BIP122_NAMESPACE, COSMOS_NAMESPACE, SUI_NAMESPACE};
+use crate::constants::{MAX_CHAIN_ID_LENGTH, MAX_HANDLE_LENGTH, MAX_SNS_DOMAIN_LENGTH};
+use crate::errors::SatiError;
 
 // ============================================================================
 // Registry State
@@ -33,16 +38,121 @@ pub struct RegistryConfig {
 
     /// PDA bump seed (stored for efficient CPI signing)
     pub bump: u8,
+
+    /// Account layout version (see migrate_registry_config)
+    pub version: u8,
+
+    /// Proposed next authority awaiting accept_registry_authority
+    /// Pubkey::default() = no transfer pending
+    pub pending_authority: Pubkey,
+
+    /// Minimum delay (seconds) between queueing and executing governance actions
+    /// 0 = timelock disabled (authority actions take effect immediately)
+    pub timelock_delay: i64,
+
+    /// Next PendingAction id (counter, used as PDA seed)
+    pub next_action_id: u64,
+
+    /// Pause bitfield (see PAUSE_* constants). 0 = fully operational.
+    pub paused: u8,
+
+    /// Mint the registration fee is paid in
+    /// Pubkey::default() = lamports
+    pub fee_mint: Pubkey,
+
+    /// Registration fee per agent (lamports or fee_mint base units). 0 = no fee.
+    pub fee_amount: u64,
 }
 
 impl RegistryConfig {
     /// Account discriminator (8) + group_mint (32) + authority (32) + total_agents (8) + bump (1)
-    pub const SIZE: usize = 8 + 32 + 32 + 8 + 1; // 81 bytes
+    /// + version (1) + pending_authority (32) + timelock_delay (8) + next_action_id (8) + paused (1)
+    /// + fee_mint (32) + fee_amount (8)
+    pub const SIZE: usize = 8 + 32 + 32 + 8 + 1 + 1 + 32 + 8 + 8 + 1 + 32 + 8; // 171 bytes
+
+    /// Size of version 0 accounts (before version + pending_authority were added)
+    pub const LEGACY_SIZE: usize = 8 + 32 + 32 + 8 + 1; // 81 bytes
+
+    /// Current account layout version
+    /// - 1: pending_authority
+    /// - 2: timelock_delay, next_action_id
+    /// - 3: paused
+    /// - 4: fee_mint, fee_amount
+    pub const CURRENT_VERSION: u8 = 4;
 
     /// Check if registry is immutable (authority renounced)
     pub fn is_immutable(&self) -> bool {
         self.authority == Pubkey::default()
     }
+
+    /// Check if an authority transfer is awaiting acceptance
+    pub fn has_pending_authority(&self) -> bool {
+        self.pending_authority != Pubkey::default()
+    }
+
+    /// Check if authority actions must go through queue_governance_action
+    pub fn is_timelocked(&self) -> bool {
+        self.timelock_delay > 0
+    }
+
+    /// Check if any of the given PAUSE_* flags are set
+    pub fn is_paused(&self, flags: u8) -> bool {
+        self.paused & flags != 0
+    }
+
+    /// Registration fee mint, or None when the fee is paid in lamports
+    pub fn fee_mint(&self) -> Option<Pubkey> {
+        (self.fee_mint != Pubkey::default()).then_some(self.fee_mint)
+    }
+}
+
+/// Governance action that can be queued behind the registry timelock.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, InitSpace)]
+pub enum GovernanceAction {
+    /// Same arguments as register_schema_config
+    RegisterSchemaConfig {
+        sas_schema: Pubkey,
+        signature_mode: SignatureMode,
+        storage_type: StorageType,
+        delegation_schema: Option<Pubkey>,
+        closeable: bool,
+        #[max_len(32)]
+        name: String,
+    },
+    /// Same as propose_registry_authority
+    ProposeAuthority { new_authority: Pubkey },
+    /// Same as update_registry_authority(None)
+    RenounceAuthority,
+    /// Change RegistryConfig.timelock_delay
+    SetTimelockDelay { delay: i64 },
+    /// Same arguments as set_registration_fee
+    SetRegistrationFee {
+        fee_mint: Option<Pubkey>,
+        amount: u64,
+    },
+    /// Same arguments as set_schema_min_verification
+    SetSchemaMinVerification { sas_schema: Pubkey, min_level: u8 },
+}
+
+/// Governance action waiting for its timelock to elapse.
+/// PDA seeds: ["pending_action", action_id.to_le_bytes()]
+#[account]
+#[derive(InitSpace)]
+pub struct PendingAction {
+    /// Sequential id (RegistryConfig.next_action_id at queue time)
+    pub action_id: u64,
+    /// Registry authority that queued the action (must still be authority at execution)
+    pub authority: Pubkey,
+    /// Receives the rent when the action is executed or cancelled
+    pub rent_recipient: Pubkey,
+    /// Action to perform
+    pub action: GovernanceAction,
+    /// Unix timestamp when queued
+    pub queued_at: i64,
+    /// Earliest unix timestamp at which anyone can execute
+    pub execute_after: i64,
+    /// PDA bump seed
+    pub bump: u8,
 }
 
 /// Agent index for enumeration via member_number.
@@ -61,6 +171,389 @@ impl AgentIndex {
     pub const SIZE: usize = 8 + 32 + 1;
 }
 
+/// Agent lifecycle status
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AgentStatus {
+    /// Registered and accepting attestations
+    Active,
+    /// NFT burned via retire_agent (terminal)
+    Retired,
+    /// Marked end-of-life by the holder; still accepts attestations
+    Deprecated,
+    /// Suspended by the registry authority; rejects new attestations
+    Suspended,
+}
+
+/// Per-mint agent record (reverse lookup mint -> member_number).
+/// Existence proves the mint is a SATI agent.
+/// PDA seeds: [b"agent", mint]
+#[account]
+pub struct AgentRecord {
+    /// Agent mint address
+    pub mint: Pubkey,
+    /// TokenGroupMember number (also the AgentIndex seed)
+    pub member_number: u64,
+    /// Slot at registration (0 if backfilled for a legacy agent)
+    pub registered_slot: u64,
+    /// Unix timestamp at registration (0 if backfilled for a legacy agent)
+    pub registered_at: i64,
+    /// Whether the NFT has the NonTransferable extension
+    pub non_transferable: bool,
+    /// Lifecycle status
+    pub status: AgentStatus,
+    /// PDA bump seed
+    pub bump: u8,
+    /// Status to restore on unsuspend (only meaningful while Suspended)
+    pub pre_suspend_status: AgentStatus,
+}
+
+impl AgentRecord {
+    /// Account discriminator (8) + mint (32) + member_number (8) + registered_slot (8)
+    /// + registered_at (8) + non_transferable (1) + status (1) + bump (1) + pre_suspend_status (1)
+    pub const SIZE: usize = 8 + 32 + 8 + 8 + 8 + 1 + 1 + 1 + 1; // 68 bytes
+
+    /// Check if the agent has been retired
+    pub fn is_retired(&self) -> bool {
+        self.status == AgentStatus::Retired
+    }
+
+    /// Check if the agent is suspended by the registry authority
+    pub fn is_suspended(&self) -> bool {
+        self.status == AgentStatus::Suspended
+    }
+
+    /// Load the AgentRecord for `mint` from an unchecked account and require the agent
+    /// to accept attestations (Active or Deprecated). Only SATI creates AgentRecord accounts (always at `["agent", mint]`),
+    /// so program ownership plus a matching `mint` field proves registry membership.
+    pub fn load_active(info: &AccountInfo, mint: &Pubkey) -> Result<AgentRecord> {
+        require!(
+            info.owner == &crate::ID && !info.data_is_empty(),
+            SatiError::AgentNotRegistered
+        );
+        let record = AgentRecord::try_deserialize(&mut &info.try_borrow_data()?[..])
+            .map_err(|_| SatiError::AgentNotRegistered)?;
+        require!(record.mint == *mint, SatiError::AgentMintAccountMismatch);
+        require!(!record.is_retired(), SatiError::AgentRetired);
+        require!(!record.is_suspended(), SatiError::AgentSuspended);
+        Ok(record)
+    }
+}
+
+/// One-way identity migration link (old mint -> successor mint).
+/// Permanent once set; the successor must not have a successor itself, so chains never cycle.
+/// PDA seeds: [b"successor", mint]
+#[account]
+pub struct AgentSuccessor {
+    /// Previous agent mint
+    pub mint: Pubkey,
+    /// Agent mint that inherits this agent's history
+    pub successor: Pubkey,
+    /// Unix timestamp when linked
+    pub linked_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl AgentSuccessor {
+    /// Account discriminator (8) + mint (32) + successor (32) + linked_at (8) + bump (1)
+    pub const SIZE: usize = 8 + 32 + 32 + 8 + 1; // 81 bytes
+}
+
+/// Agent hierarchy link (child mint -> parent mint).
+/// While `holder` still holds the child agent, the parent agent's holder is an
+/// authorized signer for the child in `verify_agent_authorization`.
+/// PDA seeds: [b"parent", mint]
+#[account]
+pub struct AgentParent {
+    /// Child agent mint
+    pub mint: Pubkey,
+    /// Parent agent mint
+    pub parent: Pubkey,
+    /// Child holder that co-signed the link
+    pub holder: Pubkey,
+    /// Unix timestamp when linked
+    pub linked_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl AgentParent {
+    /// Account discriminator (8) + mint (32) + parent (32) + holder (32) + linked_at (8) + bump (1)
+    pub const SIZE: usize = 8 + 32 + 32 + 32 + 8 + 1; // 113 bytes
+}
+
+/// Message format the EVM wallet signed when linking an address
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum EvmLinkFormat {
+    /// Raw keccak256 digest (eth_sign / programmatic signers)
+    Raw,
+    /// EIP-191 personal message (personal_sign)
+    Eip191,
+    /// EIP-712 typed data (eth_signTypedData_v4)
+    Eip712,
+}
+
+/// Persistent proof that the holder of `agent_mint` controls an EVM address on a chain.
+/// Only valid while `holder` still holds the agent NFT and before `expires_at`.
+/// PDA seeds: [b"evm_link", agent_mint, EvmLink::chain_seed(chain_id), evm_address]
+#[account]
+pub struct EvmLink {
+    /// Agent mint address
+    pub agent_mint: Pubkey,
+    /// EVM address (20 bytes)
+    pub evm_address: [u8; 20],
+    /// CAIP-2 chain identifier (max MAX_CHAIN_ID_LENGTH)
+    pub chain_id: String,
+    /// Holder that created the link (refunded on unlink)
+    pub holder: Pubkey,
+    /// Unix timestamp when linked
+    pub linked_at: i64,
+    /// Unix timestamp after which the link is void (None = no expiry)
+    pub expires_at: Option<i64>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl EvmLink {
+    /// Account discriminator (8) + agent_mint (32) + evm_address (20) + chain_id (4 + 41)
+    /// + holder (32) + linked_at (8) + expires_at (1 + 8) + bump (1)
+    pub const SIZE: usize = 8 + 32 + 20 + (4 + MAX_CHAIN_ID_LENGTH) + 32 + 8 + (1 + 8) + 1; // 155 bytes
+
+    /// Fixed-size PDA seed for a chain identifier (CAIP-2 ids can exceed 32 bytes)
+    pub fn chain_seed(chain_id: &str) -> [u8; 32] {
+        Keccak256::digest(chain_id.as_bytes()).into()
+    }
+
+    /// True once `expires_at` has passed
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expires_at.is_some_and(|expires_at| now >= expires_at)
+    }
+}
+
+/// Proof that the holder of `agent_mint` owned a `.sol` domain when linking.
+/// Only valid while `holder` still holds the agent NFT and owns the domain;
+/// closed with unlink_sns_domain.
+/// PDA seeds: [b"sns_link", agent_mint, name_account]
+#[account]
+pub struct SnsLink {
+    /// Agent mint address
+    pub agent_mint: Pubkey,
+    /// SNS name registry account of the domain
+    pub name_account: Pubkey,
+    /// Domain label without ".sol" (max MAX_SNS_DOMAIN_LENGTH)
+    pub domain: String,
+    /// Holder that created the link (agent holder and domain owner at link time)
+    pub holder: Pubkey,
+    /// Unix timestamp when linked
+    pub linked_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl SnsLink {
+    /// Account discriminator (8) + agent_mint (32) + name_account (32) + domain (4 + 63)
+    /// + holder (32) + linked_at (8) + bump (1)
+    pub const SIZE: usize = 8 + 32 + 32 + (4 + MAX_SNS_DOMAIN_LENGTH) + 32 + 8 + 1; // 180 bytes
+}
+
+/// Proof of control over a non-EVM key, one variant per supported key type.
+/// Every variant signs the link hash for (agent_mint, chain_id) under its own domain.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum ExternalIdentityProof {
+    /// Sui ed25519 account; signature in an Ed25519 precompile instruction
+    SuiEd25519 { public_key: [u8; 32] },
+    /// Aptos ed25519 account; signature in an Ed25519 precompile instruction
+    AptosEd25519 { public_key: [u8; 32] },
+    /// Cosmos ed25519 account; signature in an Ed25519 precompile instruction
+    CosmosEd25519 { public_key: [u8; 32] },
+    /// WebAuthn passkey (compressed secp256r1 key); signature in a secp256r1
+    /// precompile instruction over `authenticator_data || sha256(client_data_json)`
+    Secp256r1Passkey {
+        public_key: [u8; 33],
+        authenticator_data: Vec<u8>,
+        client_data_json: Vec<u8>,
+    },
+    /// Bitcoin BIP-137 `signmessage` signature (header || r || s) and the
+    /// 20-byte pubkey/script hash of the address it claims
+    BitcoinBip137 {
+        signature: [u8; 65],
+        address_hash: [u8; 20],
+    },
+}
+
+impl ExternalIdentityProof {
+    /// CAIP-2 namespace the link's chain_id must use (None = any chain)
+    pub fn chain_namespace(&self) -> Option<&'static str> {
+        match self {
+            Self::SuiEd25519 { .. } => Some(SUI_NAMESPACE),
+            Self::AptosEd25519 { .. } => Some(APTOS_NAMESPACE),
+            Self::CosmosEd25519 { .. } => Some(COSMOS_NAMESPACE),
+            Self::Secp256r1Passkey { .. } => None,
+            Self::BitcoinBip137 { .. } => Some(BIP122_NAMESPACE),
+        }
+    }
+}
+
+/// Key type and address derivation of a linked external identity
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ExternalIdentityType {
+    /// blake2b256(0x00 || public_key)
+    SuiEd25519,
+    /// sha3_256(public_key || 0x00)
+    AptosEd25519,
+    /// sha256(public_key)[..20]
+    CosmosEd25519,
+    /// Compressed secp256r1 public key
+    Secp256r1Passkey,
+    /// hash160(public_key), uncompressed or compressed (P2PKH)
+    BitcoinP2pkh,
+    /// hash160(0x00 0x14 || hash160(compressed key)) (P2SH-P2WPKH)
+    BitcoinP2shP2wpkh,
+    /// hash160(compressed key) (native segwit P2WPKH)
+    BitcoinP2wpkh,
+}
+
+/// Callable service endpoint (mirrors ERC-8004 registration file `endpoints[]`)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ServiceEntry {
+    /// Protocol name, e.g. "A2A", "MCP", "x402", "DID" (max 32 bytes)
+    pub protocol: String,
+    /// Endpoint URL or identifier (max 200 bytes)
+    pub endpoint: String,
+    /// Protocol version (empty = unspecified, max 32 bytes)
+    pub version: String,
+}
+
+/// Holder-maintained list of service endpoints.
+/// Sized to its contents and resized on every update.
+/// PDA seeds: [b"services", mint]
+#[account]
+pub struct AgentServices {
+    /// Agent mint address
+    pub mint: Pubkey,
+    /// Service endpoints (max MAX_SERVICES)
+    pub services: Vec<ServiceEntry>,
+    /// Unix timestamp of the last update
+    pub updated_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl AgentServices {
+    /// Account discriminator (8) + mint (32) + services (4 + entries) + updated_at (8) + bump (1)
+    pub fn space(services: &[ServiceEntry]) -> usize {
+        let entries: usize = services
+            .iter()
+            .map(|s| 4 + s.protocol.len() + 4 + s.endpoint.len() + 4 + s.version.len())
+            .sum();
+        8 + 32 + 4 + entries + 8 + 1
+    }
+}
+
+/// Unique agent handle (handle -> mint lookup).
+/// PDA seeds: [b"handle", handle_seed(handle)] (see crate::handle)
+#[account]
+pub struct AgentHandle {
+    /// Agent mint address
+    pub mint: Pubkey,
+    /// Normalized handle as claimed (lowercase, before confusable folding)
+    pub handle: String,
+    /// Receives the rent of both handle accounts on release
+    pub rent_recipient: Pubkey,
+    /// Unix timestamp when claimed
+    pub claimed_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl AgentHandle {
+    /// Account discriminator (8) + mint (32) + handle (4 + 32) + rent_recipient (32)
+    /// + claimed_at (8) + bump (1)
+    pub const SIZE: usize = 8 + 32 + (4 + MAX_HANDLE_LENGTH) + 32 + 8 + 1; // 117 bytes
+}
+
+/// Registry-issued verification badge (KYC'd operator, audited code, ...).
+/// PDA seeds: [b"verification", mint]
+#[account]
+pub struct AgentVerification {
+    /// Agent mint address
+    pub mint: Pubkey,
+    /// Verification level (1-255, meaning defined by the registry operator)
+    pub level: u8,
+    /// Registry authority that issued the badge
+    pub issuer: Pubkey,
+    /// Unix timestamp of the last update
+    pub verified_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl AgentVerification {
+    /// Account discriminator (8) + mint (32) + level (1) + issuer (32) + verified_at (8) + bump (1)
+    pub const SIZE: usize = 8 + 32 + 1 + 32 + 8 + 1; // 82 bytes
+
+    /// Require `mint` to hold a badge of at least `min_level` (no-op when `min_level` is 0).
+    /// Only SATI creates AgentVerification accounts (always at `["verification", mint]`),
+    /// so program ownership plus a matching `mint` field proves the badge.
+    pub fn require_level(info: Option<&AccountInfo>, mint: &Pubkey, min_level: u8) -> Result<()> {
+        if min_level == 0 {
+            return Ok(());
+        }
+        let info = info.ok_or(SatiError::AgentNotVerified)?;
+        require!(
+            info.owner == &crate::ID && !info.data_is_empty(),
+            SatiError::AgentNotVerified
+        );
+        let verification = AgentVerification::try_deserialize(&mut &info.try_borrow_data()?[..])
+            .map_err(|_| SatiError::AgentNotVerified)?;
+        require!(
+            verification.mint == *mint && verification.level >= min_level,
+            SatiError::AgentNotVerified
+        );
+        Ok(())
+    }
+}
+
+/// Rewrite counter for a regular (SAS) attestation. SAS attestations have no
+/// revision of their own, so SATI tracks it beside them; the counter outlives
+/// close and recreate, which lets batch signatures use it as a replay nonce.
+/// PDA seeds: [b"attestation_revision", attestation]
+#[account]
+pub struct AttestationRevision {
+    /// Number of rewrites and batch writes (0 = never written by either)
+    pub revision: u32,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl AttestationRevision {
+    /// Account discriminator (8) + revision (4) + bump (1)
+    pub const SIZE: usize = 8 + 4 + 1; // 13 bytes
+
+    /// Current revision stored at `info` (0 before the first rewrite).
+    pub fn load(info: &AccountInfo) -> Result<u32> {
+        if info.owner != &crate::ID {
+            return Ok(0);
+        }
+        Ok(AttestationRevision::try_deserialize(&mut &info.try_borrow_data()?[..])?.revision)
+    }
+}
+
+/// Reverse handle lookup (mint -> AgentHandle). Limits each agent to one handle.
+/// PDA seeds: [b"agent_handle", mint]
+#[account]
+pub struct HandleClaim {
+    /// AgentHandle account address
+    pub agent_handle: Pubkey,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl HandleClaim {
+    /// Account discriminator (8) + agent_handle (32) + bump (1) = 41 bytes
+    pub const SIZE: usize = 8 + 32 + 1;
+}
+
 // ============================================================================
 // Attestation State
 // ============================================================================
@@ -110,17 +603,24 @@ pub struct SchemaConfig {
     pub name: String,
     /// PDA bump seed
     pub bump: u8,
+    /// Minimum AgentVerification level required of attested agents (0 = none)
+    pub min_verification_level: u8,
 }
 
-// Account size: 8 (discriminator) + 32 + 1 + 1 + 1 + 32 + 1 + 4 + 32 + 1 = 113 bytes (with Option overhead)
+// Account size: 8 (discriminator) + 32 + 1 + 1 + 1 + 32 + 1 + 4 + 32 + 1 + 1 = 114 bytes (with Option overhead)
+//
+// min_verification_level was appended later. Borsh is compact, so older 113-byte accounts
+// still deserialize (reading 0) unless the name and delegation_schema fill every byte;
+// those need migrate_schema_config first.
 
 /// Compressed attestation stored via Light Protocol.
 ///
-/// The account hash is computed by Light SDK using the LightHasher trait.
-/// Fields marked with `#[hash]` are included in the Poseidon hash for account verification.
+/// The account hash is computed by Light SDK as SHA-256 over the Borsh bytes below.
+/// `revision` is only serialized once non-zero, so attestations created before it
+/// existed keep their original bytes and hash.
 ///
 /// Schema type is determined solely by `sas_schema` field - no separate data_type discriminator.
-#[derive(Clone, Debug, LightDiscriminator, LightHasher, BorshSerialize, BorshDeserialize)]
+#[derive(Clone, Debug, LightDiscriminator, LightHasher)]
 pub struct CompressedAttestation {
     /// SAS schema address (indexed via memcmp at offset 8).
     /// Determines attestation type (Feedback, Validation, etc.)
@@ -147,6 +647,10 @@ pub struct CompressedAttestation {
     /// Second signature (counterparty for DualSignature, zeroed for single-signature modes)
     #[hash]
     pub signature2: [u8; 64],
+    /// Number of amendments (0 = as created). The counterparty signature is
+    /// over the amend message for this revision once it is non-zero.
+    #[hash]
+    pub revision: u32,
 }
 
 impl Default for CompressedAttestation {
@@ -158,7 +662,53 @@ impl Default for CompressedAttestation {
             num_signatures: 0,
             signature1: [0u8; 64],
             signature2: [0u8; 64],
+            revision: 0,
+        }
+    }
+}
+
+impl BorshSerialize for CompressedAttestation {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.sas_schema.serialize(writer)?;
+        self.token_account.serialize(writer)?;
+        self.data.serialize(writer)?;
+        self.num_signatures.serialize(writer)?;
+        self.signature1.serialize(writer)?;
+        self.signature2.serialize(writer)?;
+        // Unamended attestations keep the pre-revision layout (and hash)
+        if self.revision != 0 {
+            self.revision.serialize(writer)?;
         }
+        Ok(())
+    }
+}
+
+impl BorshDeserialize for CompressedAttestation {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let sas_schema = <[u8; 32]>::deserialize_reader(reader)?;
+        let token_account = <[u8; 32]>::deserialize_reader(reader)?;
+        let data = Vec::<u8>::deserialize_reader(reader)?;
+        let num_signatures = u8::deserialize_reader(reader)?;
+        let signature1 = <[u8; 64]>::deserialize_reader(reader)?;
+        let signature2 = <[u8; 64]>::deserialize_reader(reader)?;
+        // Absent in the pre-revision layout
+        let mut revision_bytes = [0u8; 4];
+        let revision = match reader.read(&mut revision_bytes[..1])? {
+            0 => 0,
+            _ => {
+                reader.read_exact(&mut revision_bytes[1..])?;
+                u32::from_le_bytes(revision_bytes)
+            }
+        };
+        Ok(Self {
+            sas_schema,
+            token_account,
+            data,
+            num_signatures,
+            signature1,
+            signature2,
+            revision,
+        })
     }
 }
 
@@ -188,6 +738,26 @@ pub struct CreateRegularParams {
     pub expiry: i64,
 }
 
+/// Parameters for rewriting a regular (SAS) attestation
+/// Used for ReputationScore schemas (CounterpartySigned mode)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct UpdateRegularParams {
+    /// Replacement data bytes; token_account and counterparty must be unchanged
+    pub data: Vec<u8>,
+    /// Expiry timestamp (0 = never expires)
+    pub expiry: i64,
+}
+
+/// Parameters for writing several regular (SAS) attestations for one provider
+/// Used for ReputationScore schemas (CounterpartySigned mode)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct UpdateRegularBatchParams {
+    /// Data bytes per attestation, in remaining_accounts order
+    pub entries: Vec<Vec<u8>>,
+    /// Expiry timestamp for every entry (0 = never expires)
+    pub expiry: i64,
+}
+
 /// Parameters for closing a compressed attestation
 ///
 /// Uses Light Protocol types directly for proof and account metadata,
@@ -208,6 +778,31 @@ pub struct CloseParams {
     pub proof: ValidityProof,
     /// Light Protocol compressed account metadata
     pub account_meta: CompressedAccountMeta,
+    /// Current revision of the attestation (None or 0 = never amended)
+    pub revision: Option<u32>,
+}
+
+/// Parameters for amending a compressed attestation
+///
+/// The current_* fields reproduce the stored account for hash verification.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct UpdateParams {
+    /// Current attestation data (for hash verification)
+    pub current_data: Vec<u8>,
+    /// Number of signatures in the attestation
+    pub num_signatures: u8,
+    /// First signature (required)
+    pub signature1: [u8; 64],
+    /// Second signature (zeroed for single-signature modes)
+    pub signature2: [u8; 64],
+    /// Current revision of the attestation
+    pub revision: u32,
+    /// Amended attestation data; only outcome and content may change
+    pub new_data: Vec<u8>,
+    /// Light Protocol validity proof
+    pub proof: ValidityProof,
+    /// Light Protocol compressed account metadata
+    pub account_meta: CompressedAccountMeta,
 }
 
 // ============================================================================
@@ -221,8 +816,29 @@ mod tests {
     #[test]
     fn test_registry_config_size() {
         // Verify SIZE constant matches actual serialized size
-        // 8 (discriminator) + 32 (group_mint) + 32 (authority) + 8 (total_agents) + 1 (bump) = 81
-        assert_eq!(RegistryConfig::SIZE, 81);
+        // 8 (discriminator) + 32 (group_mint) + 32 (authority) + 8 (total_agents) + 1 (bump)
+        // + 1 (version) + 32 (pending_authority) + 8 (timelock_delay) + 8 (next_action_id)
+        // + 1 (paused) + 32 (fee_mint) + 8 (fee_amount) = 171
+        assert_eq!(RegistryConfig::SIZE, 171);
+        assert_eq!(RegistryConfig::LEGACY_SIZE, 81);
+
+        let config = RegistryConfig {
+            group_mint: Pubkey::new_unique(),
+            authority: Pubkey::new_unique(),
+            total_agents: 0,
+            bump: 255,
+            version: RegistryConfig::CURRENT_VERSION,
+            pending_authority: Pubkey::new_unique(),
+            timelock_delay: 0,
+            next_action_id: 0,
+            paused: 0,
+            fee_mint: Pubkey::default(),
+            fee_amount: 0,
+        };
+        assert_eq!(
+            borsh::to_vec(&config).unwrap().len() + 8,
+            RegistryConfig::SIZE
+        );
     }
 
     #[test]
@@ -232,6 +848,13 @@ mod tests {
             authority: Pubkey::new_unique(),
             total_agents: 0,
             bump: 255,
+            version: RegistryConfig::CURRENT_VERSION,
+            pending_authority: Pubkey::default(),
+            timelock_delay: 0,
+            next_action_id: 0,
+            paused: 0,
+            fee_mint: Pubkey::default(),
+            fee_amount: 0,
         };
 
         // Non-default authority = mutable
@@ -280,6 +903,216 @@ mod tests {
         assert_eq!(AgentIndex::SIZE, 41);
     }
 
+    #[test]
+    fn test_agent_record_size() {
+        let record = AgentRecord {
+            mint: Pubkey::default(),
+            member_number: 0,
+            registered_slot: 0,
+            registered_at: 0,
+            non_transferable: false,
+            status: AgentStatus::Active,
+            bump: 0,
+            pre_suspend_status: AgentStatus::Active,
+        };
+        assert_eq!(borsh::to_vec(&record).unwrap().len() + 8, AgentRecord::SIZE);
+        assert!(!record.is_retired());
+    }
+
+    #[test]
+    fn test_agent_handle_size() {
+        let handle = AgentHandle {
+            mint: Pubkey::default(),
+            handle: "h".repeat(MAX_HANDLE_LENGTH),
+            rent_recipient: Pubkey::default(),
+            claimed_at: 0,
+            bump: 0,
+        };
+        assert_eq!(borsh::to_vec(&handle).unwrap().len() + 8, AgentHandle::SIZE);
+
+        let claim = HandleClaim {
+            agent_handle: Pubkey::default(),
+            bump: 0,
+        };
+        assert_eq!(borsh::to_vec(&claim).unwrap().len() + 8, HandleClaim::SIZE);
+    }
+
+    #[test]
+    fn test_agent_successor_size() {
+        let successor = AgentSuccessor {
+            mint: Pubkey::default(),
+            successor: Pubkey::default(),
+            linked_at: 0,
+            bump: 0,
+        };
+        assert_eq!(
+            borsh::to_vec(&successor).unwrap().len() + 8,
+            AgentSuccessor::SIZE
+        );
+    }
+
+    #[test]
+    fn test_agent_parent_size() {
+        let parent = AgentParent {
+            mint: Pubkey::default(),
+            parent: Pubkey::default(),
+            holder: Pubkey::default(),
+            linked_at: 0,
+            bump: 0,
+        };
+        assert_eq!(borsh::to_vec(&parent).unwrap().len() + 8, AgentParent::SIZE);
+    }
+
+    #[test]
+    fn test_evm_link_size() {
+        let link = EvmLink {
+            agent_mint: Pubkey::default(),
+            evm_address: [0; 20],
+            chain_id: "c".repeat(MAX_CHAIN_ID_LENGTH),
+            holder: Pubkey::default(),
+            linked_at: 0,
+            expires_at: Some(0),
+            bump: 0,
+        };
+        assert_eq!(borsh::to_vec(&link).unwrap().len() + 8, EvmLink::SIZE);
+    }
+
+    #[test]
+    fn test_evm_link_expiry() {
+        let mut link = EvmLink {
+            agent_mint: Pubkey::default(),
+            evm_address: [0; 20],
+            chain_id: "eip155:1".to_string(),
+            holder: Pubkey::default(),
+            linked_at: 0,
+            expires_at: None,
+            bump: 0,
+        };
+        assert!(!link.is_expired(i64::MAX));
+        link.expires_at = Some(100);
+        assert!(!link.is_expired(99));
+        assert!(link.is_expired(100));
+    }
+
+    #[test]
+    fn test_sns_link_size() {
+        let link = SnsLink {
+            agent_mint: Pubkey::default(),
+            name_account: Pubkey::default(),
+            domain: "d".repeat(MAX_SNS_DOMAIN_LENGTH),
+            holder: Pubkey::default(),
+            linked_at: 0,
+            bump: 0,
+        };
+        assert_eq!(borsh::to_vec(&link).unwrap().len() + 8, SnsLink::SIZE);
+    }
+
+    #[test]
+    fn test_agent_verification_size() {
+        let verification = AgentVerification {
+            mint: Pubkey::default(),
+            level: 1,
+            issuer: Pubkey::default(),
+            verified_at: 0,
+            bump: 0,
+        };
+        assert_eq!(
+            borsh::to_vec(&verification).unwrap().len() + 8,
+            AgentVerification::SIZE
+        );
+    }
+
+    #[test]
+    fn test_agent_verification_require_level_without_badge() {
+        let mint = Pubkey::new_unique();
+        assert!(AgentVerification::require_level(None, &mint, 0).is_ok());
+        assert!(AgentVerification::require_level(None, &mint, 1).is_err());
+    }
+
+    #[test]
+    fn test_pending_action_max_size() {
+        // Largest variant: RegisterSchemaConfig with delegation and a 32-byte name
+        let action = PendingAction {
+            action_id: u64::MAX,
+            authority: Pubkey::new_unique(),
+            rent_recipient: Pubkey::new_unique(),
+            action: GovernanceAction::RegisterSchemaConfig {
+                sas_schema: Pubkey::new_unique(),
+                signature_mode: SignatureMode::DualSignature,
+                storage_type: StorageType::Compressed,
+                delegation_schema: Some(Pubkey::new_unique()),
+                closeable: true,
+                name: "N".repeat(32),
+            },
+            queued_at: 0,
+            execute_after: 0,
+            bump: 255,
+        };
+        assert_eq!(
+            borsh::to_vec(&action).unwrap().len(),
+            PendingAction::INIT_SPACE
+        );
+    }
+
+    fn agent_record_data(mint: Pubkey, status: AgentStatus) -> Vec<u8> {
+        let record = AgentRecord {
+            mint,
+            member_number: 1,
+            registered_slot: 0,
+            registered_at: 0,
+            non_transferable: false,
+            status,
+            bump: 255,
+            pre_suspend_status: AgentStatus::Active,
+        };
+        let mut data = Vec::new();
+        record.try_serialize(&mut data).unwrap();
+        data
+    }
+
+    fn check_load_active(owner: Pubkey, data: &mut [u8], mint: &Pubkey) -> Result<u64> {
+        let key = Pubkey::new_unique();
+        let mut lamports = 1_000_000;
+        let info = AccountInfo::new(&key, false, false, &mut lamports, data, &owner, false, 0);
+        AgentRecord::load_active(&info, mint).map(|record| record.member_number)
+    }
+
+    #[test]
+    fn test_agent_record_load_active() {
+        let mint = Pubkey::new_unique();
+
+        let mut data = agent_record_data(mint, AgentStatus::Active);
+        assert_eq!(check_load_active(crate::ID, &mut data, &mint).unwrap(), 1);
+
+        // Not owned by SATI
+        let mut data = agent_record_data(mint, AgentStatus::Active);
+        let err = check_load_active(Pubkey::new_unique(), &mut data, &mint).unwrap_err();
+        assert_eq!(err, SatiError::AgentNotRegistered.into());
+
+        // Empty account
+        let err = check_load_active(crate::ID, &mut [], &mint).unwrap_err();
+        assert_eq!(err, SatiError::AgentNotRegistered.into());
+
+        // Record for a different mint
+        let mut data = agent_record_data(Pubkey::new_unique(), AgentStatus::Active);
+        let err = check_load_active(crate::ID, &mut data, &mint).unwrap_err();
+        assert_eq!(err, SatiError::AgentMintAccountMismatch.into());
+
+        // Retired agent
+        let mut data = agent_record_data(mint, AgentStatus::Retired);
+        let err = check_load_active(crate::ID, &mut data, &mint).unwrap_err();
+        assert_eq!(err, SatiError::AgentRetired.into());
+
+        // Suspended agent
+        let mut data = agent_record_data(mint, AgentStatus::Suspended);
+        let err = check_load_active(crate::ID, &mut data, &mint).unwrap_err();
+        assert_eq!(err, SatiError::AgentSuspended.into());
+
+        // Deprecated agents still accept attestations
+        let mut data = agent_record_data(mint, AgentStatus::Deprecated);
+        assert_eq!(check_load_active(crate::ID, &mut data, &mint).unwrap(), 1);
+    }
+
     #[test]
     fn test_storage_type_values() {
         // Verify enum variants are distinct
@@ -306,6 +1139,66 @@ mod tests {
         assert_eq!(attestation.num_signatures, 0);
         assert_eq!(attestation.signature1, [0u8; 64]);
         assert_eq!(attestation.signature2, [0u8; 64]);
+        assert_eq!(attestation.revision, 0);
+    }
+
+    /// Borsh bytes of the layout that predates `revision`
+    fn legacy_attestation_bytes(attestation: &CompressedAttestation) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&attestation.sas_schema);
+        bytes.extend_from_slice(&attestation.token_account);
+        bytes.extend_from_slice(&(attestation.data.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&attestation.data);
+        bytes.push(attestation.num_signatures);
+        bytes.extend_from_slice(&attestation.signature1);
+        bytes.extend_from_slice(&attestation.signature2);
+        bytes
+    }
+
+    fn sample_attestation(revision: u32) -> CompressedAttestation {
+        CompressedAttestation {
+            sas_schema: [1u8; 32],
+            token_account: [2u8; 32],
+            data: vec![3u8; 130],
+            num_signatures: 2,
+            signature1: [4u8; 64],
+            signature2: [5u8; 64],
+            revision,
+        }
+    }
+
+    #[test]
+    fn test_compressed_attestation_unamended_matches_legacy_layout() {
+        let attestation = sample_attestation(0);
+        let legacy = legacy_attestation_bytes(&attestation);
+
+        assert_eq!(attestation.try_to_vec().unwrap(), legacy);
+        let parsed = CompressedAttestation::try_from_slice(&legacy).unwrap();
+        assert_eq!(parsed.revision, 0);
+        assert_eq!(parsed.data, attestation.data);
+    }
+
+    #[test]
+    fn test_compressed_attestation_revision_roundtrip() {
+        let attestation = sample_attestation(7);
+        let bytes = attestation.try_to_vec().unwrap();
+
+        let mut expected = legacy_attestation_bytes(&attestation);
+        expected.extend_from_slice(&7u32.to_le_bytes());
+        assert_eq!(bytes, expected);
+        assert_eq!(
+            CompressedAttestation::try_from_slice(&bytes)
+                .unwrap()
+                .revision,
+            7
+        );
+    }
+
+    #[test]
+    fn test_compressed_attestation_truncated_revision_rejected() {
+        let mut bytes = legacy_attestation_bytes(&sample_attestation(0));
+        bytes.extend_from_slice(&[1, 0]);
+        assert!(CompressedAttestation::try_from_slice(&bytes).is_err());
     }
 
     #[test]
@@ -0,0 +1,88 @@
+//! Registration file integrity commitment.
+//!
+//! Agents may commit to the content of the registration file behind `uri` by
+//! setting the reserved TokenMetadata key [`URI_HASH_KEY`] to
+//! `"<algorithm>:<digest>"`, where the algorithm is `sha256` or `keccak256` and the
+//! digest is 64 lowercase hex characters over the raw file bytes. The program only
+//! checks the format; consumers fetch the file and compare digests off-chain.
+
+use anchor_lang::prelude::*;
+
+use crate::constants::URI_HASH_KEY;
+use crate::errors::SatiError;
+use crate::state::MetadataEntry;
+
+/// Supported digest algorithms (prefix before `:`)
+pub const URI_HASH_ALGORITHMS: [&str; 2] = ["sha256", "keccak256"];
+
+/// Validate a `uri_hash` value: `sha256:<64 hex>` or `keccak256:<64 hex>` (lowercase).
+pub fn validate_uri_hash(value: &str) -> Result<()> {
+    let (algorithm, digest) = value.split_once(':').ok_or(SatiError::InvalidUriHash)?;
+    require!(
+        URI_HASH_ALGORITHMS.contains(&algorithm),
+        SatiError::InvalidUriHash
+    );
+    require!(
+        digest.len() == 64
+            && digest
+                .bytes()
+                .all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b)),
+        SatiError::InvalidUriHash
+    );
+    Ok(())
+}
+
+/// Validate the `uri_hash` entry, if present, among metadata entries.
+pub fn validate_uri_hash_entries(entries: &[MetadataEntry]) -> Result<()> {
+    entries
+        .iter()
+        .filter(|entry| entry.key == URI_HASH_KEY)
+        .try_for_each(|entry| validate_uri_hash(&entry.value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIGEST: &str = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+
+    #[test]
+    fn test_validate_uri_hash_accepts_supported_algorithms() {
+        assert!(validate_uri_hash(&format!("sha256:{DIGEST}")).is_ok());
+        assert!(validate_uri_hash(&format!("keccak256:{DIGEST}")).is_ok());
+    }
+
+    #[test]
+    fn test_validate_uri_hash_rejects_malformed() {
+        for value in [
+            DIGEST.to_string(),                          // missing algorithm
+            format!("md5:{DIGEST}"),                     // unsupported algorithm
+            format!("sha256:{}", &DIGEST[..62]),         // short digest
+            format!("sha256:{}", DIGEST.to_uppercase()), // non-canonical case
+            format!("sha256:0x{}", &DIGEST[2..]),        // hex prefix
+        ] {
+            assert!(validate_uri_hash(&value).is_err(), "{value}");
+        }
+    }
+
+    #[test]
+    fn test_validate_uri_hash_entries_ignores_other_keys() {
+        let entries = vec![
+            MetadataEntry {
+                key: "category".to_string(),
+                value: "not-a-hash".to_string(),
+            },
+            MetadataEntry {
+                key: URI_HASH_KEY.to_string(),
+                value: format!("sha256:{DIGEST}"),
+            },
+        ];
+        assert!(validate_uri_hash_entries(&entries).is_ok());
+
+        let entries = vec![MetadataEntry {
+            key: URI_HASH_KEY.to_string(),
+            value: "sha256:bad".to_string(),
+        }];
+        assert!(validate_uri_hash_entries(&entries).is_err());
+    }
+}
@@ -0,0 +1,120 @@
+//! Shared helpers for instruction handlers
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{
+    allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer,
+};
+
+/// Resize a program-owned account to `space`, keeping it exactly rent-exempt:
+/// `payer` tops up when growing and receives the excess when shrinking.
+pub(crate) fn resize_program_account<'info>(
+    payer: &AccountInfo<'info>,
+    target: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let current_lamports = target.lamports();
+
+    if rent > current_lamports {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: target.clone(),
+                },
+            ),
+            rent - current_lamports,
+        )?;
+    } else if current_lamports > rent {
+        **target.try_borrow_mut_lamports()? = rent;
+        **payer.try_borrow_mut_lamports()? = payer
+            .lamports()
+            .checked_add(current_lamports - rent)
+            .ok_or(crate::errors::SatiError::Overflow)?;
+    }
+
+    target.resize(space)?;
+    Ok(())
+}
+
+/// Close a program-owned account, sending its lamports to `recipient`
+/// (mirrors Anchor's `close` constraint).
+pub(crate) fn close_program_account<'info>(
+    target: &AccountInfo<'info>,
+    recipient: &AccountInfo<'info>,
+) -> Result<()> {
+    let lamports = target.lamports();
+    **target.try_borrow_mut_lamports()? = 0;
+    **recipient.try_borrow_mut_lamports()? = recipient
+        .lamports()
+        .checked_add(lamports)
+        .ok_or(crate::errors::SatiError::Overflow)?;
+    target.assign(&anchor_lang::system_program::ID);
+    target.resize(0)?;
+    Ok(())
+}
+
+/// Create a program-owned PDA account, handling accounts that were pre-funded
+/// (mirrors Anchor's `init` behaviour).
+pub(crate) fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    target: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let current_lamports = target.lamports();
+
+    if current_lamports == 0 {
+        return create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                CreateAccount {
+                    from: payer.clone(),
+                    to: target.clone(),
+                },
+                &[signer_seeds],
+            ),
+            rent,
+            space as u64,
+            &crate::ID,
+        );
+    }
+
+    let required_lamports = rent.saturating_sub(current_lamports);
+    if required_lamports > 0 {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: target.clone(),
+                },
+            ),
+            required_lamports,
+        )?;
+    }
+    allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Allocate {
+                account_to_allocate: target.clone(),
+            },
+            &[signer_seeds],
+        ),
+        space as u64,
+    )?;
+    assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Assign {
+                account_to_assign: target.clone(),
+            },
+            &[signer_seeds],
+        ),
+        &crate::ID,
+    )
+}
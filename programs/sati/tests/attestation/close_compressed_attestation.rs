@@ -29,8 +29,9 @@ const SCHEMA_NAME: &str = "Feedback";
 
 /// SchemaConfig account size with "Feedback" name and delegation_schema = None:
 /// 8 (discriminator) + 32 (sas_schema) + 1 (signature_mode) + 1 (storage_type)
-/// + 1 (delegation_schema=None) + 1 (closeable) + 4 (name_len) + 8 (name) + 1 (bump) = 57 bytes
-const SCHEMA_CONFIG_SIZE: usize = 57;
+/// + 1 (delegation_schema=None) + 1 (closeable) + 4 (name_len) + 8 (name) + 1 (bump)
+/// + 1 (min_verification_level) = 58 bytes
+const SCHEMA_CONFIG_SIZE: usize = 58;
 
 /// Build mock SchemaConfig account data
 fn build_schema_config_data(
@@ -51,6 +52,7 @@ fn build_schema_config_data(
     data[44..48].copy_from_slice(&(SCHEMA_NAME.len() as u32).to_le_bytes());
     data[48..48 + SCHEMA_NAME.len()].copy_from_slice(SCHEMA_NAME.as_bytes());
     data[48 + SCHEMA_NAME.len()] = bump;
+    data[49 + SCHEMA_NAME.len()] = 0; // min_verification_level
     data
 }
 
@@ -23,7 +23,9 @@ use light_sdk::{
 use solana_sdk::{account::Account, pubkey::Pubkey, signer::Signer};
 
 use crate::common::{
-    accounts::{compute_anchor_account_discriminator, derive_token22_ata},
+    accounts::{
+        compute_anchor_account_discriminator, derive_token22_ata, mock_agent_record_account,
+    },
     ed25519::{
         build_counterparty_message, compute_attestation_nonce, compute_data_hash,
         compute_interaction_hash, create_multi_ed25519_ix, generate_ed25519_keypair,
@@ -39,8 +41,8 @@ use crate::common::{
 };
 
 /// SchemaConfig account size with "Feedback" name (8 bytes) and delegation_schema = None:
-/// 8 (discriminator) + 32 (sas_schema) + 1 (signature_mode) + 1 (storage_type) + 1 (delegation_schema=None) + 1 (closeable) + 4 (name len) + 8 (name "Feedback") + 1 (bump) = 57 bytes
-const SCHEMA_CONFIG_SIZE: usize = 57;
+/// 8 (discriminator) + 32 (sas_schema) + 1 (signature_mode) + 1 (storage_type) + 1 (delegation_schema=None) + 1 (closeable) + 4 (name len) + 8 (name "Feedback") + 1 (bump) + 1 (min_verification_level) = 58 bytes
+const SCHEMA_CONFIG_SIZE: usize = 58;
 
 /// Schema name used in SIWS messages - must match build_counterparty_message calls
 const SCHEMA_NAME: &str = "Feedback";
@@ -48,7 +50,7 @@ const SCHEMA_NAME: &str = "Feedback";
 /// Create mock SchemaConfig account data
 ///
 /// Layout: discriminator(8) + sas_schema(32) + signature_mode(1) + storage_type(1)
-///         + delegation_schema(1 for None) + closeable(1) + name_len(4) + name(N) + bump(1)
+///         + delegation_schema(1 for None) + closeable(1) + name_len(4) + name(N) + bump(1) + min_verification_level(1)
 fn create_schema_config_data(
     sas_schema: &Pubkey,
     signature_mode: SignatureMode,
@@ -67,6 +69,7 @@ fn create_schema_config_data(
     data[44..48].copy_from_slice(&(SCHEMA_NAME.len() as u32).to_le_bytes()); // name length
     data[48..48 + SCHEMA_NAME.len()].copy_from_slice(SCHEMA_NAME.as_bytes()); // name
     data[48 + SCHEMA_NAME.len()] = bump; // bump
+    data[49 + SCHEMA_NAME.len()] = 0; // min_verification_level = none
     data
 }
 
@@ -163,6 +166,8 @@ async fn test_create_attestation_feedback_success() {
             rent_epoch: 0,
         },
     );
+    let (agent_record, agent_record_account) = mock_agent_record_account(&agent_mint, 1, 0);
+    rpc.set_account(agent_record, agent_record_account);
 
     // Create mock ATA with 1 token (NFT)
     rpc.set_account(
@@ -263,6 +268,7 @@ async fn test_create_attestation_feedback_success() {
     let attestation_ix = build_create_compressed_attestation_ix(
         &payer.pubkey(),
         &schema_config_pda,
+        &agent_mint,
         Some(&agent_ata),
         params,
         system_accounts,
@@ -341,6 +347,10 @@ async fn test_create_attestation_missing_signature() {
         },
     );
 
+    let (agent_record, agent_record_account) = mock_agent_record_account(&agent_mint, 1, 0);
+
+    rpc.set_account(agent_record, agent_record_account);
+
     rpc.set_account(
         agent_ata,
         Account {
@@ -418,6 +428,7 @@ async fn test_create_attestation_missing_signature() {
     let attestation_ix = build_create_compressed_attestation_ix(
         &payer.pubkey(),
         &schema_config_pda,
+        &agent_mint,
         Some(&agent_ata),
         params,
         system_accounts,
@@ -489,6 +500,10 @@ async fn test_create_attestation_invalid_signature() {
         },
     );
 
+    let (agent_record, agent_record_account) = mock_agent_record_account(&agent_mint, 1, 0);
+
+    rpc.set_account(agent_record, agent_record_account);
+
     rpc.set_account(
         agent_ata,
         Account {
@@ -567,6 +582,7 @@ async fn test_create_attestation_invalid_signature() {
     let attestation_ix = build_create_compressed_attestation_ix(
         &payer.pubkey(),
         &schema_config_pda,
+        &agent_mint,
         Some(&agent_ata),
         params,
         system_accounts,
@@ -647,6 +663,10 @@ async fn test_create_attestation_wrong_signer() {
         },
     );
 
+    let (agent_record, agent_record_account) = mock_agent_record_account(&agent_mint, 1, 0);
+
+    rpc.set_account(agent_record, agent_record_account);
+
     rpc.set_account(
         agent_ata,
         Account {
@@ -728,6 +748,7 @@ async fn test_create_attestation_wrong_signer() {
     let attestation_ix = build_create_compressed_attestation_ix(
         &payer.pubkey(),
         &schema_config_pda,
+        &agent_mint,
         Some(&agent_ata),
         params,
         system_accounts,
@@ -804,6 +825,10 @@ async fn test_create_attestation_self_attestation() {
         },
     );
 
+    let (agent_record, agent_record_account) = mock_agent_record_account(&self_mint, 1, 0);
+
+    rpc.set_account(agent_record, agent_record_account);
+
     rpc.set_account(
         self_ata,
         Account {
@@ -881,6 +906,7 @@ async fn test_create_attestation_self_attestation() {
     let attestation_ix = build_create_compressed_attestation_ix(
         &payer.pubkey(),
         &schema_config_pda,
+        &self_mint,
         Some(&self_ata),
         params,
         system_accounts,
@@ -959,6 +985,10 @@ async fn test_create_attestation_data_too_small() {
         },
     );
 
+    let (agent_record, agent_record_account) = mock_agent_record_account(&agent_mint, 1, 0);
+
+    rpc.set_account(agent_record, agent_record_account);
+
     rpc.set_account(
         agent_ata,
         Account {
@@ -1027,6 +1057,7 @@ async fn test_create_attestation_data_too_small() {
     let attestation_ix = build_create_compressed_attestation_ix(
         &payer.pubkey(),
         &schema_config_pda,
+        &agent_mint,
         Some(&agent_ata),
         params,
         system_accounts,
@@ -1103,6 +1134,10 @@ async fn test_create_attestation_wrong_storage_type() {
         },
     );
 
+    let (agent_record, agent_record_account) = mock_agent_record_account(&agent_mint, 1, 0);
+
+    rpc.set_account(agent_record, agent_record_account);
+
     rpc.set_account(
         agent_ata,
         Account {
@@ -1183,6 +1218,7 @@ async fn test_create_attestation_wrong_storage_type() {
     let attestation_ix = build_create_compressed_attestation_ix(
         &payer.pubkey(),
         &schema_config_pda,
+        &agent_mint,
         Some(&agent_ata),
         params,
         system_accounts,
@@ -1258,6 +1294,10 @@ async fn test_create_attestation_wrong_mint_ata() {
     // ATA is derived from wrong_mint but owned by agent_pubkey
     let wrong_ata = derive_token22_ata(&agent_pubkey, &wrong_mint);
 
+    // token_account (actual_agent_mint) is a registered agent
+    let (agent_record, agent_record_account) = mock_agent_record_account(&actual_agent_mint, 1, 0);
+    rpc.set_account(agent_record, agent_record_account);
+
     // Mock the wrong mint
     rpc.set_account(
         wrong_mint,
@@ -1357,6 +1397,7 @@ async fn test_create_attestation_wrong_mint_ata() {
     let attestation_ix = build_create_compressed_attestation_ix(
         &payer.pubkey(),
         &schema_config_pda,
+        &actual_agent_mint,
         Some(&wrong_ata), // Pass ATA with WRONG mint
         params,
         system_accounts,
@@ -1434,6 +1475,10 @@ async fn test_create_attestation_wrong_owner_ata() {
         },
     );
 
+    let (agent_record, agent_record_account) = mock_agent_record_account(&agent_mint, 1, 0);
+
+    rpc.set_account(agent_record, agent_record_account);
+
     // ATA owner is victim_pubkey, not attacker_pubkey
     rpc.set_account(
         victim_ata,
@@ -1516,6 +1561,7 @@ async fn test_create_attestation_wrong_owner_ata() {
     let attestation_ix = build_create_compressed_attestation_ix(
         &payer.pubkey(),
         &schema_config_pda,
+        &agent_mint,
         Some(&victim_ata), // Victim's ATA, but attacker signed
         params,
         system_accounts,
@@ -1590,6 +1636,10 @@ async fn test_create_attestation_empty_ata() {
         },
     );
 
+    let (agent_record, agent_record_account) = mock_agent_record_account(&agent_mint, 1, 0);
+
+    rpc.set_account(agent_record, agent_record_account);
+
     // ATA with ZERO balance - agent doesn't actually hold the NFT
     rpc.set_account(
         agent_ata,
@@ -1670,6 +1720,7 @@ async fn test_create_attestation_empty_ata() {
     let attestation_ix = build_create_compressed_attestation_ix(
         &payer.pubkey(),
         &schema_config_pda,
+        &agent_mint,
         Some(&agent_ata), // ATA with zero balance
         params,
         system_accounts,
@@ -1692,6 +1743,152 @@ async fn test_create_attestation_empty_ata() {
     );
 }
 
+/// Send a CounterpartySigned attestation for a fresh agent mint and return the error string.
+///
+/// `agent_record_status`: None = no AgentRecord (unregistered), Some(0) = Active, Some(1) = Retired.
+/// CounterpartySigned mode needs no agent ATA, so this exercises the registry check on its own.
+async fn send_counterparty_signed_attestation(agent_record_status: Option<u8>) -> String {
+    let LightTestEnv { mut rpc, payer, .. } = setup_light_test_env().await;
+
+    let sas_schema = Pubkey::new_unique();
+    let (schema_config_pda, bump) = derive_schema_config_pda(&sas_schema);
+
+    let schema_data = create_schema_config_data(
+        &sas_schema,
+        SignatureMode::CounterpartySigned,
+        StorageType::Compressed,
+        true,
+        bump,
+    );
+
+    rpc.set_account(
+        schema_config_pda,
+        Account {
+            lamports: 1_000_000,
+            data: schema_data,
+            owner: SATI_PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let counterparty_keypair = generate_ed25519_keypair();
+    let counterparty_pubkey = keypair_to_pubkey(&counterparty_keypair);
+
+    let agent_mint = Pubkey::new_unique();
+    if let Some(status) = agent_record_status {
+        let (agent_record, agent_record_account) =
+            mock_agent_record_account(&agent_mint, 1, status);
+        rpc.set_account(agent_record, agent_record_account);
+    }
+
+    let task_ref = [1u8; 32];
+    let data_hash = [0u8; 32];
+    let outcome: u8 = 2;
+
+    let data = AttestationDataBuilder::new(
+        task_ref,
+        agent_mint,
+        counterparty_pubkey,
+        outcome,
+        data_hash,
+    )
+    .build();
+
+    let counterparty_msg =
+        build_counterparty_message(SCHEMA_NAME, &agent_mint, &task_ref, outcome, None);
+    let counterparty_sig = sign_message(&counterparty_keypair, &counterparty_msg);
+
+    let mut remaining_accounts = PackedAccounts::default();
+    let system_config = SystemAccountMetaConfig::new(SATI_PROGRAM_ID);
+    let _ = remaining_accounts.add_system_accounts(system_config);
+
+    let address_tree_info = rpc.get_address_tree_v1();
+    let address_tree_pubkey = address_tree_info.tree;
+    let nonce =
+        compute_attestation_nonce(&task_ref, &sas_schema, &agent_mint, &counterparty_pubkey);
+    let seeds: &[&[u8]] = &[
+        b"attestation",
+        sas_schema.as_ref(),
+        agent_mint.as_ref(),
+        &nonce,
+    ];
+    let (compressed_address, _) = derive_address(seeds, &address_tree_pubkey, &SATI_PROGRAM_ID);
+
+    let rpc_result = rpc
+        .get_validity_proof(
+            vec![],
+            vec![AddressWithTree {
+                address: compressed_address,
+                tree: address_tree_pubkey,
+            }],
+            None,
+        )
+        .await
+        .expect("Failed to get validity proof")
+        .value;
+
+    let packed_tree_infos = rpc_result.pack_tree_infos(&mut remaining_accounts);
+    let address_tree_info = packed_tree_infos.address_trees[0];
+    let output_state_tree_index =
+        remaining_accounts.insert_or_get(rpc.get_random_state_tree_info().unwrap().tree);
+    let (system_accounts, _, _) = remaining_accounts.to_account_metas();
+
+    let params = CreateParams {
+        data: data.clone(),
+        output_state_tree_index,
+        proof: rpc_result.proof,
+        address_tree_info,
+    };
+
+    let ed25519_ix =
+        create_multi_ed25519_ix(&[(&counterparty_pubkey, &counterparty_msg, &counterparty_sig)]);
+    let attestation_ix = build_create_compressed_attestation_ix(
+        &payer.pubkey(),
+        &schema_config_pda,
+        &agent_mint,
+        None,
+        params,
+        system_accounts,
+    );
+
+    let result = rpc
+        .create_and_send_transaction(&[ed25519_ix, attestation_ix], &payer.pubkey(), &[&payer])
+        .await;
+
+    assert!(
+        result.is_err(),
+        "Transaction should fail for agent record status {:?}",
+        agent_record_status
+    );
+    format!("{:?}", result.unwrap_err())
+}
+
+/// Test that create_attestation rejects attestations targeting a retired agent
+#[tokio::test]
+async fn test_create_attestation_retired_agent() {
+    let err_str = send_counterparty_signed_attestation(Some(1)).await;
+    assert!(
+        err_str.contains("AgentRetired") || err_str.contains("6055"),
+        "Expected AgentRetired error (6055), got: {}",
+        err_str
+    );
+}
+
+/// Test that create_attestation rejects mints that are not SATI agents
+///
+/// Attack vector: Writing feedback for an arbitrary mint in CounterpartySigned mode,
+/// where no agent ATA is required.
+#[tokio::test]
+async fn test_create_attestation_unregistered_agent() {
+    let err_str = send_counterparty_signed_attestation(None).await;
+    assert!(
+        err_str.contains("AgentNotRegistered") || err_str.contains("6056"),
+        "Expected AgentNotRegistered error (6056), got: {}",
+        err_str
+    );
+}
+
 // ============================================================================
 // Signature Count Manipulation Tests
 // ============================================================================
@@ -1746,6 +1943,10 @@ async fn test_dual_signature_with_one_sig() {
         },
     );
 
+    let (agent_record, agent_record_account) = mock_agent_record_account(&agent_mint, 1, 0);
+
+    rpc.set_account(agent_record, agent_record_account);
+
     rpc.set_account(
         agent_ata,
         Account {
@@ -1821,6 +2022,7 @@ async fn test_dual_signature_with_one_sig() {
     let attestation_ix = build_create_compressed_attestation_ix(
         &payer.pubkey(),
         &schema_config_pda,
+        &agent_mint,
         Some(&agent_ata),
         params,
         system_accounts,
@@ -1893,6 +2095,10 @@ async fn test_counterparty_signed_with_two_sigs() {
         },
     );
 
+    let (agent_record, agent_record_account) = mock_agent_record_account(&agent_mint, 1, 0);
+
+    rpc.set_account(agent_record, agent_record_account);
+
     rpc.set_account(
         agent_ata,
         Account {
@@ -1971,6 +2177,7 @@ async fn test_counterparty_signed_with_two_sigs() {
     let attestation_ix = build_create_compressed_attestation_ix(
         &payer.pubkey(),
         &schema_config_pda,
+        &agent_mint,
         Some(&agent_ata),
         params,
         system_accounts,
@@ -2041,6 +2248,10 @@ async fn test_dual_signature_duplicate_pubkeys() {
         },
     );
 
+    let (agent_record, agent_record_account) = mock_agent_record_account(&agent_mint, 1, 0);
+
+    rpc.set_account(agent_record, agent_record_account);
+
     rpc.set_account(
         agent_ata,
         Account {
@@ -2124,6 +2335,7 @@ async fn test_dual_signature_duplicate_pubkeys() {
     let attestation_ix = build_create_compressed_attestation_ix(
         &payer.pubkey(),
         &schema_config_pda,
+        &agent_mint,
         Some(&agent_ata),
         params,
         system_accounts,
@@ -2199,6 +2411,10 @@ async fn test_data_exactly_129_bytes() {
         },
     );
 
+    let (agent_record, agent_record_account) = mock_agent_record_account(&agent_mint, 1, 0);
+
+    rpc.set_account(agent_record, agent_record_account);
+
     rpc.set_account(
         agent_ata,
         Account {
@@ -2267,6 +2483,7 @@ async fn test_data_exactly_129_bytes() {
     let attestation_ix = build_create_compressed_attestation_ix(
         &payer.pubkey(),
         &schema_config_pda,
+        &agent_mint,
         Some(&agent_ata),
         params,
         system_accounts,
@@ -2337,6 +2554,10 @@ async fn test_content_513_bytes() {
         },
     );
 
+    let (agent_record, agent_record_account) = mock_agent_record_account(&agent_mint, 1, 0);
+
+    rpc.set_account(agent_record, agent_record_account);
+
     rpc.set_account(
         agent_ata,
         Account {
@@ -2419,6 +2640,7 @@ async fn test_content_513_bytes() {
     let attestation_ix = build_create_compressed_attestation_ix(
         &payer.pubkey(),
         &schema_config_pda,
+        &agent_mint,
         Some(&agent_ata),
         params,
         system_accounts,
@@ -2492,6 +2714,10 @@ async fn test_invalid_outcome_value_3() {
         },
     );
 
+    let (agent_record, agent_record_account) = mock_agent_record_account(&agent_mint, 1, 0);
+
+    rpc.set_account(agent_record, agent_record_account);
+
     rpc.set_account(
         agent_ata,
         Account {
@@ -2572,6 +2798,7 @@ async fn test_invalid_outcome_value_3() {
     let attestation_ix = build_create_compressed_attestation_ix(
         &payer.pubkey(),
         &schema_config_pda,
+        &agent_mint,
         Some(&agent_ata),
         params,
         system_accounts,
@@ -2641,6 +2868,10 @@ async fn test_invalid_content_type_value_16() {
         },
     );
 
+    let (agent_record, agent_record_account) = mock_agent_record_account(&agent_mint, 1, 0);
+
+    rpc.set_account(agent_record, agent_record_account);
+
     rpc.set_account(
         agent_ata,
         Account {
@@ -2721,6 +2952,7 @@ async fn test_invalid_content_type_value_16() {
     let attestation_ix = build_create_compressed_attestation_ix(
         &payer.pubkey(),
         &schema_config_pda,
+        &agent_mint,
         Some(&agent_ata),
         params,
         system_accounts,
@@ -1,6 +1,10 @@
 //! Tests for attestation instructions
 //!
-//! Uses Light Protocol's LightProgramTest for compressed account testing.
+//! Uses Light Protocol's LightProgramTest for compressed account testing and
+//! LiteSVM with mocked SAS accounts for regular attestations.
 
 mod close_compressed_attestation;
 mod create_compressed_attestation;
+mod update_compressed_attestation;
+mod update_regular_attestation;
+mod update_regular_attestations_batch;
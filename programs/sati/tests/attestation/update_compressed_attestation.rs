@@ -0,0 +1,506 @@
+//! Tests for update_compressed_attestation instruction (compressed storage)
+//!
+//! These tests verify:
+//! - The counterparty can amend outcome and content with a fresh SIWS signature
+//! - Each amendment bumps the revision, so old amend signatures cannot be replayed
+//! - Fields bound to the address or the agent's signature are immutable
+//! - Schemas without a counterparty SIWS signature cannot be amended
+//! - Unamended attestations keep the pre-revision layout and can still be closed
+//!
+//! Note: Full integration tests require Light Protocol prover and localnet running.
+//! Run with: pnpm localnet && cargo test -p sati --test main attestation::update
+
+use anchor_lang::AnchorDeserialize;
+use light_program_test::{program_test::TestRpc, AddressWithTree, Indexer, LightProgramTest, Rpc};
+use light_sdk::{
+    address::v1::derive_address,
+    instruction::{
+        account_meta::CompressedAccountMeta, PackedAccounts, SystemAccountMetaConfig, ValidityProof,
+    },
+};
+use solana_sdk::{
+    account::Account,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+use crate::common::{
+    accounts::{compute_anchor_account_discriminator, mock_agent_record_account},
+    ed25519::{
+        build_counterparty_amend_message, build_counterparty_message, compute_attestation_nonce,
+        create_multi_ed25519_ix, generate_ed25519_keypair, keypair_to_pubkey, sign_message,
+        AttestationDataBuilder,
+    },
+    instructions::{
+        build_close_compressed_attestation_ix, build_create_compressed_attestation_ix,
+        build_update_compressed_attestation_ix, CloseParams, CompressedAttestation, CreateParams,
+        SignatureMode, StorageType, UpdateParams,
+    },
+    setup::{derive_schema_config_pda, setup_light_test_env, LightTestEnv, SATI_PROGRAM_ID},
+};
+
+/// SchemaConfig account size with "Feedback" name and delegation_schema = None (58 bytes)
+const SCHEMA_CONFIG_SIZE: usize = 58;
+
+/// Schema name used in SIWS messages
+const SCHEMA_NAME: &str = "Feedback";
+
+/// Build mock SchemaConfig account data
+fn create_schema_config_data(
+    sas_schema: &Pubkey,
+    signature_mode: SignatureMode,
+    bump: u8,
+) -> Vec<u8> {
+    let mut data = vec![0u8; SCHEMA_CONFIG_SIZE];
+    let discriminator = compute_anchor_account_discriminator("SchemaConfig");
+    data[0..8].copy_from_slice(&discriminator);
+    data[8..40].copy_from_slice(sas_schema.as_ref());
+    data[40] = signature_mode as u8;
+    data[41] = StorageType::Compressed as u8;
+    data[42] = 0; // delegation_schema = None
+    data[43] = 1; // closeable
+    data[44..48].copy_from_slice(&(SCHEMA_NAME.len() as u32).to_le_bytes());
+    data[48..48 + SCHEMA_NAME.len()].copy_from_slice(SCHEMA_NAME.as_bytes());
+    data[48 + SCHEMA_NAME.len()] = bump;
+    data[49 + SCHEMA_NAME.len()] = 0; // min_verification_level
+    data
+}
+
+/// A CounterpartySigned attestation created on the test validator
+struct CreatedAttestation {
+    rpc: LightProgramTest,
+    payer: Keypair,
+    schema_config: Pubkey,
+    counterparty_keypair: ed25519_dalek::SigningKey,
+    counterparty: Pubkey,
+    agent_mint: Pubkey,
+    task_ref: [u8; 32],
+    address: [u8; 32],
+}
+
+/// Create a CounterpartySigned attestation with outcome Positive for a fresh agent
+async fn create_counterparty_signed_attestation() -> CreatedAttestation {
+    let LightTestEnv { mut rpc, payer, .. } = setup_light_test_env().await;
+
+    let sas_schema = Pubkey::new_unique();
+    let (schema_config, bump) = derive_schema_config_pda(&sas_schema);
+    rpc.set_account(
+        schema_config,
+        Account {
+            lamports: 1_000_000,
+            data: create_schema_config_data(&sas_schema, SignatureMode::CounterpartySigned, bump),
+            owner: SATI_PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let counterparty_keypair = generate_ed25519_keypair();
+    let counterparty = keypair_to_pubkey(&counterparty_keypair);
+
+    let agent_mint = Pubkey::new_unique();
+    let (agent_record, agent_record_account) = mock_agent_record_account(&agent_mint, 1, 0);
+    rpc.set_account(agent_record, agent_record_account);
+
+    let task_ref = [1u8; 32];
+    let outcome: u8 = 2;
+    let data =
+        AttestationDataBuilder::new(task_ref, agent_mint, counterparty, outcome, [0u8; 32]).build();
+
+    let counterparty_msg =
+        build_counterparty_message(SCHEMA_NAME, &agent_mint, &task_ref, outcome, None);
+    let counterparty_sig = sign_message(&counterparty_keypair, &counterparty_msg);
+
+    let mut remaining_accounts = PackedAccounts::default();
+    let _ = remaining_accounts.add_system_accounts(SystemAccountMetaConfig::new(SATI_PROGRAM_ID));
+
+    let address_tree_pubkey = rpc.get_address_tree_v1().tree;
+    let nonce = compute_attestation_nonce(&task_ref, &sas_schema, &agent_mint, &counterparty);
+    let seeds: &[&[u8]] = &[
+        b"attestation",
+        sas_schema.as_ref(),
+        agent_mint.as_ref(),
+        &nonce,
+    ];
+    let (address, _) = derive_address(seeds, &address_tree_pubkey, &SATI_PROGRAM_ID);
+
+    let rpc_result = rpc
+        .get_validity_proof(
+            vec![],
+            vec![AddressWithTree {
+                address,
+                tree: address_tree_pubkey,
+            }],
+            None,
+        )
+        .await
+        .expect("Failed to get validity proof")
+        .value;
+
+    let packed_tree_infos = rpc_result.pack_tree_infos(&mut remaining_accounts);
+    let output_state_tree_index =
+        remaining_accounts.insert_or_get(rpc.get_random_state_tree_info().unwrap().tree);
+    let (system_accounts, _, _) = remaining_accounts.to_account_metas();
+
+    let params = CreateParams {
+        data,
+        output_state_tree_index,
+        proof: rpc_result.proof,
+        address_tree_info: packed_tree_infos.address_trees[0],
+    };
+
+    let ed25519_ix =
+        create_multi_ed25519_ix(&[(&counterparty, &counterparty_msg, &counterparty_sig)]);
+    let attestation_ix = build_create_compressed_attestation_ix(
+        &payer.pubkey(),
+        &schema_config,
+        &agent_mint,
+        None,
+        params,
+        system_accounts,
+    );
+
+    rpc.create_and_send_transaction(&[ed25519_ix, attestation_ix], &payer.pubkey(), &[&payer])
+        .await
+        .expect("create_compressed_attestation should succeed");
+
+    CreatedAttestation {
+        rpc,
+        payer,
+        schema_config,
+        counterparty_keypair,
+        counterparty,
+        agent_mint,
+        task_ref,
+        address,
+    }
+}
+
+/// Fetch the current attestation state from the indexer
+async fn fetch_attestation(rpc: &LightProgramTest, address: [u8; 32]) -> CompressedAttestation {
+    let account = rpc
+        .get_compressed_account(address, None)
+        .await
+        .expect("Failed to fetch compressed account")
+        .value
+        .expect("Attestation should exist");
+    let data = account.data.expect("Attestation should have data").data;
+    CompressedAttestation::deserialize(&mut &data[..]).expect("Failed to deserialize")
+}
+
+/// Amend the attestation to `new_data`, signing the amend message for `signed_revision`
+async fn send_update(
+    created: &mut CreatedAttestation,
+    new_data: Vec<u8>,
+    signed_revision: u32,
+) -> Result<(), String> {
+    let account = created
+        .rpc
+        .get_compressed_account(created.address, None)
+        .await
+        .expect("Failed to fetch compressed account")
+        .value
+        .expect("Attestation should exist");
+    let current = fetch_attestation(&created.rpc, created.address).await;
+
+    let rpc_result = created
+        .rpc
+        .get_validity_proof(vec![account.hash], vec![], None)
+        .await
+        .expect("Failed to get validity proof")
+        .value;
+
+    let mut remaining_accounts = PackedAccounts::default();
+    let _ = remaining_accounts.add_system_accounts(SystemAccountMetaConfig::new(SATI_PROGRAM_ID));
+    let state_trees = rpc_result
+        .pack_tree_infos(&mut remaining_accounts)
+        .state_trees
+        .unwrap();
+    let (system_accounts, _, _) = remaining_accounts.to_account_metas();
+
+    let outcome = new_data[97]; // outcome offset in the universal layout
+    let amend_msg = build_counterparty_amend_message(
+        SCHEMA_NAME,
+        &created.agent_mint,
+        &created.task_ref,
+        outcome,
+        None,
+        signed_revision,
+    );
+    let amend_sig = sign_message(&created.counterparty_keypair, &amend_msg);
+
+    let params = UpdateParams {
+        current_data: current.data,
+        num_signatures: current.num_signatures,
+        signature1: current.signature1,
+        signature2: current.signature2,
+        revision: current.revision,
+        new_data,
+        proof: rpc_result.proof,
+        account_meta: CompressedAccountMeta {
+            tree_info: state_trees.packed_tree_infos[0],
+            address: created.address,
+            output_state_tree_index: state_trees.output_tree_index,
+        },
+    };
+
+    let ed25519_ix = create_multi_ed25519_ix(&[(&created.counterparty, &amend_msg, &amend_sig)]);
+    let update_ix = build_update_compressed_attestation_ix(
+        &created.payer.pubkey(),
+        &created.schema_config,
+        params,
+        system_accounts,
+    );
+
+    let payer = created.payer.insecure_clone();
+    created
+        .rpc
+        .create_and_send_transaction(&[ed25519_ix, update_ix], &payer.pubkey(), &[&payer])
+        .await
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
+/// Close the attestation as the counterparty, passing `revision` as given
+async fn send_close(created: &mut CreatedAttestation, revision: Option<u32>) -> Result<(), String> {
+    let account = created
+        .rpc
+        .get_compressed_account(created.address, None)
+        .await
+        .expect("Failed to fetch compressed account")
+        .value
+        .expect("Attestation should exist");
+    let current = fetch_attestation(&created.rpc, created.address).await;
+
+    let rpc_result = created
+        .rpc
+        .get_validity_proof(vec![account.hash], vec![], None)
+        .await
+        .expect("Failed to get validity proof")
+        .value;
+
+    let mut remaining_accounts = PackedAccounts::default();
+    let _ = remaining_accounts.add_system_accounts(SystemAccountMetaConfig::new(SATI_PROGRAM_ID));
+    let state_trees = rpc_result
+        .pack_tree_infos(&mut remaining_accounts)
+        .state_trees
+        .unwrap();
+    let (system_accounts, _, _) = remaining_accounts.to_account_metas();
+
+    let params = CloseParams {
+        current_data: current.data,
+        num_signatures: current.num_signatures,
+        signature1: current.signature1,
+        signature2: current.signature2,
+        address: Pubkey::new_from_array(created.address),
+        proof: rpc_result.proof,
+        account_meta: CompressedAccountMeta {
+            tree_info: state_trees.packed_tree_infos[0],
+            address: created.address,
+            output_state_tree_index: state_trees.output_tree_index,
+        },
+        revision,
+    };
+
+    let counterparty = Keypair::try_from(&created.counterparty_keypair.to_keypair_bytes()[..])
+        .expect("Invalid counterparty keypair");
+    let close_ix = build_close_compressed_attestation_ix(
+        &counterparty.pubkey(),
+        &created.schema_config,
+        None,
+        params,
+        system_accounts,
+    );
+
+    let payer = created.payer.insecure_clone();
+    created
+        .rpc
+        .create_and_send_transaction(&[close_ix], &payer.pubkey(), &[&payer, &counterparty])
+        .await
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
+/// Borsh length of an attestation in the layout that predates `revision`
+fn legacy_layout_len(data_len: usize) -> usize {
+    32 + 32 + 4 + data_len + 1 + 64 + 64
+}
+
+/// Test that the counterparty can amend the outcome with a fresh signature
+#[tokio::test]
+async fn test_update_attestation_success() {
+    let mut created = create_counterparty_signed_attestation().await;
+
+    let new_data = AttestationDataBuilder::new(
+        created.task_ref,
+        created.agent_mint,
+        created.counterparty,
+        0,
+        [0u8; 32],
+    )
+    .build();
+    send_update(&mut created, new_data.clone(), 1)
+        .await
+        .expect("update_compressed_attestation should succeed");
+
+    let attestation = fetch_attestation(&created.rpc, created.address).await;
+    assert_eq!(attestation.data, new_data, "data should be amended");
+    assert_eq!(attestation.revision, 1, "revision should be bumped");
+
+    // A second amendment needs a signature for revision 2
+    let err = send_update(&mut created, new_data.clone(), 1)
+        .await
+        .expect_err("Stale amend signature should fail");
+    assert!(
+        err.contains("CounterpartySignatureNotFound") || err.contains("6035"),
+        "Expected CounterpartySignatureNotFound error (6035), got: {}",
+        err
+    );
+    send_update(&mut created, new_data, 2)
+        .await
+        .expect("Second amendment should succeed");
+    assert_eq!(
+        fetch_attestation(&created.rpc, created.address)
+            .await
+            .revision,
+        2
+    );
+}
+
+/// Test that an attestation in the pre-revision layout can be closed without a revision
+#[tokio::test]
+async fn test_close_legacy_layout_attestation() {
+    let mut created = create_counterparty_signed_attestation().await;
+
+    // Unamended attestations are stored without the trailing revision bytes
+    let raw = created
+        .rpc
+        .get_compressed_account(created.address, None)
+        .await
+        .expect("Failed to fetch compressed account")
+        .value
+        .expect("Attestation should exist")
+        .data
+        .expect("Attestation should have data")
+        .data;
+    let current = fetch_attestation(&created.rpc, created.address).await;
+    assert_eq!(raw.len(), legacy_layout_len(current.data.len()));
+    assert_eq!(current.revision, 0);
+
+    send_close(&mut created, None)
+        .await
+        .expect("Closing a legacy-layout attestation should succeed");
+}
+
+/// Test that a pre-revision attestation can be amended and then closed at its new revision
+#[tokio::test]
+async fn test_amend_then_close_legacy_layout_attestation() {
+    let mut created = create_counterparty_signed_attestation().await;
+
+    let new_data = AttestationDataBuilder::new(
+        created.task_ref,
+        created.agent_mint,
+        created.counterparty,
+        0,
+        [0u8; 32],
+    )
+    .build();
+    send_update(&mut created, new_data.clone(), 1)
+        .await
+        .expect("Amending a legacy-layout attestation should succeed");
+
+    // Once amended, the revision is appended to the stored bytes
+    let raw = created
+        .rpc
+        .get_compressed_account(created.address, None)
+        .await
+        .expect("Failed to fetch compressed account")
+        .value
+        .expect("Attestation should exist")
+        .data
+        .expect("Attestation should have data")
+        .data;
+    assert_eq!(raw.len(), legacy_layout_len(new_data.len()) + 4);
+
+    // The hash covers the revision, so omitting it no longer matches
+    assert!(send_close(&mut created, None).await.is_err());
+    send_close(&mut created, Some(1))
+        .await
+        .expect("Closing at the current revision should succeed");
+}
+
+/// Test that task_ref, token_account, counterparty and data_hash cannot be amended
+#[tokio::test]
+async fn test_update_attestation_immutable_field() {
+    let mut created = create_counterparty_signed_attestation().await;
+
+    let new_data = AttestationDataBuilder::new(
+        [2u8; 32],
+        created.agent_mint,
+        created.counterparty,
+        2,
+        [0u8; 32],
+    )
+    .build();
+    let err = send_update(&mut created, new_data, 1)
+        .await
+        .expect_err("Changing task_ref should fail");
+    assert!(
+        err.contains("ImmutableAttestationField") || err.contains("6106"),
+        "Expected ImmutableAttestationField error (6106), got: {}",
+        err
+    );
+}
+
+/// Test that AgentOwnerSigned attestations cannot be amended
+///
+/// They carry no counterparty SIWS signature to refresh, so the handler
+/// rejects them before touching the compressed account.
+#[tokio::test]
+async fn test_update_attestation_not_amendable() {
+    let LightTestEnv { mut rpc, payer, .. } = setup_light_test_env().await;
+
+    let sas_schema = Pubkey::new_unique();
+    let (schema_config, bump) = derive_schema_config_pda(&sas_schema);
+    rpc.set_account(
+        schema_config,
+        Account {
+            lamports: 1_000_000,
+            data: create_schema_config_data(&sas_schema, SignatureMode::AgentOwnerSigned, bump),
+            owner: SATI_PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let data = AttestationDataBuilder::new(
+        [1u8; 32],
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        2,
+        [0u8; 32],
+    )
+    .build();
+    let params = UpdateParams {
+        current_data: data.clone(),
+        num_signatures: 1,
+        signature1: [0u8; 64],
+        signature2: [0u8; 64],
+        revision: 0,
+        new_data: data,
+        proof: ValidityProof::default(),
+        account_meta: CompressedAccountMeta::default(),
+    };
+    let update_ix =
+        build_update_compressed_attestation_ix(&payer.pubkey(), &schema_config, params, vec![]);
+
+    let result = rpc
+        .create_and_send_transaction(&[update_ix], &payer.pubkey(), &[&payer])
+        .await;
+    let err = format!("{:?}", result.expect_err("AgentOwnerSigned should fail"));
+    assert!(
+        err.contains("AttestationNotAmendable") || err.contains("6105"),
+        "Expected AttestationNotAmendable error (6105), got: {}",
+        err
+    );
+}
@@ -0,0 +1,471 @@
+//! Tests for update_regular_attestation instruction (SAS storage)
+//!
+//! These tests verify:
+//! - The provider can rewrite a ReputationScore, bumping its revision
+//! - The SAS schema account and the stored attestation must belong to the
+//!   schema config's schema and the SATI credential
+//! - Only the provider named in the data can sign the rewrite
+//! - The signature must bind the data currently stored and the revision, so it
+//!   cannot be replayed once the score returns to that data
+//!
+//! The rewrite itself CPIs into SAS, so the happy path also needs the SAS
+//! program binary (see `add_sas_program`). The rejection tests fail before any
+//! CPI and run against mocked SAS accounts. The fixture helpers are shared with
+//! the batch tests.
+
+use litesvm::LiteSVM;
+use sati::signature::{compute_reputation_nonce, compute_reputation_update_hash};
+use solana_sdk::{
+    account::Account,
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+use crate::common::{
+    accounts::{
+        compute_anchor_account_discriminator, create_default_registry, create_funded_keypair,
+        create_mock_agent_record, create_mock_sas_attestation, create_mock_sas_credential,
+        create_mock_sas_schema,
+    },
+    ed25519::{
+        create_ed25519_ix, generate_ed25519_keypair, keypair_to_pubkey, sign_message,
+        AttestationDataBuilder,
+    },
+    instructions::{
+        build_update_regular_attestation_ix, SignatureMode, StorageType, UpdateRegularParams,
+    },
+    setup::{
+        add_sas_program, assert_error, derive_attestation_revision_pda, derive_sas_attestation_pda,
+        derive_sas_schema_pda, derive_sati_credential_pda, derive_sati_pda,
+        derive_schema_config_pda, send_tx, setup_litesvm, SATI_CREDENTIAL_NAME, SATI_PROGRAM_ID,
+    },
+};
+
+/// SAS schema name for ReputationScore
+const SCHEMA_NAME: &str = "SATIReputationScoreV1";
+
+/// SAS attestation header size before the data payload
+const SAS_DATA_OFFSET: usize = 1 + 32 + 32 + 32 + 4;
+
+/// A ReputationScore attestation stored in SAS for a fresh agent
+pub(super) struct ScoreFixture {
+    pub(super) svm: LiteSVM,
+    pub(super) payer: Keypair,
+    pub(super) provider_keypair: ed25519_dalek::SigningKey,
+    pub(super) provider: Pubkey,
+    pub(super) agent_mint: Pubkey,
+    pub(super) schema_config: Pubkey,
+    pub(super) sas_schema: Pubkey,
+    pub(super) credential: Pubkey,
+    pub(super) attestation: Pubkey,
+    pub(super) current_data: Vec<u8>,
+}
+
+/// Build ReputationScore data with `outcome` for `agent_mint`, scored by `provider`
+pub(super) fn score_data(agent_mint: &Pubkey, provider: &Pubkey, outcome: u8) -> Vec<u8> {
+    AttestationDataBuilder::new([1u8; 32], *agent_mint, *provider, outcome, [0u8; 32]).build()
+}
+
+/// Create a CounterpartySigned, Regular SchemaConfig for `sas_schema`
+fn create_schema_config(svm: &mut LiteSVM, sas_schema: &Pubkey) -> Pubkey {
+    let (schema_config, bump) = derive_schema_config_pda(sas_schema);
+    let mut data = compute_anchor_account_discriminator("SchemaConfig").to_vec();
+    data.extend_from_slice(sas_schema.as_ref());
+    data.push(SignatureMode::CounterpartySigned as u8);
+    data.push(StorageType::Regular as u8);
+    data.push(0); // delegation_schema = None
+    data.push(1); // closeable
+    data.extend_from_slice(&(SCHEMA_NAME.len() as u32).to_le_bytes());
+    data.extend_from_slice(SCHEMA_NAME.as_bytes());
+    data.push(bump);
+    data.push(0); // min_verification_level
+    svm.set_account(
+        schema_config,
+        Account {
+            lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+            data,
+            owner: SATI_PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+    schema_config
+}
+
+/// Create a SAS schema under the SATI credential
+pub(super) fn create_sas_schema(svm: &mut LiteSVM, credential: &Pubkey, name: &str) -> Pubkey {
+    let (sas_schema, _) = derive_sas_schema_pda(credential, name, 1);
+    create_mock_sas_schema(
+        svm,
+        &sas_schema,
+        credential,
+        name,
+        &[7, 7, 7, 0, 0, 9],
+        &[
+            "task_ref",
+            "token_account",
+            "counterparty",
+            "score",
+            "content_type",
+            "content",
+        ],
+    );
+    sas_schema
+}
+
+/// Set up a registry, the SATI credential and schema, an agent and its current score
+pub(super) fn setup_score(with_sas_program: bool) -> ScoreFixture {
+    let mut svm = setup_litesvm();
+    if with_sas_program {
+        add_sas_program(&mut svm);
+    }
+    let payer = create_funded_keypair(&mut svm, 10_000_000_000);
+    create_default_registry(&mut svm);
+
+    let sati_pda = derive_sati_pda().0;
+    let (credential, _) = derive_sati_credential_pda();
+    create_mock_sas_credential(
+        &mut svm,
+        &credential,
+        &sati_pda,
+        SATI_CREDENTIAL_NAME,
+        &sati_pda,
+    );
+    let sas_schema = create_sas_schema(&mut svm, &credential, SCHEMA_NAME);
+    let schema_config = create_schema_config(&mut svm, &sas_schema);
+
+    let provider_keypair = generate_ed25519_keypair();
+    let provider = keypair_to_pubkey(&provider_keypair);
+    let agent_mint = Pubkey::new_unique();
+    create_mock_agent_record(&mut svm, &agent_mint, 1, 0);
+
+    let current_data = score_data(&agent_mint, &provider, 2);
+    let nonce = compute_reputation_nonce(&provider, &agent_mint);
+    let (attestation, _) = derive_sas_attestation_pda(&credential, &sas_schema, &nonce);
+    create_mock_sas_attestation(
+        &mut svm,
+        &attestation,
+        &nonce,
+        &credential,
+        &sas_schema,
+        &current_data,
+        &sati_pda,
+    );
+
+    ScoreFixture {
+        svm,
+        payer,
+        provider_keypair,
+        provider,
+        agent_mint,
+        schema_config,
+        sas_schema,
+        credential,
+        attestation,
+        current_data,
+    }
+}
+
+/// Rewrite `fixture.attestation` to `new_data`, with `signer` signing over `signed_current`
+/// at the attestation's current revision
+fn send_update(
+    fixture: &mut ScoreFixture,
+    sas_schema: &Pubkey,
+    signer: &ed25519_dalek::SigningKey,
+    signed_current: &[u8],
+    new_data: Vec<u8>,
+) -> Result<(), String> {
+    let revision = current_revision(&fixture.svm, &fixture.attestation);
+    let ixs = update_ixs(
+        fixture,
+        sas_schema,
+        signer,
+        signed_current,
+        revision,
+        new_data,
+    );
+    send_as_payer(fixture, &ixs)
+}
+
+/// Ed25519 and update_regular_attestation instructions for one signed transition
+fn update_ixs(
+    fixture: &ScoreFixture,
+    sas_schema: &Pubkey,
+    signer: &ed25519_dalek::SigningKey,
+    signed_current: &[u8],
+    revision: u32,
+    new_data: Vec<u8>,
+) -> [Instruction; 2] {
+    let message =
+        compute_reputation_update_hash(&fixture.sas_schema, signed_current, &new_data, revision, 0);
+    let signature = sign_message(signer, &message);
+    let ed25519_ix = create_ed25519_ix(&keypair_to_pubkey(signer), &message, &signature);
+
+    let update_ix = build_update_regular_attestation_ix(
+        &fixture.payer.pubkey(),
+        &fixture.schema_config,
+        &fixture.credential,
+        sas_schema,
+        &fixture.attestation,
+        &fixture.agent_mint,
+        UpdateRegularParams {
+            data: new_data,
+            expiry: 0,
+        },
+    );
+    [ed25519_ix, update_ix]
+}
+
+/// Send `ixs` signed and paid by the fixture payer
+pub(super) fn send_as_payer(fixture: &mut ScoreFixture, ixs: &[Instruction]) -> Result<(), String> {
+    send_tx(&mut fixture.svm, &[&fixture.payer], ixs)
+}
+
+/// Data payload currently stored in the SAS attestation
+pub(super) fn stored_data(svm: &LiteSVM, attestation: &Pubkey) -> Vec<u8> {
+    let account = svm
+        .get_account(attestation)
+        .expect("Attestation should exist");
+    let len = u32::from_le_bytes(
+        account.data[SAS_DATA_OFFSET - 4..SAS_DATA_OFFSET]
+            .try_into()
+            .unwrap(),
+    ) as usize;
+    account.data[SAS_DATA_OFFSET..SAS_DATA_OFFSET + len].to_vec()
+}
+
+/// Revision stored in the attestation's counter PDA (discriminator(8) + revision(4) + bump(1))
+pub(super) fn stored_revision(svm: &LiteSVM, attestation: &Pubkey) -> u32 {
+    let account = svm
+        .get_account(&derive_attestation_revision_pda(attestation).0)
+        .expect("Revision account should exist");
+    u32::from_le_bytes(account.data[8..12].try_into().unwrap())
+}
+
+/// Revision the next signature must bind (0 before the first write)
+fn current_revision(svm: &LiteSVM, attestation: &Pubkey) -> u32 {
+    match svm.get_account(&derive_attestation_revision_pda(attestation).0) {
+        Some(account) if !account.data.is_empty() => stored_revision(svm, attestation),
+        _ => 0,
+    }
+}
+
+/// Test that the provider can rewrite a score, and each rewrite bumps the revision
+#[test]
+fn test_update_regular_attestation_success() {
+    let mut fixture = setup_score(true);
+    let provider_keypair = fixture.provider_keypair.clone();
+    let sas_schema = fixture.sas_schema;
+
+    let new_data = score_data(&fixture.agent_mint, &fixture.provider, 0);
+    let current_data = fixture.current_data.clone();
+    send_update(
+        &mut fixture,
+        &sas_schema,
+        &provider_keypair,
+        &current_data,
+        new_data.clone(),
+    )
+    .expect("update_regular_attestation should succeed");
+
+    assert_eq!(stored_data(&fixture.svm, &fixture.attestation), new_data);
+    assert_eq!(stored_revision(&fixture.svm, &fixture.attestation), 1);
+
+    // The next rewrite must sign over the data now stored
+    let newer_data = score_data(&fixture.agent_mint, &fixture.provider, 1);
+    send_update(
+        &mut fixture,
+        &sas_schema,
+        &provider_keypair,
+        &new_data,
+        newer_data.clone(),
+    )
+    .expect("Second rewrite should succeed");
+
+    assert_eq!(stored_data(&fixture.svm, &fixture.attestation), newer_data);
+    assert_eq!(stored_revision(&fixture.svm, &fixture.attestation), 2);
+
+    println!("✅ test_update_regular_attestation_success passed");
+}
+
+/// Test that a rewrite signature cannot be replayed once the score returns to
+/// the data it was signed over (A→B, B→A, then the A→B signature again)
+#[test]
+fn test_update_regular_attestation_replay_rejected() {
+    let mut fixture = setup_score(true);
+    let provider_keypair = fixture.provider_keypair.clone();
+    let sas_schema = fixture.sas_schema;
+
+    let data_a = fixture.current_data.clone();
+    let data_b = score_data(&fixture.agent_mint, &fixture.provider, 0);
+    let a_to_b = update_ixs(
+        &fixture,
+        &sas_schema,
+        &provider_keypair,
+        &data_a,
+        0,
+        data_b.clone(),
+    );
+    send_as_payer(&mut fixture, &a_to_b).expect("A→B should succeed");
+
+    send_update(
+        &mut fixture,
+        &sas_schema,
+        &provider_keypair,
+        &data_b,
+        data_a.clone(),
+    )
+    .expect("B→A should succeed");
+    assert_eq!(stored_data(&fixture.svm, &fixture.attestation), data_a);
+
+    let err = send_as_payer(&mut fixture, &a_to_b).expect_err("Replayed A→B should fail");
+    assert_error(&err, "CounterpartySignatureNotFound", 6037);
+    assert_eq!(stored_data(&fixture.svm, &fixture.attestation), data_a);
+    assert_eq!(stored_revision(&fixture.svm, &fixture.attestation), 2);
+
+    println!("✅ test_update_regular_attestation_replay_rejected passed");
+}
+
+/// Test that the SAS schema account must be the schema config's schema
+#[test]
+fn test_update_regular_attestation_wrong_sas_schema() {
+    let mut fixture = setup_score(false);
+    let provider_keypair = fixture.provider_keypair.clone();
+    let other_schema = create_sas_schema(&mut fixture.svm, &fixture.credential, "SATIDelegateV1");
+
+    let new_data = score_data(&fixture.agent_mint, &fixture.provider, 0);
+    let current_data = fixture.current_data.clone();
+    let err = send_update(
+        &mut fixture,
+        &other_schema,
+        &provider_keypair,
+        &current_data,
+        new_data,
+    )
+    .expect_err("Foreign SAS schema should fail");
+    assert_error(&err, "SasSchemaMismatch", 6109);
+
+    println!("✅ test_update_regular_attestation_wrong_sas_schema passed");
+}
+
+/// Test that an attestation stored under another schema cannot be rewritten
+///
+/// A Delegate attestation for the same (provider, agent) pair has the same
+/// nonce, but belongs to a schema the provider does not control.
+#[test]
+fn test_update_regular_attestation_foreign_attestation() {
+    let mut fixture = setup_score(false);
+    let provider_keypair = fixture.provider_keypair.clone();
+    let sas_schema = fixture.sas_schema;
+
+    let other_schema = create_sas_schema(&mut fixture.svm, &fixture.credential, "SATIDelegateV1");
+    let nonce = compute_reputation_nonce(&fixture.provider, &fixture.agent_mint);
+    let (foreign, _) = derive_sas_attestation_pda(&fixture.credential, &other_schema, &nonce);
+    create_mock_sas_attestation(
+        &mut fixture.svm,
+        &foreign,
+        &nonce,
+        &fixture.credential,
+        &other_schema,
+        &fixture.current_data,
+        &derive_sati_pda().0,
+    );
+    fixture.attestation = foreign;
+
+    let new_data = score_data(&fixture.agent_mint, &fixture.provider, 0);
+    let current_data = fixture.current_data.clone();
+    let err = send_update(
+        &mut fixture,
+        &sas_schema,
+        &provider_keypair,
+        &current_data,
+        new_data,
+    )
+    .expect_err("Attestation under another schema should fail");
+    assert_error(&err, "SasSchemaMismatch", 6109);
+
+    println!("✅ test_update_regular_attestation_foreign_attestation passed");
+}
+
+/// Test that an attestation issued under another credential cannot be rewritten
+#[test]
+fn test_update_regular_attestation_wrong_credential() {
+    let mut fixture = setup_score(false);
+    let provider_keypair = fixture.provider_keypair.clone();
+    let sas_schema = fixture.sas_schema;
+
+    let other_credential = Pubkey::new_unique();
+    let nonce = compute_reputation_nonce(&fixture.provider, &fixture.agent_mint);
+    let (foreign, _) = derive_sas_attestation_pda(&other_credential, &sas_schema, &nonce);
+    create_mock_sas_attestation(
+        &mut fixture.svm,
+        &foreign,
+        &nonce,
+        &other_credential,
+        &sas_schema,
+        &fixture.current_data,
+        &Pubkey::new_unique(),
+    );
+    fixture.attestation = foreign;
+
+    let new_data = score_data(&fixture.agent_mint, &fixture.provider, 0);
+    let current_data = fixture.current_data.clone();
+    let err = send_update(
+        &mut fixture,
+        &sas_schema,
+        &provider_keypair,
+        &current_data,
+        new_data,
+    )
+    .expect_err("Attestation under another credential should fail");
+    assert_error(&err, "SasCredentialMismatch", 6110);
+
+    println!("✅ test_update_regular_attestation_wrong_credential passed");
+}
+
+/// Test that only the provider named in the data can sign the rewrite
+#[test]
+fn test_update_regular_attestation_wrong_signer() {
+    let mut fixture = setup_score(false);
+    let sas_schema = fixture.sas_schema;
+    let impostor = generate_ed25519_keypair();
+
+    let new_data = score_data(&fixture.agent_mint, &fixture.provider, 0);
+    let current_data = fixture.current_data.clone();
+    let err = send_update(
+        &mut fixture,
+        &sas_schema,
+        &impostor,
+        &current_data,
+        new_data,
+    )
+    .expect_err("Non-provider signature should fail");
+    assert_error(&err, "SignatureMismatch", 6019);
+
+    println!("✅ test_update_regular_attestation_wrong_signer passed");
+}
+
+/// Test that a signature over data that is no longer stored is rejected
+#[test]
+fn test_update_regular_attestation_stale_current_hash() {
+    let mut fixture = setup_score(false);
+    let provider_keypair = fixture.provider_keypair.clone();
+    let sas_schema = fixture.sas_schema;
+
+    // Signed when the score was still Neutral; it has since moved to Positive
+    let stale_data = score_data(&fixture.agent_mint, &fixture.provider, 1);
+    let new_data = score_data(&fixture.agent_mint, &fixture.provider, 0);
+    let err = send_update(
+        &mut fixture,
+        &sas_schema,
+        &provider_keypair,
+        &stale_data,
+        new_data,
+    )
+    .expect_err("Stale current_hash should fail");
+    assert_error(&err, "CounterpartySignatureNotFound", 6037);
+
+    println!("✅ test_update_regular_attestation_stale_current_hash passed");
+}
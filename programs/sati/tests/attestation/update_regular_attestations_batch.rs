@@ -0,0 +1,338 @@
+//! Tests for update_regular_attestations_batch instruction (SAS storage)
+//!
+//! These tests verify:
+//! - One provider signature can rewrite existing scores and create new ones
+//! - The batch is all-or-nothing: a failing entry reverts the ones before it
+//! - Each entry's revision is signed, so a batch cannot be replayed after a close
+//! - The SAS schema and per-entry accounts must match the schema config
+//! - An agent or attestation may appear only once per batch
+//!
+//! Like the single rewrite tests, the successful writes need the SAS program
+//! binary (see `add_sas_program`); rejections before any CPI use mocked accounts.
+
+use sati::signature::{
+    compute_reputation_batch_hash, compute_reputation_nonce, ReputationBatchEntry,
+};
+use solana_sdk::{
+    account::Account,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signer::Signer,
+};
+
+use super::update_regular_attestation::{
+    create_sas_schema, score_data, send_as_payer, setup_score, stored_data, stored_revision,
+    ScoreFixture,
+};
+use crate::common::{
+    accounts::create_mock_agent_record,
+    ed25519::{create_ed25519_ix, keypair_to_pubkey, sign_message},
+    instructions::{build_update_regular_attestations_batch_ix, UpdateRegularBatchParams},
+    setup::{
+        assert_error, derive_agent_record_pda, derive_attestation_revision_pda,
+        derive_sas_attestation_pda,
+    },
+};
+
+/// Register a second agent and return its mint and (not yet created) score address
+fn add_agent(fixture: &mut ScoreFixture, member_number: u64) -> (Pubkey, Pubkey) {
+    let agent_mint = Pubkey::new_unique();
+    create_mock_agent_record(&mut fixture.svm, &agent_mint, member_number, 0);
+    let nonce = compute_reputation_nonce(&fixture.provider, &agent_mint);
+    let (attestation, _) =
+        derive_sas_attestation_pda(&fixture.credential, &fixture.sas_schema, &nonce);
+    (agent_mint, attestation)
+}
+
+/// Per-entry remaining accounts: attestation, attestation_revision, agent_record
+fn entry_accounts(attestation: &Pubkey, agent_mint: &Pubkey) -> Vec<AccountMeta> {
+    vec![
+        AccountMeta::new(*attestation, false),
+        AccountMeta::new(derive_attestation_revision_pda(attestation).0, false),
+        AccountMeta::new_readonly(derive_agent_record_pda(agent_mint).0, false),
+    ]
+}
+
+/// Provider's Ed25519 instruction over `(current data, new data, revision)` per entry
+fn sign_batch(fixture: &ScoreFixture, entries: &[ReputationBatchEntry]) -> Instruction {
+    let message = compute_reputation_batch_hash(&fixture.sas_schema, entries, 0);
+    let signature = sign_message(&fixture.provider_keypair, &message);
+    create_ed25519_ix(
+        &keypair_to_pubkey(&fixture.provider_keypair),
+        &message,
+        &signature,
+    )
+}
+
+/// Send a batch with the given signature instruction and per-entry accounts
+fn send_batch(
+    fixture: &mut ScoreFixture,
+    sas_schema: &Pubkey,
+    ed25519_ix: Instruction,
+    entries: Vec<Vec<u8>>,
+    accounts: Vec<AccountMeta>,
+) -> Result<(), String> {
+    let batch_ix = build_update_regular_attestations_batch_ix(
+        &fixture.payer.pubkey(),
+        &fixture.schema_config,
+        &fixture.credential,
+        sas_schema,
+        UpdateRegularBatchParams { entries, expiry: 0 },
+        accounts,
+    );
+    send_as_payer(fixture, &[ed25519_ix, batch_ix])
+}
+
+/// Test that one batch rewrites an existing score and creates a new one
+#[test]
+fn test_batch_mixed_create_and_update() {
+    let mut fixture = setup_score(true);
+    let sas_schema = fixture.sas_schema;
+    let (new_agent, new_attestation) = add_agent(&mut fixture, 2);
+
+    let updated = score_data(&fixture.agent_mint, &fixture.provider, 0);
+    let created = score_data(&new_agent, &fixture.provider, 2);
+    let ed25519_ix = sign_batch(
+        &fixture,
+        &[
+            (Some(&fixture.current_data), &updated, 0),
+            (None, &created, 0),
+        ],
+    );
+    let mut accounts = entry_accounts(&fixture.attestation, &fixture.agent_mint);
+    accounts.extend(entry_accounts(&new_attestation, &new_agent));
+
+    send_batch(
+        &mut fixture,
+        &sas_schema,
+        ed25519_ix,
+        vec![updated.clone(), created.clone()],
+        accounts,
+    )
+    .expect("Mixed batch should succeed");
+
+    assert_eq!(stored_data(&fixture.svm, &fixture.attestation), updated);
+    assert_eq!(stored_data(&fixture.svm, &new_attestation), created);
+    assert_eq!(stored_revision(&fixture.svm, &fixture.attestation), 1);
+    assert_eq!(stored_revision(&fixture.svm, &new_attestation), 1);
+
+    println!("✅ test_batch_mixed_create_and_update passed");
+}
+
+/// Test that a failing entry reverts the entries written before it
+#[test]
+fn test_batch_rolls_back_on_failure() {
+    let mut fixture = setup_score(true);
+    let sas_schema = fixture.sas_schema;
+    let (new_agent, _) = add_agent(&mut fixture, 2);
+
+    // The second entry points at an address that is not its SAS attestation PDA,
+    // so SAS rejects it after the first entry has been rewritten
+    let wrong_attestation = Pubkey::new_unique();
+    let updated = score_data(&fixture.agent_mint, &fixture.provider, 0);
+    let created = score_data(&new_agent, &fixture.provider, 2);
+    let ed25519_ix = sign_batch(
+        &fixture,
+        &[
+            (Some(&fixture.current_data), &updated, 0),
+            (None, &created, 0),
+        ],
+    );
+    let mut accounts = entry_accounts(&fixture.attestation, &fixture.agent_mint);
+    accounts.extend(entry_accounts(&wrong_attestation, &new_agent));
+
+    send_batch(
+        &mut fixture,
+        &sas_schema,
+        ed25519_ix,
+        vec![updated, created],
+        accounts,
+    )
+    .expect_err("Batch with an invalid entry should fail");
+
+    assert_eq!(
+        stored_data(&fixture.svm, &fixture.attestation),
+        fixture.current_data,
+        "first entry must be rolled back"
+    );
+    assert!(fixture
+        .svm
+        .get_account(&derive_attestation_revision_pda(&fixture.attestation).0)
+        .is_none_or(|account| account.data.is_empty()));
+
+    println!("✅ test_batch_rolls_back_on_failure passed");
+}
+
+/// Test that a batch signature cannot restore a score after it was closed
+#[test]
+fn test_batch_replay_rejected() {
+    let mut fixture = setup_score(true);
+    let sas_schema = fixture.sas_schema;
+    let (new_agent, new_attestation) = add_agent(&mut fixture, 2);
+
+    let created = score_data(&new_agent, &fixture.provider, 2);
+    let ed25519_ix = sign_batch(&fixture, &[(None, &created, 0)]);
+    send_batch(
+        &mut fixture,
+        &sas_schema,
+        ed25519_ix.clone(),
+        vec![created.clone()],
+        entry_accounts(&new_attestation, &new_agent),
+    )
+    .expect("Batch create should succeed");
+
+    // Close the score the way SAS does (the account is emptied and defunded)
+    fixture
+        .svm
+        .set_account(new_attestation, Account::default())
+        .unwrap();
+
+    let err = send_batch(
+        &mut fixture,
+        &sas_schema,
+        ed25519_ix,
+        vec![created.clone()],
+        entry_accounts(&new_attestation, &new_agent),
+    )
+    .expect_err("Replayed batch should fail");
+    assert_error(&err, "CounterpartySignatureNotFound", 6037);
+
+    // A fresh signature over the current revision is accepted
+    let ed25519_ix = sign_batch(&fixture, &[(None, &created, 1)]);
+    send_batch(
+        &mut fixture,
+        &sas_schema,
+        ed25519_ix,
+        vec![created],
+        entry_accounts(&new_attestation, &new_agent),
+    )
+    .expect("Freshly signed batch should succeed");
+    assert_eq!(stored_revision(&fixture.svm, &new_attestation), 2);
+
+    println!("✅ test_batch_replay_rejected passed");
+}
+
+/// Test that the SAS schema account must be the schema config's schema
+#[test]
+fn test_batch_wrong_sas_schema() {
+    let mut fixture = setup_score(false);
+    let other_schema = create_sas_schema(&mut fixture.svm, &fixture.credential, "SATIDelegateV1");
+
+    let updated = score_data(&fixture.agent_mint, &fixture.provider, 0);
+    let ed25519_ix = sign_batch(&fixture, &[(Some(&fixture.current_data), &updated, 0)]);
+    let accounts = entry_accounts(&fixture.attestation, &fixture.agent_mint);
+
+    let err = send_batch(
+        &mut fixture,
+        &other_schema,
+        ed25519_ix,
+        vec![updated],
+        accounts,
+    )
+    .expect_err("Foreign SAS schema should fail");
+    assert_error(&err, "SasSchemaMismatch", 6109);
+
+    println!("✅ test_batch_wrong_sas_schema passed");
+}
+
+/// Test that each entry must pass its own attestation revision PDA
+#[test]
+fn test_batch_wrong_revision_account() {
+    let mut fixture = setup_score(false);
+    let sas_schema = fixture.sas_schema;
+
+    let updated = score_data(&fixture.agent_mint, &fixture.provider, 0);
+    let ed25519_ix = sign_batch(&fixture, &[(Some(&fixture.current_data), &updated, 0)]);
+    let mut accounts = entry_accounts(&fixture.attestation, &fixture.agent_mint);
+    accounts[1] = AccountMeta::new(Pubkey::new_unique(), false);
+
+    let err = send_batch(
+        &mut fixture,
+        &sas_schema,
+        ed25519_ix,
+        vec![updated],
+        accounts,
+    )
+    .expect_err("Wrong revision account should fail");
+    assert_error(&err, "InvalidAttestationRevisionAccount", 6112);
+
+    println!("✅ test_batch_wrong_revision_account passed");
+}
+
+/// Test that the remaining accounts must match the per-entry layout
+#[test]
+fn test_batch_missing_entry_accounts() {
+    let mut fixture = setup_score(false);
+    let sas_schema = fixture.sas_schema;
+
+    let updated = score_data(&fixture.agent_mint, &fixture.provider, 0);
+    let ed25519_ix = sign_batch(&fixture, &[(Some(&fixture.current_data), &updated, 0)]);
+    let mut accounts = entry_accounts(&fixture.attestation, &fixture.agent_mint);
+    accounts.pop();
+
+    let err = send_batch(
+        &mut fixture,
+        &sas_schema,
+        ed25519_ix,
+        vec![updated],
+        accounts,
+    )
+    .expect_err("Missing agent_record should fail");
+    assert_error(&err, "InvalidAttestationBatchAccounts", 6111);
+
+    println!("✅ test_batch_missing_entry_accounts passed");
+}
+
+/// Test that a batch cannot name the same agent or attestation twice
+#[test]
+fn test_batch_duplicate_entry_rejected() {
+    let mut fixture = setup_score(false);
+    let sas_schema = fixture.sas_schema;
+    let (new_agent, _) = add_agent(&mut fixture, 2);
+
+    // The same entry repeated
+    let updated = score_data(&fixture.agent_mint, &fixture.provider, 0);
+    let ed25519_ix = sign_batch(
+        &fixture,
+        &[
+            (Some(&fixture.current_data), &updated, 0),
+            (Some(&fixture.current_data), &updated, 0),
+        ],
+    );
+    let mut accounts = entry_accounts(&fixture.attestation, &fixture.agent_mint);
+    accounts.extend(entry_accounts(&fixture.attestation, &fixture.agent_mint));
+
+    let err = send_batch(
+        &mut fixture,
+        &sas_schema,
+        ed25519_ix,
+        vec![updated.clone(), updated.clone()],
+        accounts,
+    )
+    .expect_err("Repeated entry should fail");
+    assert_error(&err, "DuplicateBatchAttestation", 6116);
+
+    // A second agent pointed at the first entry's attestation account
+    let created = score_data(&new_agent, &fixture.provider, 2);
+    let ed25519_ix = sign_batch(
+        &fixture,
+        &[
+            (Some(&fixture.current_data), &updated, 0),
+            (None, &created, 0),
+        ],
+    );
+    let mut accounts = entry_accounts(&fixture.attestation, &fixture.agent_mint);
+    accounts.extend(entry_accounts(&fixture.attestation, &new_agent));
+
+    let err = send_batch(
+        &mut fixture,
+        &sas_schema,
+        ed25519_ix,
+        vec![updated, created],
+        accounts,
+    )
+    .expect_err("Reused attestation account should fail");
+    assert_error(&err, "DuplicateBatchAttestation", 6116);
+
+    println!("✅ test_batch_duplicate_entry_rejected passed");
+}
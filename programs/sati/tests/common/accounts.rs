@@ -5,26 +5,46 @@
 //! - Only mock accounts when absolutely necessary (and compute discriminators correctly)
 
 use litesvm::LiteSVM;
-use solana_sdk::{account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer};
+use solana_attestation_service_client::programs::SOLANA_ATTESTATION_SERVICE_ID;
+use solana_sdk::{
+    account::Account, pubkey::Pubkey, rent::Rent, signature::Keypair, signer::Signer,
+};
 use spl_pod::optional_keys::OptionalNonZeroPubkey;
 use spl_token_2022::{
-    extension::{BaseStateWithExtensionsMut, ExtensionType, StateWithExtensionsMut},
+    extension::{
+        metadata_pointer::MetadataPointer, BaseStateWithExtensionsMut, ExtensionType,
+        StateWithExtensionsMut,
+    },
     state::Mint,
 };
 use spl_token_group_interface::state::TokenGroup;
+use spl_token_metadata_interface::state::TokenMetadata;
 
-use crate::common::setup::{ATA_PROGRAM_ID, SATI_PROGRAM_ID, TOKEN_2022_PROGRAM_ID};
+use crate::common::setup::{
+    derive_agent_index_pda, derive_agent_record_pda, derive_registry_config_pda, ATA_PROGRAM_ID,
+    SATI_PROGRAM_ID, TOKEN_2022_PROGRAM_ID,
+};
 
 /// RegistryConfig account size (matches Rust struct)
-pub const REGISTRY_CONFIG_SIZE: usize = 8 + 32 + 32 + 8 + 1; // 81 bytes
+pub const REGISTRY_CONFIG_SIZE: usize = 8 + 32 + 32 + 8 + 1 + 1 + 32 + 8 + 8 + 1 + 32 + 8; // 171 bytes
+
+/// Version 0 RegistryConfig size (before version + pending_authority)
+pub const LEGACY_REGISTRY_CONFIG_SIZE: usize = 8 + 32 + 32 + 8 + 1; // 81 bytes
+
+/// AgentIndex account size (matches Rust struct)
+pub const AGENT_INDEX_SIZE: usize = 8 + 32 + 1; // 41 bytes
+
+/// AgentRecord account size (matches Rust struct)
+pub const AGENT_RECORD_SIZE: usize = 8 + 32 + 8 + 8 + 8 + 1 + 1 + 1 + 1; // 68 bytes
 
 /// Maximum SchemaConfig account size (delegation_schema = Some, name = 32 chars).
 ///
 /// Fields: discriminator(8) + sas_schema(32) + signature_mode(1) + storage_type(1)
-/// + delegation_schema(33) + closeable(1) + name(36) + bump(1) = 113 bytes.
+/// + delegation_schema(33) + closeable(1) + name(36) + bump(1)
+/// + min_verification_level(1) = 114 bytes.
 ///
-/// For tests with "Feedback" (8 chars) and None delegation, actual size = 57 bytes.
-pub const SCHEMA_CONFIG_SIZE: usize = 8 + 32 + 1 + 1 + 1 + 32 + 1 + 4 + 32 + 1; // 113 bytes max
+/// For tests with "Feedback" (8 chars) and None delegation, actual size = 58 bytes.
+pub const SCHEMA_CONFIG_SIZE: usize = 8 + 32 + 1 + 1 + 1 + 32 + 1 + 4 + 32 + 1 + 1; // 114 bytes max
 
 /// Airdrop SOL to an account
 pub fn airdrop(svm: &mut LiteSVM, pubkey: &Pubkey, lamports: u64) {
@@ -98,17 +118,8 @@ pub fn compute_anchor_account_discriminator(account_name: &str) -> [u8; 8] {
     result[..8].try_into().unwrap()
 }
 
-/// Create an initialized RegistryConfig account for testing
-///
-/// This mocks an already-initialized registry config, useful for testing
-/// instructions that require an existing registry without going through initialize.
-pub fn create_initialized_registry(
-    svm: &mut LiteSVM,
-    registry_pda: &Pubkey,
-    authority: &Pubkey,
-    group_mint: &Pubkey,
-    bump: u8,
-) {
+/// Build the data of an initialized, unpaused RegistryConfig account
+pub fn mock_registry_config_data(authority: &Pubkey, group_mint: &Pubkey, bump: u8) -> Vec<u8> {
     let mut data = vec![0u8; REGISTRY_CONFIG_SIZE];
 
     // Compute correct Anchor discriminator for RegistryConfig
@@ -127,6 +138,28 @@ pub fn create_initialized_registry(
     // bump (1 byte) at offset 80
     data[80] = bump;
 
+    // version (1 byte) at offset 81
+    // pending_authority (32 bytes) at 82, timelock_delay (8) at 114,
+    // next_action_id (8) at 122, paused (1) at 130, fee_mint (32) at 131 and
+    // fee_amount (8) at 163 stay zero
+    data[81] = 4;
+
+    data
+}
+
+/// Create an initialized RegistryConfig account for testing
+///
+/// This mocks an already-initialized registry config, useful for testing
+/// instructions that require an existing registry without going through initialize.
+pub fn create_initialized_registry(
+    svm: &mut LiteSVM,
+    registry_pda: &Pubkey,
+    authority: &Pubkey,
+    group_mint: &Pubkey,
+    bump: u8,
+) {
+    let data = mock_registry_config_data(authority, group_mint, bump);
+
     let lamports = svm.minimum_balance_for_rent_exemption(REGISTRY_CONFIG_SIZE);
     let account = Account {
         lamports,
@@ -140,6 +173,112 @@ pub fn create_initialized_registry(
         .expect("Failed to set registry config");
 }
 
+/// Create a version 0 (pre-migration) RegistryConfig account for testing
+///
+/// Same as `create_initialized_registry` but truncated to the legacy 81-byte layout.
+pub fn create_legacy_registry(
+    svm: &mut LiteSVM,
+    registry_pda: &Pubkey,
+    authority: &Pubkey,
+    group_mint: &Pubkey,
+    bump: u8,
+) {
+    create_initialized_registry(svm, registry_pda, authority, group_mint, bump);
+
+    let mut account = svm.get_account(registry_pda).unwrap();
+    account.data.truncate(LEGACY_REGISTRY_CONFIG_SIZE);
+    account.lamports = svm.minimum_balance_for_rent_exemption(LEGACY_REGISTRY_CONFIG_SIZE);
+    svm.set_account(*registry_pda, account)
+        .expect("Failed to set legacy registry config");
+}
+
+/// Create an initialized RegistryConfig at the registry PDA for tests that only
+/// need it for the pause check (random authority and group mint)
+pub fn create_default_registry(svm: &mut LiteSVM) -> Pubkey {
+    let (registry_config, bump) = derive_registry_config_pda();
+    create_initialized_registry(
+        svm,
+        &registry_config,
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        bump,
+    );
+    registry_config
+}
+
+/// Create an AgentIndex account for testing
+///
+/// Layout: discriminator (8) + mint (32) + bump (1)
+pub fn create_mock_agent_index(svm: &mut LiteSVM, member_number: u64, mint: &Pubkey) -> Pubkey {
+    let (agent_index, bump) = derive_agent_index_pda(member_number);
+
+    let mut data = vec![0u8; AGENT_INDEX_SIZE];
+    let discriminator = compute_anchor_account_discriminator("AgentIndex");
+    data[0..8].copy_from_slice(&discriminator);
+    data[8..40].copy_from_slice(mint.as_ref());
+    data[40] = bump;
+
+    let lamports = svm.minimum_balance_for_rent_exemption(AGENT_INDEX_SIZE);
+    let account = Account {
+        lamports,
+        data,
+        owner: SATI_PROGRAM_ID,
+        executable: false,
+        rent_epoch: 0,
+    };
+
+    svm.set_account(agent_index, account)
+        .expect("Failed to set agent index");
+    agent_index
+}
+
+/// Build an AgentRecord account for testing (status: 0 = Active, 1 = Retired)
+///
+/// Layout: discriminator (8) + mint (32) + member_number (8) + registered_slot (8)
+/// + registered_at (8) + non_transferable (1) + status (1) + bump (1)
+///
+/// Returns the PDA address and account so it can be set on LiteSVM or LightProgramTest.
+pub fn mock_agent_record_account(
+    mint: &Pubkey,
+    member_number: u64,
+    status: u8,
+) -> (Pubkey, Account) {
+    let (agent_record, bump) = derive_agent_record_pda(mint);
+
+    let mut data = vec![0u8; AGENT_RECORD_SIZE];
+    let discriminator = compute_anchor_account_discriminator("AgentRecord");
+    data[0..8].copy_from_slice(&discriminator);
+    data[8..40].copy_from_slice(mint.as_ref());
+    data[40..48].copy_from_slice(&member_number.to_le_bytes());
+    // registered_slot (48..56) and registered_at (56..64) left zeroed
+    data[64] = 0; // non_transferable
+    data[65] = status;
+    data[66] = bump;
+    // pre_suspend_status (67) left zeroed = Active
+
+    let account = Account {
+        lamports: Rent::default().minimum_balance(AGENT_RECORD_SIZE),
+        data,
+        owner: SATI_PROGRAM_ID,
+        executable: false,
+        rent_epoch: 0,
+    };
+    (agent_record, account)
+}
+
+/// Create an AgentRecord account for testing (status: 0 = Active, 1 = Retired)
+pub fn create_mock_agent_record(
+    svm: &mut LiteSVM,
+    mint: &Pubkey,
+    member_number: u64,
+    status: u8,
+) -> Pubkey {
+    let (agent_record, account) = mock_agent_record_account(mint, member_number, status);
+    svm.set_account(agent_record, account)
+        .expect("Failed to set agent record");
+    agent_record
+}
+
 /// Create a mock Token-2022 mint for testing
 ///
 /// Creates a simple mint without extensions for testing purposes.
@@ -170,6 +309,77 @@ pub fn create_mock_token22_mint(svm: &mut LiteSVM, mint: &Pubkey, mint_authority
         .expect("Failed to set mock mint");
 }
 
+/// Create a mock agent mint with MetadataPointer and TokenMetadata extensions
+///
+/// Mirrors the layout produced by `register_agent` (metadata stored on the mint itself),
+/// so metadata instructions can be exercised without going through registration.
+pub fn create_mock_agent_mint(
+    svm: &mut LiteSVM,
+    mint: &Pubkey,
+    update_authority: &Pubkey,
+    name: &str,
+    uri: &str,
+    additional_metadata: &[(&str, &str)],
+) {
+    let metadata = TokenMetadata {
+        update_authority: OptionalNonZeroPubkey::try_from(Some(*update_authority)).unwrap(),
+        mint: *mint,
+        name: name.to_string(),
+        symbol: String::new(),
+        uri: uri.to_string(),
+        additional_metadata: additional_metadata
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect(),
+    };
+
+    let base_len =
+        ExtensionType::try_calculate_account_len::<Mint>(&[ExtensionType::MetadataPointer])
+            .unwrap();
+    let space = base_len + metadata.tlv_size_of().unwrap();
+    let mut data = vec![0u8; space];
+    let lamports = svm.minimum_balance_for_rent_exemption(space);
+
+    let mut state = StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut data).unwrap();
+    state.base.mint_authority = solana_sdk::program_option::COption::None;
+    state.base.supply = 1; // NFT
+    state.base.decimals = 0;
+    state.base.is_initialized = true;
+    state.base.freeze_authority = solana_sdk::program_option::COption::None;
+    state.pack_base();
+    state.init_account_type().unwrap();
+
+    let pointer = state.init_extension::<MetadataPointer>(true).unwrap();
+    pointer.authority = OptionalNonZeroPubkey::try_from(Some(*update_authority)).unwrap();
+    pointer.metadata_address = OptionalNonZeroPubkey::try_from(Some(*mint)).unwrap();
+
+    state
+        .init_variable_len_extension::<TokenMetadata>(&metadata, false)
+        .unwrap();
+
+    let account = Account {
+        lamports,
+        data,
+        owner: TOKEN_2022_PROGRAM_ID,
+        executable: false,
+        rent_epoch: 0,
+    };
+
+    svm.set_account(*mint, account)
+        .expect("Failed to set mock agent mint");
+}
+
+/// Read the TokenMetadata stored on an agent mint
+pub fn get_agent_metadata(svm: &LiteSVM, mint: &Pubkey) -> TokenMetadata {
+    use spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions};
+
+    let account = svm.get_account(mint).expect("Agent mint should exist");
+    let state = StateWithExtensions::<Mint>::unpack(&account.data).unwrap();
+    state
+        .get_variable_len_extension::<TokenMetadata>()
+        .expect("Agent mint should have TokenMetadata")
+}
+
 /// Derive ATA address for Token-2022
 pub fn derive_token22_ata(owner: &Pubkey, mint: &Pubkey) -> Pubkey {
     Pubkey::find_program_address(
@@ -233,3 +443,181 @@ pub fn create_mock_token22_ata(
     svm.set_account(*ata, account)
         .expect("Failed to set mock ATA");
 }
+
+/// Create a mock SNS name registry for `<domain>.sol` owned by `owner`
+///
+/// Layout: parent_name (32) + owner (32) + class (32) + record data. `parent`
+/// is normally the .sol TLD; pass another key to mock a non-.sol name.
+pub fn create_mock_sns_name_account(
+    svm: &mut LiteSVM,
+    domain: &str,
+    owner: &Pubkey,
+    parent: &Pubkey,
+) -> Pubkey {
+    let name_account = sati::sns::sol_domain_name_account(domain);
+    let space = sati::sns::NAME_REGISTRY_HEADER_SIZE + 32;
+    let mut data = vec![0u8; space];
+    data[0..32].copy_from_slice(parent.as_ref());
+    data[32..64].copy_from_slice(owner.as_ref());
+
+    let account = Account {
+        lamports: svm.minimum_balance_for_rent_exemption(space),
+        data,
+        owner: sati::sns::NAME_SERVICE_PROGRAM_ID,
+        executable: false,
+        rent_epoch: 0,
+    };
+
+    svm.set_account(name_account, account)
+        .expect("Failed to set mock name registry");
+    name_account
+}
+
+/// Store a SAS-owned account with `data`
+fn set_sas_account(svm: &mut LiteSVM, address: &Pubkey, data: Vec<u8>) {
+    let account = Account {
+        lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+        data,
+        owner: SOLANA_ATTESTATION_SERVICE_ID,
+        executable: false,
+        rent_epoch: 0,
+    };
+    svm.set_account(*address, account)
+        .expect("Failed to set mock SAS account");
+}
+
+/// Create a mock SAS credential with `authorized_signer` as its only signer
+///
+/// Layout: discriminator (1) + authority (32) + name (4 + len) + authorized_signers (4 + 32n)
+pub fn create_mock_sas_credential(
+    svm: &mut LiteSVM,
+    credential: &Pubkey,
+    authority: &Pubkey,
+    name: &str,
+    authorized_signer: &Pubkey,
+) {
+    let mut data = vec![0u8]; // Credential discriminator
+    data.extend_from_slice(authority.as_ref());
+    data.extend_from_slice(&(name.len() as u32).to_le_bytes());
+    data.extend_from_slice(name.as_bytes());
+    data.extend_from_slice(&1u32.to_le_bytes());
+    data.extend_from_slice(authorized_signer.as_ref());
+    set_sas_account(svm, credential, data);
+}
+
+/// Create a mock SAS schema (version 1, unpaused) under `credential`
+///
+/// Layout: discriminator (1) + credential (32) + name (4 + len) + description (4 + len)
+/// + layout (4 + len) + field_names (4 + len) + is_paused (1) + version (1)
+pub fn create_mock_sas_schema(
+    svm: &mut LiteSVM,
+    schema: &Pubkey,
+    credential: &Pubkey,
+    name: &str,
+    layout: &[u8],
+    field_names: &[&str],
+) {
+    let field_names = borsh_string_list(field_names);
+    let mut data = vec![1u8]; // Schema discriminator
+    data.extend_from_slice(credential.as_ref());
+    for bytes in [name.as_bytes(), b"".as_slice(), layout, &field_names] {
+        data.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+        data.extend_from_slice(bytes);
+    }
+    data.push(0); // is_paused
+    data.push(1); // version
+    set_sas_account(svm, schema, data);
+}
+
+/// SAS stores field names as a Borsh-encoded Vec<String> inside a byte vector
+fn borsh_string_list(names: &[&str]) -> Vec<u8> {
+    let mut bytes = (names.len() as u32).to_le_bytes().to_vec();
+    for name in names {
+        bytes.extend_from_slice(&(name.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(name.as_bytes());
+    }
+    bytes
+}
+
+/// Create a mock SAS attestation issued by `signer` (no expiry, not tokenized)
+///
+/// Layout: discriminator (1) + nonce (32) + credential (32) + schema (32)
+/// + data (4 + len) + signer (32) + expiry (8) + token_account (32)
+pub fn create_mock_sas_attestation(
+    svm: &mut LiteSVM,
+    attestation: &Pubkey,
+    nonce: &[u8; 32],
+    credential: &Pubkey,
+    schema: &Pubkey,
+    attestation_data: &[u8],
+    signer: &Pubkey,
+) {
+    let mut data = vec![2u8]; // Attestation discriminator
+    data.extend_from_slice(nonce);
+    data.extend_from_slice(credential.as_ref());
+    data.extend_from_slice(schema.as_ref());
+    data.extend_from_slice(&(attestation_data.len() as u32).to_le_bytes());
+    data.extend_from_slice(attestation_data);
+    data.extend_from_slice(signer.as_ref());
+    data.extend_from_slice(&0i64.to_le_bytes());
+    data.extend_from_slice(Pubkey::default().as_ref());
+    set_sas_account(svm, attestation, data);
+}
+
+/// Create an initialized RegistryConfig at the registry PDA with a mock group mint
+///
+/// Returns (registry_config, group_mint).
+pub fn setup_registry(svm: &mut LiteSVM, authority: &Pubkey) -> (Pubkey, Pubkey) {
+    let (registry_config, bump) = derive_registry_config_pda();
+    let group_mint = Keypair::new();
+    create_mock_group_mint(svm, &group_mint, &registry_config);
+    create_initialized_registry(svm, &registry_config, authority, &group_mint.pubkey(), bump);
+    (registry_config, group_mint.pubkey())
+}
+
+/// Create an agent mint with metadata under `update_authority`, held by `holder`
+///
+/// Returns (mint, holder's ATA). No AgentIndex or AgentRecord is created.
+pub fn setup_agent_mint(
+    svm: &mut LiteSVM,
+    holder: &Pubkey,
+    update_authority: &Pubkey,
+    services: &[(&str, &str)],
+) -> (Pubkey, Pubkey) {
+    let mint = Keypair::new().pubkey();
+    create_mock_agent_mint(
+        svm,
+        &mint,
+        update_authority,
+        "Agent",
+        "https://example.com/agent.json",
+        services,
+    );
+
+    let ata = derive_token22_ata(holder, &mint);
+    create_mock_token22_ata(svm, &ata, &mint, holder, 1);
+    (mint, ata)
+}
+
+/// Create a registered agent held by `holder` (status: 0 = Active, 1 = Retired)
+///
+/// Mirrors what `register_agent` leaves behind: a metadata mint under the
+/// registry PDA, the holder's ATA, an AgentIndex and an AgentRecord. A default
+/// registry is created unless the test already set one up.
+/// Returns (mint, holder's ATA).
+pub fn setup_agent(
+    svm: &mut LiteSVM,
+    holder: &Pubkey,
+    member_number: u64,
+    status: u8,
+) -> (Pubkey, Pubkey) {
+    let (registry_config, _) = derive_registry_config_pda();
+    if svm.get_account(&registry_config).is_none() {
+        create_default_registry(svm);
+    }
+
+    let (mint, ata) = setup_agent_mint(svm, holder, &registry_config, &[]);
+    create_mock_agent_index(svm, member_number, &mint);
+    create_mock_agent_record(svm, &mint, member_number, status);
+    (mint, ata)
+}
@@ -189,6 +189,26 @@ pub fn build_counterparty_message(
     text.into_bytes()
 }
 
+/// Build the SIWS message a counterparty signs to amend an attestation.
+/// MUST match on-chain build_siws_amend_message() exactly!
+pub fn build_counterparty_amend_message(
+    schema_name: &str,
+    token_account: &Pubkey,
+    task_ref: &[u8; 32],
+    outcome: u8,
+    details: Option<&str>,
+    revision: u32,
+) -> Vec<u8> {
+    let message =
+        build_counterparty_message(schema_name, token_account, task_ref, outcome, details);
+    let text = String::from_utf8(message).unwrap();
+    let fields = text
+        .strip_suffix("\n\nSign to create this attestation.")
+        .unwrap();
+
+    format!("{fields}\nRevision: {revision}\n\nSign to amend this attestation.").into_bytes()
+}
+
 /// Compute data hash for attestation data (Keccak256)
 pub fn compute_data_hash(data: &[u8]) -> [u8; 32] {
     let mut hasher = Keccak256::new();
@@ -6,34 +6,786 @@
 //! - Call .to_account_metas() for proper account metadata
 
 use anchor_lang::{InstructionData, ToAccountMetas};
+use solana_attestation_service_client::programs::SOLANA_ATTESTATION_SERVICE_ID;
 use solana_sdk::{
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
 };
 
-use crate::common::setup::SATI_PROGRAM_ID;
+use crate::common::accounts::derive_token22_ata;
+use crate::common::setup::{
+    derive_agent_index_pda, derive_agent_parent_pda, derive_agent_record_pda,
+    derive_agent_services_pda, derive_agent_successor_pda, derive_agent_verification_pda,
+    derive_attestation_revision_pda, derive_evm_link_pda, derive_handle_claim_pda,
+    derive_pending_action_pda, derive_registry_config_pda, derive_sati_pda,
+    derive_schema_config_pda, derive_sns_link_pda, derive_treasury_pda, ATA_PROGRAM_ID,
+    SATI_PROGRAM_ID,
+};
+
+/// System program ID
+const SYSTEM_PROGRAM_ID: Pubkey = solana_sdk::pubkey!("11111111111111111111111111111111");
+
+// Re-export instruction and account types from the sati crate
+pub use sati::accounts;
+pub use sati::instruction;
+pub use sati::instructions::registry::link_evm_address::LinkEvmAddressParams;
+pub use sati::instructions::registry::link_external_identity::LinkExternalIdentityParams;
+pub use sati::instructions::registry::register_agents_batch::RegisterAgentParams;
+pub use sati::instructions::registry::update_agent_metadata::UpdateAgentMetadataParams;
+pub use sati::state::{
+    AgentStatus, EvmLinkFormat, ExternalIdentityProof, GovernanceAction, ServiceEntry,
+    SignatureMode, StorageType,
+};
+
+/// Build initialize instruction using Anchor's generated types
+pub fn build_initialize_ix(
+    authority: &Pubkey,
+    registry_config: &Pubkey,
+    group_mint: &Pubkey,
+) -> Instruction {
+    let instruction_data = instruction::Initialize {};
+    let accounts = accounts::Initialize {
+        authority: *authority,
+        registry_config: *registry_config,
+        group_mint: *group_mint,
+        system_program: SYSTEM_PROGRAM_ID,
+    };
+
+    Instruction {
+        program_id: SATI_PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction_data.data(),
+    }
+}
+
+/// Build register_schema_config instruction using Anchor's generated types
+#[allow(clippy::too_many_arguments)]
+pub fn build_register_schema_config_ix(
+    payer: &Pubkey,
+    registry_config: &Pubkey,
+    authority: &Pubkey,
+    schema_config: &Pubkey,
+    sas_schema: &Pubkey,
+    signature_mode: SignatureMode,
+    storage_type: StorageType,
+    delegation_schema: Option<Pubkey>,
+    closeable: bool,
+    name: String,
+) -> Instruction {
+    let instruction_data = instruction::RegisterSchemaConfig {
+        sas_schema: *sas_schema,
+        signature_mode,
+        storage_type,
+        delegation_schema,
+        closeable,
+        name,
+    };
+    let accounts = accounts::RegisterSchemaConfig {
+        payer: *payer,
+        registry_config: *registry_config,
+        authority: *authority,
+        schema_config: *schema_config,
+        system_program: SYSTEM_PROGRAM_ID,
+    };
+
+    Instruction {
+        program_id: SATI_PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction_data.data(),
+    }
+}
+
+/// Build update_registry_authority instruction using Anchor's generated types
+pub fn build_update_authority_ix(
+    authority: &Pubkey,
+    registry_config: &Pubkey,
+    new_authority: Option<Pubkey>,
+) -> Instruction {
+    let instruction_data = instruction::UpdateRegistryAuthority { new_authority };
+    let accounts = accounts::UpdateRegistryAuthority {
+        authority: *authority,
+        registry_config: *registry_config,
+    };
+
+    Instruction {
+        program_id: SATI_PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction_data.data(),
+    }
+}
+
+/// Build propose_registry_authority instruction using Anchor's generated types
+pub fn build_propose_authority_ix(
+    authority: &Pubkey,
+    registry_config: &Pubkey,
+    new_authority: Pubkey,
+) -> Instruction {
+    let instruction_data = instruction::ProposeRegistryAuthority { new_authority };
+    let accounts = accounts::ProposeRegistryAuthority {
+        authority: *authority,
+        registry_config: *registry_config,
+    };
+
+    Instruction {
+        program_id: SATI_PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction_data.data(),
+    }
+}
+
+/// Build accept_registry_authority instruction using Anchor's generated types
+pub fn build_accept_authority_ix(new_authority: &Pubkey, registry_config: &Pubkey) -> Instruction {
+    let instruction_data = instruction::AcceptRegistryAuthority {};
+    let accounts = accounts::AcceptRegistryAuthority {
+        new_authority: *new_authority,
+        registry_config: *registry_config,
+    };
+
+    Instruction {
+        program_id: SATI_PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction_data.data(),
+    }
+}
+
+/// Build cancel_registry_authority_transfer instruction using Anchor's generated types
+pub fn build_cancel_authority_transfer_ix(
+    authority: &Pubkey,
+    registry_config: &Pubkey,
+) -> Instruction {
+    let instruction_data = instruction::CancelRegistryAuthorityTransfer {};
+    let accounts = accounts::CancelRegistryAuthorityTransfer {
+        authority: *authority,
+        registry_config: *registry_config,
+    };
+
+    Instruction {
+        program_id: SATI_PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction_data.data(),
+    }
+}
+
+/// Build set_timelock_delay instruction using Anchor's generated types
+pub fn build_set_timelock_delay_ix(
+    authority: &Pubkey,
+    registry_config: &Pubkey,
+    delay: i64,
+) -> Instruction {
+    let instruction_data = instruction::SetTimelockDelay { delay };
+    let accounts = accounts::SetTimelockDelay {
+        authority: *authority,
+        registry_config: *registry_config,
+    };
+
+    Instruction {
+        program_id: SATI_PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction_data.data(),
+    }
+}
+
+/// Build pause_registry instruction using Anchor's generated types
+pub fn build_pause_registry_ix(
+    authority: &Pubkey,
+    registry_config: &Pubkey,
+    flags: u8,
+) -> Instruction {
+    let instruction_data = instruction::PauseRegistry { flags };
+    let accounts = accounts::PauseRegistry {
+        authority: *authority,
+        registry_config: *registry_config,
+    };
+
+    Instruction {
+        program_id: SATI_PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction_data.data(),
+    }
+}
+
+/// Build unpause_registry instruction using Anchor's generated types
+pub fn build_unpause_registry_ix(
+    authority: &Pubkey,
+    registry_config: &Pubkey,
+    flags: u8,
+) -> Instruction {
+    let instruction_data = instruction::UnpauseRegistry { flags };
+    let accounts = accounts::UnpauseRegistry {
+        authority: *authority,
+        registry_config: *registry_config,
+    };
+
+    Instruction {
+        program_id: SATI_PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction_data.data(),
+    }
+}
+
+/// Build set_registration_fee instruction using Anchor's generated types
+pub fn build_set_registration_fee_ix(
+    authority: &Pubkey,
+    registry_config: &Pubkey,
+    fee_mint: Option<Pubkey>,
+    amount: u64,
+) -> Instruction {
+    let instruction_data = instruction::SetRegistrationFee { fee_mint, amount };
+    let accounts = accounts::SetRegistrationFee {
+        authority: *authority,
+        registry_config: *registry_config,
+    };
+
+    Instruction {
+        program_id: SATI_PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction_data.data(),
+    }
+}
+
+/// Build withdraw_treasury instruction (lamports) using Anchor's generated types
+pub fn build_withdraw_treasury_ix(
+    authority: &Pubkey,
+    registry_config: &Pubkey,
+    destination: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let instruction_data = instruction::WithdrawTreasury { amount };
+    let accounts = accounts::WithdrawTreasury {
+        authority: *authority,
+        registry_config: *registry_config,
+        treasury: derive_treasury_pda().0,
+        destination: *destination,
+        mint: None,
+        treasury_token_account: None,
+        token_program: None,
+    };
+
+    Instruction {
+        program_id: SATI_PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction_data.data(),
+    }
+}
+
+/// Build queue_governance_action instruction using Anchor's generated types
+pub fn build_queue_governance_action_ix(
+    payer: &Pubkey,
+    authority: &Pubkey,
+    registry_config: &Pubkey,
+    action_id: u64,
+    action: GovernanceAction,
+) -> Instruction {
+    let instruction_data = instruction::QueueGovernanceAction { action };
+    let accounts = accounts::QueueGovernanceAction {
+        payer: *payer,
+        authority: *authority,
+        registry_config: *registry_config,
+        pending_action: derive_pending_action_pda(action_id).0,
+        system_program: SYSTEM_PROGRAM_ID,
+    };
+
+    Instruction {
+        program_id: SATI_PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction_data.data(),
+    }
+}
+
+/// Build execute_governance_action instruction using Anchor's generated types
+///
+/// `schema_config` is only needed for RegisterSchemaConfig and SetSchemaMinVerification actions.
+pub fn build_execute_governance_action_ix(
+    executor: &Pubkey,
+    registry_config: &Pubkey,
+    action_id: u64,
+    rent_recipient: &Pubkey,
+    schema_config: Option<Pubkey>,
+) -> Instruction {
+    let instruction_data = instruction::ExecuteGovernanceAction {};
+    let accounts = accounts::ExecuteGovernanceAction {
+        executor: *executor,
+        registry_config: *registry_config,
+        pending_action: derive_pending_action_pda(action_id).0,
+        rent_recipient: *rent_recipient,
+        schema_config,
+        system_program: SYSTEM_PROGRAM_ID,
+    };
+
+    Instruction {
+        program_id: SATI_PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction_data.data(),
+    }
+}
+
+/// Build cancel_governance_action instruction using Anchor's generated types
+pub fn build_cancel_governance_action_ix(
+    authority: &Pubkey,
+    registry_config: &Pubkey,
+    action_id: u64,
+    rent_recipient: &Pubkey,
+) -> Instruction {
+    let instruction_data = instruction::CancelGovernanceAction {};
+    let accounts = accounts::CancelGovernanceAction {
+        authority: *authority,
+        registry_config: *registry_config,
+        pending_action: derive_pending_action_pda(action_id).0,
+        rent_recipient: *rent_recipient,
+    };
+
+    Instruction {
+        program_id: SATI_PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction_data.data(),
+    }
+}
+
+/// Build migrate_registry_config instruction using Anchor's generated types
+pub fn build_migrate_registry_config_ix(payer: &Pubkey, registry_config: &Pubkey) -> Instruction {
+    let instruction_data = instruction::MigrateRegistryConfig {};
+    let accounts = accounts::MigrateRegistryConfig {
+        payer: *payer,
+        registry_config: *registry_config,
+        system_program: SYSTEM_PROGRAM_ID,
+    };
+
+    Instruction {
+        program_id: SATI_PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction_data.data(),
+    }
+}
+
+/// Build link_evm_address instruction using Anchor's generated types
+#[allow(clippy::too_many_arguments)]
+pub fn build_link_evm_address_ix(
+    owner: &Pubkey,
+    agent_mint: &Pubkey,
+    ata: &Pubkey,
+    evm_address: [u8; 20],
+    chain_id: String,
+    signature: [u8; 64],
+    recovery_id: u8,
+    format: EvmLinkFormat,
+    expires_at: Option<i64>,
+) -> Instruction {
+    let (evm_link, _) = derive_evm_link_pda(agent_mint, &chain_id, &evm_address);
+    let instruction_data = instruction::LinkEvmAddress {
+        params: LinkEvmAddressParams {
+            evm_address,
+            chain_id,
+            signature,
+            recovery_id,
+            format,
+            expires_at,
+        },
+    };
+    let accounts = accounts::LinkEvmAddress {
+        owner: *owner,
+        registry_config: derive_registry_config_pda().0,
+        agent_mint: *agent_mint,
+        ata: *ata,
+        evm_link,
+        token_program: TOKEN_2022_PROGRAM_ID,
+        system_program: SYSTEM_PROGRAM_ID,
+    };
+
+    Instruction {
+        program_id: SATI_PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction_data.data(),
+    }
+}
+
+/// Build link_external_identity instruction using Anchor's generated types
+pub fn build_link_external_identity_ix(
+    owner: &Pubkey,
+    agent_mint: &Pubkey,
+    ata: &Pubkey,
+    chain_id: String,
+    proof: ExternalIdentityProof,
+    expires_at: Option<i64>,
+) -> Instruction {
+    let instruction_data = instruction::LinkExternalIdentity {
+        params: LinkExternalIdentityParams {
+            chain_id,
+            proof,
+            expires_at,
+        },
+    };
+    let accounts = accounts::LinkExternalIdentity {
+        owner: *owner,
+        registry_config: derive_registry_config_pda().0,
+        agent_mint: *agent_mint,
+        ata: *ata,
+        instructions_sysvar: solana_sdk::sysvar::instructions::ID,
+        token_program: TOKEN_2022_PROGRAM_ID,
+    };
+
+    Instruction {
+        program_id: SATI_PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction_data.data(),
+    }
+}
+
+/// Build link_sns_domain instruction using Anchor's generated types
+pub fn build_link_sns_domain_ix(
+    owner: &Pubkey,
+    agent_mint: &Pubkey,
+    name_account: &Pubkey,
+    domain: String,
+) -> Instruction {
+    let (sns_link, _) = derive_sns_link_pda(agent_mint, name_account);
+    let instruction_data = instruction::LinkSnsDomain { domain };
+    let accounts = accounts::LinkSnsDomain {
+        owner: *owner,
+        registry_config: derive_registry_config_pda().0,
+        agent_mint: *agent_mint,
+        ata: derive_token22_ata(owner, agent_mint),
+        name_account: *name_account,
+        sns_link,
+        token_program: TOKEN_2022_PROGRAM_ID,
+        system_program: SYSTEM_PROGRAM_ID,
+    };
+
+    Instruction {
+        program_id: SATI_PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction_data.data(),
+    }
+}
+
+/// Build unlink_sns_domain instruction using Anchor's generated types
+pub fn build_unlink_sns_domain_ix(
+    signer: &Pubkey,
+    agent_mint: &Pubkey,
+    name_account: &Pubkey,
+    holder: &Pubkey,
+    signer_ata: Option<Pubkey>,
+) -> Instruction {
+    let instruction_data = instruction::UnlinkSnsDomain {};
+    let accounts = accounts::UnlinkSnsDomain {
+        signer: *signer,
+        agent_mint: *agent_mint,
+        sns_link: derive_sns_link_pda(agent_mint, name_account).0,
+        holder: *holder,
+        holder_ata: derive_token22_ata(holder, agent_mint),
+        name_account: *name_account,
+        signer_ata,
+        token_program: TOKEN_2022_PROGRAM_ID,
+    };
+
+    Instruction {
+        program_id: SATI_PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction_data.data(),
+    }
+}
+
+/// Build unlink_evm_address instruction using Anchor's generated types
+pub fn build_unlink_evm_address_ix(
+    signer: &Pubkey,
+    agent_mint: &Pubkey,
+    evm_link: &Pubkey,
+    holder: &Pubkey,
+    signer_ata: Option<Pubkey>,
+) -> Instruction {
+    let instruction_data = instruction::UnlinkEvmAddress {};
+    let accounts = accounts::UnlinkEvmAddress {
+        signer: *signer,
+        agent_mint: *agent_mint,
+        evm_link: *evm_link,
+        holder: *holder,
+        holder_ata: derive_token22_ata(holder, agent_mint),
+        signer_ata,
+        token_program: TOKEN_2022_PROGRAM_ID,
+    };
+
+    Instruction {
+        program_id: SATI_PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction_data.data(),
+    }
+}
+
+/// Build update_agent_metadata instruction using Anchor's generated types
+pub fn build_update_agent_metadata_ix(
+    owner: &Pubkey,
+    registry_config: &Pubkey,
+    agent_mint: &Pubkey,
+    ata: &Pubkey,
+    params: UpdateAgentMetadataParams,
+) -> Instruction {
+    let instruction_data = instruction::UpdateAgentMetadata { params };
+    let accounts = accounts::UpdateAgentMetadata {
+        owner: *owner,
+        registry_config: *registry_config,
+        agent_mint: *agent_mint,
+        ata: *ata,
+        token_program: TOKEN_2022_PROGRAM_ID,
+        system_program: SYSTEM_PROGRAM_ID,
+    };
+
+    Instruction {
+        program_id: SATI_PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction_data.data(),
+    }
+}
+
+/// Build backfill_agent_record instruction using Anchor's generated types
+pub fn build_backfill_agent_record_ix(
+    payer: &Pubkey,
+    agent_mint: &Pubkey,
+    member_number: u64,
+) -> Instruction {
+    let (agent_index, _) = derive_agent_index_pda(member_number);
+    let (agent_record, _) = derive_agent_record_pda(agent_mint);
+    let instruction_data = instruction::BackfillAgentRecord { member_number };
+    let accounts = accounts::BackfillAgentRecord {
+        payer: *payer,
+        agent_mint: *agent_mint,
+        agent_index,
+        agent_record,
+        system_program: SYSTEM_PROGRAM_ID,
+    };
+
+    Instruction {
+        program_id: SATI_PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction_data.data(),
+    }
+}
+
+/// Build register_agents_batch instruction using Anchor's generated types
+///
+/// `agents` pairs each (owner, agent_mint) with its params. ATAs, AgentIndex and
+/// AgentRecord PDAs are derived, with member numbers starting at `first_member_number`.
+pub fn build_register_agents_batch_ix(
+    payer: &Pubkey,
+    registry_config: &Pubkey,
+    group_mint: &Pubkey,
+    first_member_number: u64,
+    agents: Vec<(Pubkey, Pubkey, RegisterAgentParams)>,
+) -> Instruction {
+    let mut remaining_accounts = Vec::with_capacity(agents.len() * 5);
+    let mut params = Vec::with_capacity(agents.len());
+    for (i, (owner, agent_mint, agent_params)) in agents.into_iter().enumerate() {
+        let (agent_index, _) = derive_agent_index_pda(first_member_number + i as u64);
+        let (agent_record, _) = derive_agent_record_pda(&agent_mint);
+        remaining_accounts.extend([
+            AccountMeta::new_readonly(owner, false),
+            AccountMeta::new(agent_mint, true),
+            AccountMeta::new(derive_token22_ata(&owner, &agent_mint), false),
+            AccountMeta::new(agent_index, false),
+            AccountMeta::new(agent_record, false),
+        ]);
+        params.push(agent_params);
+    }
+
+    let instruction_data = instruction::RegisterAgentsBatch { agents: params };
+    let mut account_metas = accounts::RegisterAgentsBatch {
+        payer: *payer,
+        registry_config: *registry_config,
+        group_mint: *group_mint,
+        token_2022_program: TOKEN_2022_PROGRAM_ID,
+        associated_token_program: ATA_PROGRAM_ID,
+        system_program: SYSTEM_PROGRAM_ID,
+        treasury: Some(derive_treasury_pda().0),
+        fee_mint: None,
+        payer_fee_account: None,
+        treasury_fee_account: None,
+        fee_token_program: None,
+    }
+    .to_account_metas(None);
+    account_metas.extend(remaining_accounts);
+
+    Instruction {
+        program_id: SATI_PROGRAM_ID,
+        accounts: account_metas,
+        data: instruction_data.data(),
+    }
+}
+
+/// Build retire_agent instruction using Anchor's generated types
+pub fn build_retire_agent_ix(
+    holder: &Pubkey,
+    agent_mint: &Pubkey,
+    ata: &Pubkey,
+    member_number: u64,
+) -> Instruction {
+    let (agent_record, _) = derive_agent_record_pda(agent_mint);
+    let (agent_index, _) = derive_agent_index_pda(member_number);
+    let instruction_data = instruction::RetireAgent {};
+    let accounts = accounts::RetireAgent {
+        holder: *holder,
+        registry_config: derive_registry_config_pda().0,
+        agent_mint: *agent_mint,
+        ata: *ata,
+        agent_record,
+        agent_index,
+        token_program: TOKEN_2022_PROGRAM_ID,
+    };
+
+    Instruction {
+        program_id: SATI_PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction_data.data(),
+    }
+}
+
+/// Build set_agent_services instruction using Anchor's generated types
+pub fn build_set_agent_services_ix(
+    holder: &Pubkey,
+    agent_mint: &Pubkey,
+    ata: &Pubkey,
+    services: Vec<ServiceEntry>,
+) -> Instruction {
+    let instruction_data = instruction::SetAgentServices { services };
+    let accounts = accounts::SetAgentServices {
+        holder: *holder,
+        registry_config: derive_registry_config_pda().0,
+        agent_mint: *agent_mint,
+        ata: *ata,
+        agent_record: derive_agent_record_pda(agent_mint).0,
+        agent_services: derive_agent_services_pda(agent_mint).0,
+        token_program: TOKEN_2022_PROGRAM_ID,
+        system_program: SYSTEM_PROGRAM_ID,
+    };
+
+    Instruction {
+        program_id: SATI_PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction_data.data(),
+    }
+}
+
+/// Build set_agent_status instruction using Anchor's generated types
+pub fn build_set_agent_status_ix(
+    holder: &Pubkey,
+    agent_mint: &Pubkey,
+    ata: &Pubkey,
+    status: AgentStatus,
+) -> Instruction {
+    let instruction_data = instruction::SetAgentStatus { status };
+    let accounts = accounts::SetAgentStatus {
+        holder: *holder,
+        registry_config: derive_registry_config_pda().0,
+        agent_mint: *agent_mint,
+        ata: *ata,
+        agent_record: derive_agent_record_pda(agent_mint).0,
+        token_program: TOKEN_2022_PROGRAM_ID,
+    };
+
+    Instruction {
+        program_id: SATI_PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction_data.data(),
+    }
+}
 
-/// System program ID
-const SYSTEM_PROGRAM_ID: Pubkey = solana_sdk::pubkey!("11111111111111111111111111111111");
+/// Build set_successor instruction using Anchor's generated types
+pub fn build_set_successor_ix(
+    holder: &Pubkey,
+    agent_mint: &Pubkey,
+    successor_holder: &Pubkey,
+    successor_mint: &Pubkey,
+) -> Instruction {
+    let instruction_data = instruction::SetSuccessor {};
+    let accounts = accounts::SetSuccessor {
+        holder: *holder,
+        successor_holder: *successor_holder,
+        registry_config: derive_registry_config_pda().0,
+        agent_mint: *agent_mint,
+        ata: derive_token22_ata(holder, agent_mint),
+        agent_record: derive_agent_record_pda(agent_mint).0,
+        successor_mint: *successor_mint,
+        successor_ata: derive_token22_ata(successor_holder, successor_mint),
+        successor_record: derive_agent_record_pda(successor_mint).0,
+        agent_successor: derive_agent_successor_pda(agent_mint).0,
+        successor_link: derive_agent_successor_pda(successor_mint).0,
+        token_program: TOKEN_2022_PROGRAM_ID,
+        system_program: SYSTEM_PROGRAM_ID,
+    };
 
-// Re-export instruction and account types from the sati crate
-pub use sati::accounts;
-pub use sati::instruction;
-pub use sati::instructions::registry::link_evm_address::LinkEvmAddressParams;
-pub use sati::state::{SignatureMode, StorageType};
+    Instruction {
+        program_id: SATI_PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction_data.data(),
+    }
+}
 
-/// Build initialize instruction using Anchor's generated types
-pub fn build_initialize_ix(
+/// Build set_parent_agent instruction using Anchor's generated types
+pub fn build_set_parent_agent_ix(
+    holder: &Pubkey,
+    agent_mint: &Pubkey,
+    parent_holder: &Pubkey,
+    parent_mint: &Pubkey,
+) -> Instruction {
+    let instruction_data = instruction::SetParentAgent {};
+    let accounts = accounts::SetParentAgent {
+        holder: *holder,
+        parent_holder: *parent_holder,
+        registry_config: derive_registry_config_pda().0,
+        agent_mint: *agent_mint,
+        ata: derive_token22_ata(holder, agent_mint),
+        agent_record: derive_agent_record_pda(agent_mint).0,
+        parent_mint: *parent_mint,
+        parent_ata: derive_token22_ata(parent_holder, parent_mint),
+        parent_record: derive_agent_record_pda(parent_mint).0,
+        agent_parent: derive_agent_parent_pda(agent_mint).0,
+        token_program: TOKEN_2022_PROGRAM_ID,
+        system_program: SYSTEM_PROGRAM_ID,
+    };
+
+    Instruction {
+        program_id: SATI_PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction_data.data(),
+    }
+}
+
+/// Build detach_parent_agent instruction using Anchor's generated types
+///
+/// `signer_ata` is the signer's token account for the child or parent mint;
+/// pass None to detach a stale link.
+pub fn build_detach_parent_agent_ix(
+    signer: &Pubkey,
+    agent_mint: &Pubkey,
+    holder: &Pubkey,
+    signer_ata: Option<&Pubkey>,
+) -> Instruction {
+    let instruction_data = instruction::DetachParentAgent {};
+    let accounts = accounts::DetachParentAgent {
+        signer: *signer,
+        agent_mint: *agent_mint,
+        agent_parent: derive_agent_parent_pda(agent_mint).0,
+        holder: *holder,
+        holder_ata: derive_token22_ata(holder, agent_mint),
+        signer_ata: signer_ata.copied(),
+        token_program: TOKEN_2022_PROGRAM_ID,
+    };
+
+    Instruction {
+        program_id: SATI_PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction_data.data(),
+    }
+}
+
+/// Build suspend_agent instruction using Anchor's generated types
+pub fn build_suspend_agent_ix(
     authority: &Pubkey,
     registry_config: &Pubkey,
-    group_mint: &Pubkey,
+    agent_mint: &Pubkey,
 ) -> Instruction {
-    let instruction_data = instruction::Initialize {};
-    let accounts = accounts::Initialize {
+    let instruction_data = instruction::SuspendAgent {};
+    let accounts = accounts::SuspendAgent {
         authority: *authority,
         registry_config: *registry_config,
-        group_mint: *group_mint,
-        system_program: SYSTEM_PROGRAM_ID,
+        agent_record: derive_agent_record_pda(agent_mint).0,
     };
 
     Instruction {
@@ -43,33 +795,96 @@ pub fn build_initialize_ix(
     }
 }
 
-/// Build register_schema_config instruction using Anchor's generated types
-#[allow(clippy::too_many_arguments)]
-pub fn build_register_schema_config_ix(
-    payer: &Pubkey,
-    registry_config: &Pubkey,
+/// Build unsuspend_agent instruction using Anchor's generated types
+pub fn build_unsuspend_agent_ix(
     authority: &Pubkey,
-    schema_config: &Pubkey,
-    sas_schema: &Pubkey,
-    signature_mode: SignatureMode,
-    storage_type: StorageType,
-    delegation_schema: Option<Pubkey>,
-    closeable: bool,
-    name: String,
+    registry_config: &Pubkey,
+    agent_mint: &Pubkey,
 ) -> Instruction {
-    let instruction_data = instruction::RegisterSchemaConfig {
-        sas_schema: *sas_schema,
-        signature_mode,
-        storage_type,
-        delegation_schema,
-        closeable,
-        name,
+    let instruction_data = instruction::UnsuspendAgent {};
+    let accounts = accounts::UnsuspendAgent {
+        authority: *authority,
+        registry_config: *registry_config,
+        agent_record: derive_agent_record_pda(agent_mint).0,
     };
-    let accounts = accounts::RegisterSchemaConfig {
-        payer: *payer,
+
+    Instruction {
+        program_id: SATI_PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction_data.data(),
+    }
+}
+
+/// Build claim_agent_handle instruction using Anchor's generated types
+pub fn build_claim_agent_handle_ix(
+    holder: &Pubkey,
+    agent_mint: &Pubkey,
+    ata: &Pubkey,
+    agent_handle: &Pubkey,
+    handle: &str,
+) -> Instruction {
+    let instruction_data = instruction::ClaimAgentHandle {
+        handle: handle.to_string(),
+    };
+    let accounts = accounts::ClaimAgentHandle {
+        holder: *holder,
+        registry_config: derive_registry_config_pda().0,
+        agent_mint: *agent_mint,
+        ata: *ata,
+        agent_record: derive_agent_record_pda(agent_mint).0,
+        agent_handle: *agent_handle,
+        handle_claim: derive_handle_claim_pda(agent_mint).0,
+        token_program: TOKEN_2022_PROGRAM_ID,
+        system_program: SYSTEM_PROGRAM_ID,
+    };
+
+    Instruction {
+        program_id: SATI_PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction_data.data(),
+    }
+}
+
+/// Build release_agent_handle instruction using Anchor's generated types
+pub fn build_release_agent_handle_ix(
+    signer: &Pubkey,
+    registry_config: &Pubkey,
+    agent_mint: &Pubkey,
+    agent_handle: &Pubkey,
+    rent_recipient: &Pubkey,
+    ata: Option<&Pubkey>,
+) -> Instruction {
+    let instruction_data = instruction::ReleaseAgentHandle {};
+    let accounts = accounts::ReleaseAgentHandle {
+        signer: *signer,
         registry_config: *registry_config,
+        agent_handle: *agent_handle,
+        handle_claim: derive_handle_claim_pda(agent_mint).0,
+        agent_record: derive_agent_record_pda(agent_mint).0,
+        ata: ata.copied(),
+        rent_recipient: *rent_recipient,
+    };
+
+    Instruction {
+        program_id: SATI_PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction_data.data(),
+    }
+}
+
+/// Build set_agent_verification instruction using Anchor's generated types
+pub fn build_set_agent_verification_ix(
+    authority: &Pubkey,
+    registry_config: &Pubkey,
+    agent_mint: &Pubkey,
+    level: u8,
+) -> Instruction {
+    let instruction_data = instruction::SetAgentVerification { level };
+    let accounts = accounts::SetAgentVerification {
         authority: *authority,
-        schema_config: *schema_config,
+        registry_config: *registry_config,
+        agent_record: derive_agent_record_pda(agent_mint).0,
+        agent_verification: derive_agent_verification_pda(agent_mint).0,
         system_program: SYSTEM_PROGRAM_ID,
     };
 
@@ -80,16 +895,17 @@ pub fn build_register_schema_config_ix(
     }
 }
 
-/// Build update_registry_authority instruction using Anchor's generated types
-pub fn build_update_authority_ix(
+/// Build revoke_agent_verification instruction using Anchor's generated types
+pub fn build_revoke_agent_verification_ix(
     authority: &Pubkey,
     registry_config: &Pubkey,
-    new_authority: Option<Pubkey>,
+    agent_mint: &Pubkey,
 ) -> Instruction {
-    let instruction_data = instruction::UpdateRegistryAuthority { new_authority };
-    let accounts = accounts::UpdateRegistryAuthority {
+    let instruction_data = instruction::RevokeAgentVerification {};
+    let accounts = accounts::RevokeAgentVerification {
         authority: *authority,
         registry_config: *registry_config,
+        agent_verification: derive_agent_verification_pda(agent_mint).0,
     };
 
     Instruction {
@@ -99,26 +915,58 @@ pub fn build_update_authority_ix(
     }
 }
 
-/// Build link_evm_address instruction using Anchor's generated types
-pub fn build_link_evm_address_ix(
-    owner: &Pubkey,
+/// Build set_schema_min_verification instruction using Anchor's generated types
+pub fn build_set_schema_min_verification_ix(
+    authority: &Pubkey,
+    registry_config: &Pubkey,
+    sas_schema: &Pubkey,
+    min_level: u8,
+) -> Instruction {
+    let instruction_data = instruction::SetSchemaMinVerification { min_level };
+    let accounts = accounts::SetSchemaMinVerification {
+        authority: *authority,
+        registry_config: *registry_config,
+        schema_config: derive_schema_config_pda(sas_schema).0,
+    };
+
+    Instruction {
+        program_id: SATI_PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction_data.data(),
+    }
+}
+
+/// Build migrate_schema_config instruction using Anchor's generated types
+pub fn build_migrate_schema_config_ix(payer: &Pubkey, sas_schema: &Pubkey) -> Instruction {
+    let instruction_data = instruction::MigrateSchemaConfig {
+        sas_schema: *sas_schema,
+    };
+    let accounts = accounts::MigrateSchemaConfig {
+        payer: *payer,
+        schema_config: derive_schema_config_pda(sas_schema).0,
+        system_program: SYSTEM_PROGRAM_ID,
+    };
+
+    Instruction {
+        program_id: SATI_PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: instruction_data.data(),
+    }
+}
+
+/// Build sync_agent_authority instruction using Anchor's generated types
+pub fn build_sync_agent_authority_ix(
+    holder: &Pubkey,
+    current_authority: Option<&Pubkey>,
+    registry_config: &Pubkey,
     agent_mint: &Pubkey,
     ata: &Pubkey,
-    evm_address: [u8; 20],
-    chain_id: String,
-    signature: [u8; 64],
-    recovery_id: u8,
 ) -> Instruction {
-    let instruction_data = instruction::LinkEvmAddress {
-        params: LinkEvmAddressParams {
-            evm_address,
-            chain_id,
-            signature,
-            recovery_id,
-        },
-    };
-    let accounts = accounts::LinkEvmAddress {
-        owner: *owner,
+    let instruction_data = instruction::SyncAgentAuthority {};
+    let accounts = accounts::SyncAgentAuthority {
+        holder: *holder,
+        current_authority: current_authority.copied(),
+        registry_config: *registry_config,
         agent_mint: *agent_mint,
         ata: *ata,
         token_program: TOKEN_2022_PROGRAM_ID,
@@ -135,7 +983,7 @@ pub fn build_link_evm_address_ix(
 // Attestation Instructions (Compressed - Light Protocol)
 // ============================================================================
 
-pub use sati::state::{CloseParams, CompressedAttestation, CreateParams};
+pub use sati::state::{CloseParams, CompressedAttestation, CreateParams, UpdateParams};
 
 /// Derive the Anchor event authority PDA for CPI events
 fn derive_event_authority() -> Pubkey {
@@ -151,11 +999,13 @@ const TOKEN_2022_PROGRAM_ID: Pubkey =
 /// Note: This instruction requires Ed25519 signature verification instructions
 /// to be included BEFORE this instruction in the same transaction.
 ///
+/// The AgentRecord PDA is derived from agent_mint (must equal token_account from data).
 /// The agent_ata must hold the agent NFT (mint == token_account from data).
 /// Authorization is verified via ATA ownership, not by pubkey == mint.
 pub fn build_create_compressed_attestation_ix(
     payer: &Pubkey,
     schema_config: &Pubkey,
+    agent_mint: &Pubkey,
     agent_ata: Option<&Pubkey>,
     params: CreateParams,
     remaining_accounts: Vec<AccountMeta>,
@@ -164,12 +1014,17 @@ pub fn build_create_compressed_attestation_ix(
     let mut account_metas = accounts::CreateCompressedAttestation {
         payer: *payer,
         schema_config: *schema_config,
+        registry_config: derive_registry_config_pda().0,
         instructions_sysvar: solana_sdk::sysvar::instructions::ID,
+        agent_record: derive_agent_record_pda(agent_mint).0,
+        agent_verification: None,
         agent_ata: agent_ata.copied(),
         token_program: agent_ata.map(|_| TOKEN_2022_PROGRAM_ID),
         delegation_attestation: None,
         sati_credential: None,
         clock: None,
+        agent_parent: None,
+        parent_ata: None,
         event_authority: derive_event_authority(),
         program: SATI_PROGRAM_ID,
     }
@@ -200,6 +1055,7 @@ pub fn build_close_compressed_attestation_ix(
     let mut account_metas = accounts::CloseCompressedAttestation {
         signer: *signer,
         schema_config: *schema_config,
+        registry_config: derive_registry_config_pda().0,
         agent_ata: agent_ata.copied(),
         token_program: agent_ata.map(|_| TOKEN_2022_PROGRAM_ID),
         event_authority: derive_event_authority(),
@@ -216,3 +1072,118 @@ pub fn build_close_compressed_attestation_ix(
         data: instruction_data.data(),
     }
 }
+
+/// Build update_compressed_attestation instruction for compressed storage
+///
+/// Note: The counterparty's Ed25519 signature over the amend message must be
+/// included BEFORE this instruction in the same transaction.
+pub fn build_update_compressed_attestation_ix(
+    payer: &Pubkey,
+    schema_config: &Pubkey,
+    params: UpdateParams,
+    remaining_accounts: Vec<AccountMeta>,
+) -> Instruction {
+    let instruction_data = instruction::UpdateCompressedAttestation { params };
+    let mut account_metas = accounts::UpdateCompressedAttestation {
+        payer: *payer,
+        schema_config: *schema_config,
+        registry_config: derive_registry_config_pda().0,
+        instructions_sysvar: solana_sdk::sysvar::instructions::ID,
+        event_authority: derive_event_authority(),
+        program: SATI_PROGRAM_ID,
+    }
+    .to_account_metas(None);
+
+    // Add Light Protocol remaining accounts
+    account_metas.extend(remaining_accounts);
+
+    Instruction {
+        program_id: SATI_PROGRAM_ID,
+        accounts: account_metas,
+        data: instruction_data.data(),
+    }
+}
+
+// ============================================================================
+// Attestation Instructions (Regular - SAS)
+// ============================================================================
+
+pub use sati::state::{UpdateRegularBatchParams, UpdateRegularParams};
+
+/// Build update_regular_attestation instruction for SAS storage
+///
+/// Note: The provider's Ed25519 signature over the reputation update hash must be
+/// included BEFORE this instruction in the same transaction.
+pub fn build_update_regular_attestation_ix(
+    payer: &Pubkey,
+    schema_config: &Pubkey,
+    sati_credential: &Pubkey,
+    sas_schema: &Pubkey,
+    attestation: &Pubkey,
+    agent_mint: &Pubkey,
+    params: UpdateRegularParams,
+) -> Instruction {
+    let instruction_data = instruction::UpdateRegularAttestation { params };
+    let account_metas = accounts::UpdateRegularAttestation {
+        payer: *payer,
+        schema_config: *schema_config,
+        registry_config: derive_registry_config_pda().0,
+        sati_pda: derive_sati_pda().0,
+        sati_credential: *sati_credential,
+        sas_schema: *sas_schema,
+        attestation: *attestation,
+        attestation_revision: derive_attestation_revision_pda(attestation).0,
+        instructions_sysvar: solana_sdk::sysvar::instructions::ID,
+        agent_record: derive_agent_record_pda(agent_mint).0,
+        agent_verification: None,
+        sas_program: SOLANA_ATTESTATION_SERVICE_ID,
+        system_program: SYSTEM_PROGRAM_ID,
+        event_authority: derive_event_authority(),
+        program: SATI_PROGRAM_ID,
+    }
+    .to_account_metas(None);
+
+    Instruction {
+        program_id: SATI_PROGRAM_ID,
+        accounts: account_metas,
+        data: instruction_data.data(),
+    }
+}
+
+/// Build update_regular_attestations_batch instruction for SAS storage
+///
+/// `entry_accounts` are the per-entry remaining accounts (attestation,
+/// attestation_revision, agent_record and optionally agent_verification), in
+/// `params.entries` order.
+pub fn build_update_regular_attestations_batch_ix(
+    payer: &Pubkey,
+    schema_config: &Pubkey,
+    sati_credential: &Pubkey,
+    sas_schema: &Pubkey,
+    params: UpdateRegularBatchParams,
+    entry_accounts: Vec<AccountMeta>,
+) -> Instruction {
+    let instruction_data = instruction::UpdateRegularAttestationsBatch { params };
+    let mut account_metas = accounts::UpdateRegularAttestationsBatch {
+        payer: *payer,
+        schema_config: *schema_config,
+        registry_config: derive_registry_config_pda().0,
+        sati_pda: derive_sati_pda().0,
+        sati_credential: *sati_credential,
+        sas_schema: *sas_schema,
+        instructions_sysvar: solana_sdk::sysvar::instructions::ID,
+        sas_program: SOLANA_ATTESTATION_SERVICE_ID,
+        system_program: SYSTEM_PROGRAM_ID,
+        event_authority: derive_event_authority(),
+        program: SATI_PROGRAM_ID,
+    }
+    .to_account_metas(None);
+
+    account_metas.extend(entry_accounts);
+
+    Instruction {
+        program_id: SATI_PROGRAM_ID,
+        accounts: account_metas,
+        data: instruction_data.data(),
+    }
+}
@@ -1,11 +1,17 @@
 use litesvm::LiteSVM;
-use solana_sdk::{pubkey::Pubkey, signature::Keypair};
+use solana_attestation_service_client::programs::SOLANA_ATTESTATION_SERVICE_ID;
+use solana_sdk::{
+    account::Account, instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    transaction::Transaction,
+};
+
+use crate::common::accounts::mock_registry_config_data;
 use std::path::PathBuf;
 
 // Light Protocol imports for compressed account testing
 pub use light_program_test::{
     indexer::{TestIndexer, TestIndexerExtensions},
-    program_test::LightProgramTest,
+    program_test::{LightProgramTest, TestRpc},
     ProgramTestConfig,
     Rpc, // Trait for get_payer() and other RPC methods
 };
@@ -63,16 +69,191 @@ pub fn derive_registry_config_pda() -> (Pubkey, u8) {
     Pubkey::find_program_address(&[b"registry"], &SATI_PROGRAM_ID)
 }
 
+/// Derive agent index PDA for a member number
+pub fn derive_agent_index_pda(member_number: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"agent_index", &member_number.to_le_bytes()],
+        &SATI_PROGRAM_ID,
+    )
+}
+
+/// Derive agent record PDA for a mint
+pub fn derive_agent_record_pda(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"agent", mint.as_ref()], &SATI_PROGRAM_ID)
+}
+
 /// Derive schema config PDA
 pub fn derive_schema_config_pda(sas_schema: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[b"schema_config", sas_schema.as_ref()], &SATI_PROGRAM_ID)
 }
 
+/// Derive agent handle PDA (handle is normalized and confusable-folded first)
+pub fn derive_agent_handle_pda(handle: &str) -> (Pubkey, u8) {
+    let normalized = sati::handle::normalize_handle(handle).expect("valid handle");
+    let seed = sati::handle::handle_seed(&normalized);
+    Pubkey::find_program_address(&[b"handle", seed.as_bytes()], &SATI_PROGRAM_ID)
+}
+
+/// Derive reverse handle lookup PDA
+pub fn derive_handle_claim_pda(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"agent_handle", mint.as_ref()], &SATI_PROGRAM_ID)
+}
+
+/// Derive AgentServices PDA for an agent mint
+pub fn derive_agent_services_pda(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"services", mint.as_ref()], &SATI_PROGRAM_ID)
+}
+
+/// Derive AgentSuccessor PDA for an agent mint
+pub fn derive_agent_successor_pda(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"successor", mint.as_ref()], &SATI_PROGRAM_ID)
+}
+
+/// Derive AgentParent PDA for a child agent mint
+pub fn derive_agent_parent_pda(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"parent", mint.as_ref()], &SATI_PROGRAM_ID)
+}
+
+/// Derive EvmLink PDA for an (agent mint, chain, EVM address) triple
+pub fn derive_evm_link_pda(mint: &Pubkey, chain_id: &str, evm_address: &[u8; 20]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"evm_link",
+            mint.as_ref(),
+            &sati::state::EvmLink::chain_seed(chain_id),
+            evm_address,
+        ],
+        &SATI_PROGRAM_ID,
+    )
+}
+
+/// Derive SnsLink PDA for an (agent mint, name registry) pair
+pub fn derive_sns_link_pda(mint: &Pubkey, name_account: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"sns_link", mint.as_ref(), name_account.as_ref()],
+        &SATI_PROGRAM_ID,
+    )
+}
+
+/// Derive AgentVerification PDA for an agent mint
+pub fn derive_agent_verification_pda(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"verification", mint.as_ref()], &SATI_PROGRAM_ID)
+}
+
+/// Derive registry treasury PDA
+pub fn derive_treasury_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"treasury"], &SATI_PROGRAM_ID)
+}
+
+/// Derive pending governance action PDA
+pub fn derive_pending_action_pda(action_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"pending_action", &action_id.to_le_bytes()],
+        &SATI_PROGRAM_ID,
+    )
+}
+
 /// Derive SATI attestation PDA (for SAS CPI authority)
 pub fn derive_sati_pda() -> (Pubkey, u8) {
     Pubkey::find_program_address(&[b"sati_attestation"], &SATI_PROGRAM_ID)
 }
 
+/// Derive the rewrite counter PDA for a regular (SAS) attestation
+pub fn derive_attestation_revision_pda(attestation: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"attestation_revision", attestation.as_ref()],
+        &SATI_PROGRAM_ID,
+    )
+}
+
+// ============================================================================
+// Transaction Helpers
+// ============================================================================
+
+/// Send `ixs` in one transaction signed by `signers`; the first signer pays.
+///
+/// Expires the blockhash afterwards so an identical transaction can be resent.
+pub fn send_tx(svm: &mut LiteSVM, signers: &[&Keypair], ixs: &[Instruction]) -> Result<(), String> {
+    let tx = Transaction::new_signed_with_payer(
+        ixs,
+        Some(&signers[0].pubkey()),
+        signers,
+        svm.latest_blockhash(),
+    );
+    let result = svm
+        .send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e));
+    svm.expire_blockhash();
+    result
+}
+
+/// Send a single instruction signed and paid by `signer`
+pub fn send(svm: &mut LiteSVM, signer: &Keypair, ix: Instruction) -> Result<(), String> {
+    send_tx(svm, &[signer], &[ix])
+}
+
+/// Assert that a failed transaction reports the named error or its code
+pub fn assert_error(err: &str, name: &str, code: u32) {
+    assert!(
+        err.contains(name) || err.contains(&code.to_string()),
+        "Expected {} error ({}), got: {}",
+        name,
+        code,
+        err
+    );
+}
+
+// ============================================================================
+// SAS (Solana Attestation Service) Test Setup (for regular attestation tests)
+// ============================================================================
+
+/// SATI credential name in SAS
+pub const SATI_CREDENTIAL_NAME: &str = "SATI";
+
+/// Derive the SATI credential PDA in SAS (authority = SATI attestation PDA)
+pub fn derive_sati_credential_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"credential",
+            derive_sati_pda().0.as_ref(),
+            SATI_CREDENTIAL_NAME.as_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    )
+}
+
+/// Derive a SAS schema PDA
+pub fn derive_sas_schema_pda(credential: &Pubkey, name: &str, version: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"schema", credential.as_ref(), name.as_bytes(), &[version]],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    )
+}
+
+/// Derive a SAS attestation PDA
+pub fn derive_sas_attestation_pda(
+    credential: &Pubkey,
+    schema: &Pubkey,
+    nonce: &[u8; 32],
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"attestation", credential.as_ref(), schema.as_ref(), nonce],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    )
+}
+
+/// Load the SAS program into LiteSVM (for tests whose CPIs reach SAS)
+///
+/// **Prerequisite:** dump the deployed program once:
+/// `solana program dump -u m 22zoJMtdu4tQc2PzL74ZUT7FrwgB1Udec8DdW4yw4BdG tests/fixtures/solana_attestation_service.so`
+pub fn add_sas_program(svm: &mut LiteSVM) {
+    let program_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/solana_attestation_service.so");
+    svm.add_program_from_file(SOLANA_ATTESTATION_SERVICE_ID, program_path)
+        .expect("Failed to load SAS program. Dump it to tests/fixtures first.");
+}
+
 // ============================================================================
 // Light Protocol Test Setup (for compressed attestation tests)
 // ============================================================================
@@ -114,11 +295,25 @@ pub async fn setup_light_test_env() -> LightTestEnv {
     // Enable prover - requires localnet running
     config.with_prover = true;
 
-    let rpc = LightProgramTest::new(config).await.expect(
+    let mut rpc = LightProgramTest::new(config).await.expect(
         "Failed to setup Light Protocol test environment. \
                  Make sure to run `pnpm localnet` first.",
     );
 
+    // Attestation handlers check the registry pause switch, so every Light
+    // test needs an (unpaused) RegistryConfig
+    let (registry_config, bump) = derive_registry_config_pda();
+    rpc.set_account(
+        registry_config,
+        Account {
+            lamports: 10_000_000,
+            data: mock_registry_config_data(&Pubkey::new_unique(), &Pubkey::new_unique(), bump),
+            owner: SATI_PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
     let env = rpc.test_accounts.clone();
     let payer = rpc.get_payer().insecure_clone();
     let indexer = TestIndexer::init_from_acounts(&payer, &env, 0).await;
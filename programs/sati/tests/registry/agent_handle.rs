@@ -0,0 +1,271 @@
+//! Tests for claim_agent_handle / release_agent_handle
+//!
+//! Handle normalization itself is unit-tested in src/handle.rs; these tests
+//! cover uniqueness, one-handle-per-agent, and the release policy.
+
+use litesvm::LiteSVM;
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+
+use crate::common::{
+    accounts::{
+        create_funded_keypair, create_mock_agent_record, create_mock_token22_ata,
+        derive_token22_ata, setup_agent, setup_registry,
+    },
+    instructions::{build_claim_agent_handle_ix, build_release_agent_handle_ix},
+    setup::{assert_error, derive_agent_handle_pda, derive_handle_claim_pda, send, setup_litesvm},
+};
+
+fn claim(
+    svm: &mut LiteSVM,
+    holder: &Keypair,
+    mint: &Pubkey,
+    ata: &Pubkey,
+    handle: &str,
+) -> Result<(), String> {
+    let (agent_handle, _) = derive_agent_handle_pda(handle);
+    let ix = build_claim_agent_handle_ix(&holder.pubkey(), mint, ata, &agent_handle, handle);
+    send(svm, holder, ix)
+}
+
+fn is_closed(svm: &LiteSVM, address: &Pubkey) -> bool {
+    svm.get_account(address)
+        .is_none_or(|account| account.lamports == 0)
+}
+
+/// Test claiming a handle stores the normalized handle and both lookups
+#[test]
+fn test_claim_handle_success() {
+    let mut svm = setup_litesvm();
+    let holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    setup_registry(&mut svm, &Keypair::new().pubkey());
+    let (mint, ata) = setup_agent(&mut svm, &holder.pubkey(), 1, 0);
+
+    claim(&mut svm, &holder, &mint, &ata, "My-Agent").expect("Claim should succeed");
+
+    let (agent_handle, _) = derive_agent_handle_pda("my-agent");
+    let account = svm.get_account(&agent_handle).expect("AgentHandle exists");
+    assert_eq!(
+        &account.data[8..40],
+        mint.as_ref(),
+        "Handle resolves to mint"
+    );
+    let len = u32::from_le_bytes(account.data[40..44].try_into().unwrap()) as usize;
+    assert_eq!(&account.data[44..44 + len], b"my-agent", "Stored lowercase");
+
+    let (handle_claim, _) = derive_handle_claim_pda(&mint);
+    let account = svm.get_account(&handle_claim).expect("HandleClaim exists");
+    assert_eq!(&account.data[8..40], agent_handle.as_ref());
+
+    println!("✅ test_claim_handle_success passed");
+}
+
+/// Test that case and confusable variants of a claimed handle are rejected
+#[test]
+fn test_claim_confusable_handle_taken() {
+    let mut svm = setup_litesvm();
+    let holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let impersonator = create_funded_keypair(&mut svm, 10_000_000_000);
+    setup_registry(&mut svm, &Keypair::new().pubkey());
+    let (mint, ata) = setup_agent(&mut svm, &holder.pubkey(), 1, 0);
+    let (other_mint, other_ata) = setup_agent(&mut svm, &impersonator.pubkey(), 2, 0);
+
+    claim(&mut svm, &holder, &mint, &ata, "paypal").expect("Claim should succeed");
+
+    for variant in ["PayPal", "paypa1", "PAYPA1"] {
+        let err = claim(&mut svm, &impersonator, &other_mint, &other_ata, variant)
+            .expect_err("Confusable variant should fail");
+        assert_error(&err, "HandleTaken", 6075);
+    }
+
+    println!("✅ test_claim_confusable_handle_taken passed");
+}
+
+/// Test that each agent can hold at most one handle
+#[test]
+fn test_claim_second_handle_rejected() {
+    let mut svm = setup_litesvm();
+    let holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    setup_registry(&mut svm, &Keypair::new().pubkey());
+    let (mint, ata) = setup_agent(&mut svm, &holder.pubkey(), 1, 0);
+
+    claim(&mut svm, &holder, &mint, &ata, "first").expect("Claim should succeed");
+    let result = claim(&mut svm, &holder, &mint, &ata, "second");
+    assert!(result.is_err(), "Second handle should fail");
+    assert!(is_closed(&svm, &derive_agent_handle_pda("second").0));
+
+    println!("✅ test_claim_second_handle_rejected passed");
+}
+
+/// Test that malformed handles are rejected
+#[test]
+fn test_claim_invalid_handle() {
+    let mut svm = setup_litesvm();
+    let holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    setup_registry(&mut svm, &Keypair::new().pubkey());
+    let (mint, ata) = setup_agent(&mut svm, &holder.pubkey(), 1, 0);
+
+    // Cyrillic 'а' homoglyph, too short, bad separators
+    for handle in ["p\u{0430}ypal", "ab", "-agent", "my agent"] {
+        let placeholder = Keypair::new().pubkey();
+        let ix = build_claim_agent_handle_ix(&holder.pubkey(), &mint, &ata, &placeholder, handle);
+        let err = send(&mut svm, &holder, ix).expect_err("Invalid handle should fail");
+        assert_error(&err, "InvalidHandle", 6074);
+    }
+
+    println!("✅ test_claim_invalid_handle passed");
+}
+
+/// Test that only the current holder of an active agent can claim
+#[test]
+fn test_claim_handle_requires_active_holder() {
+    let mut svm = setup_litesvm();
+    let holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let attacker = create_funded_keypair(&mut svm, 10_000_000_000);
+    setup_registry(&mut svm, &Keypair::new().pubkey());
+    let (mint, _) = setup_agent(&mut svm, &holder.pubkey(), 1, 0);
+
+    // Attacker has an ATA for the mint but no balance
+    let attacker_ata = derive_token22_ata(&attacker.pubkey(), &mint);
+    create_mock_token22_ata(&mut svm, &attacker_ata, &mint, &attacker.pubkey(), 0);
+    let err = claim(&mut svm, &attacker, &mint, &attacker_ata, "stolen")
+        .expect_err("Non-holder claim should fail");
+    assert_error(&err, "InvalidAuthority", 6001);
+
+    // Retired agents cannot claim
+    let (retired_mint, retired_ata) = setup_agent(&mut svm, &holder.pubkey(), 2, 0);
+    create_mock_agent_record(&mut svm, &retired_mint, 2, 1);
+    let err = claim(&mut svm, &holder, &retired_mint, &retired_ata, "retired")
+        .expect_err("Retired agent claim should fail");
+    assert_error(&err, "AgentRetired", 6055);
+
+    println!("✅ test_claim_handle_requires_active_holder passed");
+}
+
+/// Test the holder can release and the handle becomes claimable again
+#[test]
+fn test_release_handle_by_holder() {
+    let mut svm = setup_litesvm();
+    let holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let other = create_funded_keypair(&mut svm, 10_000_000_000);
+    let registry_config = setup_registry(&mut svm, &Keypair::new().pubkey()).0;
+    let (mint, ata) = setup_agent(&mut svm, &holder.pubkey(), 1, 0);
+    let (other_mint, other_ata) = setup_agent(&mut svm, &other.pubkey(), 2, 0);
+
+    claim(&mut svm, &holder, &mint, &ata, "shared").expect("Claim should succeed");
+    let (agent_handle, _) = derive_agent_handle_pda("shared");
+
+    let ix = build_release_agent_handle_ix(
+        &holder.pubkey(),
+        &registry_config,
+        &mint,
+        &agent_handle,
+        &holder.pubkey(),
+        Some(&ata),
+    );
+    send(&mut svm, &holder, ix).expect("Release should succeed");
+    assert!(is_closed(&svm, &agent_handle));
+    assert!(is_closed(&svm, &derive_handle_claim_pda(&mint).0));
+
+    claim(&mut svm, &other, &other_mint, &other_ata, "shared")
+        .expect("Released handle should be claimable");
+
+    println!("✅ test_release_handle_by_holder passed");
+}
+
+/// Test release permissions: strangers rejected, authority and (after retirement) anyone allowed
+#[test]
+fn test_release_handle_permissions() {
+    let mut svm = setup_litesvm();
+    let holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let stranger = create_funded_keypair(&mut svm, 10_000_000_000);
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let registry_config = setup_registry(&mut svm, &authority.pubkey()).0;
+    let (mint, ata) = setup_agent(&mut svm, &holder.pubkey(), 1, 0);
+    let (second_mint, second_ata) = setup_agent(&mut svm, &holder.pubkey(), 2, 0);
+
+    claim(&mut svm, &holder, &mint, &ata, "moderated").expect("Claim should succeed");
+    claim(&mut svm, &holder, &second_mint, &second_ata, "retiring").expect("Claim should succeed");
+    let (moderated, _) = derive_agent_handle_pda("moderated");
+    let (retiring, _) = derive_agent_handle_pda("retiring");
+
+    // Stranger cannot release an active agent's handle
+    let ix = build_release_agent_handle_ix(
+        &stranger.pubkey(),
+        &registry_config,
+        &mint,
+        &moderated,
+        &holder.pubkey(),
+        None,
+    );
+    let err = send(&mut svm, &stranger, ix).expect_err("Stranger release should fail");
+    assert_error(&err, "HandleReleaseNotAllowed", 6076);
+
+    // Registry authority can (moderation); rent still goes to the claimer
+    let ix = build_release_agent_handle_ix(
+        &authority.pubkey(),
+        &registry_config,
+        &mint,
+        &moderated,
+        &holder.pubkey(),
+        None,
+    );
+    send(&mut svm, &authority, ix).expect("Authority release should succeed");
+    assert!(is_closed(&svm, &moderated));
+
+    // Anyone can clean up once the agent is retired
+    create_mock_agent_record(&mut svm, &second_mint, 2, 1);
+    let ix = build_release_agent_handle_ix(
+        &stranger.pubkey(),
+        &registry_config,
+        &second_mint,
+        &retiring,
+        &holder.pubkey(),
+        None,
+    );
+    send(&mut svm, &stranger, ix).expect("Release of retired agent's handle should succeed");
+    assert!(is_closed(&svm, &retiring));
+
+    println!("✅ test_release_handle_permissions passed");
+}
+
+/// Test that the handle follows the NFT: after a transfer only the new holder can release
+#[test]
+fn test_handle_follows_transfer() {
+    let mut svm = setup_litesvm();
+    let old_holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let new_holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let registry_config = setup_registry(&mut svm, &Keypair::new().pubkey()).0;
+    let (mint, old_ata) = setup_agent(&mut svm, &old_holder.pubkey(), 1, 0);
+
+    claim(&mut svm, &old_holder, &mint, &old_ata, "portable").expect("Claim should succeed");
+    let (agent_handle, _) = derive_agent_handle_pda("portable");
+
+    // Simulate the NFT transfer
+    create_mock_token22_ata(&mut svm, &old_ata, &mint, &old_holder.pubkey(), 0);
+    let new_ata = derive_token22_ata(&new_holder.pubkey(), &mint);
+    create_mock_token22_ata(&mut svm, &new_ata, &mint, &new_holder.pubkey(), 1);
+
+    let ix = build_release_agent_handle_ix(
+        &old_holder.pubkey(),
+        &registry_config,
+        &mint,
+        &agent_handle,
+        &old_holder.pubkey(),
+        Some(&old_ata),
+    );
+    let err = send(&mut svm, &old_holder, ix).expect_err("Previous holder release should fail");
+    assert_error(&err, "HandleReleaseNotAllowed", 6076);
+
+    let ix = build_release_agent_handle_ix(
+        &new_holder.pubkey(),
+        &registry_config,
+        &mint,
+        &agent_handle,
+        &old_holder.pubkey(),
+        Some(&new_ata),
+    );
+    send(&mut svm, &new_holder, ix).expect("New holder release should succeed");
+    assert!(is_closed(&svm, &agent_handle));
+
+    println!("✅ test_handle_follows_transfer passed");
+}
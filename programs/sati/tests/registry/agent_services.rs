@@ -0,0 +1,106 @@
+//! Tests for the set_agent_services instruction
+//!
+//! Entry validation and sizing are unit-tested in
+//! src/instructions/registry/set_agent_services.rs.
+
+use sati::state::AgentServices;
+use solana_sdk::signer::Signer;
+
+use crate::common::{
+    accounts::{create_funded_keypair, create_mock_token22_ata, derive_token22_ata, setup_agent},
+    instructions::{build_set_agent_services_ix, ServiceEntry},
+    setup::{assert_error, derive_agent_services_pda, send, setup_litesvm},
+};
+
+fn service(protocol: &str, endpoint: &str, version: &str) -> ServiceEntry {
+    ServiceEntry {
+        protocol: protocol.to_string(),
+        endpoint: endpoint.to_string(),
+        version: version.to_string(),
+    }
+}
+
+/// Test creating, growing, shrinking and removing the service list
+#[test]
+fn test_set_agent_services_lifecycle() {
+    let mut svm = setup_litesvm();
+    let holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (mint, ata) = setup_agent(&mut svm, &holder.pubkey(), 1, 0);
+    let (agent_services, _) = derive_agent_services_pda(&mint);
+
+    let one = vec![service("MCP", "https://mcp.agent.example/", "2025-06-18")];
+    let ix = build_set_agent_services_ix(&holder.pubkey(), &mint, &ata, one.clone());
+    send(&mut svm, &holder, ix).expect("Create should succeed");
+
+    let account = svm
+        .get_account(&agent_services)
+        .expect("AgentServices exists");
+    assert_eq!(account.data.len(), AgentServices::space(&one));
+    let small_lamports = account.lamports;
+
+    let two = vec![
+        service("A2A", "https://agent.example/agent-card.json", "0.3.0"),
+        service("MCP", "https://mcp.agent.example/", "2025-06-18"),
+    ];
+    let ix = build_set_agent_services_ix(&holder.pubkey(), &mint, &ata, two.clone());
+    send(&mut svm, &holder, ix).expect("Grow should succeed");
+    let account = svm.get_account(&agent_services).unwrap();
+    assert_eq!(account.data.len(), AgentServices::space(&two));
+    assert!(account.lamports > small_lamports, "Rent topped up");
+
+    let ix = build_set_agent_services_ix(&holder.pubkey(), &mint, &ata, one.clone());
+    send(&mut svm, &holder, ix).expect("Shrink should succeed");
+    let account = svm.get_account(&agent_services).unwrap();
+    assert_eq!(account.data.len(), AgentServices::space(&one));
+    assert_eq!(account.lamports, small_lamports, "Excess rent refunded");
+
+    let ix = build_set_agent_services_ix(&holder.pubkey(), &mint, &ata, vec![]);
+    send(&mut svm, &holder, ix).expect("Clear should succeed");
+    assert!(
+        svm.get_account(&agent_services)
+            .is_none_or(|account| account.lamports == 0),
+        "AgentServices should be closed"
+    );
+
+    println!("✅ test_set_agent_services_lifecycle passed");
+}
+
+/// Test that only the holder can edit and entries are validated
+#[test]
+fn test_set_agent_services_rejections() {
+    let mut svm = setup_litesvm();
+    let holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let attacker = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (mint, ata) = setup_agent(&mut svm, &holder.pubkey(), 1, 0);
+
+    let attacker_ata = derive_token22_ata(&attacker.pubkey(), &mint);
+    create_mock_token22_ata(&mut svm, &attacker_ata, &mint, &attacker.pubkey(), 0);
+    let ix = build_set_agent_services_ix(
+        &attacker.pubkey(),
+        &mint,
+        &attacker_ata,
+        vec![service("MCP", "https://evil.example/", "")],
+    );
+    let err = send(&mut svm, &attacker, ix).expect_err("Non-holder should fail");
+    assert_error(&err, "InvalidAuthority", 6001);
+
+    let ix = build_set_agent_services_ix(
+        &holder.pubkey(),
+        &mint,
+        &ata,
+        vec![service("", "https://x.example/", "")],
+    );
+    let err = send(&mut svm, &holder, ix).expect_err("Empty protocol should fail");
+    assert_error(&err, "InvalidServiceEntry", 6084);
+
+    let ix = build_set_agent_services_ix(
+        &holder.pubkey(),
+        &mint,
+        &ata,
+        vec![service("A2A", "https://a.example/", ""); 9],
+    );
+    let err = send(&mut svm, &holder, ix).expect_err("Too many entries should fail");
+    assert_error(&err, "TooManyServices", 6083);
+
+    println!("✅ test_set_agent_services_rejections passed");
+}
@@ -0,0 +1,153 @@
+//! Tests for set_agent_status / suspend_agent / unsuspend_agent
+//!
+//! Attestation handlers reject suspended agents via AgentRecord::load_active,
+//! which is unit-tested in src/state.rs.
+
+use litesvm::LiteSVM;
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+
+use crate::common::{
+    accounts::{
+        create_funded_keypair, create_mock_token22_ata, derive_token22_ata, setup_agent,
+        setup_registry,
+    },
+    instructions::{
+        build_set_agent_status_ix, build_suspend_agent_ix, build_unsuspend_agent_ix, AgentStatus,
+    },
+    setup::{assert_error, derive_agent_record_pda, send, setup_litesvm},
+};
+
+/// AgentRecord.status offset (after discriminator, mint, member_number,
+/// registered_slot, registered_at, non_transferable)
+const STATUS_OFFSET: usize = 65;
+
+fn status_of(svm: &LiteSVM, mint: &Pubkey) -> u8 {
+    let (agent_record, _) = derive_agent_record_pda(mint);
+    svm.get_account(&agent_record).unwrap().data[STATUS_OFFSET]
+}
+
+/// Test holder deprecating and reactivating an agent
+#[test]
+fn test_holder_deprecate_and_reactivate() {
+    let mut svm = setup_litesvm();
+    let holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    setup_registry(&mut svm, &Keypair::new().pubkey());
+    let (mint, ata) = setup_agent(&mut svm, &holder.pubkey(), 1, 0);
+
+    let ix = build_set_agent_status_ix(&holder.pubkey(), &mint, &ata, AgentStatus::Deprecated);
+    send(&mut svm, &holder, ix).expect("Deprecate should succeed");
+    assert_eq!(status_of(&svm, &mint), AgentStatus::Deprecated as u8);
+
+    // Setting the same status again is rejected
+    let ix = build_set_agent_status_ix(&holder.pubkey(), &mint, &ata, AgentStatus::Deprecated);
+    let err = send(&mut svm, &holder, ix).expect_err("Unchanged status should fail");
+    assert_error(&err, "InvalidAgentStatus", 6081);
+
+    let ix = build_set_agent_status_ix(&holder.pubkey(), &mint, &ata, AgentStatus::Active);
+    send(&mut svm, &holder, ix).expect("Reactivate should succeed");
+    assert_eq!(status_of(&svm, &mint), AgentStatus::Active as u8);
+
+    println!("✅ test_holder_deprecate_and_reactivate passed");
+}
+
+/// Test that holders cannot set Suspended or Retired, and non-holders cannot set anything
+#[test]
+fn test_holder_status_restrictions() {
+    let mut svm = setup_litesvm();
+    let holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let attacker = create_funded_keypair(&mut svm, 10_000_000_000);
+    setup_registry(&mut svm, &Keypair::new().pubkey());
+    let (mint, ata) = setup_agent(&mut svm, &holder.pubkey(), 1, 0);
+
+    for status in [AgentStatus::Suspended, AgentStatus::Retired] {
+        let ix = build_set_agent_status_ix(&holder.pubkey(), &mint, &ata, status);
+        let err = send(&mut svm, &holder, ix).expect_err("Holder cannot set this status");
+        assert_error(&err, "InvalidAgentStatus", 6081);
+    }
+
+    // Attacker has an ATA for the mint but no balance
+    let attacker_ata = derive_token22_ata(&attacker.pubkey(), &mint);
+    create_mock_token22_ata(&mut svm, &attacker_ata, &mint, &attacker.pubkey(), 0);
+    let ix = build_set_agent_status_ix(
+        &attacker.pubkey(),
+        &mint,
+        &attacker_ata,
+        AgentStatus::Deprecated,
+    );
+    let err = send(&mut svm, &attacker, ix).expect_err("Non-holder should fail");
+    assert_error(&err, "InvalidAuthority", 6001);
+
+    // Retired agents are terminal
+    let (retired_mint, retired_ata) = setup_agent(&mut svm, &holder.pubkey(), 1, 1);
+    let ix = build_set_agent_status_ix(
+        &holder.pubkey(),
+        &retired_mint,
+        &retired_ata,
+        AgentStatus::Active,
+    );
+    let err = send(&mut svm, &holder, ix).expect_err("Retired agent should fail");
+    assert_error(&err, "AgentRetired", 6055);
+
+    println!("✅ test_holder_status_restrictions passed");
+}
+
+/// Test authority suspension locks the holder out until lifted
+#[test]
+fn test_suspend_and_unsuspend() {
+    let mut svm = setup_litesvm();
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let registry_config = setup_registry(&mut svm, &authority.pubkey()).0;
+    let (mint, ata) = setup_agent(&mut svm, &holder.pubkey(), 1, 0); // Active
+
+    let ix = build_suspend_agent_ix(&holder.pubkey(), &registry_config, &mint);
+    let err = send(&mut svm, &holder, ix).expect_err("Holder cannot suspend");
+    assert_error(&err, "InvalidAuthority", 6001);
+
+    let ix = build_suspend_agent_ix(&authority.pubkey(), &registry_config, &mint);
+    send(&mut svm, &authority, ix).expect("Suspend should succeed");
+    assert_eq!(status_of(&svm, &mint), AgentStatus::Suspended as u8);
+
+    // Holder cannot lift a suspension
+    let ix = build_set_agent_status_ix(&holder.pubkey(), &mint, &ata, AgentStatus::Active);
+    let err = send(&mut svm, &holder, ix).expect_err("Suspended holder should fail");
+    assert_error(&err, "AgentSuspended", 6080);
+
+    let ix = build_suspend_agent_ix(&authority.pubkey(), &registry_config, &mint);
+    let err = send(&mut svm, &authority, ix).expect_err("Double suspend should fail");
+    assert_error(&err, "InvalidAgentStatus", 6081);
+
+    let ix = build_unsuspend_agent_ix(&authority.pubkey(), &registry_config, &mint);
+    send(&mut svm, &authority, ix).expect("Unsuspend should succeed");
+    assert_eq!(status_of(&svm, &mint), AgentStatus::Active as u8);
+
+    let ix = build_unsuspend_agent_ix(&authority.pubkey(), &registry_config, &mint);
+    let err = send(&mut svm, &authority, ix).expect_err("Unsuspend of active agent should fail");
+    assert_error(&err, "InvalidAgentStatus", 6081);
+
+    println!("✅ test_suspend_and_unsuspend passed");
+}
+
+/// Test unsuspend restores the status the agent had before suspension
+#[test]
+fn test_unsuspend_restores_deprecated() {
+    let mut svm = setup_litesvm();
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let registry_config = setup_registry(&mut svm, &authority.pubkey()).0;
+    let (mint, _ata) = setup_agent(&mut svm, &holder.pubkey(), 1, 2); // Deprecated
+
+    let ix = build_suspend_agent_ix(&authority.pubkey(), &registry_config, &mint);
+    send(&mut svm, &authority, ix).expect("Suspend should succeed");
+    assert_eq!(status_of(&svm, &mint), AgentStatus::Suspended as u8);
+
+    let ix = build_unsuspend_agent_ix(&authority.pubkey(), &registry_config, &mint);
+    send(&mut svm, &authority, ix).expect("Unsuspend should succeed");
+    assert_eq!(
+        status_of(&svm, &mint),
+        AgentStatus::Deprecated as u8,
+        "unsuspend should restore Deprecated, not reset to Active"
+    );
+
+    println!("✅ test_unsuspend_restores_deprecated passed");
+}
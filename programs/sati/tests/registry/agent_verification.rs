@@ -0,0 +1,214 @@
+//! Tests for agent verification badges and schema verification requirements
+//!
+//! Enforcement in create_*_attestation needs the Light/SAS stack; these tests
+//! cover issuing, revoking, the schema requirement and SchemaConfig migration.
+
+use litesvm::LiteSVM;
+use solana_sdk::{account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer};
+
+use crate::common::{
+    accounts::{
+        compute_anchor_account_discriminator, create_funded_keypair, create_mock_agent_record,
+        setup_registry,
+    },
+    instructions::{
+        build_migrate_schema_config_ix, build_register_schema_config_ix,
+        build_revoke_agent_verification_ix, build_set_agent_verification_ix,
+        build_set_schema_min_verification_ix, SignatureMode, StorageType,
+    },
+    setup::{
+        assert_error, derive_agent_verification_pda, derive_schema_config_pda, send, setup_litesvm,
+        SATI_PROGRAM_ID,
+    },
+};
+
+const SCHEMA_NAME: &str = "Feedback";
+
+/// Helper to register a schema config (delegation None, "Feedback" name)
+fn setup_schema(svm: &mut LiteSVM, authority: &Keypair, registry_config: &Pubkey) -> Pubkey {
+    let sas_schema = Pubkey::new_unique();
+    let (schema_config, _) = derive_schema_config_pda(&sas_schema);
+    let ix = build_register_schema_config_ix(
+        &authority.pubkey(),
+        registry_config,
+        &authority.pubkey(),
+        &schema_config,
+        &sas_schema,
+        SignatureMode::DualSignature,
+        StorageType::Compressed,
+        None,
+        true,
+        SCHEMA_NAME.to_string(),
+    );
+    send(svm, authority, ix).expect("Schema registration should succeed");
+    sas_schema
+}
+
+/// Test issuing a badge and updating its level
+#[test]
+fn test_set_agent_verification_success() {
+    let mut svm = setup_litesvm();
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let registry_config = setup_registry(&mut svm, &authority.pubkey()).0;
+    let mint = Pubkey::new_unique();
+    create_mock_agent_record(&mut svm, &mint, 1, 0);
+    let (agent_verification, bump) = derive_agent_verification_pda(&mint);
+
+    let ix = build_set_agent_verification_ix(&authority.pubkey(), &registry_config, &mint, 1);
+    send(&mut svm, &authority, ix).expect("Issuing badge should succeed");
+
+    let account = svm
+        .get_account(&agent_verification)
+        .expect("AgentVerification should exist");
+    assert_eq!(account.owner, SATI_PROGRAM_ID);
+    assert_eq!(account.data.len(), 82);
+    assert_eq!(&account.data[8..40], mint.as_ref(), "mint");
+    assert_eq!(account.data[40], 1, "level");
+    assert_eq!(&account.data[41..73], authority.pubkey().as_ref(), "issuer");
+    assert_eq!(account.data[81], bump, "bump");
+
+    // Re-issuing updates the existing account in place
+    let ix = build_set_agent_verification_ix(&authority.pubkey(), &registry_config, &mint, 3);
+    send(&mut svm, &authority, ix).expect("Updating badge should succeed");
+    let account = svm.get_account(&agent_verification).unwrap();
+    assert_eq!(account.data[40], 3, "level updated");
+
+    println!("✅ test_set_agent_verification_success passed");
+}
+
+/// Test that only the authority can issue badges, and only to active agents
+#[test]
+fn test_set_agent_verification_rejections() {
+    let mut svm = setup_litesvm();
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let attacker = create_funded_keypair(&mut svm, 10_000_000_000);
+    let registry_config = setup_registry(&mut svm, &authority.pubkey()).0;
+    let mint = Pubkey::new_unique();
+    create_mock_agent_record(&mut svm, &mint, 1, 0);
+
+    let ix = build_set_agent_verification_ix(&attacker.pubkey(), &registry_config, &mint, 1);
+    let err = send(&mut svm, &attacker, ix).expect_err("Non-authority should fail");
+    assert_error(&err, "InvalidAuthority", 6001);
+
+    let ix = build_set_agent_verification_ix(&authority.pubkey(), &registry_config, &mint, 0);
+    let err = send(&mut svm, &authority, ix).expect_err("Level 0 should fail");
+    assert_error(&err, "InvalidVerificationLevel", 6078);
+
+    let retired_mint = Pubkey::new_unique();
+    create_mock_agent_record(&mut svm, &retired_mint, 2, 1);
+    let ix =
+        build_set_agent_verification_ix(&authority.pubkey(), &registry_config, &retired_mint, 1);
+    let err = send(&mut svm, &authority, ix).expect_err("Retired agent should fail");
+    assert_error(&err, "AgentRetired", 6055);
+
+    println!("✅ test_set_agent_verification_rejections passed");
+}
+
+/// Test revoking a badge closes the account
+#[test]
+fn test_revoke_agent_verification() {
+    let mut svm = setup_litesvm();
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let attacker = create_funded_keypair(&mut svm, 10_000_000_000);
+    let registry_config = setup_registry(&mut svm, &authority.pubkey()).0;
+    let mint = Pubkey::new_unique();
+    create_mock_agent_record(&mut svm, &mint, 1, 0);
+
+    let ix = build_set_agent_verification_ix(&authority.pubkey(), &registry_config, &mint, 2);
+    send(&mut svm, &authority, ix).expect("Issuing badge should succeed");
+
+    let ix = build_revoke_agent_verification_ix(&attacker.pubkey(), &registry_config, &mint);
+    let err = send(&mut svm, &attacker, ix).expect_err("Non-authority revoke should fail");
+    assert_error(&err, "InvalidAuthority", 6001);
+
+    let ix = build_revoke_agent_verification_ix(&authority.pubkey(), &registry_config, &mint);
+    send(&mut svm, &authority, ix).expect("Revoke should succeed");
+
+    let (agent_verification, _) = derive_agent_verification_pda(&mint);
+    assert!(
+        svm.get_account(&agent_verification)
+            .is_none_or(|account| account.lamports == 0),
+        "AgentVerification should be closed"
+    );
+
+    println!("✅ test_revoke_agent_verification passed");
+}
+
+/// Test setting a schema's minimum verification level
+#[test]
+fn test_set_schema_min_verification() {
+    let mut svm = setup_litesvm();
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let attacker = create_funded_keypair(&mut svm, 10_000_000_000);
+    let registry_config = setup_registry(&mut svm, &authority.pubkey()).0;
+    let sas_schema = setup_schema(&mut svm, &authority, &registry_config);
+    let (schema_config, _) = derive_schema_config_pda(&sas_schema);
+
+    // min_verification_level follows bump: 48 + name + bump(1)
+    let offset = 49 + SCHEMA_NAME.len();
+    let account = svm.get_account(&schema_config).unwrap();
+    assert_eq!(account.data[offset], 0, "No requirement by default");
+
+    let ix =
+        build_set_schema_min_verification_ix(&attacker.pubkey(), &registry_config, &sas_schema, 1);
+    let err = send(&mut svm, &attacker, ix).expect_err("Non-authority should fail");
+    assert_error(&err, "InvalidAuthority", 6001);
+
+    let ix =
+        build_set_schema_min_verification_ix(&authority.pubkey(), &registry_config, &sas_schema, 2);
+    send(&mut svm, &authority, ix).expect("Setting requirement should succeed");
+    let account = svm.get_account(&schema_config).unwrap();
+    assert_eq!(account.data[offset], 2, "min_verification_level");
+
+    println!("✅ test_set_schema_min_verification passed");
+}
+
+/// Test migrating a fully packed pre-verification SchemaConfig
+#[test]
+fn test_migrate_schema_config() {
+    let mut svm = setup_litesvm();
+    let payer = create_funded_keypair(&mut svm, 10_000_000_000);
+    let sas_schema = Pubkey::new_unique();
+    let (schema_config, bump) = derive_schema_config_pda(&sas_schema);
+
+    // Legacy 113-byte layout with delegation_schema = Some and a 32-char name
+    let name = "n".repeat(32);
+    let mut data = Vec::with_capacity(113);
+    data.extend_from_slice(&compute_anchor_account_discriminator("SchemaConfig"));
+    data.extend_from_slice(sas_schema.as_ref());
+    data.extend_from_slice(&[0, 0, 1]); // signature_mode, storage_type, Some
+    data.extend_from_slice(Pubkey::new_unique().as_ref()); // delegation_schema
+    data.push(1); // closeable
+    data.extend_from_slice(&(name.len() as u32).to_le_bytes());
+    data.extend_from_slice(name.as_bytes());
+    data.push(bump);
+    assert_eq!(data.len(), 113);
+
+    let lamports = svm.minimum_balance_for_rent_exemption(data.len());
+    svm.set_account(
+        schema_config,
+        Account {
+            lamports,
+            data,
+            owner: SATI_PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+
+    let ix = build_migrate_schema_config_ix(&payer.pubkey(), &sas_schema);
+    send(&mut svm, &payer, ix).expect("Migration should succeed");
+
+    let account = svm.get_account(&schema_config).unwrap();
+    assert_eq!(account.data.len(), 114);
+    assert_eq!(account.data[112], bump, "bump preserved");
+    assert_eq!(account.data[113], 0, "min_verification_level = 0");
+    assert!(account.lamports >= svm.minimum_balance_for_rent_exemption(114));
+
+    let ix = build_migrate_schema_config_ix(&payer.pubkey(), &sas_schema);
+    let err = send(&mut svm, &payer, ix).expect_err("Second migration should fail");
+    assert_error(&err, "SchemaConfigAlreadyMigrated", 6079);
+
+    println!("✅ test_migrate_schema_config passed");
+}
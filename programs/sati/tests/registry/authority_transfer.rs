@@ -0,0 +1,261 @@
+//! Tests for the two-step registry authority transfer
+//! (propose_registry_authority / accept_registry_authority / cancel_registry_authority_transfer)
+
+use litesvm::LiteSVM;
+use solana_sdk::{pubkey::Pubkey, signer::Signer};
+
+use crate::common::{
+    accounts::{create_funded_keypair, setup_registry},
+    instructions::{
+        build_accept_authority_ix, build_cancel_authority_transfer_ix, build_pause_registry_ix,
+        build_propose_authority_ix, build_set_registration_fee_ix, build_unpause_registry_ix,
+        build_update_authority_ix,
+    },
+    setup::{send, setup_litesvm},
+};
+
+fn stored_authority(svm: &LiteSVM, registry_config: &Pubkey) -> Pubkey {
+    let account = svm.get_account(registry_config).unwrap();
+    Pubkey::try_from(&account.data[40..72]).unwrap()
+}
+
+fn stored_pending_authority(svm: &LiteSVM, registry_config: &Pubkey) -> Pubkey {
+    let account = svm.get_account(registry_config).unwrap();
+    Pubkey::try_from(&account.data[82..114]).unwrap()
+}
+
+/// Test propose + accept moves authority and clears the proposal
+#[test]
+fn test_propose_and_accept_authority() {
+    let mut svm = setup_litesvm();
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let new_authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let registry_config = setup_registry(&mut svm, &authority.pubkey()).0;
+
+    let ix = build_propose_authority_ix(
+        &authority.pubkey(),
+        &registry_config,
+        new_authority.pubkey(),
+    );
+    send(&mut svm, &authority, ix).expect("Propose should succeed");
+
+    // Authority unchanged until accepted
+    assert_eq!(stored_authority(&svm, &registry_config), authority.pubkey());
+    assert_eq!(
+        stored_pending_authority(&svm, &registry_config),
+        new_authority.pubkey()
+    );
+
+    let ix = build_accept_authority_ix(&new_authority.pubkey(), &registry_config);
+    send(&mut svm, &new_authority, ix).expect("Accept should succeed");
+
+    assert_eq!(
+        stored_authority(&svm, &registry_config),
+        new_authority.pubkey()
+    );
+    assert_eq!(
+        stored_pending_authority(&svm, &registry_config),
+        Pubkey::default()
+    );
+
+    println!("✅ test_propose_and_accept_authority passed");
+}
+
+/// Test that only the pending authority can accept
+#[test]
+fn test_accept_wrong_signer() {
+    let mut svm = setup_litesvm();
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let new_authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let attacker = create_funded_keypair(&mut svm, 10_000_000_000);
+    let registry_config = setup_registry(&mut svm, &authority.pubkey()).0;
+
+    let ix = build_propose_authority_ix(
+        &authority.pubkey(),
+        &registry_config,
+        new_authority.pubkey(),
+    );
+    send(&mut svm, &authority, ix).expect("Propose should succeed");
+
+    let ix = build_accept_authority_ix(&attacker.pubkey(), &registry_config);
+    let err = send(&mut svm, &attacker, ix).expect_err("Wrong signer should fail");
+    assert!(
+        err.contains("InvalidPendingAuthority") || err.contains("6060"),
+        "Expected InvalidPendingAuthority error (6060), got: {}",
+        err
+    );
+    assert_eq!(stored_authority(&svm, &registry_config), authority.pubkey());
+
+    println!("✅ test_accept_wrong_signer passed");
+}
+
+/// Test that renouncing cannot be done through propose
+#[test]
+fn test_propose_default_rejected() {
+    let mut svm = setup_litesvm();
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let registry_config = setup_registry(&mut svm, &authority.pubkey()).0;
+
+    let ix = build_propose_authority_ix(&authority.pubkey(), &registry_config, Pubkey::default());
+    let err = send(&mut svm, &authority, ix).expect_err("Proposing default should fail");
+    assert!(
+        err.contains("InvalidPendingAuthority") || err.contains("6060"),
+        "Expected InvalidPendingAuthority error (6060), got: {}",
+        err
+    );
+
+    println!("✅ test_propose_default_rejected passed");
+}
+
+/// Test that only the current authority can propose
+#[test]
+fn test_propose_wrong_signer() {
+    let mut svm = setup_litesvm();
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let attacker = create_funded_keypair(&mut svm, 10_000_000_000);
+    let registry_config = setup_registry(&mut svm, &authority.pubkey()).0;
+
+    let ix = build_propose_authority_ix(&attacker.pubkey(), &registry_config, attacker.pubkey());
+    let result = send(&mut svm, &attacker, ix);
+    assert!(result.is_err(), "Propose by non-authority should fail");
+    assert_eq!(
+        stored_pending_authority(&svm, &registry_config),
+        Pubkey::default()
+    );
+
+    println!("✅ test_propose_wrong_signer passed");
+}
+
+/// Test cancelling a proposal prevents acceptance
+#[test]
+fn test_cancel_authority_transfer() {
+    let mut svm = setup_litesvm();
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let new_authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let registry_config = setup_registry(&mut svm, &authority.pubkey()).0;
+
+    let ix = build_propose_authority_ix(
+        &authority.pubkey(),
+        &registry_config,
+        new_authority.pubkey(),
+    );
+    send(&mut svm, &authority, ix).expect("Propose should succeed");
+
+    let ix = build_cancel_authority_transfer_ix(&authority.pubkey(), &registry_config);
+    send(&mut svm, &authority, ix).expect("Cancel should succeed");
+    assert_eq!(
+        stored_pending_authority(&svm, &registry_config),
+        Pubkey::default()
+    );
+
+    let ix = build_accept_authority_ix(&new_authority.pubkey(), &registry_config);
+    let err = send(&mut svm, &new_authority, ix).expect_err("Accept after cancel should fail");
+    assert!(
+        err.contains("NoPendingAuthority") || err.contains("6061"),
+        "Expected NoPendingAuthority error (6061), got: {}",
+        err
+    );
+
+    // Nothing left to cancel
+    svm.expire_blockhash();
+    let ix = build_cancel_authority_transfer_ix(&authority.pubkey(), &registry_config);
+    let err = send(&mut svm, &authority, ix).expect_err("Second cancel should fail");
+    assert!(
+        err.contains("NoPendingAuthority") || err.contains("6061"),
+        "Expected NoPendingAuthority error (6061), got: {}",
+        err
+    );
+
+    println!("✅ test_cancel_authority_transfer passed");
+}
+
+/// Test that renouncing clears any pending proposal
+#[test]
+fn test_renounce_clears_pending_authority() {
+    let mut svm = setup_litesvm();
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let new_authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let registry_config = setup_registry(&mut svm, &authority.pubkey()).0;
+
+    let ix = build_propose_authority_ix(
+        &authority.pubkey(),
+        &registry_config,
+        new_authority.pubkey(),
+    );
+    send(&mut svm, &authority, ix).expect("Propose should succeed");
+
+    let ix = build_update_authority_ix(&authority.pubkey(), &registry_config, None);
+    send(&mut svm, &authority, ix).expect("Renounce should succeed");
+    assert_eq!(stored_authority(&svm, &registry_config), Pubkey::default());
+
+    let ix = build_accept_authority_ix(&new_authority.pubkey(), &registry_config);
+    let result = send(&mut svm, &new_authority, ix);
+    assert!(result.is_err(), "Accept after renounce should fail");
+    assert_eq!(stored_authority(&svm, &registry_config), Pubkey::default());
+
+    println!("✅ test_renounce_clears_pending_authority passed");
+}
+
+/// Test that renouncing is rejected while any pause flag is set
+///
+/// Nobody could unpause afterwards, so the paused flows would be frozen for good.
+#[test]
+fn test_renounce_rejected_while_paused() {
+    let mut svm = setup_litesvm();
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let registry_config = setup_registry(&mut svm, &authority.pubkey()).0;
+
+    let ix = build_pause_registry_ix(&authority.pubkey(), &registry_config, 1 << 4);
+    send(&mut svm, &authority, ix).expect("Pause should succeed");
+
+    let ix = build_update_authority_ix(&authority.pubkey(), &registry_config, None);
+    let err = send(&mut svm, &authority, ix).expect_err("Renounce should fail while paused");
+    assert!(
+        err.contains("RenounceWhilePaused") || err.contains("6113"),
+        "Expected RenounceWhilePaused error (6113), got: {}",
+        err
+    );
+    assert_eq!(stored_authority(&svm, &registry_config), authority.pubkey());
+
+    let ix = build_unpause_registry_ix(&authority.pubkey(), &registry_config, 1 << 4);
+    send(&mut svm, &authority, ix).expect("Unpause should succeed");
+    svm.expire_blockhash();
+
+    let ix = build_update_authority_ix(&authority.pubkey(), &registry_config, None);
+    send(&mut svm, &authority, ix).expect("Renounce should succeed once unpaused");
+    assert_eq!(stored_authority(&svm, &registry_config), Pubkey::default());
+
+    println!("✅ test_renounce_rejected_while_paused passed");
+}
+
+/// Test that renouncing is rejected while a registration fee is set
+///
+/// Registrants would keep paying into a treasury nobody can withdraw from.
+#[test]
+fn test_renounce_rejected_with_fee() {
+    let mut svm = setup_litesvm();
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let registry_config = setup_registry(&mut svm, &authority.pubkey()).0;
+
+    let ix = build_set_registration_fee_ix(&authority.pubkey(), &registry_config, None, 1_000_000);
+    send(&mut svm, &authority, ix).expect("Setting fee should succeed");
+
+    let ix = build_update_authority_ix(&authority.pubkey(), &registry_config, None);
+    let err = send(&mut svm, &authority, ix).expect_err("Renounce should fail with a fee set");
+    assert!(
+        err.contains("RenounceWithFee") || err.contains("6114"),
+        "Expected RenounceWithFee error (6114), got: {}",
+        err
+    );
+    assert_eq!(stored_authority(&svm, &registry_config), authority.pubkey());
+
+    let ix = build_set_registration_fee_ix(&authority.pubkey(), &registry_config, None, 0);
+    send(&mut svm, &authority, ix).expect("Clearing fee should succeed");
+    svm.expire_blockhash();
+
+    let ix = build_update_authority_ix(&authority.pubkey(), &registry_config, None);
+    send(&mut svm, &authority, ix).expect("Renounce should succeed once free");
+    assert_eq!(stored_authority(&svm, &registry_config), Pubkey::default());
+
+    println!("✅ test_renounce_rejected_with_fee passed");
+}
@@ -0,0 +1,115 @@
+//! Tests for the backfill_agent_record instruction
+//!
+//! Legacy agents (registered before AgentRecord existed) only have an AgentIndex.
+//! Backfill is permissionless and derives flags from the mint.
+
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer, transaction::Transaction};
+
+use crate::common::{
+    accounts::{create_funded_keypair, create_mock_agent_index, create_mock_agent_mint},
+    instructions::build_backfill_agent_record_ix,
+    setup::{derive_agent_record_pda, derive_registry_config_pda, setup_litesvm},
+};
+
+/// Helper to set up a legacy agent (mint + AgentIndex, no AgentRecord)
+fn setup_legacy_agent(svm: &mut litesvm::LiteSVM, member_number: u64) -> Pubkey {
+    let mint = Keypair::new().pubkey();
+    let (registry_config, _) = derive_registry_config_pda();
+    create_mock_agent_mint(
+        svm,
+        &mint,
+        &registry_config,
+        "Agent",
+        "https://example.com/agent.json",
+        &[],
+    );
+    create_mock_agent_index(svm, member_number, &mint);
+    mint
+}
+
+/// Test that anyone can backfill an AgentRecord for a legacy agent
+#[test]
+fn test_backfill_agent_record_success() {
+    let mut svm = setup_litesvm();
+    let payer = create_funded_keypair(&mut svm, 10_000_000_000);
+    let agent_mint = setup_legacy_agent(&mut svm, 7);
+
+    let ix = build_backfill_agent_record_ix(&payer.pubkey(), &agent_mint, 7);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer.pubkey()),
+        &[&payer],
+        svm.latest_blockhash(),
+    );
+
+    let result = svm.send_transaction(tx);
+    assert!(
+        result.is_ok(),
+        "Backfill should succeed: {:?}",
+        result.err()
+    );
+
+    let (agent_record, _) = derive_agent_record_pda(&agent_mint);
+    let account = svm.get_account(&agent_record).unwrap();
+    assert_eq!(&account.data[8..40], agent_mint.as_ref());
+    assert_eq!(account.data[40..48], 7u64.to_le_bytes());
+    assert_eq!(account.data[64], 0, "non_transferable should be false");
+    assert_eq!(account.data[65], 0, "status should be Active");
+
+    println!("✅ test_backfill_agent_record_success passed");
+}
+
+/// Test that backfill fails when the AgentIndex references another mint
+#[test]
+fn test_backfill_agent_record_index_mismatch() {
+    let mut svm = setup_litesvm();
+    let payer = create_funded_keypair(&mut svm, 10_000_000_000);
+    let _agent_mint = setup_legacy_agent(&mut svm, 1);
+    let other_mint = setup_legacy_agent(&mut svm, 2);
+
+    // member_number 1 belongs to a different agent
+    let ix = build_backfill_agent_record_ix(&payer.pubkey(), &other_mint, 1);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer.pubkey()),
+        &[&payer],
+        svm.latest_blockhash(),
+    );
+
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Mismatched agent index should fail");
+
+    println!("✅ test_backfill_agent_record_index_mismatch passed");
+}
+
+/// Test that a non-SATI mint (no AgentIndex) cannot be backfilled
+#[test]
+fn test_backfill_agent_record_not_registered() {
+    let mut svm = setup_litesvm();
+    let payer = create_funded_keypair(&mut svm, 10_000_000_000);
+    let mint: Pubkey = Keypair::new().pubkey();
+    create_mock_agent_mint(
+        &mut svm,
+        &mint,
+        &payer.pubkey(),
+        "Impostor",
+        "https://example.com/fake.json",
+        &[],
+    );
+
+    let ix = build_backfill_agent_record_ix(&payer.pubkey(), &mint, 1);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer.pubkey()),
+        &[&payer],
+        svm.latest_blockhash(),
+    );
+
+    let result = svm.send_transaction(tx);
+    assert!(
+        result.is_err(),
+        "Unregistered mint should not be backfilled"
+    );
+
+    println!("✅ test_backfill_agent_record_not_registered passed");
+}
@@ -0,0 +1,338 @@
+//! Tests for timelocked governance
+//! (set_timelock_delay / queue_governance_action / execute_governance_action /
+//! cancel_governance_action)
+
+use litesvm::LiteSVM;
+use solana_sdk::{clock::Clock, pubkey::Pubkey, signature::Keypair, signer::Signer};
+
+use crate::common::{
+    accounts::{create_funded_keypair, setup_registry},
+    instructions::{
+        build_cancel_governance_action_ix, build_execute_governance_action_ix,
+        build_pause_registry_ix, build_propose_authority_ix, build_queue_governance_action_ix,
+        build_register_schema_config_ix, build_set_timelock_delay_ix, GovernanceAction,
+        SignatureMode, StorageType,
+    },
+    setup::{
+        assert_error, derive_pending_action_pda, derive_schema_config_pda, send, setup_litesvm,
+    },
+};
+
+const DELAY: i64 = 2 * 24 * 60 * 60; // 2 days
+
+/// Helper to create a registry controlled by `authority` with a timelock of DELAY
+fn setup_timelocked_registry(svm: &mut LiteSVM, authority: &Keypair) -> Pubkey {
+    let (registry_config, _) = setup_registry(svm, &authority.pubkey());
+
+    let ix = build_set_timelock_delay_ix(&authority.pubkey(), &registry_config, DELAY);
+    send(svm, authority, ix).expect("Enabling timelock should succeed");
+    registry_config
+}
+
+fn warp_forward(svm: &mut LiteSVM, seconds: i64) {
+    let mut clock = svm.get_sysvar::<Clock>();
+    clock.unix_timestamp += seconds;
+    svm.set_sysvar(&clock);
+}
+
+fn stored_pending_authority(svm: &LiteSVM, registry_config: &Pubkey) -> Pubkey {
+    let account = svm.get_account(registry_config).unwrap();
+    Pubkey::try_from(&account.data[82..114]).unwrap()
+}
+
+/// Test that direct authority actions are rejected while timelocked
+#[test]
+fn test_timelock_blocks_direct_actions() {
+    let mut svm = setup_litesvm();
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let registry_config = setup_timelocked_registry(&mut svm, &authority);
+
+    let stored_delay = {
+        let account = svm.get_account(&registry_config).unwrap();
+        i64::from_le_bytes(account.data[114..122].try_into().unwrap())
+    };
+    assert_eq!(stored_delay, DELAY);
+
+    let ix = build_propose_authority_ix(
+        &authority.pubkey(),
+        &registry_config,
+        Keypair::new().pubkey(),
+    );
+    let err = send(&mut svm, &authority, ix).expect_err("Direct propose should fail");
+    assert_error(&err, "TimelockRequired", 6063);
+
+    let sas_schema = Keypair::new().pubkey();
+    let (schema_config, _) = derive_schema_config_pda(&sas_schema);
+    let ix = build_register_schema_config_ix(
+        &authority.pubkey(),
+        &registry_config,
+        &authority.pubkey(),
+        &schema_config,
+        &sas_schema,
+        SignatureMode::DualSignature,
+        StorageType::Compressed,
+        None,
+        false,
+        "Feedback".to_string(),
+    );
+    let err = send(&mut svm, &authority, ix).expect_err("Direct schema registration should fail");
+    assert_error(&err, "TimelockRequired", 6063);
+
+    // The delay itself can't be lowered directly either
+    let ix = build_set_timelock_delay_ix(&authority.pubkey(), &registry_config, 0);
+    let err = send(&mut svm, &authority, ix).expect_err("Direct delay change should fail");
+    assert_error(&err, "TimelockRequired", 6063);
+
+    println!("✅ test_timelock_blocks_direct_actions passed");
+}
+
+/// Test that out-of-range delays are rejected
+#[test]
+fn test_set_timelock_delay_invalid() {
+    let mut svm = setup_litesvm();
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (registry_config, _) = setup_registry(&mut svm, &authority.pubkey());
+
+    let ix = build_set_timelock_delay_ix(&authority.pubkey(), &registry_config, 31 * 24 * 60 * 60);
+    let err = send(&mut svm, &authority, ix).expect_err("Delay above 30 days should fail");
+    assert_error(&err, "InvalidTimelockDelay", 6065);
+
+    let ix = build_set_timelock_delay_ix(&authority.pubkey(), &registry_config, -1);
+    let err = send(&mut svm, &authority, ix).expect_err("Negative delay should fail");
+    assert_error(&err, "InvalidTimelockDelay", 6065);
+
+    println!("✅ test_set_timelock_delay_invalid passed");
+}
+
+/// Test queue -> wait -> execute for an authority proposal
+#[test]
+fn test_queue_and_execute_propose_authority() {
+    let mut svm = setup_litesvm();
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let executor = create_funded_keypair(&mut svm, 10_000_000_000);
+    let new_authority = Keypair::new().pubkey();
+    let registry_config = setup_timelocked_registry(&mut svm, &authority);
+
+    let ix = build_queue_governance_action_ix(
+        &authority.pubkey(),
+        &authority.pubkey(),
+        &registry_config,
+        0,
+        GovernanceAction::ProposeAuthority { new_authority },
+    );
+    send(&mut svm, &authority, ix).expect("Queue should succeed");
+
+    let (pending_action, _) = derive_pending_action_pda(0);
+    assert!(svm.get_account(&pending_action).is_some());
+
+    // Too early
+    let ix = build_execute_governance_action_ix(
+        &executor.pubkey(),
+        &registry_config,
+        0,
+        &authority.pubkey(),
+        None,
+    );
+    let err = send(&mut svm, &executor, ix.clone()).expect_err("Early execute should fail");
+    assert_error(&err, "TimelockNotElapsed", 6064);
+
+    // After the delay anyone can execute
+    warp_forward(&mut svm, DELAY);
+    send(&mut svm, &executor, ix).expect("Execute should succeed");
+
+    assert_eq!(
+        stored_pending_authority(&svm, &registry_config),
+        new_authority
+    );
+    let closed = svm.get_account(&pending_action);
+    assert!(
+        closed.is_none() || closed.unwrap().lamports == 0,
+        "PendingAction should be closed"
+    );
+
+    println!("✅ test_queue_and_execute_propose_authority passed");
+}
+
+/// Test a queued schema registration creates the SchemaConfig on execution
+#[test]
+fn test_queue_and_execute_register_schema_config() {
+    let mut svm = setup_litesvm();
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let registry_config = setup_timelocked_registry(&mut svm, &authority);
+
+    let sas_schema = Keypair::new().pubkey();
+    let (schema_config, _) = derive_schema_config_pda(&sas_schema);
+
+    let ix = build_queue_governance_action_ix(
+        &authority.pubkey(),
+        &authority.pubkey(),
+        &registry_config,
+        0,
+        GovernanceAction::RegisterSchemaConfig {
+            sas_schema,
+            signature_mode: SignatureMode::CounterpartySigned,
+            storage_type: StorageType::Compressed,
+            delegation_schema: None,
+            closeable: true,
+            name: "Feedback".to_string(),
+        },
+    );
+    send(&mut svm, &authority, ix).expect("Queue should succeed");
+    warp_forward(&mut svm, DELAY);
+
+    // Missing schema_config account
+    let ix = build_execute_governance_action_ix(
+        &authority.pubkey(),
+        &registry_config,
+        0,
+        &authority.pubkey(),
+        None,
+    );
+    let err = send(&mut svm, &authority, ix).expect_err("Execute without schema_config fails");
+    assert_error(&err, "MissingSchemaConfigAccount", 6067);
+
+    let ix = build_execute_governance_action_ix(
+        &authority.pubkey(),
+        &registry_config,
+        0,
+        &authority.pubkey(),
+        Some(schema_config),
+    );
+    send(&mut svm, &authority, ix).expect("Execute should succeed");
+
+    let account = svm
+        .get_account(&schema_config)
+        .expect("SchemaConfig should exist");
+    assert_eq!(&account.data[8..40], sas_schema.as_ref());
+    assert_eq!(account.data[40], 1, "signature_mode = CounterpartySigned");
+
+    println!("✅ test_queue_and_execute_register_schema_config passed");
+}
+
+/// Test that the authority can cancel a queued action
+#[test]
+fn test_cancel_governance_action() {
+    let mut svm = setup_litesvm();
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let registry_config = setup_timelocked_registry(&mut svm, &authority);
+
+    let ix = build_queue_governance_action_ix(
+        &authority.pubkey(),
+        &authority.pubkey(),
+        &registry_config,
+        0,
+        GovernanceAction::SetTimelockDelay { delay: 0 },
+    );
+    send(&mut svm, &authority, ix).expect("Queue should succeed");
+
+    let ix = build_cancel_governance_action_ix(
+        &authority.pubkey(),
+        &registry_config,
+        0,
+        &authority.pubkey(),
+    );
+    send(&mut svm, &authority, ix).expect("Cancel should succeed");
+
+    warp_forward(&mut svm, DELAY);
+    let ix = build_execute_governance_action_ix(
+        &authority.pubkey(),
+        &registry_config,
+        0,
+        &authority.pubkey(),
+        None,
+    );
+    let result = send(&mut svm, &authority, ix);
+    assert!(result.is_err(), "Cancelled action cannot be executed");
+
+    println!("✅ test_cancel_governance_action passed");
+}
+
+/// Test that only the authority can queue actions
+#[test]
+fn test_queue_governance_action_wrong_signer() {
+    let mut svm = setup_litesvm();
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let attacker = create_funded_keypair(&mut svm, 10_000_000_000);
+    let registry_config = setup_timelocked_registry(&mut svm, &authority);
+
+    let ix = build_queue_governance_action_ix(
+        &attacker.pubkey(),
+        &attacker.pubkey(),
+        &registry_config,
+        0,
+        GovernanceAction::RenounceAuthority,
+    );
+    let result = send(&mut svm, &attacker, ix);
+    assert!(result.is_err(), "Non-authority queue should fail");
+
+    println!("✅ test_queue_governance_action_wrong_signer passed");
+}
+
+/// Test that actions queued by a previous authority cannot be executed
+#[test]
+fn test_execute_stale_governance_action() {
+    let mut svm = setup_litesvm();
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let registry_config = setup_timelocked_registry(&mut svm, &authority);
+
+    let ix = build_queue_governance_action_ix(
+        &authority.pubkey(),
+        &authority.pubkey(),
+        &registry_config,
+        0,
+        GovernanceAction::RenounceAuthority,
+    );
+    send(&mut svm, &authority, ix).expect("Queue should succeed");
+
+    // Simulate an authority handover after queueing
+    let mut account = svm.get_account(&registry_config).unwrap();
+    account.data[40..72].copy_from_slice(Keypair::new().pubkey().as_ref());
+    svm.set_account(registry_config, account).unwrap();
+
+    warp_forward(&mut svm, DELAY);
+    let ix = build_execute_governance_action_ix(
+        &authority.pubkey(),
+        &registry_config,
+        0,
+        &authority.pubkey(),
+        None,
+    );
+    let err = send(&mut svm, &authority, ix).expect_err("Stale action should fail");
+    assert_error(&err, "StaleGovernanceAction", 6066);
+
+    println!("✅ test_execute_stale_governance_action passed");
+}
+
+/// Test that a queued renounce cannot execute while the registry is paused
+#[test]
+fn test_execute_renounce_rejected_while_paused() {
+    let mut svm = setup_litesvm();
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let registry_config = setup_timelocked_registry(&mut svm, &authority);
+
+    let ix = build_queue_governance_action_ix(
+        &authority.pubkey(),
+        &authority.pubkey(),
+        &registry_config,
+        0,
+        GovernanceAction::RenounceAuthority,
+    );
+    send(&mut svm, &authority, ix).expect("Queue should succeed");
+
+    // Pausing bypasses the timelock
+    let ix = build_pause_registry_ix(&authority.pubkey(), &registry_config, 1 << 0);
+    send(&mut svm, &authority, ix).expect("Pause should succeed");
+
+    warp_forward(&mut svm, DELAY);
+    let ix = build_execute_governance_action_ix(
+        &authority.pubkey(),
+        &registry_config,
+        0,
+        &authority.pubkey(),
+        None,
+    );
+    let err = send(&mut svm, &authority, ix).expect_err("Renounce should fail while paused");
+    assert_error(&err, "RenounceWhilePaused", 6113);
+
+    println!("✅ test_execute_renounce_rejected_while_paused passed");
+}
@@ -1,6 +1,6 @@
 //! Tests for the initialize instruction
 
-use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer, transaction::Transaction};
 
 use crate::common::{
     accounts::{create_funded_keypair, create_mock_group_mint},
@@ -54,7 +54,11 @@ fn test_initialize_success() {
     assert!(registry_account.is_some(), "Registry config should exist");
 
     let account = registry_account.unwrap();
-    assert_eq!(account.data.len(), 81, "Registry config should be 81 bytes");
+    assert_eq!(
+        account.data.len(),
+        171,
+        "Registry config should be 171 bytes"
+    );
 
     // Verify authority is set correctly (at offset 40 after discriminator + group_mint)
     let stored_authority = &account.data[40..72];
@@ -76,6 +80,21 @@ fn test_initialize_success() {
     let total_agents = u64::from_le_bytes(account.data[72..80].try_into().unwrap());
     assert_eq!(total_agents, 0, "Total agents should be 0");
 
+    // Verify version is current and no authority transfer is pending
+    assert_eq!(account.data[81], 4, "Version should be 4");
+    assert_eq!(
+        &account.data[82..114],
+        Pubkey::default().as_ref(),
+        "No pending authority"
+    );
+    assert_eq!(
+        &account.data[114..130],
+        &[0u8; 16],
+        "Timelock disabled, no queued actions"
+    );
+    assert_eq!(account.data[130], 0, "Nothing paused");
+    assert_eq!(&account.data[131..171], &[0u8; 40], "No registration fee");
+
     println!("✅ test_initialize_success passed");
 }
 
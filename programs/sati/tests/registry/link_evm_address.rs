@@ -4,31 +4,40 @@
 //! Uses k256 crate following Anza/Solana SDK recommendations.
 
 use k256::ecdsa::{RecoveryId, Signature, SigningKey, VerifyingKey};
+use litesvm::LiteSVM;
+use sati::signature::compute_evm_link_digest;
+use sati::state::EvmLink;
 use sha3::{Digest, Keccak256};
-use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+use solana_sdk::{
+    clock::Clock, pubkey::Pubkey, signature::Keypair, signer::Signer, transaction::Transaction,
+};
 
 use crate::common::{
     accounts::{
-        create_funded_keypair, create_mock_token22_ata, create_mock_token22_mint,
-        derive_token22_ata,
+        create_default_registry, create_funded_keypair, create_mock_token22_ata,
+        create_mock_token22_mint, derive_token22_ata, setup_agent,
     },
-    instructions::build_link_evm_address_ix,
-    setup::setup_litesvm,
+    instructions::{build_link_evm_address_ix, build_unlink_evm_address_ix, EvmLinkFormat},
+    setup::{assert_error, derive_evm_link_pda, send, setup_litesvm, SATI_PROGRAM_ID},
 };
 
 /// Domain separator for EVM link hash (matches program constant)
-const DOMAIN_EVM_LINK: &[u8] = b"SATI:evm_link:v1";
+const DOMAIN_EVM_LINK: &[u8] = b"SATI:evm_link:v2";
 
 /// Compute the EVM link hash that will be verified by the program
 fn compute_evm_link_hash(
-    agent_mint: &solana_sdk::pubkey::Pubkey,
+    agent_mint: &Pubkey,
+    holder: &Pubkey,
     evm_address: &[u8; 20],
     chain_id: &str,
+    expires_at: Option<i64>,
 ) -> [u8; 32] {
     let mut hasher = Keccak256::new();
     hasher.update(DOMAIN_EVM_LINK);
     hasher.update(agent_mint.as_ref());
+    hasher.update(holder.as_ref());
     hasher.update(evm_address);
+    hasher.update(expires_at.unwrap_or(0).to_le_bytes());
     hasher.update(chain_id.as_bytes());
     hasher.finalize().into()
 }
@@ -58,22 +67,43 @@ fn sign_message_hash(signing_key: &SigningKey, message_hash: &[u8; 32]) -> ([u8;
     (signature_bytes, recovery_id_byte)
 }
 
-/// Helper to set up an agent with Token-2022 mint and ATA
-fn setup_agent(
-    svm: &mut litesvm::LiteSVM,
+/// Helper to sign and submit a link for `signing_key`'s address
+fn link(
+    svm: &mut LiteSVM,
     owner: &Keypair,
-) -> (solana_sdk::pubkey::Pubkey, solana_sdk::pubkey::Pubkey) {
-    let agent_mint = Keypair::new();
-    let mint_pubkey = agent_mint.pubkey();
-
-    // Create mock mint
-    create_mock_token22_mint(svm, &mint_pubkey, &owner.pubkey());
-
-    // Derive and create ATA with balance of 1 (NFT)
-    let ata = derive_token22_ata(&owner.pubkey(), &mint_pubkey);
-    create_mock_token22_ata(svm, &ata, &mint_pubkey, &owner.pubkey(), 1);
+    agent_mint: &Pubkey,
+    signing_key: &SigningKey,
+    chain_id: &str,
+    expires_at: Option<i64>,
+) -> Result<[u8; 20], String> {
+    let evm_address = eth_address_from_pubkey(signing_key.verifying_key());
+    let message_hash = compute_evm_link_hash(
+        agent_mint,
+        &owner.pubkey(),
+        &evm_address,
+        chain_id,
+        expires_at,
+    );
+    let (signature, recovery_id) = sign_message_hash(signing_key, &message_hash);
+    let ata = derive_token22_ata(&owner.pubkey(), agent_mint);
+    let ix = build_link_evm_address_ix(
+        &owner.pubkey(),
+        agent_mint,
+        &ata,
+        evm_address,
+        chain_id.to_string(),
+        signature,
+        recovery_id,
+        EvmLinkFormat::Raw,
+        expires_at,
+    );
+    send(svm, owner, ix).map(|_| evm_address)
+}
 
-    (mint_pubkey, ata)
+fn warp_forward(svm: &mut LiteSVM, seconds: i64) {
+    let mut clock = svm.get_sysvar::<Clock>();
+    clock.unix_timestamp += seconds;
+    svm.set_sysvar(&clock);
 }
 
 /// Test successful EVM address linking with valid signature
@@ -83,7 +113,7 @@ fn test_link_evm_address_success() {
     let owner = create_funded_keypair(&mut svm, 10_000_000_000);
 
     // Set up agent
-    let (agent_mint, ata) = setup_agent(&mut svm, &owner);
+    let (agent_mint, ata) = setup_agent(&mut svm, &owner.pubkey(), 1, 0);
 
     // Generate secp256k1 keypair
     let signing_key = SigningKey::random(&mut rand::thread_rng());
@@ -94,7 +124,8 @@ fn test_link_evm_address_success() {
     let chain_id = "eip155:1";
 
     // Compute message hash and sign
-    let message_hash = compute_evm_link_hash(&agent_mint, &evm_address, chain_id);
+    let message_hash =
+        compute_evm_link_hash(&agent_mint, &owner.pubkey(), &evm_address, chain_id, None);
     let (signature, recovery_id) = sign_message_hash(&signing_key, &message_hash);
 
     // Build and execute instruction
@@ -106,6 +137,8 @@ fn test_link_evm_address_success() {
         chain_id.to_string(),
         signature,
         recovery_id,
+        EvmLinkFormat::Raw,
+        None,
     );
 
     let tx = Transaction::new_signed_with_payer(
@@ -133,7 +166,7 @@ fn test_link_evm_address_base_chain() {
     let mut svm = setup_litesvm();
     let owner = create_funded_keypair(&mut svm, 10_000_000_000);
 
-    let (agent_mint, ata) = setup_agent(&mut svm, &owner);
+    let (agent_mint, ata) = setup_agent(&mut svm, &owner.pubkey(), 1, 0);
 
     let signing_key = SigningKey::random(&mut rand::thread_rng());
     let verifying_key = signing_key.verifying_key();
@@ -142,7 +175,8 @@ fn test_link_evm_address_base_chain() {
     // Base mainnet chain ID
     let chain_id = "eip155:8453";
 
-    let message_hash = compute_evm_link_hash(&agent_mint, &evm_address, chain_id);
+    let message_hash =
+        compute_evm_link_hash(&agent_mint, &owner.pubkey(), &evm_address, chain_id, None);
     let (signature, recovery_id) = sign_message_hash(&signing_key, &message_hash);
 
     let ix = build_link_evm_address_ix(
@@ -153,6 +187,8 @@ fn test_link_evm_address_base_chain() {
         chain_id.to_string(),
         signature,
         recovery_id,
+        EvmLinkFormat::Raw,
+        None,
     );
 
     let tx = Transaction::new_signed_with_payer(
@@ -178,7 +214,7 @@ fn test_link_evm_address_wrong_recovery_id() {
     let mut svm = setup_litesvm();
     let owner = create_funded_keypair(&mut svm, 10_000_000_000);
 
-    let (agent_mint, ata) = setup_agent(&mut svm, &owner);
+    let (agent_mint, ata) = setup_agent(&mut svm, &owner.pubkey(), 1, 0);
 
     let signing_key = SigningKey::random(&mut rand::thread_rng());
     let verifying_key = signing_key.verifying_key();
@@ -186,7 +222,8 @@ fn test_link_evm_address_wrong_recovery_id() {
 
     let chain_id = "eip155:1";
 
-    let message_hash = compute_evm_link_hash(&agent_mint, &evm_address, chain_id);
+    let message_hash =
+        compute_evm_link_hash(&agent_mint, &owner.pubkey(), &evm_address, chain_id, None);
     let (signature, recovery_id) = sign_message_hash(&signing_key, &message_hash);
 
     // Use wrong recovery ID (flip 0 <-> 1)
@@ -200,6 +237,8 @@ fn test_link_evm_address_wrong_recovery_id() {
         chain_id.to_string(),
         signature,
         wrong_recovery_id,
+        EvmLinkFormat::Raw,
+        None,
     );
 
     let tx = Transaction::new_signed_with_payer(
@@ -221,7 +260,7 @@ fn test_link_evm_address_mismatch() {
     let mut svm = setup_litesvm();
     let owner = create_funded_keypair(&mut svm, 10_000_000_000);
 
-    let (agent_mint, ata) = setup_agent(&mut svm, &owner);
+    let (agent_mint, ata) = setup_agent(&mut svm, &owner.pubkey(), 1, 0);
 
     let signing_key = SigningKey::random(&mut rand::thread_rng());
 
@@ -234,7 +273,13 @@ fn test_link_evm_address_mismatch() {
     let chain_id = "eip155:1";
 
     // Sign with the wrong address in the hash
-    let message_hash = compute_evm_link_hash(&agent_mint, &wrong_evm_address, chain_id);
+    let message_hash = compute_evm_link_hash(
+        &agent_mint,
+        &owner.pubkey(),
+        &wrong_evm_address,
+        chain_id,
+        None,
+    );
     let (signature, recovery_id) = sign_message_hash(&signing_key, &message_hash);
 
     let ix = build_link_evm_address_ix(
@@ -245,6 +290,8 @@ fn test_link_evm_address_mismatch() {
         chain_id.to_string(),
         signature,
         recovery_id,
+        EvmLinkFormat::Raw,
+        None,
     );
 
     let tx = Transaction::new_signed_with_payer(
@@ -270,7 +317,7 @@ fn test_link_evm_address_non_owner() {
     let owner = create_funded_keypair(&mut svm, 10_000_000_000);
     let non_owner = create_funded_keypair(&mut svm, 10_000_000_000);
 
-    let (agent_mint, ata) = setup_agent(&mut svm, &owner);
+    let (agent_mint, ata) = setup_agent(&mut svm, &owner.pubkey(), 1, 0);
 
     let signing_key = SigningKey::random(&mut rand::thread_rng());
     let verifying_key = signing_key.verifying_key();
@@ -278,7 +325,8 @@ fn test_link_evm_address_non_owner() {
 
     let chain_id = "eip155:1";
 
-    let message_hash = compute_evm_link_hash(&agent_mint, &evm_address, chain_id);
+    let message_hash =
+        compute_evm_link_hash(&agent_mint, &owner.pubkey(), &evm_address, chain_id, None);
     let (signature, recovery_id) = sign_message_hash(&signing_key, &message_hash);
 
     // Non-owner tries to link (will fail because their ATA doesn't match)
@@ -290,6 +338,8 @@ fn test_link_evm_address_non_owner() {
         chain_id.to_string(),
         signature,
         recovery_id,
+        EvmLinkFormat::Raw,
+        None,
     );
 
     let tx = Transaction::new_signed_with_payer(
@@ -310,6 +360,7 @@ fn test_link_evm_address_non_owner() {
 fn test_link_evm_address_zero_balance() {
     let mut svm = setup_litesvm();
     let owner = create_funded_keypair(&mut svm, 10_000_000_000);
+    create_default_registry(&mut svm);
 
     let agent_mint = Keypair::new();
     let mint_pubkey = agent_mint.pubkey();
@@ -327,7 +378,8 @@ fn test_link_evm_address_zero_balance() {
 
     let chain_id = "eip155:1";
 
-    let message_hash = compute_evm_link_hash(&mint_pubkey, &evm_address, chain_id);
+    let message_hash =
+        compute_evm_link_hash(&mint_pubkey, &owner.pubkey(), &evm_address, chain_id, None);
     let (signature, recovery_id) = sign_message_hash(&signing_key, &message_hash);
 
     let ix = build_link_evm_address_ix(
@@ -338,6 +390,8 @@ fn test_link_evm_address_zero_balance() {
         chain_id.to_string(),
         signature,
         recovery_id,
+        EvmLinkFormat::Raw,
+        None,
     );
 
     let tx = Transaction::new_signed_with_payer(
@@ -359,7 +413,7 @@ fn test_link_evm_address_corrupted_signature() {
     let mut svm = setup_litesvm();
     let owner = create_funded_keypair(&mut svm, 10_000_000_000);
 
-    let (agent_mint, ata) = setup_agent(&mut svm, &owner);
+    let (agent_mint, ata) = setup_agent(&mut svm, &owner.pubkey(), 1, 0);
 
     let signing_key = SigningKey::random(&mut rand::thread_rng());
     let verifying_key = signing_key.verifying_key();
@@ -367,7 +421,8 @@ fn test_link_evm_address_corrupted_signature() {
 
     let chain_id = "eip155:1";
 
-    let message_hash = compute_evm_link_hash(&agent_mint, &evm_address, chain_id);
+    let message_hash =
+        compute_evm_link_hash(&agent_mint, &owner.pubkey(), &evm_address, chain_id, None);
     let (mut signature, recovery_id) = sign_message_hash(&signing_key, &message_hash);
 
     // Corrupt the signature
@@ -382,6 +437,8 @@ fn test_link_evm_address_corrupted_signature() {
         chain_id.to_string(),
         signature,
         recovery_id,
+        EvmLinkFormat::Raw,
+        None,
     );
 
     let tx = Transaction::new_signed_with_payer(
@@ -403,7 +460,7 @@ fn test_link_multiple_evm_addresses() {
     let mut svm = setup_litesvm();
     let owner = create_funded_keypair(&mut svm, 10_000_000_000);
 
-    let (agent_mint, ata) = setup_agent(&mut svm, &owner);
+    let (agent_mint, ata) = setup_agent(&mut svm, &owner.pubkey(), 1, 0);
 
     // Link first EVM address (Ethereum mainnet)
     let signing_key1 = SigningKey::random(&mut rand::thread_rng());
@@ -411,7 +468,8 @@ fn test_link_multiple_evm_addresses() {
     let evm_address1 = eth_address_from_pubkey(verifying_key1);
     let chain_id1 = "eip155:1";
 
-    let message_hash1 = compute_evm_link_hash(&agent_mint, &evm_address1, chain_id1);
+    let message_hash1 =
+        compute_evm_link_hash(&agent_mint, &owner.pubkey(), &evm_address1, chain_id1, None);
     let (signature1, recovery_id1) = sign_message_hash(&signing_key1, &message_hash1);
 
     let ix1 = build_link_evm_address_ix(
@@ -422,6 +480,8 @@ fn test_link_multiple_evm_addresses() {
         chain_id1.to_string(),
         signature1,
         recovery_id1,
+        EvmLinkFormat::Raw,
+        None,
     );
 
     let tx1 = Transaction::new_signed_with_payer(
@@ -444,7 +504,8 @@ fn test_link_multiple_evm_addresses() {
     let evm_address2 = eth_address_from_pubkey(verifying_key2);
     let chain_id2 = "eip155:8453";
 
-    let message_hash2 = compute_evm_link_hash(&agent_mint, &evm_address2, chain_id2);
+    let message_hash2 =
+        compute_evm_link_hash(&agent_mint, &owner.pubkey(), &evm_address2, chain_id2, None);
     let (signature2, recovery_id2) = sign_message_hash(&signing_key2, &message_hash2);
 
     let ix2 = build_link_evm_address_ix(
@@ -455,6 +516,8 @@ fn test_link_multiple_evm_addresses() {
         chain_id2.to_string(),
         signature2,
         recovery_id2,
+        EvmLinkFormat::Raw,
+        None,
     );
 
     let tx2 = Transaction::new_signed_with_payer(
@@ -473,3 +536,440 @@ fn test_link_multiple_evm_addresses() {
 
     println!("✅ test_link_multiple_evm_addresses passed");
 }
+
+/// Test that linking stores an EvmLink PDA with the linking holder and expiry
+#[test]
+fn test_link_evm_address_stores_link() {
+    let mut svm = setup_litesvm();
+    let owner = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (agent_mint, _) = setup_agent(&mut svm, &owner.pubkey(), 1, 0);
+    let signing_key = SigningKey::random(&mut rand::thread_rng());
+    let chain_id = "eip155:8453";
+    let expires_at = svm.get_sysvar::<Clock>().unix_timestamp + 3600;
+
+    let evm_address = link(
+        &mut svm,
+        &owner,
+        &agent_mint,
+        &signing_key,
+        chain_id,
+        Some(expires_at),
+    )
+    .expect("Link should succeed");
+
+    let (evm_link, bump) = derive_evm_link_pda(&agent_mint, chain_id, &evm_address);
+    let account = svm.get_account(&evm_link).expect("EvmLink should exist");
+    assert_eq!(account.owner, SATI_PROGRAM_ID);
+    assert_eq!(account.data.len(), EvmLink::SIZE);
+
+    let data = &account.data[8..];
+    assert_eq!(&data[..32], agent_mint.as_ref(), "agent_mint");
+    assert_eq!(&data[32..52], &evm_address, "evm_address");
+    let chain_len = u32::from_le_bytes(data[52..56].try_into().unwrap()) as usize;
+    assert_eq!(&data[56..56 + chain_len], chain_id.as_bytes(), "chain_id");
+    let rest = &data[56 + chain_len..];
+    assert_eq!(&rest[..32], owner.pubkey().as_ref(), "holder");
+    assert_eq!(rest[40], 1, "expires_at is Some");
+    assert_eq!(
+        i64::from_le_bytes(rest[41..49].try_into().unwrap()),
+        expires_at
+    );
+    assert_eq!(rest[49], bump, "bump");
+
+    // The same triple cannot be linked twice without unlinking first
+    assert!(
+        link(&mut svm, &owner, &agent_mint, &signing_key, chain_id, None).is_err(),
+        "Duplicate link should fail"
+    );
+
+    println!("✅ test_link_evm_address_stores_link passed");
+}
+
+/// Test chain ID and expiry validation
+#[test]
+fn test_link_evm_address_invalid_params() {
+    let mut svm = setup_litesvm();
+    let owner = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (agent_mint, _) = setup_agent(&mut svm, &owner.pubkey(), 1, 0);
+    let signing_key = SigningKey::random(&mut rand::thread_rng());
+    let now = svm.get_sysvar::<Clock>().unix_timestamp;
+
+    let err = link(&mut svm, &owner, &agent_mint, &signing_key, "", None)
+        .expect_err("Empty chain ID should fail");
+    assert_error(&err, "InvalidChainId", 6086);
+
+    let long_chain_id = format!("eip155:{}", "1".repeat(35));
+    let err = link(
+        &mut svm,
+        &owner,
+        &agent_mint,
+        &signing_key,
+        &long_chain_id,
+        None,
+    )
+    .expect_err("Over-long chain ID should fail");
+    assert_error(&err, "InvalidChainId", 6086);
+
+    let err = link(
+        &mut svm,
+        &owner,
+        &agent_mint,
+        &signing_key,
+        "eip155:1",
+        Some(now),
+    )
+    .expect_err("Expiry in the past should fail");
+    assert_error(&err, "InvalidEvmLinkExpiry", 6087);
+
+    println!("✅ test_link_evm_address_invalid_params passed");
+}
+
+/// Test that only the current holder can unlink an active link
+#[test]
+fn test_unlink_evm_address_by_holder() {
+    let mut svm = setup_litesvm();
+    let owner = create_funded_keypair(&mut svm, 10_000_000_000);
+    let attacker = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (agent_mint, ata) = setup_agent(&mut svm, &owner.pubkey(), 1, 0);
+    let signing_key = SigningKey::random(&mut rand::thread_rng());
+    let chain_id = "eip155:1";
+
+    let evm_address = link(&mut svm, &owner, &agent_mint, &signing_key, chain_id, None)
+        .expect("Link should succeed");
+    let (evm_link, _) = derive_evm_link_pda(&agent_mint, chain_id, &evm_address);
+    let rent = svm.get_account(&evm_link).unwrap().lamports;
+
+    let ix = build_unlink_evm_address_ix(
+        &attacker.pubkey(),
+        &agent_mint,
+        &evm_link,
+        &owner.pubkey(),
+        None,
+    );
+    let err = send(&mut svm, &attacker, ix).expect_err("Non-holder unlink should fail");
+    assert_error(&err, "EvmUnlinkNotAllowed", 6088);
+
+    let attacker_ata = derive_token22_ata(&attacker.pubkey(), &agent_mint);
+    create_mock_token22_ata(&mut svm, &attacker_ata, &agent_mint, &attacker.pubkey(), 0);
+    let ix = build_unlink_evm_address_ix(
+        &attacker.pubkey(),
+        &agent_mint,
+        &evm_link,
+        &owner.pubkey(),
+        Some(attacker_ata),
+    );
+    let err = send(&mut svm, &attacker, ix).expect_err("Empty ATA unlink should fail");
+    assert_error(&err, "EvmUnlinkNotAllowed", 6088);
+
+    let balance_before = svm.get_balance(&owner.pubkey()).unwrap();
+    let ix = build_unlink_evm_address_ix(
+        &owner.pubkey(),
+        &agent_mint,
+        &evm_link,
+        &owner.pubkey(),
+        Some(ata),
+    );
+    send(&mut svm, &owner, ix).expect("Holder unlink should succeed");
+    assert!(
+        svm.get_account(&evm_link)
+            .is_none_or(|account| account.lamports == 0),
+        "EvmLink should be closed"
+    );
+    assert_eq!(
+        svm.get_balance(&owner.pubkey()).unwrap(),
+        balance_before + rent - 5000,
+        "Rent refunded to holder (minus fee)"
+    );
+
+    // The address can be linked again afterwards
+    link(&mut svm, &owner, &agent_mint, &signing_key, chain_id, None)
+        .expect("Relink should succeed");
+
+    println!("✅ test_unlink_evm_address_by_holder passed");
+}
+
+/// Test that anyone can close a link once the holder changed or it expired
+#[test]
+fn test_unlink_stale_evm_link() {
+    let mut svm = setup_litesvm();
+    let owner = create_funded_keypair(&mut svm, 10_000_000_000);
+    let buyer = create_funded_keypair(&mut svm, 10_000_000_000);
+    let cranker = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (agent_mint, ata) = setup_agent(&mut svm, &owner.pubkey(), 1, 0);
+    let now = svm.get_sysvar::<Clock>().unix_timestamp;
+
+    // Expired link
+    let expiring_key = SigningKey::random(&mut rand::thread_rng());
+    let expiring = link(
+        &mut svm,
+        &owner,
+        &agent_mint,
+        &expiring_key,
+        "eip155:1",
+        Some(now + 60),
+    )
+    .expect("Expiring link should succeed");
+    let (expiring_link, _) = derive_evm_link_pda(&agent_mint, "eip155:1", &expiring);
+
+    let ix = build_unlink_evm_address_ix(
+        &cranker.pubkey(),
+        &agent_mint,
+        &expiring_link,
+        &owner.pubkey(),
+        None,
+    );
+    let err = send(&mut svm, &cranker, ix.clone()).expect_err("Live link should not close");
+    assert_error(&err, "EvmUnlinkNotAllowed", 6088);
+
+    warp_forward(&mut svm, 60);
+    send(&mut svm, &cranker, ix).expect("Expired link should close");
+    assert!(svm
+        .get_account(&expiring_link)
+        .is_none_or(|account| account.lamports == 0));
+
+    // Holder changed: the NFT moves to the buyer
+    let signing_key = SigningKey::random(&mut rand::thread_rng());
+    let evm_address = link(
+        &mut svm,
+        &owner,
+        &agent_mint,
+        &signing_key,
+        "eip155:8453",
+        None,
+    )
+    .expect("Link should succeed");
+    let (evm_link, _) = derive_evm_link_pda(&agent_mint, "eip155:8453", &evm_address);
+
+    create_mock_token22_ata(&mut svm, &ata, &agent_mint, &owner.pubkey(), 0);
+    let buyer_ata = derive_token22_ata(&buyer.pubkey(), &agent_mint);
+    create_mock_token22_ata(&mut svm, &buyer_ata, &agent_mint, &buyer.pubkey(), 1);
+
+    let owner_balance = svm.get_balance(&owner.pubkey()).unwrap();
+    let rent = svm.get_account(&evm_link).unwrap().lamports;
+    let ix = build_unlink_evm_address_ix(
+        &cranker.pubkey(),
+        &agent_mint,
+        &evm_link,
+        &owner.pubkey(),
+        None,
+    );
+    send(&mut svm, &cranker, ix).expect("Stale link should close");
+    assert_eq!(
+        svm.get_balance(&owner.pubkey()).unwrap(),
+        owner_balance + rent,
+        "Rent refunded to the linking holder"
+    );
+
+    // The new holder can link the same address again
+    link(
+        &mut svm,
+        &buyer,
+        &agent_mint,
+        &signing_key,
+        "eip155:8453",
+        None,
+    )
+    .expect("New holder link should succeed");
+
+    println!("✅ test_unlink_stale_evm_link passed");
+}
+
+/// Test linking with wallet-friendly EIP-191 and EIP-712 signatures
+#[test]
+fn test_link_evm_address_wallet_formats() {
+    let mut svm = setup_litesvm();
+    let owner = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (agent_mint, ata) = setup_agent(&mut svm, &owner.pubkey(), 1, 0);
+
+    for (format, chain_id) in [
+        (EvmLinkFormat::Eip191, "eip155:1"),
+        (EvmLinkFormat::Eip712, "eip155:8453"),
+    ] {
+        let signing_key = SigningKey::random(&mut rand::thread_rng());
+        let evm_address = eth_address_from_pubkey(signing_key.verifying_key());
+        let digest = compute_evm_link_digest(
+            format,
+            &agent_mint,
+            &owner.pubkey(),
+            &evm_address,
+            chain_id,
+            None,
+        );
+        let (signature, recovery_id) = sign_message_hash(&signing_key, &digest);
+
+        // A signature over one format does not verify under another
+        let ix = build_link_evm_address_ix(
+            &owner.pubkey(),
+            &agent_mint,
+            &ata,
+            evm_address,
+            chain_id.to_string(),
+            signature,
+            recovery_id,
+            EvmLinkFormat::Raw,
+            None,
+        );
+        assert!(
+            send(&mut svm, &owner, ix).is_err(),
+            "{:?} signature must not verify as Raw",
+            format
+        );
+
+        let ix = build_link_evm_address_ix(
+            &owner.pubkey(),
+            &agent_mint,
+            &ata,
+            evm_address,
+            chain_id.to_string(),
+            signature,
+            recovery_id,
+            format,
+            None,
+        );
+        send(&mut svm, &owner, ix).unwrap_or_else(|e| panic!("{:?} link failed: {}", format, e));
+    }
+
+    println!("✅ test_link_evm_address_wallet_formats passed");
+}
+
+/// Test that the signature is bound to the linking holder and the requested expiry
+#[test]
+fn test_link_evm_address_binds_holder_and_expiry() {
+    let mut svm = setup_litesvm();
+    let owner = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (agent_mint, ata) = setup_agent(&mut svm, &owner.pubkey(), 1, 0);
+    let signing_key = SigningKey::random(&mut rand::thread_rng());
+    let evm_address = eth_address_from_pubkey(signing_key.verifying_key());
+    let chain_id = "eip155:1";
+    let expires_at = svm.get_sysvar::<Clock>().unix_timestamp + 3600;
+
+    let link_ix = |message_hash: [u8; 32], expires_at: Option<i64>| {
+        let (signature, recovery_id) = sign_message_hash(&signing_key, &message_hash);
+        build_link_evm_address_ix(
+            &owner.pubkey(),
+            &agent_mint,
+            &ata,
+            evm_address,
+            chain_id.to_string(),
+            signature,
+            recovery_id,
+            EvmLinkFormat::Raw,
+            expires_at,
+        )
+    };
+
+    // Signed for another holder (e.g. the previous owner of the NFT)
+    let other_holder = Pubkey::new_unique();
+    let hash = compute_evm_link_hash(&agent_mint, &other_holder, &evm_address, chain_id, None);
+    let err = send(&mut svm, &owner, link_ix(hash, None)).expect_err("Foreign holder should fail");
+    assert_error(&err, "EvmAddressMismatch", 6047);
+
+    // Signed for a bounded link, submitted without expiry
+    let hash = compute_evm_link_hash(
+        &agent_mint,
+        &owner.pubkey(),
+        &evm_address,
+        chain_id,
+        Some(expires_at),
+    );
+    let err = send(&mut svm, &owner, link_ix(hash, None)).expect_err("Dropped expiry should fail");
+    assert_error(&err, "EvmAddressMismatch", 6047);
+
+    // Signed for a different expiry
+    let err = send(&mut svm, &owner, link_ix(hash, Some(expires_at + 1)))
+        .expect_err("Extended expiry should fail");
+    assert_error(&err, "EvmAddressMismatch", 6047);
+
+    send(&mut svm, &owner, link_ix(hash, Some(expires_at))).expect("Matching link should succeed");
+
+    println!("✅ test_link_evm_address_binds_holder_and_expiry passed");
+}
+
+/// secp256k1 curve order n (big-endian)
+const SECP256K1_N: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe,
+    0xba, 0xae, 0xdc, 0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c, 0xd0, 0x36, 0x41, 0x41,
+];
+
+/// Turn a low-s signature into its malleable high-s twin (r, n - s)
+fn to_high_s(signature: &[u8; 64]) -> [u8; 64] {
+    let mut out = *signature;
+    let mut borrow = 0i16;
+    for i in (0..32).rev() {
+        let diff = SECP256K1_N[i] as i16 - signature[32 + i] as i16 - borrow;
+        borrow = (diff < 0) as i16;
+        out[32 + i] = diff.rem_euclid(256) as u8;
+    }
+    out
+}
+
+/// Test low-s enforcement and Ethereum-style recovery IDs
+#[test]
+fn test_link_evm_address_signature_canonical() {
+    let mut svm = setup_litesvm();
+    let owner = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (agent_mint, ata) = setup_agent(&mut svm, &owner.pubkey(), 1, 0);
+    let chain_id = "eip155:1";
+
+    let link_ix = |evm_address: [u8; 20], signature: [u8; 64], recovery_id: u8| {
+        build_link_evm_address_ix(
+            &owner.pubkey(),
+            &agent_mint,
+            &ata,
+            evm_address,
+            chain_id.to_string(),
+            signature,
+            recovery_id,
+            EvmLinkFormat::Raw,
+            None,
+        )
+    };
+
+    let signing_key = SigningKey::random(&mut rand::thread_rng());
+    let evm_address = eth_address_from_pubkey(signing_key.verifying_key());
+    let message_hash =
+        compute_evm_link_hash(&agent_mint, &owner.pubkey(), &evm_address, chain_id, None);
+    let (signature, recovery_id) = sign_message_hash(&signing_key, &message_hash);
+
+    // The high-s twin recovers the same key with the flipped recovery ID
+    let ix = link_ix(evm_address, to_high_s(&signature), recovery_id ^ 1);
+    let err = send(&mut svm, &owner, ix).expect_err("High-s signature should fail");
+    assert_error(&err, "Secp256k1HighS", 6089);
+
+    let ix = link_ix(evm_address, signature, recovery_id + 2);
+    let err = send(&mut svm, &owner, ix).expect_err("Recovery ID 2/3 should fail");
+    assert_error(&err, "InvalidRecoveryId", 6090);
+
+    // Ethereum v (27/28) is accepted
+    let ix = link_ix(evm_address, signature, recovery_id + 27);
+    send(&mut svm, &owner, ix).expect("v = 27/28 should succeed");
+
+    println!("✅ test_link_evm_address_signature_canonical passed");
+}
+
+/// Test that only canonical eip155 chain IDs can be linked
+#[test]
+fn test_link_evm_address_chain_id_canonical() {
+    let mut svm = setup_litesvm();
+    let owner = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (agent_mint, _) = setup_agent(&mut svm, &owner.pubkey(), 1, 0);
+    let signing_key = SigningKey::random(&mut rand::thread_rng());
+
+    for (chain_id, name, code) in [
+        ("eip155:01", "InvalidChainReference", 6092),
+        ("eip155:0x1", "InvalidChainReference", 6092),
+        ("eip155:0", "InvalidChainReference", 6092),
+        (
+            "solana:5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp",
+            "UnsupportedChainNamespace",
+            6091,
+        ),
+        ("EIP155:1", "InvalidChainId", 6086),
+    ] {
+        let err = link(&mut svm, &owner, &agent_mint, &signing_key, chain_id, None)
+            .expect_err("Non-canonical chain ID should fail");
+        assert_error(&err, name, code);
+    }
+
+    println!("✅ test_link_evm_address_chain_id_canonical passed");
+}
@@ -0,0 +1,460 @@
+//! Tests for the link_external_identity instruction
+//!
+//! Covers each proof type: ed25519 keys from Sui/Aptos/Cosmos (Ed25519
+//! precompile), WebAuthn passkeys (secp256r1 precompile) and Bitcoin BIP-137
+//! signed messages (secp256k1_recover). Every proof is bound to the NFT holder
+//! and an optional expiry.
+
+use litesvm::LiteSVM;
+use p256::ecdsa::{signature::Signer as _, Signature as P256Signature, SigningKey as P256Key};
+use sati::constants::{DOMAIN_BITCOIN_LINK, DOMAIN_ED25519_LINK, DOMAIN_SECP256R1_LINK};
+use sati::external_identity::{
+    build_bitcoin_link_message, compute_bip137_hash, compute_external_link_hash, hash160,
+};
+use sati::signature::SECP256R1_PROGRAM_ID;
+use solana_sdk::{
+    clock::Clock, instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer,
+};
+
+use crate::common::{
+    accounts::{create_funded_keypair, create_mock_token22_ata, derive_token22_ata, setup_agent},
+    ed25519::{create_ed25519_ix, generate_ed25519_keypair, keypair_to_pubkey, sign_message},
+    instructions::{build_link_external_identity_ix, ExternalIdentityProof},
+    setup::{assert_error, send_tx, setup_litesvm},
+};
+
+const SUI_MAINNET: &str = "sui:mainnet";
+const BITCOIN_MAINNET: &str = "bip122:000000000019d6689c085ae165831e93";
+
+/// Wraps an ed25519 public key in the proof variant under test
+type Ed25519ProofBuilder = fn([u8; 32]) -> ExternalIdentityProof;
+
+/// Create a secp256r1 precompile instruction (same offsets layout as Ed25519,
+/// with a 33-byte compressed public key)
+fn create_secp256r1_ix(public_key: &[u8; 33], message: &[u8], signature: &[u8; 64]) -> Instruction {
+    let public_key_offset: u16 = 16;
+    let signature_offset: u16 = 49;
+    let message_offset: u16 = 113;
+
+    let mut data = vec![1, 0];
+    data.extend_from_slice(&signature_offset.to_le_bytes());
+    data.extend_from_slice(&u16::MAX.to_le_bytes());
+    data.extend_from_slice(&public_key_offset.to_le_bytes());
+    data.extend_from_slice(&u16::MAX.to_le_bytes());
+    data.extend_from_slice(&message_offset.to_le_bytes());
+    data.extend_from_slice(&(message.len() as u16).to_le_bytes());
+    data.extend_from_slice(&u16::MAX.to_le_bytes());
+    data.extend_from_slice(public_key);
+    data.extend_from_slice(signature);
+    data.extend_from_slice(message);
+
+    Instruction {
+        program_id: SECP256R1_PROGRAM_ID,
+        accounts: vec![],
+        data,
+    }
+}
+
+/// Sign the ed25519 link hash for (agent_mint, signed_chain_id) and submit a link for chain_id
+fn link_ed25519(
+    svm: &mut LiteSVM,
+    owner: &Keypair,
+    agent_mint: &Pubkey,
+    chain_id: &str,
+    signed_chain_id: &str,
+    make_proof: Ed25519ProofBuilder,
+) -> Result<(), String> {
+    let keypair = generate_ed25519_keypair();
+    let public_key = keypair_to_pubkey(&keypair);
+    let link_hash = compute_external_link_hash(
+        DOMAIN_ED25519_LINK,
+        agent_mint,
+        &owner.pubkey(),
+        signed_chain_id,
+        None,
+    );
+    let signature = sign_message(&keypair, &link_hash);
+
+    let ata = derive_token22_ata(&owner.pubkey(), agent_mint);
+    let ixs = [
+        create_ed25519_ix(&public_key, &link_hash, &signature),
+        build_link_external_identity_ix(
+            &owner.pubkey(),
+            agent_mint,
+            &ata,
+            chain_id.to_string(),
+            make_proof(public_key.to_bytes()),
+            None,
+        ),
+    ];
+    send_tx(svm, &[owner], &ixs)
+}
+
+/// WebAuthn clientDataJSON for a `webauthn.get` over `challenge`
+fn client_data_json(challenge: &[u8; 32]) -> Vec<u8> {
+    use base64::Engine;
+    let challenge = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(challenge);
+    format!(
+        "{{\"type\":\"webauthn.get\",\"challenge\":\"{challenge}\",\"origin\":\"https://agent.example\"}}"
+    )
+    .into_bytes()
+}
+
+/// Produce a passkey assertion over `challenge`; returns (proof, precompile ix)
+fn passkey_assertion(key: &P256Key, challenge: &[u8; 32]) -> (ExternalIdentityProof, Instruction) {
+    let public_key: [u8; 33] = key
+        .verifying_key()
+        .to_encoded_point(true)
+        .as_bytes()
+        .try_into()
+        .unwrap();
+    let mut authenticator_data = vec![0u8; 37];
+    authenticator_data[32] = 0x01; // user present
+    let client_data_json = client_data_json(challenge);
+
+    let mut message = authenticator_data.clone();
+    message.extend_from_slice(&solana_sdk::hash::hash(&client_data_json).to_bytes());
+    let signature: P256Signature = key.sign(&message);
+    let signature = signature.normalize_s().unwrap_or(signature);
+
+    (
+        ExternalIdentityProof::Secp256r1Passkey {
+            public_key,
+            authenticator_data,
+            client_data_json,
+        },
+        create_secp256r1_ix(&public_key, &message, &signature.to_bytes().into()),
+    )
+}
+
+/// Sign the Bitcoin link message with a compressed P2WPKH key (BIP-137 header 39-42)
+fn sign_bitcoin_link(
+    key: &k256::ecdsa::SigningKey,
+    agent_mint: &Pubkey,
+    holder: &Pubkey,
+    chain_id: &str,
+    expires_at: Option<i64>,
+) -> ([u8; 65], [u8; 20]) {
+    let link_hash = compute_external_link_hash(
+        DOMAIN_BITCOIN_LINK,
+        agent_mint,
+        holder,
+        chain_id,
+        expires_at,
+    );
+    let message_hash = compute_bip137_hash(build_bitcoin_link_message(&link_hash).as_bytes());
+    let (signature, recovery_id) = key.sign_prehash_recoverable(&message_hash).unwrap();
+
+    let mut out = [0u8; 65];
+    out[0] = 39 + recovery_id.to_byte();
+    out[1..].copy_from_slice(&signature.to_bytes());
+    let address_hash = hash160(key.verifying_key().to_encoded_point(true).as_bytes());
+    (out, address_hash)
+}
+
+#[test]
+fn test_link_external_identity_ed25519_chains() {
+    let mut svm = setup_litesvm();
+    let owner = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (agent_mint, _) = setup_agent(&mut svm, &owner.pubkey(), 1, 0);
+
+    let cases: [(&str, Ed25519ProofBuilder); 3] = [
+        (SUI_MAINNET, |public_key| {
+            ExternalIdentityProof::SuiEd25519 { public_key }
+        }),
+        ("aptos:1", |public_key| {
+            ExternalIdentityProof::AptosEd25519 { public_key }
+        }),
+        ("cosmos:cosmoshub-4", |public_key| {
+            ExternalIdentityProof::CosmosEd25519 { public_key }
+        }),
+    ];
+    for (chain_id, make_proof) in cases {
+        let result = link_ed25519(
+            &mut svm,
+            &owner,
+            &agent_mint,
+            chain_id,
+            chain_id,
+            make_proof,
+        );
+        assert!(result.is_ok(), "{chain_id}: {:?}", result.err());
+    }
+
+    println!("✅ test_link_external_identity_ed25519_chains passed");
+}
+
+#[test]
+fn test_link_external_identity_ed25519_wrong_message() {
+    let mut svm = setup_litesvm();
+    let owner = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (agent_mint, _) = setup_agent(&mut svm, &owner.pubkey(), 1, 0);
+
+    // Signature over sui:testnet cannot link sui:mainnet
+    let err = link_ed25519(
+        &mut svm,
+        &owner,
+        &agent_mint,
+        SUI_MAINNET,
+        "sui:testnet",
+        |public_key| ExternalIdentityProof::SuiEd25519 { public_key },
+    )
+    .unwrap_err();
+    assert_error(&err, "ExternalSignatureNotFound", 6095);
+
+    println!("✅ test_link_external_identity_ed25519_wrong_message passed");
+}
+
+#[test]
+fn test_link_external_identity_chain_mismatch() {
+    let mut svm = setup_litesvm();
+    let owner = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (agent_mint, _) = setup_agent(&mut svm, &owner.pubkey(), 1, 0);
+
+    // Sui key presented for an Aptos chain
+    let err = link_ed25519(
+        &mut svm,
+        &owner,
+        &agent_mint,
+        "aptos:1",
+        "aptos:1",
+        |public_key| ExternalIdentityProof::SuiEd25519 { public_key },
+    )
+    .unwrap_err();
+    assert_error(&err, "ExternalIdentityChainMismatch", 6094);
+
+    // Malformed CAIP-2 reference
+    let err = link_ed25519(
+        &mut svm,
+        &owner,
+        &agent_mint,
+        "sui:main net",
+        "sui:main net",
+        |public_key| ExternalIdentityProof::SuiEd25519 { public_key },
+    )
+    .unwrap_err();
+    assert_error(&err, "InvalidChainReference", 6092);
+
+    println!("✅ test_link_external_identity_chain_mismatch passed");
+}
+
+#[test]
+fn test_link_external_identity_passkey() {
+    let mut svm = setup_litesvm();
+    let owner = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (agent_mint, ata) = setup_agent(&mut svm, &owner.pubkey(), 1, 0);
+    let key = P256Key::random(&mut rand::thread_rng());
+    let chain_id = "solana:5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp";
+
+    let challenge = compute_external_link_hash(
+        DOMAIN_SECP256R1_LINK,
+        &agent_mint,
+        &owner.pubkey(),
+        chain_id,
+        None,
+    );
+    let (proof, precompile_ix) = passkey_assertion(&key, &challenge);
+    let ix = build_link_external_identity_ix(
+        &owner.pubkey(),
+        &agent_mint,
+        &ata,
+        chain_id.to_string(),
+        proof,
+        None,
+    );
+    let result = send_tx(&mut svm, &[&owner], &[precompile_ix, ix]);
+    assert!(result.is_ok(), "Passkey link failed: {:?}", result.err());
+
+    println!("✅ test_link_external_identity_passkey passed");
+}
+
+#[test]
+fn test_link_external_identity_passkey_wrong_challenge() {
+    let mut svm = setup_litesvm();
+    let owner = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (agent_mint, ata) = setup_agent(&mut svm, &owner.pubkey(), 1, 0);
+    let key = P256Key::random(&mut rand::thread_rng());
+
+    // Assertion for another agent's challenge
+    let challenge = compute_external_link_hash(
+        DOMAIN_SECP256R1_LINK,
+        &Pubkey::new_unique(),
+        &owner.pubkey(),
+        SUI_MAINNET,
+        None,
+    );
+    let (proof, precompile_ix) = passkey_assertion(&key, &challenge);
+    let ix = build_link_external_identity_ix(
+        &owner.pubkey(),
+        &agent_mint,
+        &ata,
+        SUI_MAINNET.to_string(),
+        proof,
+        None,
+    );
+    let err = send_tx(&mut svm, &[&owner], &[precompile_ix, ix]).unwrap_err();
+    assert_error(&err, "InvalidWebAuthnAssertion", 6096);
+
+    println!("✅ test_link_external_identity_passkey_wrong_challenge passed");
+}
+
+#[test]
+fn test_link_external_identity_bitcoin() {
+    let mut svm = setup_litesvm();
+    let owner = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (agent_mint, ata) = setup_agent(&mut svm, &owner.pubkey(), 1, 0);
+    let key = k256::ecdsa::SigningKey::random(&mut rand::thread_rng());
+
+    let (signature, address_hash) =
+        sign_bitcoin_link(&key, &agent_mint, &owner.pubkey(), BITCOIN_MAINNET, None);
+    let ix = build_link_external_identity_ix(
+        &owner.pubkey(),
+        &agent_mint,
+        &ata,
+        BITCOIN_MAINNET.to_string(),
+        ExternalIdentityProof::BitcoinBip137 {
+            signature,
+            address_hash,
+        },
+        None,
+    );
+    let result = send_tx(&mut svm, &[&owner], &[ix]);
+    assert!(result.is_ok(), "Bitcoin link failed: {:?}", result.err());
+
+    println!("✅ test_link_external_identity_bitcoin passed");
+}
+
+#[test]
+fn test_link_external_identity_bitcoin_address_mismatch() {
+    let mut svm = setup_litesvm();
+    let owner = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (agent_mint, ata) = setup_agent(&mut svm, &owner.pubkey(), 1, 0);
+    let key = k256::ecdsa::SigningKey::random(&mut rand::thread_rng());
+
+    let (signature, mut address_hash) =
+        sign_bitcoin_link(&key, &agent_mint, &owner.pubkey(), BITCOIN_MAINNET, None);
+    address_hash[0] ^= 0xff;
+    let ix = build_link_external_identity_ix(
+        &owner.pubkey(),
+        &agent_mint,
+        &ata,
+        BITCOIN_MAINNET.to_string(),
+        ExternalIdentityProof::BitcoinBip137 {
+            signature,
+            address_hash,
+        },
+        None,
+    );
+    let err = send_tx(&mut svm, &[&owner], &[ix]).unwrap_err();
+    assert_error(&err, "ExternalIdentityMismatch", 6097);
+
+    println!("✅ test_link_external_identity_bitcoin_address_mismatch passed");
+}
+
+#[test]
+fn test_link_external_identity_non_holder() {
+    let mut svm = setup_litesvm();
+    let owner = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (agent_mint, ata) = setup_agent(&mut svm, &owner.pubkey(), 1, 0);
+    create_mock_token22_ata(&mut svm, &ata, &agent_mint, &owner.pubkey(), 0);
+    let key = k256::ecdsa::SigningKey::random(&mut rand::thread_rng());
+
+    let (signature, address_hash) =
+        sign_bitcoin_link(&key, &agent_mint, &owner.pubkey(), BITCOIN_MAINNET, None);
+    let ix = build_link_external_identity_ix(
+        &owner.pubkey(),
+        &agent_mint,
+        &ata,
+        BITCOIN_MAINNET.to_string(),
+        ExternalIdentityProof::BitcoinBip137 {
+            signature,
+            address_hash,
+        },
+        None,
+    );
+    let err = send_tx(&mut svm, &[&owner], &[ix]).unwrap_err();
+    assert_error(&err, "InvalidAuthority", 6001);
+
+    println!("✅ test_link_external_identity_non_holder passed");
+}
+
+/// Test that a proof signed for one holder is rejected when the next holder submits it
+#[test]
+fn test_link_external_identity_other_holder_rejected() {
+    let mut svm = setup_litesvm();
+    let holder_a = create_funded_keypair(&mut svm, 10_000_000_000);
+    let holder_b = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (agent_mint, ata_a) = setup_agent(&mut svm, &holder_a.pubkey(), 1, 0);
+    let key = k256::ecdsa::SigningKey::random(&mut rand::thread_rng());
+
+    // Holder A collects a proof, then the NFT moves to holder B
+    let (signature, address_hash) =
+        sign_bitcoin_link(&key, &agent_mint, &holder_a.pubkey(), BITCOIN_MAINNET, None);
+    create_mock_token22_ata(&mut svm, &ata_a, &agent_mint, &holder_a.pubkey(), 0);
+    let ata_b = derive_token22_ata(&holder_b.pubkey(), &agent_mint);
+    create_mock_token22_ata(&mut svm, &ata_b, &agent_mint, &holder_b.pubkey(), 1);
+
+    let ix = build_link_external_identity_ix(
+        &holder_b.pubkey(),
+        &agent_mint,
+        &ata_b,
+        BITCOIN_MAINNET.to_string(),
+        ExternalIdentityProof::BitcoinBip137 {
+            signature,
+            address_hash,
+        },
+        None,
+    );
+    let err = send_tx(&mut svm, &[&holder_b], &[ix]).unwrap_err();
+    assert_error(&err, "ExternalIdentityMismatch", 6097);
+
+    println!("✅ test_link_external_identity_other_holder_rejected passed");
+}
+
+/// Test that the signed expiry is enforced and must lie in the future
+#[test]
+fn test_link_external_identity_expiry() {
+    let mut svm = setup_litesvm();
+    let owner = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (agent_mint, ata) = setup_agent(&mut svm, &owner.pubkey(), 1, 0);
+    let key = k256::ecdsa::SigningKey::random(&mut rand::thread_rng());
+    let now = svm.get_sysvar::<Clock>().unix_timestamp;
+
+    let link_ix = |expires_at: Option<i64>, signed_expires_at: Option<i64>| {
+        let (signature, address_hash) = sign_bitcoin_link(
+            &key,
+            &agent_mint,
+            &owner.pubkey(),
+            BITCOIN_MAINNET,
+            signed_expires_at,
+        );
+        build_link_external_identity_ix(
+            &owner.pubkey(),
+            &agent_mint,
+            &ata,
+            BITCOIN_MAINNET.to_string(),
+            ExternalIdentityProof::BitcoinBip137 {
+                signature,
+                address_hash,
+            },
+            expires_at,
+        )
+    };
+
+    // An expiry in the past is rejected
+    let err = send_tx(&mut svm, &[&owner], &[link_ix(Some(now), Some(now))]).unwrap_err();
+    assert_error(&err, "InvalidExternalLinkExpiry", 6115);
+
+    // The expiry is part of the signed message
+    let err = send_tx(&mut svm, &[&owner], &[link_ix(Some(now + 3600), None)]).unwrap_err();
+    assert_error(&err, "ExternalIdentityMismatch", 6097);
+
+    let result = send_tx(
+        &mut svm,
+        &[&owner],
+        &[link_ix(Some(now + 3600), Some(now + 3600))],
+    );
+    assert!(result.is_ok(), "Expiring link failed: {:?}", result.err());
+
+    println!("✅ test_link_external_identity_expiry passed");
+}
@@ -0,0 +1,266 @@
+//! Tests for the link_sns_domain and unlink_sns_domain instructions
+//!
+//! Name registry accounts are constructed locally with the SNS header layout,
+//! so no SNS program is needed in LiteSVM.
+
+use litesvm::LiteSVM;
+use sati::sns::SOL_TLD_AUTHORITY;
+use sati::state::SnsLink;
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+
+use crate::common::{
+    accounts::{
+        create_funded_keypair, create_mock_sns_name_account, create_mock_token22_ata,
+        derive_token22_ata, setup_agent,
+    },
+    instructions::{build_link_sns_domain_ix, build_unlink_sns_domain_ix},
+    setup::{assert_error, derive_sns_link_pda, send, setup_litesvm, SATI_PROGRAM_ID},
+};
+
+/// Link `<domain>.sol`, owned by `owner`, to `agent_mint`; returns the name account
+fn link_domain(svm: &mut LiteSVM, owner: &Keypair, agent_mint: &Pubkey, domain: &str) -> Pubkey {
+    let name_account =
+        create_mock_sns_name_account(svm, domain, &owner.pubkey(), &SOL_TLD_AUTHORITY);
+    let ix = build_link_sns_domain_ix(
+        &owner.pubkey(),
+        agent_mint,
+        &name_account,
+        domain.to_string(),
+    );
+    send(svm, owner, ix).expect("Link should succeed");
+    name_account
+}
+
+#[test]
+fn test_link_sns_domain_stores_link() {
+    let mut svm = setup_litesvm();
+    let owner = create_funded_keypair(&mut svm, 10_000_000_000);
+    let agent_mint = setup_agent(&mut svm, &owner.pubkey(), 1, 0).0;
+    let domain = "myagent";
+    let name_account =
+        create_mock_sns_name_account(&mut svm, domain, &owner.pubkey(), &SOL_TLD_AUTHORITY);
+
+    let ix = build_link_sns_domain_ix(
+        &owner.pubkey(),
+        &agent_mint,
+        &name_account,
+        domain.to_string(),
+    );
+    let result = send(&mut svm, &owner, ix.clone());
+    assert!(result.is_ok(), "Link failed: {:?}", result.err());
+
+    let (sns_link, bump) = derive_sns_link_pda(&agent_mint, &name_account);
+    let account = svm.get_account(&sns_link).expect("SnsLink should exist");
+    assert_eq!(account.owner, SATI_PROGRAM_ID);
+    assert_eq!(account.data.len(), SnsLink::SIZE);
+
+    let data = &account.data[8..];
+    assert_eq!(&data[..32], agent_mint.as_ref(), "agent_mint");
+    assert_eq!(&data[32..64], name_account.as_ref(), "name_account");
+    let domain_len = u32::from_le_bytes(data[64..68].try_into().unwrap()) as usize;
+    assert_eq!(&data[68..68 + domain_len], domain.as_bytes(), "domain");
+    let rest = &data[68 + domain_len..];
+    assert_eq!(&rest[..32], owner.pubkey().as_ref(), "holder");
+    assert_eq!(rest[40], bump, "bump");
+
+    // The same domain cannot be linked to the agent twice
+    assert!(
+        send(&mut svm, &owner, ix).is_err(),
+        "Duplicate link should fail"
+    );
+
+    println!("✅ test_link_sns_domain_stores_link passed");
+}
+
+#[test]
+fn test_link_sns_domain_not_domain_owner() {
+    let mut svm = setup_litesvm();
+    let owner = create_funded_keypair(&mut svm, 10_000_000_000);
+    let agent_mint = setup_agent(&mut svm, &owner.pubkey(), 1, 0).0;
+    let name_account = create_mock_sns_name_account(
+        &mut svm,
+        "someoneelse",
+        &Pubkey::new_unique(),
+        &SOL_TLD_AUTHORITY,
+    );
+
+    let ix = build_link_sns_domain_ix(
+        &owner.pubkey(),
+        &agent_mint,
+        &name_account,
+        "someoneelse".to_string(),
+    );
+    let err = send(&mut svm, &owner, ix).unwrap_err();
+    assert_error(&err, "SnsOwnerMismatch", 6100);
+
+    println!("✅ test_link_sns_domain_not_domain_owner passed");
+}
+
+#[test]
+fn test_link_sns_domain_not_agent_holder() {
+    let mut svm = setup_litesvm();
+    let owner = create_funded_keypair(&mut svm, 10_000_000_000);
+    let agent_mint = setup_agent(&mut svm, &owner.pubkey(), 1, 0).0;
+    let ata = derive_token22_ata(&owner.pubkey(), &agent_mint);
+    create_mock_token22_ata(&mut svm, &ata, &agent_mint, &owner.pubkey(), 0);
+    let name_account =
+        create_mock_sns_name_account(&mut svm, "myagent", &owner.pubkey(), &SOL_TLD_AUTHORITY);
+
+    let ix = build_link_sns_domain_ix(
+        &owner.pubkey(),
+        &agent_mint,
+        &name_account,
+        "myagent".to_string(),
+    );
+    let err = send(&mut svm, &owner, ix).unwrap_err();
+    assert_error(&err, "InvalidAuthority", 6001);
+
+    println!("✅ test_link_sns_domain_not_agent_holder passed");
+}
+
+#[test]
+fn test_link_sns_domain_wrong_registry() {
+    let mut svm = setup_litesvm();
+    let owner = create_funded_keypair(&mut svm, 10_000_000_000);
+    let agent_mint = setup_agent(&mut svm, &owner.pubkey(), 1, 0).0;
+
+    // Registry for "myagent" presented as "otheragent"
+    let name_account =
+        create_mock_sns_name_account(&mut svm, "myagent", &owner.pubkey(), &SOL_TLD_AUTHORITY);
+    let ix = build_link_sns_domain_ix(
+        &owner.pubkey(),
+        &agent_mint,
+        &name_account,
+        "otheragent".to_string(),
+    );
+    let err = send(&mut svm, &owner, ix).unwrap_err();
+    assert_error(&err, "InvalidNameRegistry", 6099);
+
+    // Name whose parent is not the .sol TLD
+    let name_account =
+        create_mock_sns_name_account(&mut svm, "subname", &owner.pubkey(), &Pubkey::new_unique());
+    let ix = build_link_sns_domain_ix(
+        &owner.pubkey(),
+        &agent_mint,
+        &name_account,
+        "subname".to_string(),
+    );
+    let err = send(&mut svm, &owner, ix).unwrap_err();
+    assert_error(&err, "InvalidNameRegistry", 6099);
+
+    // Account at the right address but not owned by the name service
+    let name_account =
+        create_mock_sns_name_account(&mut svm, "spoofed", &owner.pubkey(), &SOL_TLD_AUTHORITY);
+    let mut account = svm.get_account(&name_account).unwrap();
+    account.owner = Pubkey::new_unique();
+    svm.set_account(name_account, account).unwrap();
+    let ix = build_link_sns_domain_ix(
+        &owner.pubkey(),
+        &agent_mint,
+        &name_account,
+        "spoofed".to_string(),
+    );
+    let err = send(&mut svm, &owner, ix).unwrap_err();
+    assert_error(&err, "InvalidNameRegistry", 6099);
+
+    println!("✅ test_link_sns_domain_wrong_registry passed");
+}
+
+#[test]
+fn test_link_sns_domain_invalid_domain() {
+    let mut svm = setup_litesvm();
+    let owner = create_funded_keypair(&mut svm, 10_000_000_000);
+    let agent_mint = setup_agent(&mut svm, &owner.pubkey(), 1, 0).0;
+
+    // "myagent.sol" must be passed as the label "myagent"
+    let name_account =
+        create_mock_sns_name_account(&mut svm, "myagent.sol", &owner.pubkey(), &SOL_TLD_AUTHORITY);
+    let ix = build_link_sns_domain_ix(
+        &owner.pubkey(),
+        &agent_mint,
+        &name_account,
+        "myagent.sol".to_string(),
+    );
+    let err = send(&mut svm, &owner, ix).unwrap_err();
+    assert_error(&err, "InvalidSnsDomain", 6098);
+
+    println!("✅ test_link_sns_domain_invalid_domain passed");
+}
+
+/// Test that only the current holder can unlink a live link, and the domain can be relinked
+#[test]
+fn test_unlink_sns_domain_by_holder() {
+    let mut svm = setup_litesvm();
+    let owner = create_funded_keypair(&mut svm, 10_000_000_000);
+    let outsider = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (agent_mint, ata) = setup_agent(&mut svm, &owner.pubkey(), 1, 0);
+    let name_account = link_domain(&mut svm, &owner, &agent_mint, "myagent");
+    let (sns_link, _) = derive_sns_link_pda(&agent_mint, &name_account);
+
+    let ix = build_unlink_sns_domain_ix(
+        &outsider.pubkey(),
+        &agent_mint,
+        &name_account,
+        &owner.pubkey(),
+        None,
+    );
+    let err = send(&mut svm, &outsider, ix).unwrap_err();
+    assert_error(&err, "SnsUnlinkNotAllowed", 6117);
+
+    let ix = build_unlink_sns_domain_ix(
+        &owner.pubkey(),
+        &agent_mint,
+        &name_account,
+        &owner.pubkey(),
+        Some(ata),
+    );
+    send(&mut svm, &owner, ix).expect("Holder unlink should succeed");
+    assert!(svm
+        .get_account(&sns_link)
+        .is_none_or(|account| account.lamports == 0));
+
+    // The closed link no longer blocks linking the domain again
+    link_domain(&mut svm, &owner, &agent_mint, "myagent");
+
+    println!("✅ test_unlink_sns_domain_by_holder passed");
+}
+
+/// Test that anyone can unlink once the domain or the agent NFT changed hands
+#[test]
+fn test_unlink_sns_domain_stale() {
+    let mut svm = setup_litesvm();
+    let owner = create_funded_keypair(&mut svm, 10_000_000_000);
+    let outsider = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (agent_mint, ata) = setup_agent(&mut svm, &owner.pubkey(), 1, 0);
+
+    // Domain transferred to another wallet
+    let name_account = link_domain(&mut svm, &owner, &agent_mint, "myagent");
+    create_mock_sns_name_account(
+        &mut svm,
+        "myagent",
+        &Pubkey::new_unique(),
+        &SOL_TLD_AUTHORITY,
+    );
+    let ix = build_unlink_sns_domain_ix(
+        &outsider.pubkey(),
+        &agent_mint,
+        &name_account,
+        &owner.pubkey(),
+        None,
+    );
+    send(&mut svm, &outsider, ix).expect("Unlink after domain transfer should succeed");
+
+    // Agent NFT left the linking holder
+    let name_account = link_domain(&mut svm, &owner, &agent_mint, "otheragent");
+    create_mock_token22_ata(&mut svm, &ata, &agent_mint, &owner.pubkey(), 0);
+    let ix = build_unlink_sns_domain_ix(
+        &outsider.pubkey(),
+        &agent_mint,
+        &name_account,
+        &owner.pubkey(),
+        None,
+    );
+    send(&mut svm, &outsider, ix).expect("Unlink after NFT transfer should succeed");
+
+    println!("✅ test_unlink_sns_domain_stale passed");
+}
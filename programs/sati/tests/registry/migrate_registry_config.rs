@@ -0,0 +1,189 @@
+//! Tests for the migrate_registry_config instruction
+//!
+//! Version 0 registries (81 bytes) cannot be deserialized until migrated.
+//! Migration is permissionless and only appends zero-initialized fields.
+
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer, transaction::Transaction};
+
+use crate::common::{
+    accounts::{
+        create_funded_keypair, create_initialized_registry, create_legacy_registry,
+        create_mock_group_mint, LEGACY_REGISTRY_CONFIG_SIZE, REGISTRY_CONFIG_SIZE,
+    },
+    instructions::{build_migrate_registry_config_ix, build_propose_authority_ix},
+    setup::{derive_registry_config_pda, setup_litesvm},
+};
+
+/// Test migrating a legacy registry preserves existing fields
+#[test]
+fn test_migrate_legacy_registry() {
+    let mut svm = setup_litesvm();
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let payer = create_funded_keypair(&mut svm, 10_000_000_000);
+    let new_authority = Keypair::new();
+    let (registry_config, bump) = derive_registry_config_pda();
+
+    let group_mint = Keypair::new();
+    create_mock_group_mint(&mut svm, &group_mint, &registry_config);
+    create_legacy_registry(
+        &mut svm,
+        &registry_config,
+        &authority.pubkey(),
+        &group_mint.pubkey(),
+        bump,
+    );
+    let before = svm.get_account(&registry_config).unwrap();
+    assert_eq!(before.data.len(), LEGACY_REGISTRY_CONFIG_SIZE);
+
+    // Legacy layout is unusable until migrated
+    let propose_ix = build_propose_authority_ix(
+        &authority.pubkey(),
+        &registry_config,
+        new_authority.pubkey(),
+    );
+    let tx = Transaction::new_signed_with_payer(
+        std::slice::from_ref(&propose_ix),
+        Some(&authority.pubkey()),
+        &[&authority],
+        svm.latest_blockhash(),
+    );
+    assert!(
+        svm.send_transaction(tx).is_err(),
+        "Legacy registry should not deserialize"
+    );
+
+    // Anyone can migrate
+    let ix = build_migrate_registry_config_ix(&payer.pubkey(), &registry_config);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer.pubkey()),
+        &[&payer],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(
+        result.is_ok(),
+        "Migration should succeed: {:?}",
+        result.err()
+    );
+
+    let after = svm.get_account(&registry_config).unwrap();
+    assert_eq!(after.data.len(), REGISTRY_CONFIG_SIZE);
+    assert_eq!(
+        after.data[..LEGACY_REGISTRY_CONFIG_SIZE],
+        before.data[..],
+        "Existing fields should be preserved"
+    );
+    assert_eq!(after.data[81], 4, "Version should be 4");
+    assert_eq!(&after.data[82..114], Pubkey::default().as_ref());
+    assert_eq!(&after.data[114..171], &[0u8; 57]);
+    assert!(after.lamports >= svm.minimum_balance_for_rent_exemption(REGISTRY_CONFIG_SIZE));
+
+    // Now usable
+    svm.expire_blockhash();
+    let tx = Transaction::new_signed_with_payer(
+        &[propose_ix],
+        Some(&authority.pubkey()),
+        &[&authority],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(
+        result.is_ok(),
+        "Propose after migration should succeed: {:?}",
+        result.err()
+    );
+
+    println!("✅ test_migrate_legacy_registry passed");
+}
+
+/// Test migrating a version 1 registry (pending_authority, no timelock fields)
+#[test]
+fn test_migrate_v1_registry() {
+    let mut svm = setup_litesvm();
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let pending = Keypair::new().pubkey();
+    let (registry_config, bump) = derive_registry_config_pda();
+
+    let group_mint = Keypair::new();
+    create_mock_group_mint(&mut svm, &group_mint, &registry_config);
+    create_initialized_registry(
+        &mut svm,
+        &registry_config,
+        &authority.pubkey(),
+        &group_mint.pubkey(),
+        bump,
+    );
+
+    // Rewrite as a version 1 account with a pending transfer
+    let mut account = svm.get_account(&registry_config).unwrap();
+    account.data.truncate(114);
+    account.data[81] = 1;
+    account.data[82..114].copy_from_slice(pending.as_ref());
+    account.lamports = svm.minimum_balance_for_rent_exemption(114);
+    svm.set_account(registry_config, account).unwrap();
+
+    let ix = build_migrate_registry_config_ix(&authority.pubkey(), &registry_config);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[&authority],
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    assert!(
+        result.is_ok(),
+        "Migration should succeed: {:?}",
+        result.err()
+    );
+
+    let after = svm.get_account(&registry_config).unwrap();
+    assert_eq!(after.data.len(), REGISTRY_CONFIG_SIZE);
+    assert_eq!(after.data[81], 4, "Version should be 4");
+    assert_eq!(
+        &after.data[82..114],
+        pending.as_ref(),
+        "Pending authority should be preserved"
+    );
+    assert_eq!(&after.data[114..171], &[0u8; 57]);
+
+    println!("✅ test_migrate_v1_registry passed");
+}
+
+/// Test that a current-version registry cannot be migrated again
+#[test]
+fn test_migrate_already_current() {
+    let mut svm = setup_litesvm();
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (registry_config, bump) = derive_registry_config_pda();
+
+    let group_mint = Keypair::new();
+    create_mock_group_mint(&mut svm, &group_mint, &registry_config);
+    create_initialized_registry(
+        &mut svm,
+        &registry_config,
+        &authority.pubkey(),
+        &group_mint.pubkey(),
+        bump,
+    );
+
+    let ix = build_migrate_registry_config_ix(&authority.pubkey(), &registry_config);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&authority.pubkey()),
+        &[&authority],
+        svm.latest_blockhash(),
+    );
+    let err_str = format!(
+        "{:?}",
+        svm.send_transaction(tx)
+            .expect_err("Second migration should fail")
+    );
+    assert!(
+        err_str.contains("RegistryAlreadyMigrated") || err_str.contains("6062"),
+        "Expected RegistryAlreadyMigrated error (6062), got: {}",
+        err_str
+    );
+
+    println!("✅ test_migrate_already_current passed");
+}
@@ -1,5 +1,23 @@
+mod agent_handle;
+mod agent_services;
+mod agent_status;
+mod agent_verification;
+mod authority_transfer;
+mod backfill_agent_record;
+mod governance_timelock;
 mod initialize;
 mod link_evm_address;
+mod link_external_identity;
+mod link_sns_domain;
+mod migrate_registry_config;
+mod parent_agent;
+mod pause_registry;
 mod register_agent;
+mod register_agents_batch;
 mod register_schema_config;
+mod registration_fee;
+mod retire_agent;
+mod set_successor;
+mod sync_agent_authority;
+mod update_agent_metadata;
 mod update_authority;
@@ -0,0 +1,176 @@
+//! Tests for the set_parent_agent and detach_parent_agent instructions
+
+use sati::state::AgentParent;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+use crate::common::{
+    accounts::{create_funded_keypair, create_mock_token22_ata, derive_token22_ata, setup_agent},
+    instructions::{build_detach_parent_agent_ix, build_set_parent_agent_ix, AgentStatus},
+    setup::{assert_error, derive_agent_parent_pda, send, send_tx, setup_litesvm},
+};
+
+/// Test attaching a child agent to a parent held by a different holder
+#[test]
+fn test_set_parent_agent_success() {
+    let mut svm = setup_litesvm();
+    let holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let parent_holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let child = setup_agent(&mut svm, &holder.pubkey(), 1, 0).0;
+    let parent = setup_agent(&mut svm, &parent_holder.pubkey(), 2, 0).0;
+
+    let ix = build_set_parent_agent_ix(&holder.pubkey(), &child, &parent_holder.pubkey(), &parent);
+    send_tx(
+        &mut svm,
+        &[&holder, &parent_holder],
+        std::slice::from_ref(&ix),
+    )
+    .expect("set_parent_agent should succeed");
+
+    let (agent_parent, bump) = derive_agent_parent_pda(&child);
+    let account = svm
+        .get_account(&agent_parent)
+        .expect("AgentParent should exist");
+    assert_eq!(account.data.len(), AgentParent::SIZE);
+    assert_eq!(&account.data[8..40], child.as_ref(), "mint");
+    assert_eq!(&account.data[40..72], parent.as_ref(), "parent");
+    assert_eq!(&account.data[72..104], holder.pubkey().as_ref(), "holder");
+    assert_eq!(account.data[112], bump, "bump");
+
+    // An agent has at most one parent
+    assert!(
+        send_tx(&mut svm, &[&holder, &parent_holder], &[ix]).is_err(),
+        "Second parent should fail"
+    );
+
+    println!("✅ test_set_parent_agent_success passed");
+}
+
+/// Test that both holders must sign and hold their NFTs
+#[test]
+fn test_set_parent_agent_requires_both_holders() {
+    let mut svm = setup_litesvm();
+    let holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let child = setup_agent(&mut svm, &holder.pubkey(), 1, 0).0;
+    let other = setup_agent(&mut svm, &Keypair::new().pubkey(), 2, 0).0;
+
+    // Holder cannot claim an agent they don't hold as parent
+    let other_ata = derive_token22_ata(&holder.pubkey(), &other);
+    create_mock_token22_ata(&mut svm, &other_ata, &other, &holder.pubkey(), 0);
+    let ix = build_set_parent_agent_ix(&holder.pubkey(), &child, &holder.pubkey(), &other);
+    let err = send(&mut svm, &holder, ix).expect_err("Non-holder of parent should fail");
+    assert_error(&err, "InvalidAuthority", 6001);
+
+    // Nor attach someone else's agent as a child
+    let ix = build_set_parent_agent_ix(&holder.pubkey(), &other, &holder.pubkey(), &child);
+    let err = send(&mut svm, &holder, ix).expect_err("Non-holder of child should fail");
+    assert_error(&err, "InvalidAuthority", 6001);
+
+    println!("✅ test_set_parent_agent_requires_both_holders passed");
+}
+
+/// Test that an agent cannot be its own parent and both agents must be active
+#[test]
+fn test_set_parent_agent_invalid_links() {
+    let mut svm = setup_litesvm();
+    let holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let child = setup_agent(&mut svm, &holder.pubkey(), 1, 0).0;
+    let suspended = setup_agent(&mut svm, &holder.pubkey(), 2, AgentStatus::Suspended as u8).0;
+
+    let ix = build_set_parent_agent_ix(&holder.pubkey(), &child, &holder.pubkey(), &child);
+    let err = send(&mut svm, &holder, ix).expect_err("Self parent should fail");
+    assert_error(&err, "InvalidParentAgent", 6101);
+
+    let ix = build_set_parent_agent_ix(&holder.pubkey(), &child, &holder.pubkey(), &suspended);
+    let err = send(&mut svm, &holder, ix).expect_err("Suspended parent should fail");
+    assert_error(&err, "AgentSuspended", 6080);
+
+    println!("✅ test_set_parent_agent_invalid_links passed");
+}
+
+/// Test that either side of the hierarchy can detach, but outsiders cannot
+#[test]
+fn test_detach_parent_agent() {
+    let mut svm = setup_litesvm();
+    let holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let parent_holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let outsider = create_funded_keypair(&mut svm, 10_000_000_000);
+    let child = setup_agent(&mut svm, &holder.pubkey(), 1, 0).0;
+    let parent = setup_agent(&mut svm, &parent_holder.pubkey(), 2, 0).0;
+    let (agent_parent, _) = derive_agent_parent_pda(&child);
+
+    let attach =
+        build_set_parent_agent_ix(&holder.pubkey(), &child, &parent_holder.pubkey(), &parent);
+    send_tx(
+        &mut svm,
+        &[&holder, &parent_holder],
+        std::slice::from_ref(&attach),
+    )
+    .unwrap();
+
+    // Outsider holding an unrelated agent
+    let unrelated = setup_agent(&mut svm, &outsider.pubkey(), 3, 0).0;
+    let ix = build_detach_parent_agent_ix(
+        &outsider.pubkey(),
+        &child,
+        &holder.pubkey(),
+        Some(&derive_token22_ata(&outsider.pubkey(), &unrelated)),
+    );
+    let err = send(&mut svm, &outsider, ix).expect_err("Outsider should fail");
+    assert_error(&err, "InvalidAuthority", 6001);
+
+    // Parent holder detaches
+    let ix = build_detach_parent_agent_ix(
+        &parent_holder.pubkey(),
+        &child,
+        &holder.pubkey(),
+        Some(&derive_token22_ata(&parent_holder.pubkey(), &parent)),
+    );
+    send(&mut svm, &parent_holder, ix).expect("Parent holder detach should succeed");
+    assert!(svm
+        .get_account(&agent_parent)
+        .is_none_or(|a| a.lamports == 0));
+
+    // Child holder detaches
+    send_tx(&mut svm, &[&holder, &parent_holder], &[attach]).unwrap();
+    let ix = build_detach_parent_agent_ix(
+        &holder.pubkey(),
+        &child,
+        &holder.pubkey(),
+        Some(&derive_token22_ata(&holder.pubkey(), &child)),
+    );
+    send(&mut svm, &holder, ix).expect("Child holder detach should succeed");
+    assert!(svm
+        .get_account(&agent_parent)
+        .is_none_or(|a| a.lamports == 0));
+
+    println!("✅ test_detach_parent_agent passed");
+}
+
+/// Test that anyone can detach once the child has left the linking holder
+#[test]
+fn test_detach_parent_agent_stale() {
+    let mut svm = setup_litesvm();
+    let holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let parent_holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let anyone = create_funded_keypair(&mut svm, 10_000_000_000);
+    let child = setup_agent(&mut svm, &holder.pubkey(), 1, 0).0;
+    let parent = setup_agent(&mut svm, &parent_holder.pubkey(), 2, 0).0;
+
+    let ix = build_set_parent_agent_ix(&holder.pubkey(), &child, &parent_holder.pubkey(), &parent);
+    send_tx(&mut svm, &[&holder, &parent_holder], &[ix]).unwrap();
+
+    let ix = build_detach_parent_agent_ix(&anyone.pubkey(), &child, &holder.pubkey(), None);
+    let err = send(&mut svm, &anyone, ix.clone()).expect_err("Live link should not close");
+    assert_error(&err, "InvalidAuthority", 6001);
+
+    // Child transferred away: the link is stale
+    let ata = derive_token22_ata(&holder.pubkey(), &child);
+    create_mock_token22_ata(&mut svm, &ata, &child, &holder.pubkey(), 0);
+    send(&mut svm, &anyone, ix).expect("Stale link should close");
+    let (agent_parent, _) = derive_agent_parent_pda(&child);
+    assert!(svm
+        .get_account(&agent_parent)
+        .is_none_or(|a| a.lamports == 0));
+
+    println!("✅ test_detach_parent_agent_stale passed");
+}
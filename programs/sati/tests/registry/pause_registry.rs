@@ -0,0 +1,560 @@
+//! Tests for pause_registry / unpause_registry instructions
+
+use light_sdk::instruction::{account_meta::CompressedAccountMeta, ValidityProof};
+use litesvm::LiteSVM;
+use solana_sdk::{
+    account::Account, instruction::AccountMeta, pubkey::Pubkey, signature::Keypair, signer::Signer,
+};
+
+use crate::common::{
+    accounts::{
+        compute_anchor_account_discriminator, create_funded_keypair, derive_token22_ata,
+        setup_agent, setup_registry,
+    },
+    instructions::{
+        build_claim_agent_handle_ix, build_close_compressed_attestation_ix,
+        build_link_evm_address_ix, build_link_external_identity_ix, build_link_sns_domain_ix,
+        build_pause_registry_ix, build_register_agents_batch_ix, build_retire_agent_ix,
+        build_set_agent_services_ix, build_set_agent_status_ix, build_set_parent_agent_ix,
+        build_set_successor_ix, build_set_timelock_delay_ix, build_sync_agent_authority_ix,
+        build_unpause_registry_ix, build_update_agent_metadata_ix,
+        build_update_compressed_attestation_ix, build_update_regular_attestations_batch_ix,
+        AgentStatus, CloseParams, EvmLinkFormat, ExternalIdentityProof, RegisterAgentParams,
+        SignatureMode, StorageType, UpdateAgentMetadataParams, UpdateParams,
+        UpdateRegularBatchParams,
+    },
+    setup::{
+        assert_error, derive_agent_handle_pda, derive_agent_record_pda, derive_registry_config_pda,
+        derive_schema_config_pda, send, send_tx, setup_litesvm, SATI_PROGRAM_ID,
+    },
+};
+
+const PAUSE_REGISTRATION: u8 = 1 << 0;
+const PAUSE_COMPRESSED_ATTESTATIONS: u8 = 1 << 1;
+const PAUSE_REGULAR_ATTESTATIONS: u8 = 1 << 2;
+const PAUSE_CLOSES: u8 = 1 << 3;
+const PAUSE_AGENT_UPDATES: u8 = 1 << 4;
+
+/// Schema name for the mock SchemaConfig layout
+const SCHEMA_NAME: &str = "Feedback";
+
+/// Helper to create a registry with `flags` paused and an agent held by `holder`
+fn setup_paused_agent(svm: &mut LiteSVM, holder: &Keypair, flags: u8) -> (Pubkey, Pubkey) {
+    let authority = create_funded_keypair(svm, 10_000_000_000);
+    let (registry_config, _) = setup_registry(svm, &authority.pubkey());
+    let ix = build_pause_registry_ix(&authority.pubkey(), &registry_config, flags);
+    send(svm, &authority, ix).expect("Pause should succeed");
+
+    setup_agent(svm, &holder.pubkey(), 1, 0)
+}
+
+/// Helper to create a SchemaConfig for `sas_schema`
+fn create_schema_config(
+    svm: &mut LiteSVM,
+    sas_schema: &Pubkey,
+    signature_mode: SignatureMode,
+    storage_type: StorageType,
+) -> Pubkey {
+    let (schema_config, bump) = derive_schema_config_pda(sas_schema);
+    let mut data = compute_anchor_account_discriminator("SchemaConfig").to_vec();
+    data.extend_from_slice(sas_schema.as_ref());
+    data.push(signature_mode as u8);
+    data.push(storage_type as u8);
+    data.push(0); // delegation_schema = None
+    data.push(1); // closeable
+    data.extend_from_slice(&(SCHEMA_NAME.len() as u32).to_le_bytes());
+    data.extend_from_slice(SCHEMA_NAME.as_bytes());
+    data.push(bump);
+    data.push(0); // min_verification_level
+    svm.set_account(
+        schema_config,
+        Account {
+            lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+            data,
+            owner: SATI_PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+    schema_config
+}
+
+fn stored_paused(svm: &LiteSVM, registry_config: &Pubkey) -> u8 {
+    svm.get_account(registry_config).unwrap().data[130]
+}
+
+/// Test that flags are set and cleared independently
+#[test]
+fn test_pause_and_unpause() {
+    let mut svm = setup_litesvm();
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (registry_config, _) = setup_registry(&mut svm, &authority.pubkey());
+
+    let ix = build_pause_registry_ix(
+        &authority.pubkey(),
+        &registry_config,
+        PAUSE_REGISTRATION | PAUSE_CLOSES,
+    );
+    send(&mut svm, &authority, ix).expect("Pause should succeed");
+    assert_eq!(
+        stored_paused(&svm, &registry_config),
+        PAUSE_REGISTRATION | PAUSE_CLOSES
+    );
+
+    let ix = build_pause_registry_ix(
+        &authority.pubkey(),
+        &registry_config,
+        PAUSE_COMPRESSED_ATTESTATIONS,
+    );
+    send(&mut svm, &authority, ix).expect("Pause should succeed");
+    assert_eq!(
+        stored_paused(&svm, &registry_config),
+        PAUSE_REGISTRATION | PAUSE_COMPRESSED_ATTESTATIONS | PAUSE_CLOSES
+    );
+
+    let ix = build_unpause_registry_ix(
+        &authority.pubkey(),
+        &registry_config,
+        PAUSE_REGISTRATION | PAUSE_REGULAR_ATTESTATIONS,
+    );
+    send(&mut svm, &authority, ix).expect("Unpause should succeed");
+    assert_eq!(
+        stored_paused(&svm, &registry_config),
+        PAUSE_COMPRESSED_ATTESTATIONS | PAUSE_CLOSES
+    );
+
+    println!("✅ test_pause_and_unpause passed");
+}
+
+/// Test that pausing still works while a governance timelock is active
+#[test]
+fn test_pause_not_timelocked() {
+    let mut svm = setup_litesvm();
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (registry_config, _) = setup_registry(&mut svm, &authority.pubkey());
+
+    let ix = build_set_timelock_delay_ix(&authority.pubkey(), &registry_config, 24 * 60 * 60);
+    send(&mut svm, &authority, ix).expect("Enabling timelock should succeed");
+
+    let ix = build_pause_registry_ix(&authority.pubkey(), &registry_config, PAUSE_REGISTRATION);
+    send(&mut svm, &authority, ix).expect("Pause should not require the timelock");
+    assert_eq!(stored_paused(&svm, &registry_config), PAUSE_REGISTRATION);
+
+    println!("✅ test_pause_not_timelocked passed");
+}
+
+/// Test that only the authority can pause or unpause
+#[test]
+fn test_pause_wrong_signer() {
+    let mut svm = setup_litesvm();
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let attacker = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (registry_config, _) = setup_registry(&mut svm, &authority.pubkey());
+
+    let ix = build_pause_registry_ix(&attacker.pubkey(), &registry_config, PAUSE_CLOSES);
+    let err = send(&mut svm, &attacker, ix).expect_err("Non-authority pause should fail");
+    assert_error(&err, "InvalidAuthority", 6001);
+
+    let ix = build_pause_registry_ix(&authority.pubkey(), &registry_config, PAUSE_CLOSES);
+    send(&mut svm, &authority, ix).expect("Pause should succeed");
+
+    let ix = build_unpause_registry_ix(&attacker.pubkey(), &registry_config, PAUSE_CLOSES);
+    let err = send(&mut svm, &attacker, ix).expect_err("Non-authority unpause should fail");
+    assert_error(&err, "InvalidAuthority", 6001);
+    assert_eq!(stored_paused(&svm, &registry_config), PAUSE_CLOSES);
+
+    println!("✅ test_pause_wrong_signer passed");
+}
+
+/// Test that zero and undefined flag bits are rejected
+#[test]
+fn test_pause_invalid_flags() {
+    let mut svm = setup_litesvm();
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (registry_config, _) = setup_registry(&mut svm, &authority.pubkey());
+
+    let ix = build_pause_registry_ix(&authority.pubkey(), &registry_config, 0);
+    let err = send(&mut svm, &authority, ix).expect_err("Zero flags should fail");
+    assert_error(&err, "InvalidPauseFlags", 6069);
+
+    let ix = build_pause_registry_ix(&authority.pubkey(), &registry_config, 1 << 5);
+    let err = send(&mut svm, &authority, ix).expect_err("Undefined flag should fail");
+    assert_error(&err, "InvalidPauseFlags", 6069);
+
+    let ix = build_unpause_registry_ix(&authority.pubkey(), &registry_config, 0x80);
+    let err = send(&mut svm, &authority, ix).expect_err("Undefined flag should fail");
+    assert_error(&err, "InvalidPauseFlags", 6069);
+
+    println!("✅ test_pause_invalid_flags passed");
+}
+
+/// Test that registration is rejected while PAUSE_REGISTRATION is set
+#[test]
+fn test_registration_paused() {
+    let mut svm = setup_litesvm();
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (registry_config, group_mint) = setup_registry(&mut svm, &authority.pubkey());
+
+    let ix = build_pause_registry_ix(&authority.pubkey(), &registry_config, PAUSE_REGISTRATION);
+    send(&mut svm, &authority, ix).expect("Pause should succeed");
+
+    let agent_mint = Keypair::new();
+    let ix = build_register_agents_batch_ix(
+        &authority.pubkey(),
+        &registry_config,
+        &group_mint,
+        0,
+        vec![(
+            authority.pubkey(),
+            agent_mint.pubkey(),
+            RegisterAgentParams {
+                name: "Agent".to_string(),
+                symbol: String::new(),
+                uri: "https://example.com/agent.json".to_string(),
+                additional_metadata: None,
+                non_transferable: false,
+            },
+        )],
+    );
+    let err = send_tx(&mut svm, &[&authority, &agent_mint], &[ix])
+        .expect_err("Registration should fail while paused");
+    assert_error(&err, "RegistryPaused", 6068);
+
+    println!("✅ test_registration_paused passed");
+}
+
+/// Test that compressed attestation writes are rejected while PAUSE_COMPRESSED_ATTESTATIONS is set
+#[test]
+fn test_compressed_attestations_paused() {
+    let mut svm = setup_litesvm();
+    let payer = create_funded_keypair(&mut svm, 10_000_000_000);
+    setup_paused_agent(&mut svm, &payer, PAUSE_COMPRESSED_ATTESTATIONS);
+    let schema_config = create_schema_config(
+        &mut svm,
+        &Pubkey::new_unique(),
+        SignatureMode::CounterpartySigned,
+        StorageType::Compressed,
+    );
+
+    let params = UpdateParams {
+        current_data: vec![],
+        num_signatures: 1,
+        signature1: [0u8; 64],
+        signature2: [0u8; 64],
+        revision: 0,
+        new_data: vec![],
+        proof: ValidityProof::default(),
+        account_meta: CompressedAccountMeta::default(),
+    };
+    let ix =
+        build_update_compressed_attestation_ix(&payer.pubkey(), &schema_config, params, vec![]);
+    let err = send(&mut svm, &payer, ix).expect_err("Amendment should fail while paused");
+    assert_error(&err, "RegistryPaused", 6068);
+
+    println!("✅ test_compressed_attestations_paused passed");
+}
+
+/// Test that batch score writes are rejected while PAUSE_REGULAR_ATTESTATIONS is set
+#[test]
+fn test_regular_attestations_batch_paused() {
+    let mut svm = setup_litesvm();
+    let payer = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (agent_mint, _) = setup_paused_agent(&mut svm, &payer, PAUSE_REGULAR_ATTESTATIONS);
+    let sas_schema = Pubkey::new_unique();
+    let schema_config = create_schema_config(
+        &mut svm,
+        &sas_schema,
+        SignatureMode::CounterpartySigned,
+        StorageType::Regular,
+    );
+
+    let params = UpdateRegularBatchParams {
+        entries: vec![vec![]],
+        expiry: 0,
+    };
+    let ix = build_update_regular_attestations_batch_ix(
+        &payer.pubkey(),
+        &schema_config,
+        &Pubkey::new_unique(),
+        &sas_schema,
+        params,
+        vec![
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(derive_agent_record_pda(&agent_mint).0, false),
+        ],
+    );
+    let err = send(&mut svm, &payer, ix).expect_err("Batch should fail while paused");
+    assert_error(&err, "RegistryPaused", 6068);
+
+    println!("✅ test_regular_attestations_batch_paused passed");
+}
+
+/// Test that closes are rejected while PAUSE_CLOSES is set
+#[test]
+fn test_closes_paused() {
+    let mut svm = setup_litesvm();
+    let signer = create_funded_keypair(&mut svm, 10_000_000_000);
+    setup_paused_agent(&mut svm, &signer, PAUSE_CLOSES);
+    let schema_config = create_schema_config(
+        &mut svm,
+        &Pubkey::new_unique(),
+        SignatureMode::CounterpartySigned,
+        StorageType::Compressed,
+    );
+
+    let params = CloseParams {
+        current_data: vec![],
+        num_signatures: 1,
+        signature1: [0u8; 64],
+        signature2: [0u8; 64],
+        address: Pubkey::new_unique(),
+        proof: ValidityProof::default(),
+        account_meta: CompressedAccountMeta::default(),
+        revision: None,
+    };
+    let ix = build_close_compressed_attestation_ix(
+        &signer.pubkey(),
+        &schema_config,
+        None,
+        params,
+        vec![],
+    );
+    let err = send(&mut svm, &signer, ix).expect_err("Close should fail while paused");
+    assert_error(&err, "RegistryPaused", 6068);
+
+    println!("✅ test_closes_paused passed");
+}
+
+/// Test that metadata updates are rejected while PAUSE_AGENT_UPDATES is set,
+/// and go through again once it is cleared
+#[test]
+fn test_update_agent_metadata_paused() {
+    let mut svm = setup_litesvm();
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (registry_config, _) = setup_registry(&mut svm, &authority.pubkey());
+    let agent_mint = setup_agent(&mut svm, &holder.pubkey(), 1, 0).0;
+    let ata = derive_token22_ata(&holder.pubkey(), &agent_mint);
+
+    let ix = build_pause_registry_ix(&authority.pubkey(), &registry_config, PAUSE_AGENT_UPDATES);
+    send(&mut svm, &authority, ix).expect("Pause should succeed");
+
+    let params = UpdateAgentMetadataParams {
+        name: Some("Renamed Agent".to_string()),
+        uri: None,
+        additional_metadata: vec![],
+        remove_keys: vec![],
+    };
+    let ix = build_update_agent_metadata_ix(
+        &holder.pubkey(),
+        &registry_config,
+        &agent_mint,
+        &ata,
+        params.clone(),
+    );
+    let err = send(&mut svm, &holder, ix).expect_err("Update should fail while paused");
+    assert_error(&err, "RegistryPaused", 6068);
+
+    let ix = build_unpause_registry_ix(&authority.pubkey(), &registry_config, PAUSE_AGENT_UPDATES);
+    send(&mut svm, &authority, ix).expect("Unpause should succeed");
+
+    let ix = build_update_agent_metadata_ix(
+        &holder.pubkey(),
+        &registry_config,
+        &agent_mint,
+        &ata,
+        params,
+    );
+    send(&mut svm, &holder, ix).expect("Update should succeed once unpaused");
+
+    println!("✅ test_update_agent_metadata_paused passed");
+}
+
+/// Test that retiring is rejected while PAUSE_AGENT_UPDATES is set
+#[test]
+fn test_retire_agent_paused() {
+    let mut svm = setup_litesvm();
+    let holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (agent_mint, ata) = setup_paused_agent(&mut svm, &holder, PAUSE_AGENT_UPDATES);
+
+    let ix = build_retire_agent_ix(&holder.pubkey(), &agent_mint, &ata, 1);
+    let err = send(&mut svm, &holder, ix).expect_err("Retire should fail while paused");
+    assert_error(&err, "RegistryPaused", 6068);
+
+    println!("✅ test_retire_agent_paused passed");
+}
+
+/// Test that handle claims are rejected while PAUSE_AGENT_UPDATES is set
+#[test]
+fn test_claim_agent_handle_paused() {
+    let mut svm = setup_litesvm();
+    let holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (agent_mint, ata) = setup_paused_agent(&mut svm, &holder, PAUSE_AGENT_UPDATES);
+
+    let (agent_handle, _) = derive_agent_handle_pda("agent");
+    let ix =
+        build_claim_agent_handle_ix(&holder.pubkey(), &agent_mint, &ata, &agent_handle, "agent");
+    let err = send(&mut svm, &holder, ix).expect_err("Claim should fail while paused");
+    assert_error(&err, "RegistryPaused", 6068);
+
+    println!("✅ test_claim_agent_handle_paused passed");
+}
+
+/// Test that parent links are rejected while PAUSE_AGENT_UPDATES is set
+#[test]
+fn test_set_parent_agent_paused() {
+    let mut svm = setup_litesvm();
+    let holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (agent_mint, _) = setup_paused_agent(&mut svm, &holder, PAUSE_AGENT_UPDATES);
+    let parent_mint = setup_agent(&mut svm, &holder.pubkey(), 2, 0).0;
+
+    let ix = build_set_parent_agent_ix(
+        &holder.pubkey(),
+        &agent_mint,
+        &holder.pubkey(),
+        &parent_mint,
+    );
+    let err = send(&mut svm, &holder, ix).expect_err("Parent link should fail while paused");
+    assert_error(&err, "RegistryPaused", 6068);
+
+    println!("✅ test_set_parent_agent_paused passed");
+}
+
+/// Test that EVM links are rejected while PAUSE_AGENT_UPDATES is set
+///
+/// The pause is checked during account validation, before the signature.
+#[test]
+fn test_link_evm_address_paused() {
+    let mut svm = setup_litesvm();
+    let holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (agent_mint, ata) = setup_paused_agent(&mut svm, &holder, PAUSE_AGENT_UPDATES);
+
+    let ix = build_link_evm_address_ix(
+        &holder.pubkey(),
+        &agent_mint,
+        &ata,
+        [0x11; 20],
+        "eip155:1".to_string(),
+        [0u8; 64],
+        0,
+        EvmLinkFormat::Raw,
+        None,
+    );
+    let err = send(&mut svm, &holder, ix).expect_err("Link should fail while paused");
+    assert_error(&err, "RegistryPaused", 6068);
+
+    println!("✅ test_link_evm_address_paused passed");
+}
+
+/// Test that external identity links are rejected while PAUSE_AGENT_UPDATES is set
+///
+/// The pause is checked during account validation, before the proof.
+#[test]
+fn test_link_external_identity_paused() {
+    let mut svm = setup_litesvm();
+    let holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (agent_mint, ata) = setup_paused_agent(&mut svm, &holder, PAUSE_AGENT_UPDATES);
+
+    let ix = build_link_external_identity_ix(
+        &holder.pubkey(),
+        &agent_mint,
+        &ata,
+        "sui:mainnet".to_string(),
+        ExternalIdentityProof::SuiEd25519 {
+            public_key: [0x22; 32],
+        },
+        None,
+    );
+    let err = send(&mut svm, &holder, ix).expect_err("Link should fail while paused");
+    assert_error(&err, "RegistryPaused", 6068);
+
+    println!("✅ test_link_external_identity_paused passed");
+}
+
+/// Test that service updates are rejected while PAUSE_AGENT_UPDATES is set
+#[test]
+fn test_set_agent_services_paused() {
+    let mut svm = setup_litesvm();
+    let holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (agent_mint, ata) = setup_paused_agent(&mut svm, &holder, PAUSE_AGENT_UPDATES);
+
+    let ix = build_set_agent_services_ix(&holder.pubkey(), &agent_mint, &ata, vec![]);
+    let err = send(&mut svm, &holder, ix).expect_err("Services should fail while paused");
+    assert_error(&err, "RegistryPaused", 6068);
+
+    println!("✅ test_set_agent_services_paused passed");
+}
+
+/// Test that holder status changes are rejected while PAUSE_AGENT_UPDATES is set
+#[test]
+fn test_set_agent_status_paused() {
+    let mut svm = setup_litesvm();
+    let holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (agent_mint, ata) = setup_paused_agent(&mut svm, &holder, PAUSE_AGENT_UPDATES);
+
+    let ix =
+        build_set_agent_status_ix(&holder.pubkey(), &agent_mint, &ata, AgentStatus::Deprecated);
+    let err = send(&mut svm, &holder, ix).expect_err("Status change should fail while paused");
+    assert_error(&err, "RegistryPaused", 6068);
+
+    println!("✅ test_set_agent_status_paused passed");
+}
+
+/// Test that successor links are rejected while PAUSE_AGENT_UPDATES is set
+#[test]
+fn test_set_successor_paused() {
+    let mut svm = setup_litesvm();
+    let holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (agent_mint, _) = setup_paused_agent(&mut svm, &holder, PAUSE_AGENT_UPDATES);
+    let successor_mint = setup_agent(&mut svm, &holder.pubkey(), 2, 0).0;
+
+    let ix = build_set_successor_ix(
+        &holder.pubkey(),
+        &agent_mint,
+        &holder.pubkey(),
+        &successor_mint,
+    );
+    let err = send(&mut svm, &holder, ix).expect_err("Successor should fail while paused");
+    assert_error(&err, "RegistryPaused", 6068);
+
+    println!("✅ test_set_successor_paused passed");
+}
+
+/// Test that SNS links are rejected while PAUSE_AGENT_UPDATES is set
+///
+/// The pause is checked before the name registry account.
+#[test]
+fn test_link_sns_domain_paused() {
+    let mut svm = setup_litesvm();
+    let holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (agent_mint, _) = setup_paused_agent(&mut svm, &holder, PAUSE_AGENT_UPDATES);
+
+    let ix = build_link_sns_domain_ix(
+        &holder.pubkey(),
+        &agent_mint,
+        &Pubkey::new_unique(),
+        "agent".to_string(),
+    );
+    let err = send(&mut svm, &holder, ix).expect_err("Link should fail while paused");
+    assert_error(&err, "RegistryPaused", 6068);
+
+    println!("✅ test_link_sns_domain_paused passed");
+}
+
+/// Test that metadata authority syncs are rejected while PAUSE_AGENT_UPDATES is set
+#[test]
+fn test_sync_agent_authority_paused() {
+    let mut svm = setup_litesvm();
+    let holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (agent_mint, ata) = setup_paused_agent(&mut svm, &holder, PAUSE_AGENT_UPDATES);
+    let (registry_config, _) = derive_registry_config_pda();
+
+    let ix =
+        build_sync_agent_authority_ix(&holder.pubkey(), None, &registry_config, &agent_mint, &ata);
+    let err = send(&mut svm, &holder, ix).expect_err("Sync should fail while paused");
+    assert_error(&err, "RegistryPaused", 6068);
+
+    println!("✅ test_sync_agent_authority_paused passed");
+}
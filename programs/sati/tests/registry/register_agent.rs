@@ -16,8 +16,8 @@ use solana_sdk::{
 
 use crate::common::instructions::{accounts, build_initialize_ix, instruction};
 use crate::common::setup::{
-    derive_registry_config_pda, setup_litesvm, ATA_PROGRAM_ID, SATI_PROGRAM_ID,
-    TOKEN_2022_PROGRAM_ID,
+    derive_agent_record_pda, derive_registry_config_pda, derive_treasury_pda, setup_litesvm,
+    ATA_PROGRAM_ID, SATI_PROGRAM_ID, TOKEN_2022_PROGRAM_ID,
 };
 
 use sati::state::MetadataEntry;
@@ -43,6 +43,7 @@ fn build_register_agent_ix(
     agent_mint: &Pubkey,
     agent_token_account: &Pubkey,
     agent_index: &Pubkey,
+    agent_record: &Pubkey,
     name: String,
     symbol: String,
     uri: String,
@@ -64,9 +65,15 @@ fn build_register_agent_ix(
         agent_mint: *agent_mint,
         agent_token_account: *agent_token_account,
         agent_index: *agent_index,
+        agent_record: *agent_record,
         token_2022_program: TOKEN_2022_PROGRAM_ID,
         associated_token_program: ATA_PROGRAM_ID,
         system_program: SYSTEM_PROGRAM_ID,
+        treasury: Some(derive_treasury_pda().0),
+        fee_mint: None,
+        payer_fee_account: None,
+        treasury_fee_account: None,
+        fee_token_program: None,
     };
 
     Instruction {
@@ -128,6 +135,7 @@ fn test_register_agent_name_too_long() {
 
     // First agent gets member_number 1
     let agent_index = derive_agent_index_pda(1);
+    let (agent_record, _) = derive_agent_record_pda(&agent_mint.pubkey());
 
     let ix = build_register_agent_ix(
         &authority.pubkey(),
@@ -137,6 +145,7 @@ fn test_register_agent_name_too_long() {
         &agent_mint.pubkey(),
         &agent_ata,
         &agent_index,
+        &agent_record,
         long_name,
         "SYM".to_string(),
         "https://example.com".to_string(),
@@ -180,6 +189,7 @@ fn test_register_agent_symbol_too_long() {
 
     // First agent gets member_number 1
     let agent_index = derive_agent_index_pda(1);
+    let (agent_record, _) = derive_agent_record_pda(&agent_mint.pubkey());
 
     let ix = build_register_agent_ix(
         &authority.pubkey(),
@@ -189,6 +199,7 @@ fn test_register_agent_symbol_too_long() {
         &agent_mint.pubkey(),
         &agent_ata,
         &agent_index,
+        &agent_record,
         "TestAgent".to_string(),
         long_symbol,
         "https://example.com".to_string(),
@@ -232,6 +243,7 @@ fn test_register_agent_uri_too_long() {
 
     // First agent gets member_number 1
     let agent_index = derive_agent_index_pda(1);
+    let (agent_record, _) = derive_agent_record_pda(&agent_mint.pubkey());
 
     let ix = build_register_agent_ix(
         &authority.pubkey(),
@@ -241,6 +253,7 @@ fn test_register_agent_uri_too_long() {
         &agent_mint.pubkey(),
         &agent_ata,
         &agent_index,
+        &agent_record,
         "TestAgent".to_string(),
         "SYM".to_string(),
         long_uri,
@@ -289,6 +302,7 @@ fn test_register_agent_too_many_metadata_entries() {
 
     // First agent gets member_number 1
     let agent_index = derive_agent_index_pda(1);
+    let (agent_record, _) = derive_agent_record_pda(&agent_mint.pubkey());
 
     let ix = build_register_agent_ix(
         &authority.pubkey(),
@@ -298,6 +312,7 @@ fn test_register_agent_too_many_metadata_entries() {
         &agent_mint.pubkey(),
         &agent_ata,
         &agent_index,
+        &agent_record,
         "TestAgent".to_string(),
         "SYM".to_string(),
         "https://example.com".to_string(),
@@ -347,6 +362,7 @@ fn test_register_agent_metadata_key_too_long() {
 
     // First agent gets member_number 1
     let agent_index = derive_agent_index_pda(1);
+    let (agent_record, _) = derive_agent_record_pda(&agent_mint.pubkey());
 
     let ix = build_register_agent_ix(
         &authority.pubkey(),
@@ -356,6 +372,7 @@ fn test_register_agent_metadata_key_too_long() {
         &agent_mint.pubkey(),
         &agent_ata,
         &agent_index,
+        &agent_record,
         "TestAgent".to_string(),
         "SYM".to_string(),
         "https://example.com".to_string(),
@@ -402,6 +419,7 @@ fn test_register_agent_metadata_value_too_long() {
 
     // First agent gets member_number 1
     let agent_index = derive_agent_index_pda(1);
+    let (agent_record, _) = derive_agent_record_pda(&agent_mint.pubkey());
 
     let ix = build_register_agent_ix(
         &authority.pubkey(),
@@ -411,6 +429,7 @@ fn test_register_agent_metadata_value_too_long() {
         &agent_mint.pubkey(),
         &agent_ata,
         &agent_index,
+        &agent_record,
         "TestAgent".to_string(),
         "SYM".to_string(),
         "https://example.com".to_string(),
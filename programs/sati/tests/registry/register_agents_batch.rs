@@ -0,0 +1,192 @@
+//! Tests for register_agents_batch instruction
+//!
+//! As with register_agent, full mint creation needs Token-2022 TokenGroup setup,
+//! so these tests focus on batch validation:
+//! - Batch size limits
+//! - Remaining account layout and PDA derivation
+//! - Per-agent metadata validation failing the whole batch
+
+use litesvm::LiteSVM;
+use solana_sdk::{
+    instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    transaction::Transaction,
+};
+
+use crate::common::accounts::create_mock_group_mint;
+use crate::common::instructions::{
+    build_initialize_ix, build_register_agents_batch_ix, RegisterAgentParams,
+};
+use crate::common::setup::{derive_registry_config_pda, send_tx, setup_litesvm};
+
+/// Initialize registry for testing using proper Token-2022 mock
+fn initialize_test_registry(svm: &mut LiteSVM, authority: &Keypair) -> (Pubkey, Pubkey) {
+    let (registry_pda, _bump) = derive_registry_config_pda();
+
+    let group_mint = Keypair::new();
+    create_mock_group_mint(svm, &group_mint, &registry_pda);
+
+    let init_ix = build_initialize_ix(&authority.pubkey(), &registry_pda, &group_mint.pubkey());
+    let tx = Transaction::new_signed_with_payer(
+        &[init_ix],
+        Some(&authority.pubkey()),
+        &[authority],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx)
+        .expect("Registry init should succeed");
+
+    (registry_pda, group_mint.pubkey())
+}
+
+fn agent_params(name: &str) -> RegisterAgentParams {
+    RegisterAgentParams {
+        name: name.to_string(),
+        symbol: String::new(),
+        uri: "https://example.com/agent.json".to_string(),
+        additional_metadata: None,
+        non_transferable: false,
+    }
+}
+
+/// Send a batch registration signed by the payer and every new mint
+fn send_batch(
+    svm: &mut LiteSVM,
+    payer: &Keypair,
+    mints: &[Keypair],
+    ix: Instruction,
+) -> Result<(), String> {
+    let mut signers: Vec<&Keypair> = vec![payer];
+    signers.extend(mints.iter());
+    send_tx(svm, &signers, &[ix])
+}
+
+#[test]
+fn test_register_agents_batch_empty() {
+    let mut svm = setup_litesvm();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+    let (registry_pda, group_mint) = initialize_test_registry(&mut svm, &authority);
+
+    let ix =
+        build_register_agents_batch_ix(&authority.pubkey(), &registry_pda, &group_mint, 1, vec![]);
+
+    let err = send_batch(&mut svm, &authority, &[], ix).expect_err("Empty batch should fail");
+    assert!(
+        err.contains("InvalidBatchSize") || err.contains("6057"),
+        "Expected InvalidBatchSize error (6057), got: {}",
+        err
+    );
+
+    println!("✅ test_register_agents_batch_empty passed");
+}
+
+#[test]
+fn test_register_agents_batch_too_many_agents() {
+    let mut svm = setup_litesvm();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+    let (registry_pda, group_mint) = initialize_test_registry(&mut svm, &authority);
+
+    let mints: Vec<Keypair> = (0..6).map(|_| Keypair::new()).collect();
+    let agents = mints
+        .iter()
+        .map(|m| (authority.pubkey(), m.pubkey(), agent_params("Agent")))
+        .collect();
+    let ix =
+        build_register_agents_batch_ix(&authority.pubkey(), &registry_pda, &group_mint, 1, agents);
+
+    let result = send_batch(&mut svm, &authority, &mints, ix);
+    assert!(result.is_err(), "Batch above MAX_BATCH_AGENTS should fail");
+
+    println!("✅ test_register_agents_batch_too_many_agents passed");
+}
+
+#[test]
+fn test_register_agents_batch_missing_accounts() {
+    let mut svm = setup_litesvm();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+    let (registry_pda, group_mint) = initialize_test_registry(&mut svm, &authority);
+
+    let mints = [Keypair::new(), Keypair::new()];
+    let agents = mints
+        .iter()
+        .map(|m| (authority.pubkey(), m.pubkey(), agent_params("Agent")))
+        .collect();
+    let mut ix =
+        build_register_agents_batch_ix(&authority.pubkey(), &registry_pda, &group_mint, 1, agents);
+    // Drop the last agent's agent_record
+    ix.accounts.pop();
+
+    let err =
+        send_batch(&mut svm, &authority, &mints, ix).expect_err("Short account list should fail");
+    assert!(
+        err.contains("InvalidBatchAccounts") || err.contains("6058"),
+        "Expected InvalidBatchAccounts error (6058), got: {}",
+        err
+    );
+
+    println!("✅ test_register_agents_batch_missing_accounts passed");
+}
+
+#[test]
+fn test_register_agents_batch_wrong_member_number() {
+    let mut svm = setup_litesvm();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+    let (registry_pda, group_mint) = initialize_test_registry(&mut svm, &authority);
+
+    let mints = [Keypair::new()];
+    // Registry is empty, so the first AgentIndex must be member_number 1
+    let ix = build_register_agents_batch_ix(
+        &authority.pubkey(),
+        &registry_pda,
+        &group_mint,
+        2,
+        vec![(authority.pubkey(), mints[0].pubkey(), agent_params("Agent"))],
+    );
+
+    let err = send_batch(&mut svm, &authority, &mints, ix)
+        .expect_err("Skipped member number should fail");
+    assert!(
+        err.contains("InvalidBatchAccounts") || err.contains("6058"),
+        "Expected InvalidBatchAccounts error (6058), got: {}",
+        err
+    );
+
+    println!("✅ test_register_agents_batch_wrong_member_number passed");
+}
+
+#[test]
+fn test_register_agents_batch_invalid_agent_fails_batch() {
+    let mut svm = setup_litesvm();
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 10_000_000_000).unwrap();
+    let (registry_pda, group_mint) = initialize_test_registry(&mut svm, &authority);
+
+    let mints = [Keypair::new(), Keypair::new()];
+    // Second agent's name exceeds 32 bytes
+    let ix = build_register_agents_batch_ix(
+        &authority.pubkey(),
+        &registry_pda,
+        &group_mint,
+        1,
+        vec![
+            (authority.pubkey(), mints[0].pubkey(), agent_params("Agent")),
+            (
+                authority.pubkey(),
+                mints[1].pubkey(),
+                agent_params(&"A".repeat(33)),
+            ),
+        ],
+    );
+
+    let result = send_batch(&mut svm, &authority, &mints, ix);
+    assert!(result.is_err(), "Invalid agent should fail the whole batch");
+    assert!(
+        svm.get_account(&mints[0].pubkey()).is_none(),
+        "No agent should be created when the batch fails"
+    );
+
+    println!("✅ test_register_agents_batch_invalid_agent_fails_batch passed");
+}
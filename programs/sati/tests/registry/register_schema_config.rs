@@ -67,8 +67,9 @@ fn test_register_schema_config_success() {
 
     let account = schema_account.unwrap();
     // Size: 8 (discriminator) + 32 (sas_schema) + 1 (sig_mode) + 1 (storage_type)
-    //       + 33 (delegation_schema Option<Pubkey>) + 1 (closeable) + 36 (name String) + 1 (bump) = 113 bytes
-    assert_eq!(account.data.len(), 113, "Schema config should be 113 bytes");
+    //       + 33 (delegation_schema Option<Pubkey>) + 1 (closeable) + 36 (name String) + 1 (bump)
+    //       + 1 (min_verification_level) = 114 bytes
+    assert_eq!(account.data.len(), 114, "Schema config should be 114 bytes");
 
     // Verify fields (after 8-byte discriminator)
     let stored_sas_schema = &account.data[8..40];
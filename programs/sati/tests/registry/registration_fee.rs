@@ -0,0 +1,262 @@
+//! Tests for the registration fee and treasury
+//! (set_registration_fee / withdraw_treasury / fee collection in registration)
+
+use litesvm::LiteSVM;
+use solana_sdk::{
+    account::Account, clock::Clock, pubkey::Pubkey, signature::Keypair, signer::Signer,
+};
+
+use crate::common::{
+    accounts::{create_funded_keypair, setup_registry},
+    instructions::{
+        build_execute_governance_action_ix, build_queue_governance_action_ix,
+        build_register_agents_batch_ix, build_set_registration_fee_ix, build_set_timelock_delay_ix,
+        build_withdraw_treasury_ix, GovernanceAction, RegisterAgentParams,
+    },
+    setup::{assert_error, derive_treasury_pda, send, send_tx, setup_litesvm, SATI_PROGRAM_ID},
+};
+
+const FEE: u64 = 10_000_000; // 0.01 SOL
+
+/// Mock a funded, program-owned treasury (as left behind by earlier fee payments)
+fn create_funded_treasury(svm: &mut LiteSVM, fees: u64) -> Pubkey {
+    let (treasury, _) = derive_treasury_pda();
+    let lamports = svm.minimum_balance_for_rent_exemption(0) + fees;
+    svm.set_account(
+        treasury,
+        Account {
+            lamports,
+            data: vec![],
+            owner: SATI_PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+    treasury
+}
+
+/// Read (fee_mint, fee_amount) from the registry account
+fn stored_fee(svm: &LiteSVM, registry_config: &Pubkey) -> (Pubkey, u64) {
+    let account = svm.get_account(registry_config).unwrap();
+    (
+        Pubkey::try_from(&account.data[131..163]).unwrap(),
+        u64::from_le_bytes(account.data[163..171].try_into().unwrap()),
+    )
+}
+
+/// Test setting lamport and SPL fees, and clearing the fee
+#[test]
+fn test_set_registration_fee() {
+    let mut svm = setup_litesvm();
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (registry_config, _) = setup_registry(&mut svm, &authority.pubkey());
+
+    let ix = build_set_registration_fee_ix(&authority.pubkey(), &registry_config, None, FEE);
+    send(&mut svm, &authority, ix).expect("Lamport fee should succeed");
+    assert_eq!(stored_fee(&svm, &registry_config), (Pubkey::default(), FEE));
+
+    let fee_mint = Keypair::new().pubkey();
+    let ix = build_set_registration_fee_ix(
+        &authority.pubkey(),
+        &registry_config,
+        Some(fee_mint),
+        5_000_000,
+    );
+    send(&mut svm, &authority, ix).expect("SPL fee should succeed");
+    assert_eq!(stored_fee(&svm, &registry_config), (fee_mint, 5_000_000));
+
+    let ix = build_set_registration_fee_ix(&authority.pubkey(), &registry_config, None, 0);
+    send(&mut svm, &authority, ix).expect("Clearing the fee should succeed");
+    assert_eq!(stored_fee(&svm, &registry_config), (Pubkey::default(), 0));
+
+    println!("✅ test_set_registration_fee passed");
+}
+
+/// Test that the default pubkey is rejected as a fee mint
+#[test]
+fn test_set_registration_fee_invalid_mint() {
+    let mut svm = setup_litesvm();
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (registry_config, _) = setup_registry(&mut svm, &authority.pubkey());
+
+    let ix = build_set_registration_fee_ix(
+        &authority.pubkey(),
+        &registry_config,
+        Some(Pubkey::default()),
+        FEE,
+    );
+    let err = send(&mut svm, &authority, ix).expect_err("Default mint should fail");
+    assert_error(&err, "InvalidRegistrationFee", 6072);
+
+    println!("✅ test_set_registration_fee_invalid_mint passed");
+}
+
+/// Test that only the authority can change the fee
+#[test]
+fn test_set_registration_fee_wrong_signer() {
+    let mut svm = setup_litesvm();
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let attacker = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (registry_config, _) = setup_registry(&mut svm, &authority.pubkey());
+
+    let ix = build_set_registration_fee_ix(&attacker.pubkey(), &registry_config, None, FEE);
+    let err = send(&mut svm, &attacker, ix).expect_err("Non-authority should fail");
+    assert_error(&err, "InvalidAuthority", 6001);
+
+    println!("✅ test_set_registration_fee_wrong_signer passed");
+}
+
+/// Test that fee changes go through the timelock once it is enabled
+#[test]
+fn test_set_registration_fee_timelocked() {
+    let mut svm = setup_litesvm();
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (registry_config, _) = setup_registry(&mut svm, &authority.pubkey());
+
+    let delay = 24 * 60 * 60;
+    let ix = build_set_timelock_delay_ix(&authority.pubkey(), &registry_config, delay);
+    send(&mut svm, &authority, ix).expect("Enabling timelock should succeed");
+
+    let ix = build_set_registration_fee_ix(&authority.pubkey(), &registry_config, None, FEE);
+    let err = send(&mut svm, &authority, ix).expect_err("Direct fee change should fail");
+    assert_error(&err, "TimelockRequired", 6063);
+
+    let ix = build_queue_governance_action_ix(
+        &authority.pubkey(),
+        &authority.pubkey(),
+        &registry_config,
+        0,
+        GovernanceAction::SetRegistrationFee {
+            fee_mint: None,
+            amount: FEE,
+        },
+    );
+    send(&mut svm, &authority, ix).expect("Queue should succeed");
+
+    let mut clock = svm.get_sysvar::<Clock>();
+    clock.unix_timestamp += delay;
+    svm.set_sysvar(&clock);
+
+    let ix = build_execute_governance_action_ix(
+        &authority.pubkey(),
+        &registry_config,
+        0,
+        &authority.pubkey(),
+        None,
+    );
+    send(&mut svm, &authority, ix).expect("Execute should succeed");
+    assert_eq!(stored_fee(&svm, &registry_config), (Pubkey::default(), FEE));
+
+    println!("✅ test_set_registration_fee_timelocked passed");
+}
+
+/// Test that registration fails without the fee accounts for an SPL fee
+#[test]
+fn test_register_missing_fee_accounts() {
+    let mut svm = setup_litesvm();
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (registry_config, group_mint) = setup_registry(&mut svm, &authority.pubkey());
+
+    let ix = build_set_registration_fee_ix(
+        &authority.pubkey(),
+        &registry_config,
+        Some(Keypair::new().pubkey()),
+        FEE,
+    );
+    send(&mut svm, &authority, ix).expect("SPL fee should succeed");
+
+    let agent_mint = Keypair::new();
+    let ix = build_register_agents_batch_ix(
+        &authority.pubkey(),
+        &registry_config,
+        &group_mint,
+        0,
+        vec![(
+            authority.pubkey(),
+            agent_mint.pubkey(),
+            RegisterAgentParams {
+                name: "Agent".to_string(),
+                symbol: String::new(),
+                uri: "https://example.com/agent.json".to_string(),
+                additional_metadata: None,
+                non_transferable: false,
+            },
+        )],
+    );
+    let err = send_tx(&mut svm, &[&authority, &agent_mint], &[ix])
+        .expect_err("Registration without fee token accounts should fail");
+    assert_error(&err, "MissingFeeAccounts", 6070);
+
+    println!("✅ test_register_missing_fee_accounts passed");
+}
+
+/// Test withdrawing lamport fees while keeping the treasury rent-exempt
+#[test]
+fn test_withdraw_treasury_lamports() {
+    let mut svm = setup_litesvm();
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (registry_config, _) = setup_registry(&mut svm, &authority.pubkey());
+    let treasury = create_funded_treasury(&mut svm, 3 * FEE);
+    let destination = Keypair::new().pubkey();
+
+    let ix =
+        build_withdraw_treasury_ix(&authority.pubkey(), &registry_config, &destination, 2 * FEE);
+    send(&mut svm, &authority, ix).expect("Withdraw should succeed");
+    assert_eq!(svm.get_account(&destination).unwrap().lamports, 2 * FEE);
+
+    // Only the remaining fees are withdrawable, not the rent reserve
+    let ix =
+        build_withdraw_treasury_ix(&authority.pubkey(), &registry_config, &destination, FEE + 1);
+    let err = send(&mut svm, &authority, ix).expect_err("Over-withdraw should fail");
+    assert_error(&err, "InsufficientTreasuryBalance", 6073);
+
+    let ix = build_withdraw_treasury_ix(&authority.pubkey(), &registry_config, &destination, FEE);
+    send(&mut svm, &authority, ix).expect("Withdrawing the rest should succeed");
+    assert_eq!(
+        svm.get_account(&treasury).unwrap().lamports,
+        svm.minimum_balance_for_rent_exemption(0)
+    );
+
+    println!("✅ test_withdraw_treasury_lamports passed");
+}
+
+/// Test that withdrawing from a treasury that never received fees fails
+#[test]
+fn test_withdraw_treasury_empty() {
+    let mut svm = setup_litesvm();
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (registry_config, _) = setup_registry(&mut svm, &authority.pubkey());
+
+    let ix = build_withdraw_treasury_ix(
+        &authority.pubkey(),
+        &registry_config,
+        &authority.pubkey(),
+        1,
+    );
+    let err = send(&mut svm, &authority, ix).expect_err("Empty treasury should fail");
+    assert_error(&err, "InsufficientTreasuryBalance", 6073);
+
+    println!("✅ test_withdraw_treasury_empty passed");
+}
+
+/// Test that only the authority can withdraw
+#[test]
+fn test_withdraw_treasury_wrong_signer() {
+    let mut svm = setup_litesvm();
+    let authority = create_funded_keypair(&mut svm, 10_000_000_000);
+    let attacker = create_funded_keypair(&mut svm, 10_000_000_000);
+    let (registry_config, _) = setup_registry(&mut svm, &authority.pubkey());
+    create_funded_treasury(&mut svm, FEE);
+
+    let ix = build_withdraw_treasury_ix(
+        &attacker.pubkey(),
+        &registry_config,
+        &attacker.pubkey(),
+        FEE,
+    );
+    let err = send(&mut svm, &attacker, ix).expect_err("Non-authority should fail");
+    assert_error(&err, "InvalidAuthority", 6001);
+
+    println!("✅ test_withdraw_treasury_wrong_signer passed");
+}
@@ -0,0 +1,124 @@
+//! Tests for the set_successor instruction
+
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+use crate::common::{
+    accounts::{create_funded_keypair, create_mock_token22_ata, derive_token22_ata, setup_agent},
+    instructions::{build_set_successor_ix, AgentStatus},
+    setup::{
+        assert_error, derive_agent_record_pda, derive_agent_successor_pda, send, send_tx,
+        setup_litesvm,
+    },
+};
+
+/// Test linking two agents held by different holders
+#[test]
+fn test_set_successor_success() {
+    let mut svm = setup_litesvm();
+    let holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let successor_holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let mint = setup_agent(&mut svm, &holder.pubkey(), 1, 0).0;
+    let successor = setup_agent(&mut svm, &successor_holder.pubkey(), 2, 0).0;
+
+    let ix = build_set_successor_ix(
+        &holder.pubkey(),
+        &mint,
+        &successor_holder.pubkey(),
+        &successor,
+    );
+    send_tx(&mut svm, &[&holder, &successor_holder], &[ix]).expect("set_successor should succeed");
+
+    let (agent_successor, bump) = derive_agent_successor_pda(&mint);
+    let account = svm
+        .get_account(&agent_successor)
+        .expect("AgentSuccessor should exist");
+    assert_eq!(account.data.len(), 81);
+    assert_eq!(&account.data[8..40], mint.as_ref(), "mint");
+    assert_eq!(&account.data[40..72], successor.as_ref(), "successor");
+    assert_eq!(account.data[80], bump, "bump");
+
+    // Previous agent is deprecated
+    let record = svm.get_account(&derive_agent_record_pda(&mint).0).unwrap();
+    assert_eq!(record.data[65], AgentStatus::Deprecated as u8);
+
+    println!("✅ test_set_successor_success passed");
+}
+
+/// Test that both holders must sign and hold their NFTs
+#[test]
+fn test_set_successor_requires_both_holders() {
+    let mut svm = setup_litesvm();
+    let holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let attacker = create_funded_keypair(&mut svm, 10_000_000_000);
+    let mint = setup_agent(&mut svm, &holder.pubkey(), 1, 0).0;
+    let victim = setup_agent(&mut svm, &Keypair::new().pubkey(), 2, 0).0;
+
+    // Attacker claims another holder's reputation: has an empty ATA for `mint`
+    let attacker_ata = derive_token22_ata(&attacker.pubkey(), &mint);
+    create_mock_token22_ata(&mut svm, &attacker_ata, &mint, &attacker.pubkey(), 0);
+    let attacker_agent = setup_agent(&mut svm, &attacker.pubkey(), 3, 0).0;
+    let ix = build_set_successor_ix(
+        &attacker.pubkey(),
+        &mint,
+        &attacker.pubkey(),
+        &attacker_agent,
+    );
+    let err = send(&mut svm, &attacker, ix).expect_err("Non-holder of old mint should fail");
+    assert_error(&err, "InvalidAuthority", 6001);
+
+    // Holder cannot point at an agent they don't hold
+    let victim_ata = derive_token22_ata(&holder.pubkey(), &victim);
+    create_mock_token22_ata(&mut svm, &victim_ata, &victim, &holder.pubkey(), 0);
+    let ix = build_set_successor_ix(&holder.pubkey(), &mint, &holder.pubkey(), &victim);
+    let err = send(&mut svm, &holder, ix).expect_err("Non-holder of successor should fail");
+    assert_error(&err, "InvalidAuthority", 6001);
+
+    println!("✅ test_set_successor_requires_both_holders passed");
+}
+
+/// Test that links are one-way, permanent and acyclic
+#[test]
+fn test_set_successor_invalid_links() {
+    let mut svm = setup_litesvm();
+    let holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let a = setup_agent(&mut svm, &holder.pubkey(), 1, 0).0;
+    let b = setup_agent(&mut svm, &holder.pubkey(), 2, 0).0;
+    let c = setup_agent(&mut svm, &holder.pubkey(), 3, 0).0;
+
+    let ix = build_set_successor_ix(&holder.pubkey(), &a, &holder.pubkey(), &a);
+    let err = send(&mut svm, &holder, ix).expect_err("Self link should fail");
+    assert_error(&err, "InvalidSuccessor", 6082);
+
+    let ix = build_set_successor_ix(&holder.pubkey(), &a, &holder.pubkey(), &b);
+    send(&mut svm, &holder, ix).expect("a -> b should succeed");
+
+    // a already has a successor
+    let ix = build_set_successor_ix(&holder.pubkey(), &a, &holder.pubkey(), &c);
+    assert!(send(&mut svm, &holder, ix).is_err(), "Relink should fail");
+
+    // b -> a would close a cycle
+    let ix = build_set_successor_ix(&holder.pubkey(), &b, &holder.pubkey(), &a);
+    let err = send(&mut svm, &holder, ix).expect_err("Cycle should fail");
+    assert_error(&err, "InvalidSuccessor", 6082);
+
+    // Chains extend forward: b -> c
+    let ix = build_set_successor_ix(&holder.pubkey(), &b, &holder.pubkey(), &c);
+    send(&mut svm, &holder, ix).expect("b -> c should succeed");
+
+    println!("✅ test_set_successor_invalid_links passed");
+}
+
+/// Test that suspended agents cannot migrate away from moderation
+#[test]
+fn test_set_successor_rejects_suspended() {
+    let mut svm = setup_litesvm();
+    let holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let suspended = setup_agent(&mut svm, &holder.pubkey(), 1, AgentStatus::Suspended as u8).0;
+    let fresh = setup_agent(&mut svm, &holder.pubkey(), 2, 0).0;
+
+    let ix = build_set_successor_ix(&holder.pubkey(), &suspended, &holder.pubkey(), &fresh);
+    let err = send(&mut svm, &holder, ix).expect_err("Suspended agent should fail");
+    assert_error(&err, "AgentSuspended", 6080);
+
+    println!("✅ test_set_successor_rejects_suspended passed");
+}
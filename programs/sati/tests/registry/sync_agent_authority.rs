@@ -0,0 +1,290 @@
+//! Tests for the sync_agent_authority instruction
+//!
+//! Covers handing TokenMetadata / MetadataPointer authority of legacy agents
+//! (owner-controlled metadata) over to the registry PDA.
+
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer, transaction::Transaction};
+use spl_pod::optional_keys::OptionalNonZeroPubkey;
+use spl_token_2022::{
+    extension::{
+        metadata_pointer::MetadataPointer, BaseStateWithExtensions, BaseStateWithExtensionsMut,
+        StateWithExtensions, StateWithExtensionsMut,
+    },
+    state::Mint,
+};
+
+use crate::common::{
+    accounts::{
+        create_funded_keypair, create_mock_token22_ata, derive_token22_ata, get_agent_metadata,
+        setup_agent_mint, setup_registry,
+    },
+    instructions::build_sync_agent_authority_ix,
+    setup::setup_litesvm,
+};
+
+/// Read the MetadataPointer authority stored on an agent mint
+fn get_pointer_authority(svm: &litesvm::LiteSVM, mint: &Pubkey) -> Option<Pubkey> {
+    let account = svm.get_account(mint).unwrap();
+    let state = StateWithExtensions::<Mint>::unpack(&account.data).unwrap();
+    state
+        .get_extension::<MetadataPointer>()
+        .unwrap()
+        .authority
+        .into()
+}
+
+/// Overwrite the MetadataPointer authority stored on an agent mint
+fn set_pointer_authority(svm: &mut litesvm::LiteSVM, mint: &Pubkey, authority: &Pubkey) {
+    let mut account = svm.get_account(mint).unwrap();
+    let mut state = StateWithExtensionsMut::<Mint>::unpack(&mut account.data).unwrap();
+    state
+        .get_extension_mut::<MetadataPointer>()
+        .unwrap()
+        .authority = OptionalNonZeroPubkey::try_from(Some(*authority)).unwrap();
+    svm.set_account(*mint, account).unwrap();
+}
+
+/// Test that the original owner (still the holder) can sync authority
+#[test]
+fn test_sync_agent_authority_original_owner() {
+    let mut svm = setup_litesvm();
+    let owner = create_funded_keypair(&mut svm, 10_000_000_000);
+    let registry_config = setup_registry(&mut svm, &Pubkey::new_unique()).0;
+    let (agent_mint, ata) = setup_agent_mint(&mut svm, &owner.pubkey(), &owner.pubkey(), &[]);
+
+    let ix = build_sync_agent_authority_ix(
+        &owner.pubkey(),
+        Some(&owner.pubkey()),
+        &registry_config,
+        &agent_mint,
+        &ata,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&owner.pubkey()),
+        &[&owner],
+        svm.latest_blockhash(),
+    );
+
+    let result = svm.send_transaction(tx);
+    assert!(
+        result.is_ok(),
+        "Sync by original owner should succeed: {:?}",
+        result.err()
+    );
+
+    let metadata = get_agent_metadata(&svm, &agent_mint);
+    let update_authority: Option<Pubkey> = metadata.update_authority.into();
+    assert_eq!(update_authority, Some(registry_config));
+    assert_eq!(
+        get_pointer_authority(&svm, &agent_mint),
+        Some(registry_config)
+    );
+
+    println!("✅ test_sync_agent_authority_original_owner passed");
+}
+
+/// Test that a new holder can take over when the previous owner co-signs
+#[test]
+fn test_sync_agent_authority_after_transfer() {
+    let mut svm = setup_litesvm();
+    let previous_owner = create_funded_keypair(&mut svm, 10_000_000_000);
+    let holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let registry_config = setup_registry(&mut svm, &Pubkey::new_unique()).0;
+    let (agent_mint, ata) =
+        setup_agent_mint(&mut svm, &holder.pubkey(), &previous_owner.pubkey(), &[]);
+
+    let ix = build_sync_agent_authority_ix(
+        &holder.pubkey(),
+        Some(&previous_owner.pubkey()),
+        &registry_config,
+        &agent_mint,
+        &ata,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&holder.pubkey()),
+        &[&holder, &previous_owner],
+        svm.latest_blockhash(),
+    );
+
+    let result = svm.send_transaction(tx);
+    assert!(
+        result.is_ok(),
+        "Sync after transfer should succeed: {:?}",
+        result.err()
+    );
+
+    let metadata = get_agent_metadata(&svm, &agent_mint);
+    let update_authority: Option<Pubkey> = metadata.update_authority.into();
+    assert_eq!(update_authority, Some(registry_config));
+
+    println!("✅ test_sync_agent_authority_after_transfer passed");
+}
+
+/// Test that the holder alone can sync once the legacy owner handed them the
+/// update authority
+#[test]
+fn test_sync_agent_authority_holder_after_handover() {
+    let mut svm = setup_litesvm();
+    let holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let registry_config = setup_registry(&mut svm, &Pubkey::new_unique()).0;
+    let (agent_mint, ata) = setup_agent_mint(&mut svm, &holder.pubkey(), &holder.pubkey(), &[]);
+
+    let ix =
+        build_sync_agent_authority_ix(&holder.pubkey(), None, &registry_config, &agent_mint, &ata);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&holder.pubkey()),
+        &[&holder],
+        svm.latest_blockhash(),
+    );
+
+    let result = svm.send_transaction(tx);
+    assert!(
+        result.is_ok(),
+        "Holder-only sync should succeed: {:?}",
+        result.err()
+    );
+
+    let metadata = get_agent_metadata(&svm, &agent_mint);
+    let update_authority: Option<Pubkey> = metadata.update_authority.into();
+    assert_eq!(update_authority, Some(registry_config));
+    assert_eq!(
+        get_pointer_authority(&svm, &agent_mint),
+        Some(registry_config)
+    );
+
+    println!("✅ test_sync_agent_authority_holder_after_handover passed");
+}
+
+/// Test that syncing an already-synced agent fails
+#[test]
+fn test_sync_agent_authority_already_synced() {
+    let mut svm = setup_litesvm();
+    let holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let registry_config = setup_registry(&mut svm, &Pubkey::new_unique()).0;
+    let (agent_mint, ata) = setup_agent_mint(&mut svm, &holder.pubkey(), &registry_config, &[]);
+
+    let ix =
+        build_sync_agent_authority_ix(&holder.pubkey(), None, &registry_config, &agent_mint, &ata);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&holder.pubkey()),
+        &[&holder],
+        svm.latest_blockhash(),
+    );
+
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Sync of synced agent should fail");
+
+    println!("✅ test_sync_agent_authority_already_synced passed");
+}
+
+/// Test that a signer who is not the current update authority cannot sync
+#[test]
+fn test_sync_agent_authority_wrong_current_authority() {
+    let mut svm = setup_litesvm();
+    let previous_owner = Keypair::new();
+    let holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let registry_config = setup_registry(&mut svm, &Pubkey::new_unique()).0;
+    let (agent_mint, ata) =
+        setup_agent_mint(&mut svm, &holder.pubkey(), &previous_owner.pubkey(), &[]);
+
+    // Holder signs alone while the previous owner still holds the authority
+    let ix =
+        build_sync_agent_authority_ix(&holder.pubkey(), None, &registry_config, &agent_mint, &ata);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&holder.pubkey()),
+        &[&holder],
+        svm.latest_blockhash(),
+    );
+
+    let result = svm.send_transaction(tx);
+    assert!(
+        result.is_err(),
+        "Sync without the current update authority should fail"
+    );
+
+    println!("✅ test_sync_agent_authority_wrong_current_authority passed");
+}
+
+/// Test that a previous owner who no longer holds the NFT cannot sync alone
+#[test]
+fn test_sync_agent_authority_non_holder() {
+    let mut svm = setup_litesvm();
+    let previous_owner = create_funded_keypair(&mut svm, 10_000_000_000);
+    let holder = Keypair::new();
+    let registry_config = setup_registry(&mut svm, &Pubkey::new_unique()).0;
+    let (agent_mint, _ata) =
+        setup_agent_mint(&mut svm, &holder.pubkey(), &previous_owner.pubkey(), &[]);
+
+    // Previous owner has an empty ATA
+    let empty_ata = derive_token22_ata(&previous_owner.pubkey(), &agent_mint);
+    create_mock_token22_ata(
+        &mut svm,
+        &empty_ata,
+        &agent_mint,
+        &previous_owner.pubkey(),
+        0,
+    );
+
+    let ix = build_sync_agent_authority_ix(
+        &previous_owner.pubkey(),
+        None,
+        &registry_config,
+        &agent_mint,
+        &empty_ata,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&previous_owner.pubkey()),
+        &[&previous_owner],
+        svm.latest_blockhash(),
+    );
+
+    let result = svm.send_transaction(tx);
+    assert!(result.is_err(), "Non-holder should not be able to sync");
+
+    println!("✅ test_sync_agent_authority_non_holder passed");
+}
+
+/// Test that sync fails when the MetadataPointer authority is held by someone
+/// other than the current update authority or the registry
+#[test]
+fn test_sync_agent_authority_split_pointer_authority() {
+    let mut svm = setup_litesvm();
+    let previous_owner = Keypair::new();
+    let holder = create_funded_keypair(&mut svm, 10_000_000_000);
+    let registry_config = setup_registry(&mut svm, &Pubkey::new_unique()).0;
+    let (agent_mint, ata) = setup_agent_mint(&mut svm, &holder.pubkey(), &holder.pubkey(), &[]);
+
+    // Legacy owner handed over the update authority but kept the pointer authority
+    set_pointer_authority(&mut svm, &agent_mint, &previous_owner.pubkey());
+
+    let ix =
+        build_sync_agent_authority_ix(&holder.pubkey(), None, &registry_config, &agent_mint, &ata);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&holder.pubkey()),
+        &[&holder],
+        svm.latest_blockhash(),
+    );
+
+    let result = svm.send_transaction(tx);
+    assert!(
+        result.is_err(),
+        "Sync with a split pointer authority should fail"
+    );
+
+    let metadata = get_agent_metadata(&svm, &agent_mint);
+    let update_authority: Option<Pubkey> = metadata.update_authority.into();
+    assert_eq!(update_authority, Some(holder.pubkey()));
+    assert_eq!(
+        get_pointer_authority(&svm, &agent_mint),
+        Some(previous_owner.pubkey())
+    );
+
+    println!("✅ test_sync_agent_authority_split_pointer_authority passed");
+}
@@ -0,0 +1,325 @@
+//! Tests for the update_agent_metadata instruction
+//!
+//! Uses a mocked Token-2022 agent mint (MetadataPointer + TokenMetadata on the mint)
+//! so the real Token-2022 program processes the metadata CPIs.
+
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+
+use sati::state::MetadataEntry;
+
+use crate::common::{
+    accounts::{
+        create_funded_keypair, create_mock_token22_ata, derive_token22_ata, get_agent_metadata,
+        setup_agent_mint, setup_registry,
+    },
+    instructions::{build_update_agent_metadata_ix, UpdateAgentMetadataParams},
+    setup::{send, setup_litesvm},
+};
+
+/// Additional metadata on the mocked agent mint
+const AGENT_SERVICES: &[(&str, &str)] = &[("mcp", "https://mcp.example.com")];
+
+fn empty_params() -> UpdateAgentMetadataParams {
+    UpdateAgentMetadataParams {
+        name: None,
+        uri: None,
+        additional_metadata: vec![],
+        remove_keys: vec![],
+    }
+}
+
+/// Test updating name, uri and additional metadata in one instruction
+#[test]
+fn test_update_agent_metadata_success() {
+    let mut svm = setup_litesvm();
+    let owner = create_funded_keypair(&mut svm, 10_000_000_000);
+    let registry_config = setup_registry(&mut svm, &Pubkey::new_unique()).0;
+    let (agent_mint, ata) =
+        setup_agent_mint(&mut svm, &owner.pubkey(), &registry_config, AGENT_SERVICES);
+
+    let params = UpdateAgentMetadataParams {
+        name: Some("Renamed Agent".to_string()),
+        uri: Some("ipfs://bafy-new-registration".to_string()),
+        additional_metadata: vec![
+            MetadataEntry {
+                key: "mcp".to_string(),
+                value: "https://mcp-v2.example.com".to_string(),
+            },
+            MetadataEntry {
+                key: "a2a".to_string(),
+                value: "https://a2a.example.com/.well-known/agent.json".to_string(),
+            },
+        ],
+        remove_keys: vec![],
+    };
+
+    let ix = build_update_agent_metadata_ix(
+        &owner.pubkey(),
+        &registry_config,
+        &agent_mint,
+        &ata,
+        params,
+    );
+    let result = send(&mut svm, &owner, ix);
+    assert!(
+        result.is_ok(),
+        "Metadata update should succeed: {:?}",
+        result.err()
+    );
+
+    let metadata = get_agent_metadata(&svm, &agent_mint);
+    assert_eq!(metadata.name, "Renamed Agent");
+    assert_eq!(metadata.uri, "ipfs://bafy-new-registration");
+    assert_eq!(
+        metadata.additional_metadata,
+        vec![
+            ("mcp".to_string(), "https://mcp-v2.example.com".to_string()),
+            (
+                "a2a".to_string(),
+                "https://a2a.example.com/.well-known/agent.json".to_string()
+            ),
+        ]
+    );
+
+    // Mint must remain rent-exempt after growing
+    let account = svm.get_account(&agent_mint).unwrap();
+    assert!(account.lamports >= svm.minimum_balance_for_rent_exemption(account.data.len()));
+
+    println!("✅ test_update_agent_metadata_success passed");
+}
+
+/// Test removing an additional metadata key
+#[test]
+fn test_update_agent_metadata_remove_key() {
+    let mut svm = setup_litesvm();
+    let owner = create_funded_keypair(&mut svm, 10_000_000_000);
+    let registry_config = setup_registry(&mut svm, &Pubkey::new_unique()).0;
+    let (agent_mint, ata) =
+        setup_agent_mint(&mut svm, &owner.pubkey(), &registry_config, AGENT_SERVICES);
+
+    let mut params = empty_params();
+    params.remove_keys = vec!["mcp".to_string()];
+
+    let ix = build_update_agent_metadata_ix(
+        &owner.pubkey(),
+        &registry_config,
+        &agent_mint,
+        &ata,
+        params,
+    );
+    let result = send(&mut svm, &owner, ix);
+    assert!(
+        result.is_ok(),
+        "Removing a key should succeed: {:?}",
+        result.err()
+    );
+
+    let metadata = get_agent_metadata(&svm, &agent_mint);
+    assert!(metadata.additional_metadata.is_empty());
+
+    println!("✅ test_update_agent_metadata_remove_key passed");
+}
+
+/// Test that removing a missing key fails
+#[test]
+fn test_update_agent_metadata_remove_missing_key() {
+    let mut svm = setup_litesvm();
+    let owner = create_funded_keypair(&mut svm, 10_000_000_000);
+    let registry_config = setup_registry(&mut svm, &Pubkey::new_unique()).0;
+    let (agent_mint, ata) =
+        setup_agent_mint(&mut svm, &owner.pubkey(), &registry_config, AGENT_SERVICES);
+
+    let mut params = empty_params();
+    params.remove_keys = vec!["did".to_string()];
+
+    let ix = build_update_agent_metadata_ix(
+        &owner.pubkey(),
+        &registry_config,
+        &agent_mint,
+        &ata,
+        params,
+    );
+    let result = send(&mut svm, &owner, ix);
+    assert!(result.is_err(), "Removing a missing key should fail");
+
+    println!("✅ test_update_agent_metadata_remove_missing_key passed");
+}
+
+/// Test that an empty update is rejected
+#[test]
+fn test_update_agent_metadata_empty_update() {
+    let mut svm = setup_litesvm();
+    let owner = create_funded_keypair(&mut svm, 10_000_000_000);
+    let registry_config = setup_registry(&mut svm, &Pubkey::new_unique()).0;
+    let (agent_mint, ata) =
+        setup_agent_mint(&mut svm, &owner.pubkey(), &registry_config, AGENT_SERVICES);
+
+    let ix = build_update_agent_metadata_ix(
+        &owner.pubkey(),
+        &registry_config,
+        &agent_mint,
+        &ata,
+        empty_params(),
+    );
+    let result = send(&mut svm, &owner, ix);
+    assert!(result.is_err(), "Empty update should fail");
+
+    println!("✅ test_update_agent_metadata_empty_update passed");
+}
+
+/// Test that a name over MAX_NAME_LENGTH is rejected
+#[test]
+fn test_update_agent_metadata_name_too_long() {
+    let mut svm = setup_litesvm();
+    let owner = create_funded_keypair(&mut svm, 10_000_000_000);
+    let registry_config = setup_registry(&mut svm, &Pubkey::new_unique()).0;
+    let (agent_mint, ata) =
+        setup_agent_mint(&mut svm, &owner.pubkey(), &registry_config, AGENT_SERVICES);
+
+    let mut params = empty_params();
+    params.name = Some("A".repeat(33));
+
+    let ix = build_update_agent_metadata_ix(
+        &owner.pubkey(),
+        &registry_config,
+        &agent_mint,
+        &ata,
+        params,
+    );
+    let result = send(&mut svm, &owner, ix);
+    assert!(result.is_err(), "Should fail with name too long");
+
+    println!("✅ test_update_agent_metadata_name_too_long passed");
+}
+
+/// Test that exceeding MAX_METADATA_ENTRIES in the resulting metadata is rejected
+#[test]
+fn test_update_agent_metadata_too_many_entries() {
+    let mut svm = setup_litesvm();
+    let owner = create_funded_keypair(&mut svm, 10_000_000_000);
+    let registry_config = setup_registry(&mut svm, &Pubkey::new_unique()).0;
+    let (agent_mint, ata) =
+        setup_agent_mint(&mut svm, &owner.pubkey(), &registry_config, AGENT_SERVICES);
+
+    // 1 existing entry + 10 new entries = 11 > MAX_METADATA_ENTRIES
+    let mut params = empty_params();
+    params.additional_metadata = (0..10)
+        .map(|i| MetadataEntry {
+            key: format!("key{}", i),
+            value: "value".to_string(),
+        })
+        .collect();
+
+    let ix = build_update_agent_metadata_ix(
+        &owner.pubkey(),
+        &registry_config,
+        &agent_mint,
+        &ata,
+        params,
+    );
+    let result = send(&mut svm, &owner, ix);
+    assert!(
+        result.is_err(),
+        "Should fail with too many metadata entries"
+    );
+
+    println!("✅ test_update_agent_metadata_too_many_entries passed");
+}
+
+/// Test that a non-holder cannot update metadata
+#[test]
+fn test_update_agent_metadata_non_holder() {
+    let mut svm = setup_litesvm();
+    let owner = create_funded_keypair(&mut svm, 10_000_000_000);
+    let attacker = create_funded_keypair(&mut svm, 10_000_000_000);
+    let registry_config = setup_registry(&mut svm, &Pubkey::new_unique()).0;
+    let (agent_mint, _ata) =
+        setup_agent_mint(&mut svm, &owner.pubkey(), &registry_config, AGENT_SERVICES);
+
+    // Attacker has an ATA for the mint but holds no token
+    let attacker_ata = derive_token22_ata(&attacker.pubkey(), &agent_mint);
+    create_mock_token22_ata(&mut svm, &attacker_ata, &agent_mint, &attacker.pubkey(), 0);
+
+    let mut params = empty_params();
+    params.name = Some("Hijacked".to_string());
+
+    let ix = build_update_agent_metadata_ix(
+        &attacker.pubkey(),
+        &registry_config,
+        &agent_mint,
+        &attacker_ata,
+        params,
+    );
+    let result = send(&mut svm, &attacker, ix);
+    assert!(result.is_err(), "Non-holder should not update metadata");
+
+    let metadata = get_agent_metadata(&svm, &agent_mint);
+    assert_eq!(metadata.name, "Agent");
+
+    println!("✅ test_update_agent_metadata_non_holder passed");
+}
+
+/// Test that a legacy agent (owner is the TokenMetadata update authority) can still update
+#[test]
+fn test_update_agent_metadata_legacy_owner_authority() {
+    let mut svm = setup_litesvm();
+    let owner = create_funded_keypair(&mut svm, 10_000_000_000);
+    let registry_config = setup_registry(&mut svm, &Pubkey::new_unique()).0;
+    let (agent_mint, ata) =
+        setup_agent_mint(&mut svm, &owner.pubkey(), &owner.pubkey(), AGENT_SERVICES);
+
+    let mut params = empty_params();
+    params.uri = Some("https://example.com/v2.json".to_string());
+
+    let ix = build_update_agent_metadata_ix(
+        &owner.pubkey(),
+        &registry_config,
+        &agent_mint,
+        &ata,
+        params,
+    );
+    let result = send(&mut svm, &owner, ix);
+    assert!(
+        result.is_ok(),
+        "Legacy owner-authority update should succeed: {:?}",
+        result.err()
+    );
+
+    let metadata = get_agent_metadata(&svm, &agent_mint);
+    assert_eq!(metadata.uri, "https://example.com/v2.json");
+
+    println!("✅ test_update_agent_metadata_legacy_owner_authority passed");
+}
+
+/// Test that a holder cannot edit when a third party is the update authority
+#[test]
+fn test_update_agent_metadata_foreign_authority() {
+    let mut svm = setup_litesvm();
+    let owner = create_funded_keypair(&mut svm, 10_000_000_000);
+    let previous_owner = Keypair::new();
+    let registry_config = setup_registry(&mut svm, &Pubkey::new_unique()).0;
+    let (agent_mint, ata) = setup_agent_mint(
+        &mut svm,
+        &owner.pubkey(),
+        &previous_owner.pubkey(),
+        AGENT_SERVICES,
+    );
+
+    let mut params = empty_params();
+    params.name = Some("New Name".to_string());
+
+    let ix = build_update_agent_metadata_ix(
+        &owner.pubkey(),
+        &registry_config,
+        &agent_mint,
+        &ata,
+        params,
+    );
+    let result = send(&mut svm, &owner, ix);
+    assert!(
+        result.is_err(),
+        "Holder should not edit metadata controlled by a previous owner"
+    );
+
+    println!("✅ test_update_agent_metadata_foreign_authority passed");
+}